@@ -1,9 +1,10 @@
 //! Traits and type for interpolating between values.
 
 use crate::util;
-use bevy_color::{Laba, LinearRgba, Oklaba, Srgba, Xyza};
+use bevy_app::App;
+use bevy_color::{Color, Laba, LinearRgba, Oklaba, Srgba, Xyza};
 use bevy_math::*;
-use bevy_reflect::Reflect;
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect};
 use bevy_transform::prelude::Transform;
 
 /// An individual input for [`Animatable::blend`].
@@ -29,6 +30,101 @@ pub trait Animatable: Reflect + Sized + Send + Sync + 'static {
     fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self;
 }
 
+/// A struct used to interpolate and blend reflected [`Animatable`] values without knowing their
+/// concrete type.
+///
+/// This is what allows [`AnimatedPathCurve`] to animate fields that are only known at runtime.
+/// The [`AnimationPlugin`] registers it for the built-in animatable types, and other types can
+/// register it with `#[reflect(Animatable)]`.
+///
+/// A [`ReflectAnimatable`] for type `T` can be obtained via
+/// [`TypeRegistration::data`](bevy_reflect::TypeRegistration::data).
+///
+/// [`AnimatedPathCurve`]: crate::animation_curves::AnimatedPathCurve
+/// [`AnimationPlugin`]: crate::AnimationPlugin
+#[derive(Clone)]
+pub struct ReflectAnimatable {
+    interpolate: fn(&dyn PartialReflect, &dyn PartialReflect, f32) -> Option<Box<dyn Reflect>>,
+    blend: fn(
+        &mut dyn Iterator<Item = BlendInput<Box<dyn PartialReflect>>>,
+    ) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectAnimatable {
+    /// Interpolates between `a` and `b` using [`Animatable::interpolate`].
+    ///
+    /// Returns `None` if either value cannot be converted to the animatable type.
+    pub fn interpolate(
+        &self,
+        a: &dyn PartialReflect,
+        b: &dyn PartialReflect,
+        time: f32,
+    ) -> Option<Box<dyn Reflect>> {
+        (self.interpolate)(a, b, time)
+    }
+
+    /// Blends one or more values together using [`Animatable::blend`].
+    ///
+    /// Returns `None` if any of the values cannot be converted to the animatable type.
+    pub fn blend(
+        &self,
+        inputs: impl IntoIterator<Item = BlendInput<Box<dyn PartialReflect>>>,
+    ) -> Option<Box<dyn Reflect>> {
+        (self.blend)(&mut inputs.into_iter())
+    }
+}
+
+impl<T: Animatable + FromReflect> FromType<T> for ReflectAnimatable {
+    fn from_type() -> Self {
+        ReflectAnimatable {
+            interpolate: |a, b, time| {
+                let (owned_a, owned_b);
+                let a = match a.try_downcast_ref::<T>() {
+                    Some(a) => a,
+                    None => {
+                        owned_a = T::from_reflect(a)?;
+                        &owned_a
+                    }
+                };
+                let b = match b.try_downcast_ref::<T>() {
+                    Some(b) => b,
+                    None => {
+                        owned_b = T::from_reflect(b)?;
+                        &owned_b
+                    }
+                };
+                Some(Box::new(T::interpolate(a, b, time)))
+            },
+            blend: |inputs| {
+                let inputs = inputs
+                    .map(|input| {
+                        Some(BlendInput {
+                            weight: input.weight,
+                            value: T::take_from_reflect(input.value).ok()?,
+                            additive: input.additive,
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Box::new(T::blend(inputs.into_iter())))
+            },
+        }
+    }
+}
+
+/// Registers [`ReflectAnimatable`] for the built-in [`Animatable`] types.
+pub(crate) fn register_reflect_animatable(app: &mut App) {
+    macro_rules! register {
+        ($($ty:ty),*) => {
+            $(
+                app.register_type::<$ty>()
+                    .register_type_data::<$ty, ReflectAnimatable>();
+            )*
+        };
+    }
+    register!(f32, f64, bool, Vec2, Vec3, Vec3A, Vec4, DVec2, DVec3, DVec4, Quat, Rot2);
+    register!(Transform, Color, LinearRgba, Laba, Oklaba, Srgba, Xyza);
+}
+
 macro_rules! impl_float_animatable {
     ($ty: ty, $base: ty) => {
         impl Animatable for $ty {
@@ -95,6 +191,25 @@ impl_color_animatable!(Oklaba);
 impl_color_animatable!(Srgba);
 impl_color_animatable!(Xyza);
 
+impl Animatable for Color {
+    /// Interpolates in linear RGB space, returning a [`Color::LinearRgba`].
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        LinearRgba::interpolate(&a.to_linear(), &b.to_linear(), t).into()
+    }
+
+    /// Blends in linear RGB space, returning a [`Color::LinearRgba`].
+    #[inline]
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        LinearRgba::blend(inputs.map(|input| BlendInput {
+            weight: input.weight,
+            value: input.value.to_linear(),
+            additive: input.additive,
+        }))
+        .into()
+    }
+}
+
 // Vec3 is special cased to use Vec3A internally for blending
 impl Animatable for Vec3 {
     #[inline]
@@ -410,4 +525,39 @@ mod tests {
             blended.as_radians().to_degrees()
         );
     }
+
+    #[test]
+    fn test_reflect_animatable() {
+        let reflect_animatable = <ReflectAnimatable as FromType<Vec3>>::from_type();
+
+        let value = reflect_animatable
+            .interpolate(&Vec3::ZERO, &Vec3::new(2.0, 4.0, 0.0), 0.5)
+            .unwrap();
+        assert_eq!(
+            value.downcast_ref::<Vec3>(),
+            Some(&Vec3::new(1.0, 2.0, 0.0))
+        );
+        assert!(reflect_animatable
+            .interpolate(&0.0f32, &1.0f32, 0.5)
+            .is_none());
+
+        let value = reflect_animatable
+            .blend([
+                BlendInput {
+                    weight: 1.0,
+                    value: Box::new(Vec3::X) as Box<dyn PartialReflect>,
+                    additive: false,
+                },
+                BlendInput {
+                    weight: 0.5,
+                    value: Box::new(Vec3::Y),
+                    additive: true,
+                },
+            ])
+            .unwrap();
+        assert_eq!(
+            value.downcast_ref::<Vec3>(),
+            Some(&Vec3::new(1.0, 0.5, 0.0))
+        );
+    }
 }
@@ -73,6 +73,13 @@
 //! Animation of arbitrary aspects of entities can be accomplished using [`AnimatableProperty`] in
 //! conjunction with [`AnimatableCurve`]. See the documentation [there] for details.
 //!
+//! ## Reflected paths
+//!
+//! When the animated field is only known at runtime, for example because the animation was loaded
+//! from a file, an [`AnimatedPath`] can be parsed from a string such as
+//! `"MyComponent.color.alpha"`. Combined with reflected keyframe values in an [`AnimatedPathCurve`],
+//! the field is animated using the [`ReflectAnimatable`] type data registered for the field type.
+//!
 //! ## Custom [`AnimationCurve`] and [`AnimationCurveEvaluator`]
 //!
 //! This is the lowest-level option with the most control, but it is also the most complicated.
@@ -82,10 +89,13 @@
 //! [`AnimationClip`]: crate::AnimationClip
 //! [there]: AnimatableProperty
 //! [`animated_field`]: crate::animated_field
+//! [`ReflectAnimatable`]: crate::animatable::ReflectAnimatable
 
+use alloc::sync::Arc;
 use core::{
     any::{Any, TypeId},
     fmt::{self, Debug, Formatter},
+    hash::BuildHasher,
     marker::PhantomData,
 };
 
@@ -93,17 +103,24 @@ use core::{
 pub use crate::morph::*;
 use crate::{
     graph::AnimationNodeIndex,
-    prelude::{Animatable, BlendInput},
+    prelude::{Animatable, BlendInput, ReflectAnimatable},
     AnimationEntityMut, AnimationEvaluationError,
 };
-use bevy_ecs::component::{Component, Mutable};
+use bevy_ecs::{
+    component::{Component, Mutable},
+    reflect::ReflectComponent,
+};
 use bevy_math::curve::{
-    cores::{UnevenCore, UnevenCoreError},
+    cores::{InterpolationDatum, UnevenCore, UnevenCoreError},
     Curve, Interval,
 };
-use bevy_platform::hash::Hashed;
-use bevy_reflect::{FromReflect, Reflect, Reflectable, TypeInfo, Typed};
+use bevy_platform::hash::{FixedHasher, Hashed};
+use bevy_reflect::{
+    FromReflect, ParsedPath, Reflect, ReflectPath, Reflectable, TypeInfo, TypeRegistration,
+    TypeRegistry, Typed,
+};
 use downcast_rs::{impl_downcast, Downcast};
+use thiserror::Error;
 
 /// A trait for exposing a value in an entity so that it can be animated.
 ///
@@ -761,6 +778,414 @@ where
     }
 }
 
+/// A component field identified by a reflection path that is resolved at runtime.
+///
+/// Unlike [`AnimatedField`], this doesn't need a compile-time accessor for the field, which makes
+/// it suitable for data-driven animation, such as animations loaded from files. It's animated by
+/// an [`AnimatedPathCurve`].
+///
+/// The path starts with the name of the component, which may be either its full or its short type
+/// path, followed by a [reflection path] to the animated field:
+///
+/// ```
+/// # use bevy_animation::animation_curves::AnimatedPath;
+/// # use bevy_reflect::TypeRegistry;
+/// # use bevy_transform::components::Transform;
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register::<Transform>();
+/// let path = AnimatedPath::parse("Transform.translation.y", &type_registry)
+///     .expect("Failed to resolve path");
+/// ```
+///
+/// [reflection path]: bevy_reflect::GetPath
+#[derive(Clone)]
+pub struct AnimatedPath {
+    component: TypeId,
+    component_type_path: &'static str,
+    reflect_component: ReflectComponent,
+    path: ParsedPath,
+    /// A pre-hashed (component-type-id, path-hash) pair, uniquely identifying the animated field.
+    ///
+    /// Dynamic components all share the same [`TypeId`], so the path hash includes the type path
+    /// of the component.
+    evaluator_id: Hashed<(TypeId, usize)>,
+}
+
+impl AnimatedPath {
+    /// Parses a path such as `"MyComponent.color.alpha"`, looking up the component in the given
+    /// type registry.
+    pub fn parse(path: &str, type_registry: &TypeRegistry) -> Result<Self, AnimatedPathError> {
+        let (type_name, field_path) = path.split_at(type_name_len(path));
+        let registration = type_registry
+            .get_with_type_path(type_name)
+            .or_else(|| type_registry.get_with_short_type_path(type_name))
+            .ok_or_else(|| AnimatedPathError::UnknownType(type_name.into()))?;
+        let field_path = if field_path.is_empty() {
+            ParsedPath(Vec::new())
+        } else {
+            ParsedPath::parse(field_path).map_err(|err| AnimatedPathError::InvalidPath {
+                path: path.into(),
+                message: err.to_string(),
+            })?
+        };
+        Self::new(registration, field_path)
+    }
+
+    /// Creates an [`AnimatedPath`] for the field at `path` within the component described by
+    /// `registration`.
+    pub fn new(
+        registration: &TypeRegistration,
+        path: ParsedPath,
+    ) -> Result<Self, AnimatedPathError> {
+        let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+            AnimatedPathError::NotAComponent(registration.type_info().type_path().into())
+        })?;
+        let component = registration.type_id();
+        let component_type_path = registration.type_info().type_path();
+        let path_hash = FixedHasher.hash_one((component_type_path, &path)) as usize;
+        Ok(Self {
+            component,
+            component_type_path,
+            reflect_component: reflect_component.clone(),
+            evaluator_id: Hashed::new((component, path_hash)),
+            path,
+        })
+    }
+
+    /// The [`TypeId`] of the animated component.
+    ///
+    /// This is the same for all [dynamic](ReflectComponent::dynamic) components: use
+    /// [`AnimatedPath::component_type_path`] to tell them apart.
+    pub fn component_type_id(&self) -> TypeId {
+        self.component
+    }
+
+    /// The type path of the animated component.
+    pub fn component_type_path(&self) -> &'static str {
+        self.component_type_path
+    }
+
+    /// The reflection path from the component to the animated field.
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+}
+
+/// Returns the length of the type name at the start of an [`AnimatedPath`], which ends at the
+/// first `.`, `[` or `#` that is not within generic arguments, as in `Foo<[f32; 3]>.0`.
+fn type_name_len(path: &str) -> usize {
+    let mut depth = 0usize;
+    for (index, c) in path.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            '.' | '[' | '#' if depth == 0 => return index,
+            _ => {}
+        }
+    }
+    path.len()
+}
+
+/// An error that occurs when creating an [`AnimatedPath`] or an [`AnimatedPathCurve`].
+#[derive(Debug, Error)]
+pub enum AnimatedPathError {
+    /// The field path could not be parsed.
+    #[error("invalid path `{path}`: {message}")]
+    InvalidPath {
+        /// The path that failed to parse.
+        path: String,
+        /// A description of the parse error.
+        message: String,
+    },
+    /// No type with the given name is registered.
+    #[error("no type named `{0}` is registered")]
+    UnknownType(String),
+    /// The type does not have [`ReflectComponent`] registered.
+    #[error("type `{0}` is not a reflected component")]
+    NotAComponent(String),
+    /// The keyframe values do not have [`ReflectAnimatable`] registered.
+    #[error("type `{0}` does not have `ReflectAnimatable` registered")]
+    NotAnimatable(String),
+    /// The keyframe values are not all of the same type.
+    #[error("keyframe values must all be of the same type")]
+    MixedKeyframeTypes,
+    /// There were not enough keyframes.
+    #[error(transparent)]
+    Keyframes(#[from] UnevenCoreError),
+}
+
+/// An [`AnimationCurve`] which animates the field at an [`AnimatedPath`] using keyframes of
+/// reflected values.
+///
+/// The keyframes are interpolated and blended using the [`ReflectAnimatable`] type data
+/// registered for their type, so they don't need to be known at compile time. The sampled value
+/// is applied to the field with [`PartialReflect::try_apply`], which means that the keyframes must
+/// be of the field type, or of a type that can be applied to it.
+///
+/// ```
+/// # use bevy_animation::{animatable::ReflectAnimatable, animation_curves::*};
+/// # use bevy_math::Vec3;
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_transform::components::Transform;
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register::<Transform>();
+/// type_registry.register_type_data::<Vec3, ReflectAnimatable>();
+/// let path = AnimatedPath::parse("Transform.scale", &type_registry).unwrap();
+/// let curve = AnimatedPathCurve::new(
+///     path,
+///     [
+///         (0.0, Box::new(Vec3::ONE) as Box<dyn Reflect>),
+///         (1.0, Box::new(Vec3::splat(2.0))),
+///     ],
+///     &type_registry,
+/// )
+/// .expect("Failed to create scale curve");
+/// ```
+///
+/// [`PartialReflect::try_apply`]: bevy_reflect::PartialReflect::try_apply
+#[derive(Clone)]
+pub struct AnimatedPathCurve {
+    path: AnimatedPath,
+    animatable: ReflectAnimatable,
+    keyframes: Arc<UnevenCore<Box<dyn Reflect>>>,
+}
+
+impl AnimatedPathCurve {
+    /// Creates an [`AnimatedPathCurve`] which animates `path` using the given keyframes. The
+    /// [`ReflectAnimatable`] for the keyframe type is looked up in `type_registry`.
+    ///
+    /// There must be at least two keyframes, all of the same type.
+    pub fn new(
+        path: AnimatedPath,
+        keyframes: impl IntoIterator<Item = (f32, Box<dyn Reflect>)>,
+        type_registry: &TypeRegistry,
+    ) -> Result<Self, AnimatedPathError> {
+        let keyframes = UnevenCore::new(keyframes)?;
+        let value_type = keyframes.samples[0].as_ref().as_any().type_id();
+        if keyframes
+            .samples
+            .iter()
+            .any(|value| value.as_ref().as_any().type_id() != value_type)
+        {
+            return Err(AnimatedPathError::MixedKeyframeTypes);
+        }
+        let animatable = type_registry
+            .get_type_data::<ReflectAnimatable>(value_type)
+            .ok_or_else(|| {
+                AnimatedPathError::NotAnimatable(keyframes.samples[0].reflect_type_path().into())
+            })?
+            .clone();
+        Ok(Self {
+            path,
+            animatable,
+            keyframes: Arc::new(keyframes),
+        })
+    }
+
+    /// The field animated by this curve.
+    pub fn path(&self) -> &AnimatedPath {
+        &self.path
+    }
+
+    fn sample(&self, t: f32) -> Option<Box<dyn Reflect>> {
+        match self.keyframes.sample_interp(t) {
+            // Interpolating a keyframe with itself produces an owned copy of it.
+            InterpolationDatum::Exact(value)
+            | InterpolationDatum::LeftTail(value)
+            | InterpolationDatum::RightTail(value) => self.animatable.interpolate(
+                value.as_partial_reflect(),
+                value.as_partial_reflect(),
+                0.0,
+            ),
+            InterpolationDatum::Between(a, b, t) => {
+                self.animatable
+                    .interpolate(a.as_partial_reflect(), b.as_partial_reflect(), t)
+            }
+        }
+    }
+}
+
+impl Debug for AnimatedPathCurve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimatedPathCurve")
+            .field("path", &self.path.path)
+            .field("times", &self.keyframes.times)
+            .finish()
+    }
+}
+
+impl AnimationCurve for AnimatedPathCurve {
+    fn clone_value(&self) -> Box<dyn AnimationCurve> {
+        Box::new(self.clone())
+    }
+
+    fn domain(&self) -> Interval {
+        self.keyframes.domain()
+    }
+
+    fn evaluator_id(&self) -> EvaluatorId<'_> {
+        EvaluatorId::ComponentField(&self.path.evaluator_id)
+    }
+
+    fn create_evaluator(&self) -> Box<dyn AnimationCurveEvaluator> {
+        Box::new(AnimatedPathCurveEvaluator {
+            path: self.path.clone(),
+            animatable: self.animatable.clone(),
+            stack: Vec::new(),
+            blend_register: None,
+        })
+    }
+
+    fn apply(
+        &self,
+        curve_evaluator: &mut dyn AnimationCurveEvaluator,
+        t: f32,
+        weight: f32,
+        graph_node: AnimationNodeIndex,
+    ) -> Result<(), AnimationEvaluationError> {
+        let curve_evaluator = curve_evaluator
+            .downcast_mut::<AnimatedPathCurveEvaluator>()
+            .ok_or_else(inconsistent::<AnimatedPathCurveEvaluator>)?;
+        let value = self
+            .sample(t)
+            .ok_or_else(inconsistent::<AnimatedPathCurveEvaluator>)?;
+        curve_evaluator
+            .stack
+            .push(AnimatedPathCurveEvaluatorStackElement {
+                value,
+                weight,
+                graph_node,
+            });
+        Ok(())
+    }
+
+    fn sample_clamped(&self, t: f32) -> Box<dyn Any> {
+        self.sample(t)
+            .expect("keyframes should all be of the animatable type")
+            .into_any()
+    }
+}
+
+/// An [`AnimationCurveEvaluator`] for [`AnimatedPathCurve`]s. This behaves like
+/// [`AnimatableCurveEvaluator`], but operates on reflected values.
+struct AnimatedPathCurveEvaluator {
+    path: AnimatedPath,
+    animatable: ReflectAnimatable,
+    stack: Vec<AnimatedPathCurveEvaluatorStackElement>,
+    blend_register: Option<(Box<dyn Reflect>, f32)>,
+}
+
+struct AnimatedPathCurveEvaluatorStackElement {
+    value: Box<dyn Reflect>,
+    weight: f32,
+    graph_node: AnimationNodeIndex,
+}
+
+impl AnimatedPathCurveEvaluator {
+    fn combine(
+        &mut self,
+        graph_node: AnimationNodeIndex,
+        additive: bool,
+    ) -> Result<(), AnimationEvaluationError> {
+        let Some(top) = self.stack.last() else {
+            return Ok(());
+        };
+        if top.graph_node != graph_node {
+            return Ok(());
+        }
+
+        let AnimatedPathCurveEvaluatorStackElement {
+            value: value_to_blend,
+            weight: weight_to_blend,
+            graph_node: _,
+        } = self.stack.pop().unwrap();
+
+        let blend_register = match self.blend_register.take() {
+            None if additive => {
+                let scaled_value = self.animatable.blend([BlendInput {
+                    weight: weight_to_blend,
+                    value: value_to_blend.into_partial_reflect(),
+                    additive: true,
+                }]);
+                (scaled_value, weight_to_blend)
+            }
+            None => (Some(value_to_blend), weight_to_blend),
+            Some((current_value, mut current_weight)) => {
+                current_weight += weight_to_blend;
+                let value = if additive {
+                    self.animatable.blend([
+                        BlendInput {
+                            weight: 1.0,
+                            value: current_value.into_partial_reflect(),
+                            additive: true,
+                        },
+                        BlendInput {
+                            weight: weight_to_blend,
+                            value: value_to_blend.into_partial_reflect(),
+                            additive: true,
+                        },
+                    ])
+                } else {
+                    self.animatable.interpolate(
+                        current_value.as_partial_reflect(),
+                        value_to_blend.as_partial_reflect(),
+                        weight_to_blend / current_weight,
+                    )
+                };
+                (value, current_weight)
+            }
+        };
+        let (Some(value), weight) = blend_register else {
+            return Err(inconsistent::<AnimatedPathCurveEvaluator>());
+        };
+        self.blend_register = Some((value, weight));
+        Ok(())
+    }
+}
+
+impl AnimationCurveEvaluator for AnimatedPathCurveEvaluator {
+    fn blend(&mut self, graph_node: AnimationNodeIndex) -> Result<(), AnimationEvaluationError> {
+        self.combine(graph_node, /*additive=*/ false)
+    }
+
+    fn add(&mut self, graph_node: AnimationNodeIndex) -> Result<(), AnimationEvaluationError> {
+        self.combine(graph_node, /*additive=*/ true)
+    }
+
+    fn push_blend_register(
+        &mut self,
+        weight: f32,
+        graph_node: AnimationNodeIndex,
+    ) -> Result<(), AnimationEvaluationError> {
+        if let Some((value, _)) = self.blend_register.take() {
+            self.stack.push(AnimatedPathCurveEvaluatorStackElement {
+                value,
+                weight,
+                graph_node,
+            });
+        }
+        Ok(())
+    }
+
+    fn commit(&mut self, mut entity: AnimationEntityMut) -> Result<(), AnimationEvaluationError> {
+        let value = self
+            .stack
+            .pop()
+            .ok_or_else(inconsistent::<AnimatedPathCurveEvaluator>)?
+            .value;
+        self.stack.clear();
+        let mut component = self.path.reflect_component.reflect_mut(&mut entity).ok_or(
+            AnimationEvaluationError::ComponentNotPresent(self.path.component),
+        )?;
+        let value_type = value.as_ref().as_any().type_id();
+        (&self.path.path)
+            .reflect_element_mut(component.as_partial_reflect_mut())
+            .map_err(|_| AnimationEvaluationError::PropertyNotPresent(value_type))?
+            .try_apply(value.as_partial_reflect())
+            .map_err(|_| AnimationEvaluationError::PropertyNotPresent(value_type))
+    }
+}
+
 fn inconsistent<P>() -> AnimationEvaluationError
 where
     P: 'static + ?Sized,
@@ -803,6 +1228,10 @@ macro_rules! animated_field {
 mod tests {
     use super::*;
     use crate::VariableCurve;
+    use bevy_ecs::{
+        system::{Query, RunSystemOnce},
+        world::World,
+    };
     use bevy_math::Vec3;
     use bevy_transform::components::Transform;
 
@@ -842,4 +1271,88 @@ mod tests {
             .unwrap();
         assert_eq!(*value, Vec3::new(1., 0., 0.));
     }
+
+    #[test]
+    fn test_animated_paths_of_dynamic_components() {
+        use bevy_reflect::{
+            structs::StructInfo, NamedField, Type, TypeInfo, TypePathTable, TypeRegistration,
+        };
+
+        let mut type_registry = TypeRegistry::default();
+        for type_path in ["my_game::Health", "my_game::Mana"] {
+            let fields = [NamedField::new::<f32>("value")];
+            let ty = Type::runtime(TypePathTable::from_path(type_path));
+            let type_info: &'static TypeInfo = Box::leak(Box::new(TypeInfo::Struct(
+                StructInfo::from_type(ty, &fields),
+            )));
+            let mut registration = TypeRegistration::from_type_info(type_info);
+            registration.insert(ReflectComponent::dynamic(type_info));
+            type_registry.add_registration(registration);
+        }
+
+        let health = AnimatedPath::parse("my_game::Health.value", &type_registry).unwrap();
+        let mana = AnimatedPath::parse("my_game::Mana.value", &type_registry).unwrap();
+        assert_eq!(health.component_type_id(), mana.component_type_id());
+        assert_eq!(health.component_type_path(), "my_game::Health");
+        assert!(health.evaluator_id != mana.evaluator_id);
+    }
+
+    #[test]
+    fn test_animated_path_curve() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Transform>();
+        type_registry.register_type_data::<Vec3, ReflectAnimatable>();
+
+        assert!(matches!(
+            AnimatedPath::parse("Missing.translation", &type_registry),
+            Err(AnimatedPathError::UnknownType(_))
+        ));
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Wrapper<T: Reflect + bevy_reflect::TypePath>(T);
+        type_registry.register::<Wrapper<[f32; 3]>>();
+        assert!(AnimatedPath::parse("Wrapper<[f32; 3]>.0[1]", &type_registry).is_ok());
+        assert!(AnimatedPath::parse("Wrapper<[f32; 3]>", &type_registry).is_ok());
+        assert!(matches!(
+            AnimatedPathCurve::new(
+                AnimatedPath::parse("Transform.translation.x", &type_registry).unwrap(),
+                [
+                    (0.0, Box::new(0.0f32) as Box<dyn Reflect>),
+                    (1.0, Box::new(1.0f32)),
+                ],
+                &type_registry,
+            ),
+            Err(AnimatedPathError::NotAnimatable(_))
+        ));
+
+        let path = AnimatedPath::parse("Transform.translation", &type_registry).unwrap();
+        let curve = AnimatedPathCurve::new(
+            path,
+            [
+                (0.0, Box::new(Vec3::ZERO) as Box<dyn Reflect>),
+                (1.0, Box::new(Vec3::new(2.0, 0.0, 0.0))),
+            ],
+            &type_registry,
+        )
+        .unwrap();
+        let value = curve.sample_clamped(0.5).downcast::<Vec3>().unwrap();
+        assert_eq!(*value, Vec3::new(1.0, 0.0, 0.0));
+
+        let graph_node = AnimationNodeIndex::new(0);
+        let mut evaluator = curve.create_evaluator();
+        curve.apply(&mut *evaluator, 0.25, 1.0, graph_node).unwrap();
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        world
+            .run_system_once(move |mut query: Query<AnimationEntityMut>| {
+                evaluator.commit(query.get_mut(entity).unwrap()).unwrap();
+            })
+            .unwrap();
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(0.5, 0.0, 0.0)
+        );
+    }
 }
@@ -1305,6 +1305,7 @@ impl Plugin for AnimationPlugin {
                     .in_set(AnimationSystems)
                     .before(TransformSystems::Propagate),
             );
+        animatable::register_reflect_animatable(app);
     }
 }
 
@@ -382,6 +382,26 @@ impl App {
         self.main_mut().register_system(system)
     }
 
+    /// Registers a system under the given name, so that it can be run through a
+    /// [`SystemRef`](bevy_ecs::reflect::SystemRef).
+    ///
+    /// Unlike a [`SystemId`], a `SystemRef` can be reflected and serialized, which makes it
+    /// suitable for callbacks stored in components that are authored in scenes.
+    ///
+    /// See [`World::register_named_system`] for more details.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_named_system<I, O, M>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.main_mut().register_named_system(name, system)
+    }
+
     /// Configures a collection of system sets in the provided schedule, adding any sets that do not exist.
     #[track_caller]
     pub fn configure_sets<M>(
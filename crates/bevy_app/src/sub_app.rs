@@ -247,6 +247,20 @@ impl SubApp {
         self.world.register_system(system)
     }
 
+    /// See [`App::register_named_system`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_named_system<I, O, M>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        self.world.register_named_system(name, system)
+    }
+
     /// See [`App::configure_sets`].
     #[track_caller]
     pub fn configure_sets<M>(
@@ -179,6 +179,7 @@ mod handle;
 mod id;
 mod loader;
 mod loader_builders;
+mod locale;
 mod path;
 mod reflect;
 mod render_asset;
@@ -195,6 +196,7 @@ pub use handle::*;
 pub use id::*;
 pub use loader::*;
 pub use loader_builders::NestedLoadBuilder;
+pub use locale::*;
 pub use path::*;
 pub use reflect::*;
 pub use render_asset::*;
@@ -211,8 +213,11 @@ use alloc::{
     sync::Arc,
     vec::Vec,
 };
-use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
-use bevy_ecs::{prelude::Component, schedule::common_conditions::resource_exists};
+use bevy_app::{App, Plugin, PostUpdate, PreStartup, PreUpdate};
+use bevy_ecs::{
+    prelude::Component,
+    schedule::common_conditions::{resource_changed, resource_exists},
+};
 use bevy_ecs::{
     reflect::AppTypeRegistry,
     schedule::{IntoScheduleConfigs, SystemSet},
@@ -414,7 +419,16 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
+            .init_resource::<AssetLocale>()
             .add_message::<UntypedAssetLoadFailedEvent>()
+            .add_systems(
+                PreStartup,
+                sync_asset_locale.run_if(resource_changed::<AssetLocale>),
+            )
+            .add_systems(
+                PreUpdate,
+                sync_asset_locale.run_if(resource_changed::<AssetLocale>),
+            )
             .configure_sets(
                 PreUpdate,
                 AssetTrackingSystems.after(handle_internal_asset_events),
@@ -556,6 +570,10 @@ pub trait AssetApp {
     fn register_asset_loader<L: AssetLoader>(&mut self, loader: L) -> &mut Self;
     /// Registers the given `processor` in the [`App`]'s [`AssetProcessor`].
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self;
+    /// Registers the given [`AssetValidator`](processor::AssetValidator) in the [`App`]'s
+    /// [`AssetProcessor`], if the asset processor is enabled.
+    fn register_asset_validator<V: processor::AssetValidator>(&mut self, validator: V)
+        -> &mut Self;
     /// Registers the given [`AssetSourceBuilder`] with the given `id`.
     ///
     /// Note that asset sources must be registered before adding [`AssetPlugin`] to your application,
@@ -604,6 +622,16 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_validator<V: processor::AssetValidator>(
+        &mut self,
+        validator: V,
+    ) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.register_validator(validator);
+        }
+        self
+    }
+
     fn register_asset_source(
         &mut self,
         id: impl Into<AssetSourceId<'static>>,
@@ -720,9 +748,10 @@ mod tests {
             AssetWatcher, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, InvalidGenerationError, LoadState, LoadedAsset,
-        UnapprovedPathMode, UntypedHandle, VisitAssetDependencies, WriteDefaultMetaError,
+        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetLocale,
+        AssetPath, AssetPlugin, AssetServer, Assets, InvalidGenerationError, LoadState,
+        LoadedAsset, UnapprovedPathMode, UntypedHandle, VisitAssetDependencies,
+        WriteDefaultMetaError,
     };
     use alloc::{
         boxed::Box,
@@ -1556,6 +1585,173 @@ mod tests {
         assert_eq!(get_started_load_count(app.world()), 3);
     }
 
+    #[test]
+    fn dependency_graph() {
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: ["a_sub"]
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [
+        "c.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: []
+)"#;
+        let c_path = "c.cool.ron";
+
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+        dir.insert_asset_text(Path::new(c_path), SIMPLE_TEXT);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(&a).then_some(())
+        });
+
+        let a_id = a.id().untyped();
+        let b_id = asset_server.get_path_ids(b_path)[0];
+        let c_id = asset_server.get_path_ids(c_path)[0];
+        let sub_id = asset_server.get_path_ids("a.cool.ron#a_sub")[0];
+
+        let graph = asset_server.dependency_graph();
+        assert_eq!(graph.len(), 4);
+        let a_node = graph.get(a_id).unwrap();
+        // `CoolText` holds a handle to its sub-texts, so they are dependencies as well.
+        assert_eq!(a_node.dependencies, HashSet::from_iter([b_id, sub_id]));
+        assert_eq!(a_node.labeled_assets, HashSet::from_iter([sub_id]));
+        assert_eq!(
+            graph.get(sub_id).unwrap().source_assets,
+            HashSet::from_iter([a_id])
+        );
+        assert_eq!(
+            graph.get(c_id).unwrap().dependents,
+            HashSet::from_iter([b_id])
+        );
+        assert_eq!(
+            graph.recursive_dependencies(a_id),
+            HashSet::from_iter([b_id, c_id, sub_id])
+        );
+        assert_eq!(
+            graph.recursive_dependents(c_id),
+            HashSet::from_iter([a_id, b_id])
+        );
+        assert!(graph.pending_dependencies(a_id).is_empty());
+
+        let b_graph = asset_server.get_dependency_graph(b_id).unwrap();
+        assert_eq!(b_graph.len(), 3);
+        assert!(!b_graph.contains(sub_id));
+        let a_node = b_graph.get(a_id).unwrap();
+        assert_eq!(a_node.dependencies, HashSet::from_iter([b_id]));
+        assert!(a_node.labeled_assets.is_empty());
+
+        let dot = b_graph.to_dot();
+        assert!(dot.starts_with("digraph assets {\n"));
+        assert!(dot.contains("n0 [label=\"a.cool.ron\", color=green];"));
+        assert!(dot.contains("n0 -> n1;"));
+        assert!(dot.contains("n1 -> n2;"));
+
+        let json = b_graph.to_json();
+        assert!(json.starts_with("{\"nodes\":[{\"id\":0,"));
+        assert!(json.contains(
+            "\"path\":\"b.cool.ron\",\"load_state\":\"Loaded\",\"dependency_load_state\":\"Loaded\",\"recursive_dependency_load_state\":\"Loaded\",\"dependencies\":[2],\"dependents\":[0]"
+        ));
+    }
+
+    #[test]
+    fn localized_assets() {
+        fn cool_text(text: &str) -> String {
+            format!(
+                "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+            )
+        }
+
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(Path::new("title.cool.ron"), &cool_text("hello"));
+        dir.insert_asset_text(Path::new("title.fr.cool.ron"), &cool_text("bonjour"));
+        dir.insert_asset_text(Path::new("title.de.cool.ron"), &cool_text("hallo"));
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .insert_resource(AssetLocale::new("fr-CA"));
+        // Apply the locale before loading anything.
+        app.update();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load("title.cool.ron");
+        let id = handle.id();
+        let wait_for_text = |app: &mut App, expected: &str| {
+            run_app_until(app, |world| {
+                get::<CoolText>(world, id)
+                    .is_some_and(|asset| asset.text == expected)
+                    .then_some(())
+            });
+        };
+        // `fr-CA` has no variant, so the `fr` variant is used.
+        wait_for_text(&mut app, "bonjour");
+        assert_eq!(asset_server.get_path(id).unwrap(), "title.cool.ron".into());
+
+        // Switching the locale reloads the asset from the new variant.
+        *app.world_mut().resource_mut::<AssetLocale>() = AssetLocale::new("de");
+        wait_for_text(&mut app, "hallo");
+
+        // Without a matching variant, the fallback locale and then the unlocalized asset are used.
+        *app.world_mut().resource_mut::<AssetLocale>() = AssetLocale::new("it").with_fallback("fr");
+        wait_for_text(&mut app, "bonjour");
+        *app.world_mut().resource_mut::<AssetLocale>() = AssetLocale::new("it");
+        wait_for_text(&mut app, "hello");
+    }
+
+    #[test]
+    fn localized_paths_are_removed_when_assets_are_dropped() {
+        let (mut app, dir) = create_app();
+        dir.insert_asset_text(
+            Path::new("title.cool.ron"),
+            "(text: \"hello\", dependencies: [], embedded_dependencies: [], sub_texts: [])",
+        );
+        dir.insert_asset_text(
+            Path::new("title.fr.cool.ron"),
+            "(text: \"bonjour\", dependencies: [], embedded_dependencies: [], sub_texts: [])",
+        );
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .insert_resource(AssetLocale::new("fr"));
+        app.update();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load("title.cool.ron");
+        let id = handle.id();
+        run_app_until(&mut app, |world| get::<CoolText>(world, id).map(|_| ()));
+        assert_eq!(
+            asset_server
+                .read_infos()
+                .localized_paths
+                .get(&AssetPath::from("title.cool.ron")),
+            Some(&PathBuf::from("title.fr.cool.ron"))
+        );
+
+        drop(handle);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, id).is_none().then_some(())
+        });
+        assert!(asset_server.read_infos().localized_paths.is_empty());
+    }
+
     const SIMPLE_TEXT: &str = r#"
 (
     text: "dep",
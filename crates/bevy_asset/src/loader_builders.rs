@@ -276,6 +276,7 @@ impl<'ctx, 'builder> NestedLoadBuilder<'ctx, 'builder> {
             .write_infos()
             .stats
             .started_load_tasks += 1;
+        let read_path;
         let (mut meta, loader, mut reader) = if let Some(reader) = reader {
             let loader = if let Some(type_id) = type_id {
                 self.load_context
@@ -299,10 +300,15 @@ impl<'ctx, 'builder> NestedLoadBuilder<'ctx, 'builder> {
             let meta = loader.default_meta();
             (meta, loader, ReaderRef::Borrowed(reader))
         } else {
+            read_path = self
+                .load_context
+                .asset_server
+                .resolve_localized_path(path)
+                .await;
             let (meta, loader, reader) = self
                 .load_context
                 .asset_server
-                .get_meta_loader_and_reader(path, type_id)
+                .get_meta_loader_and_reader(path, &read_path, type_id)
                 .await
                 .map_err(|error| LoadDirectError::LoadError {
                     dependency: path.clone(),
@@ -0,0 +1,150 @@
+use crate::AssetServer;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// The locale used by the [`AssetServer`] to pick localized variants of assets.
+///
+/// When a locale is set, loading `ui/title.png` with a locale of `fr-CA` will read the first
+/// file that exists out of:
+///
+/// 1. `ui/title.fr-CA.png`
+/// 2. `ui/title.fr.png`
+/// 3. `ui/title.png`
+///
+/// The locale tag is inserted before the full extension of the file, so `level.scene.ron` is
+/// localized as `level.fr.scene.ron`. The handle (and path) of the asset is always the
+/// unlocalized path: only the bytes that are read change.
+///
+/// Changing this resource switches the locale of the [`AssetServer`] and reloads every loaded
+/// asset that resolves to a different file with the new locale. See [`AssetServer::set_locale`].
+///
+/// Note that while a locale is set, each asset load first checks for the existence of its
+/// localized variants, which costs one read attempt per locale in the
+/// [fallback chain](AssetLocale::fallback_chain).
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetLocale {
+    locale: Option<String>,
+    fallbacks: Vec<String>,
+}
+
+impl AssetLocale {
+    /// Creates a new [`AssetLocale`] for the given language tag, such as `fr-CA`.
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: Some(locale.into()),
+            fallbacks: Vec::new(),
+        }
+    }
+
+    /// Adds a locale to try once all the variants of the primary locale have been tried, but
+    /// before falling back to the unlocalized asset. For example, a game could fall back to `en`
+    /// when no variant exists for `fr-CA` or `fr`.
+    pub fn with_fallback(mut self, locale: impl Into<String>) -> Self {
+        self.fallbacks.push(locale.into());
+        self
+    }
+
+    /// Returns the primary locale, if one is set.
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Returns the locales that are tried, in order, when resolving an asset path.
+    ///
+    /// Each locale is followed by its less specific parents: `zh-Hant-TW` produces `zh-Hant-TW`,
+    /// `zh-Hant` and `zh`. Duplicate locales are only tried once.
+    pub fn fallback_chain(&self) -> Vec<&str> {
+        let mut chain: Vec<&str> = Vec::new();
+        for locale in self.locale.iter().chain(self.fallbacks.iter()) {
+            let mut locale = locale.as_str();
+            while !locale.is_empty() {
+                if !chain.contains(&locale) {
+                    chain.push(locale);
+                }
+                locale = match locale.rfind('-') {
+                    Some(index) => &locale[..index],
+                    None => "",
+                };
+            }
+        }
+        chain
+    }
+
+    /// Returns the path of the variant of `path` for the given `locale`, or [`None`] if `path`
+    /// does not have a file name.
+    ///
+    /// ```
+    /// # use bevy_asset::AssetLocale;
+    /// # use std::path::Path;
+    /// assert_eq!(
+    ///     AssetLocale::localized_path(Path::new("ui/title.png"), "fr").unwrap(),
+    ///     Path::new("ui/title.fr.png"),
+    /// );
+    /// ```
+    pub fn localized_path(path: &Path, locale: &str) -> Option<PathBuf> {
+        let file_name = path.file_name()?.to_str()?;
+        let localized = match file_name.split_once('.') {
+            Some((stem, extension)) => [stem, ".", locale, ".", extension].concat(),
+            None => [file_name, ".", locale].concat(),
+        };
+        Some(path.with_file_name(localized))
+    }
+
+    /// Returns the paths of all the localized variants of `path`, in the order they are tried.
+    pub(crate) fn candidates(&self, path: &Path) -> Vec<PathBuf> {
+        self.fallback_chain()
+            .into_iter()
+            .filter_map(|locale| Self::localized_path(path, locale))
+            .collect()
+    }
+}
+
+impl From<&str> for AssetLocale {
+    fn from(locale: &str) -> Self {
+        Self::new(locale.to_string())
+    }
+}
+
+/// Applies changes to the [`AssetLocale`] resource to the [`AssetServer`].
+pub fn sync_asset_locale(locale: Res<AssetLocale>, asset_server: Res<AssetServer>) {
+    asset_server.set_locale(locale.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AssetLocale;
+    use alloc::vec;
+    use std::path::Path;
+
+    #[test]
+    fn fallback_chain() {
+        let locale = AssetLocale::new("zh-Hant-TW")
+            .with_fallback("zh-Hans")
+            .with_fallback("en");
+        assert_eq!(
+            locale.fallback_chain(),
+            vec!["zh-Hant-TW", "zh-Hant", "zh", "zh-Hans", "en"]
+        );
+        assert!(AssetLocale::default().fallback_chain().is_empty());
+    }
+
+    #[test]
+    fn localized_path() {
+        assert_eq!(
+            AssetLocale::localized_path(Path::new("ui/title.png"), "fr-CA").unwrap(),
+            Path::new("ui/title.fr-CA.png")
+        );
+        assert_eq!(
+            AssetLocale::localized_path(Path::new("levels/one.scene.ron"), "fr").unwrap(),
+            Path::new("levels/one.fr.scene.ron")
+        );
+        assert_eq!(
+            AssetLocale::localized_path(Path::new("LICENSE"), "fr").unwrap(),
+            Path::new("LICENSE.fr")
+        );
+    }
+}
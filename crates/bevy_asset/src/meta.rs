@@ -276,6 +276,31 @@ pub(crate) async fn get_asset_hash(
     Ok(*hasher.finalize().as_bytes())
 }
 
+/// Combines `asset_hash` with the [`AssetValidator`](crate::processor::AssetValidator)s the
+/// asset is processed with, so that changing them reprocesses (and revalidates) the asset. The
+/// hash is unchanged if there are no validators.
+///
+/// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
+pub(crate) fn get_validated_asset_hash<'a>(
+    asset_hash: AssetHash,
+    validator_type_paths: impl IntoIterator<Item = &'a str>,
+    fail_on_validation_errors: bool,
+) -> AssetHash {
+    let mut validator_type_paths: Vec<_> = validator_type_paths.into_iter().collect();
+    if validator_type_paths.is_empty() {
+        return asset_hash;
+    }
+    validator_type_paths.sort_unstable();
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&asset_hash);
+    for type_path in validator_type_paths {
+        hasher.update(&(type_path.len() as u64).to_le_bytes());
+        hasher.update(type_path.as_bytes());
+    }
+    hasher.update(&[fail_on_validation_errors as u8]);
+    *hasher.finalize().as_bytes()
+}
+
 /// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
 pub(crate) fn get_full_asset_hash(
     asset_hash: AssetHash,
@@ -0,0 +1,208 @@
+use crate::{
+    meta::{AssetHash, META_FORMAT_VERSION},
+    processor::ValidationIssue,
+};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use bevy_ecs::error::BevyError;
+use bevy_tasks::BoxedFuture;
+use core::fmt::Write;
+use futures_lite::AsyncWriteExt;
+use std::path::{Path, PathBuf};
+
+/// Identifies the output of processing an asset, independent of the machine it was processed on.
+///
+/// The key is derived from the [`AssetHash`] of the source asset (which covers both the asset
+/// bytes and its .meta file, and therefore the processor settings), the type path of the
+/// processor and its [`Process::VERSION`](crate::processor::Process::VERSION), and the type paths
+/// of the registered [`AssetValidator`](crate::processor::AssetValidator)s, whose issues are
+/// stored with the processed asset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ProcessedAssetCacheKey(pub AssetHash);
+
+impl ProcessedAssetCacheKey {
+    /// Computes the cache key for the asset with the given `source_hash`, when processed by the
+    /// processor with the given `processor_type_path` and `processor_version` and validated by
+    /// the validators with the given `validator_type_paths` (in any order).
+    pub fn new<'a>(
+        source_hash: AssetHash,
+        processor_type_path: &str,
+        processor_version: u32,
+        validator_type_paths: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(META_FORMAT_VERSION.as_bytes());
+        hasher.update(&source_hash);
+        hasher.update(processor_type_path.as_bytes());
+        hasher.update(&processor_version.to_le_bytes());
+        let mut validator_type_paths: Vec<_> = validator_type_paths.into_iter().collect();
+        validator_type_paths.sort_unstable();
+        for type_path in validator_type_paths {
+            // Prefix each path with its length so that different lists can't hash the same.
+            hasher.update(&(type_path.len() as u64).to_le_bytes());
+            hasher.update(type_path.as_bytes());
+        }
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Returns the key as a lowercase hexadecimal string, suitable for use as a file name.
+    pub fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(self.0.len() * 2);
+        for byte in self.0 {
+            let _ = write!(hex, "{byte:02x}");
+        }
+        hex
+    }
+}
+
+/// The processed bytes of an asset and of its processed .meta file, as stored in a
+/// [`ProcessedAssetCache`].
+#[derive(Clone, Debug)]
+pub struct CachedProcessedAsset {
+    /// The bytes of the processed asset.
+    pub asset_bytes: Vec<u8>,
+    /// The bytes of the processed asset's .meta file. This includes the
+    /// [`ProcessedInfo`](crate::meta::ProcessedInfo) of the asset, which is used to validate its
+    /// process dependencies before the entry is reused.
+    pub meta_bytes: Vec<u8>,
+    /// The issues reported by [`AssetValidator`](crate::processor::AssetValidator)s while
+    /// processing the asset. They are added to the
+    /// [`AssetProcessor::validation_report`](crate::processor::AssetProcessor::validation_report)
+    /// when the entry is reused.
+    pub validation_issues: Vec<ValidationIssue>,
+}
+
+/// A content-addressed store of processed assets that can be shared between machines.
+///
+/// Before processing an asset, the [`AssetProcessor`](crate::processor::AssetProcessor) looks up
+/// its [`ProcessedAssetCacheKey`] in the cache. If an entry exists and the process dependencies it
+/// was produced with are unchanged, the entry is written to the processed asset source instead of
+/// running the processor. Newly processed assets are stored in the cache.
+///
+/// Errors returned by the cache are logged and otherwise ignored: the processor falls back to
+/// processing the asset itself.
+///
+/// The cache can be configured with [`AssetProcessorData::set_cache`]. [`FileProcessedAssetCache`]
+/// stores entries in a local (or network-mounted) directory.
+///
+/// [`AssetProcessorData::set_cache`]: crate::processor::AssetProcessorData::set_cache
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the entry stored for `key`, or [`None`] if there is none.
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>>;
+
+    /// Stores `asset` under `key`, replacing any previous entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), BevyError>>;
+}
+
+/// A [`ProcessedAssetCache`] that stores its entries as files in a directory.
+///
+/// Each entry is stored as three files named after the hex-encoded [`ProcessedAssetCacheKey`]:
+/// one for the asset bytes, one (with a `.meta` extension) for the meta bytes and one (with an
+/// `.issues` extension) for the validation issues, serialized as RON. Files are first
+/// written to a temporary file and then renamed, so the directory can safely be shared by several
+/// processors at once (for example, on a network drive populated by CI).
+pub struct FileProcessedAssetCache {
+    /// The directory the cache entries are stored in.
+    pub root: PathBuf,
+}
+
+impl FileProcessedAssetCache {
+    /// Creates a cache that stores its entries in `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn entry_paths(&self, key: &ProcessedAssetCacheKey) -> EntryPaths {
+        let hex = key.to_hex();
+        // Shard the entries by the first byte of the key to keep directories reasonably small.
+        let folder = self.root.join(&hex[..2]);
+        EntryPaths {
+            meta: folder.join(format!("{hex}.meta")),
+            issues: folder.join(format!("{hex}.issues")),
+            asset: folder.join(hex),
+        }
+    }
+}
+
+/// The paths of the files storing a [`FileProcessedAssetCache`] entry.
+struct EntryPaths {
+    asset: PathBuf,
+    meta: PathBuf,
+    issues: PathBuf,
+}
+
+impl ProcessedAssetCache for FileProcessedAssetCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>> {
+        Box::pin(async move {
+            let paths = self.entry_paths(key);
+            // The meta file is written last, so an entry is only complete once it exists.
+            let Some(meta_bytes) = read_if_exists(&paths.meta).await? else {
+                return Ok(None);
+            };
+            let Some(asset_bytes) = read_if_exists(&paths.asset).await? else {
+                return Ok(None);
+            };
+            let Some(issues_bytes) = read_if_exists(&paths.issues).await? else {
+                return Ok(None);
+            };
+            Ok(Some(CachedProcessedAsset {
+                asset_bytes,
+                meta_bytes,
+                validation_issues: ron::de::from_bytes(&issues_bytes)?,
+            }))
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), BevyError>> {
+        Box::pin(async move {
+            let paths = self.entry_paths(key);
+            if let Some(folder) = paths.asset.parent() {
+                async_fs::create_dir_all(folder).await?;
+            }
+            let issues = ron::ser::to_string(&asset.validation_issues)?;
+            write_atomically(paths.asset, &asset.asset_bytes).await?;
+            write_atomically(paths.issues, issues.as_bytes()).await?;
+            write_atomically(paths.meta, &asset.meta_bytes).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Reads the file at `path`, returning [`None`] if it doesn't exist.
+async fn read_if_exists(path: &Path) -> Result<Option<Vec<u8>>, BevyError> {
+    match async_fs::read(path).await {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(err) if err.kind() == futures_io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Writes `bytes` to a uniquely named temporary file next to `path`, then renames it to `path`.
+async fn write_atomically(path: PathBuf, bytes: &[u8]) -> Result<(), BevyError> {
+    let mut temp_path = path.clone().into_os_string();
+    temp_path.push(format!(".{}.tmp", uuid::Uuid::new_v4()));
+    let temp_path = PathBuf::from(temp_path);
+
+    let mut file = async_fs::File::create(&temp_path).await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await?;
+    drop(file);
+    if let Err(err) = async_fs::rename(&temp_path, &path).await {
+        let _ = async_fs::remove_file(&temp_path).await;
+        return Err(err.into());
+    }
+    Ok(())
+}
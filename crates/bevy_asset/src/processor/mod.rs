@@ -22,6 +22,7 @@
 //!
 //! To register a new asset processor, use [`AssetProcessor::register_processor`].
 //! To set the default asset processor for a given extension, use [`AssetProcessor::set_default_processor`].
+//! To check assets for problems while they are processed, register an [`AssetValidator`] with [`AssetProcessor::register_validator`].
 //! In most cases, these methods will be called directly on [`App`](bevy_app::App) using the [`AssetApp`](crate::AssetApp) extension trait.
 //!
 //! If a default asset processor is set, assets with a matching extension will be processed using that processor before loading.
@@ -37,34 +38,48 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
+mod validate;
 
 use async_lock::RwLockReadGuardArc;
+pub use cache::*;
 pub use log::*;
 pub use process::*;
+pub use validate::*;
 
 use crate::{
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
-        AssetSources, AssetWriterError, ErasedAssetReader, MissingAssetSourceError,
+        AssetSources, AssetWriterError, ErasedAssetReader, ErasedAssetWriter,
+        MissingAssetSourceError,
     },
     meta::{
-        get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
-        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
+        get_asset_hash, get_full_asset_hash, get_validated_asset_hash, AssetAction,
+        AssetActionMinimal, AssetHash, AssetMeta, AssetMetaDyn, AssetMetaMinimal, ProcessedInfo,
+        ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
     MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
 };
-use alloc::{borrow::ToOwned, boxed::Box, string::String, sync::Arc, vec, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use bevy_ecs::prelude::*;
 use bevy_platform::{
     collections::{hash_map::Entry, HashMap, HashSet},
     sync::{PoisonError, RwLock},
 };
 use bevy_tasks::IoTaskPool;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_io::ErrorKind;
-use futures_lite::{AsyncWriteExt, StreamExt};
+use futures_lite::StreamExt;
 use futures_util::{select_biased, FutureExt};
 use std::{
     path::{Path, PathBuf},
@@ -74,10 +89,7 @@ use thiserror::Error;
 use tracing::{debug, error, trace, warn};
 
 #[cfg(feature = "trace")]
-use {
-    alloc::string::ToString,
-    tracing::{info_span, instrument::Instrument},
-};
+use tracing::{info_span, instrument::Instrument};
 
 /// A "background" asset processor that reads asset values from a source [`AssetSource`] (which corresponds to an [`AssetReader`](crate::io::AssetReader) / [`AssetWriter`](crate::io::AssetWriter) pair),
 /// processes them in some way, and writes them to a destination [`AssetSource`].
@@ -111,8 +123,16 @@ pub struct AssetProcessorData {
     /// avoids needing to use [`block_on`](bevy_tasks::block_on) to set the factory).
     log_factory: Mutex<Option<Box<dyn ProcessorTransactionLogFactory>>>,
     log: async_lock::RwLock<Option<Box<dyn ProcessorTransactionLog>>>,
+    /// The cache that processed assets are fetched from and stored in, if any.
+    cache: RwLock<Option<Arc<dyn ProcessedAssetCache>>>,
     /// The processors that will be used to process assets.
     processors: RwLock<Processors>,
+    /// The validators that are run on assets loaded while processing.
+    validators: RwLock<Vec<Arc<dyn ErasedAssetValidator>>>,
+    /// The issues reported by validators, by the path of the processed asset.
+    validation_issues: RwLock<HashMap<AssetPath<'static>, Vec<ValidationIssue>>>,
+    /// Whether validation errors fail the processing of the asset.
+    fail_on_validation_errors: AtomicBool,
     sources: Arc<AssetSources>,
 }
 
@@ -788,6 +808,90 @@ impl AssetProcessor {
             .insert(extension.into(), P::type_path());
     }
 
+    /// Registers the given [`AssetValidator`], which will check every asset of its
+    /// [`AssetValidator::Asset`] type loaded while processing.
+    ///
+    /// The registered validators are part of the hash of each asset, so assets that are up to
+    /// date are reprocessed (and validated) when a validator is added.
+    pub fn register_validator<V: AssetValidator>(&self, validator: V) {
+        self.data
+            .validators
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(validator));
+    }
+
+    /// Returns the registered [`AssetValidator`]s.
+    pub(crate) fn validators(&self) -> Vec<Arc<dyn ErasedAssetValidator>> {
+        self.data
+            .validators
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns the issues reported by [`AssetValidator`]s for the assets processed so far, ordered
+    /// by asset path.
+    ///
+    /// Only assets that were processed by this [`AssetProcessor`] are included: assets that were
+    /// already up to date are not validated. Assets restored from the [`ProcessedAssetCache`]
+    /// report the issues stored with them. The issues of an asset are replaced each time it is
+    /// reprocessed.
+    pub fn validation_report(&self) -> ValidationReport {
+        let validation_issues = self
+            .data
+            .validation_issues
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut paths: Vec<_> = validation_issues.keys().collect();
+        paths.sort_by_cached_key(ToString::to_string);
+        ValidationReport {
+            issues: paths
+                .into_iter()
+                .flat_map(|path| validation_issues[path].iter().cloned())
+                .collect(),
+        }
+    }
+
+    /// Logs the validation `issues` of the asset at `asset_path` and stores them in the
+    /// [`AssetProcessor::validation_report`].
+    ///
+    /// Returns the errors that should fail the processing of the asset, which is empty unless
+    /// [`AssetProcessorData::set_fail_on_validation_errors`] is enabled.
+    fn record_validation_issues(
+        &self,
+        asset_path: &AssetPath<'static>,
+        issues: Vec<ValidationIssue>,
+    ) -> Vec<String> {
+        for issue in &issues {
+            match issue.severity {
+                ValidationSeverity::Warning => warn!("Asset validation {issue}"),
+                ValidationSeverity::Error => error!("Asset validation {issue}"),
+            }
+        }
+        let errors: Vec<String> = if self.data.fail_on_validation_errors() {
+            issues
+                .iter()
+                .filter(|issue| issue.severity == ValidationSeverity::Error)
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut validation_issues = self
+            .data
+            .validation_issues
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if issues.is_empty() {
+            validation_issues.remove(asset_path);
+        } else {
+            validation_issues.insert(asset_path.clone(), issues);
+        }
+        errors
+    }
+
     /// Returns the default processor for the given `extension`, if it exists.
     pub fn get_default_processor(&self, extension: &str) -> Option<Arc<dyn ErasedProcessor>> {
         let processors = self
@@ -1109,9 +1213,18 @@ impl AssetProcessor {
             // as soon as the hash is computed.
             let mut reader_for_hash = reader.read(path).await.map_err(reader_err)?;
 
-            get_asset_hash(&meta_bytes, &mut reader_for_hash)
+            let hash = get_asset_hash(&meta_bytes, &mut reader_for_hash)
                 .await
-                .map_err(reader_err)?
+                .map_err(reader_err)?;
+            // Validators only run while the asset is processed, so they must be part of the hash
+            // for assets to be revalidated when they change.
+            get_validated_asset_hash(
+                hash,
+                self.validators()
+                    .iter()
+                    .map(|validator| validator.type_path()),
+                self.data.fail_on_validation_errors(),
+            )
         };
         let mut new_processed_info = ProcessedInfo {
             hash: new_hash,
@@ -1166,6 +1279,25 @@ impl AssetProcessor {
             // `AssetAction::Process` (which includes its settings).
             let settings = source_meta.process_settings().unwrap();
 
+            let cache = self.data.cache().map(|cache| {
+                let validators = self.validators();
+                let key = ProcessedAssetCacheKey::new(
+                    new_hash,
+                    processor.type_path(),
+                    processor.version(),
+                    validators.iter().map(|validator| validator.type_path()),
+                );
+                (cache, key)
+            });
+            if let Some((cache, key)) = &cache
+                && let Some(processed_info) = self
+                    .restore_from_cache(&**cache, key, asset_path, processed_writer)
+                    .await?
+            {
+                self.log_end_processing(asset_path).await;
+                return Ok(ProcessResult::Processed(processed_info));
+            }
+
             // Create a reader just for the actual process. Note: this means that we're performing
             // two reads for the same file (but we avoid having to load the whole file into memory).
             // For some sources (like local file systems), this is not a big deal, but for other
@@ -1177,7 +1309,10 @@ impl AssetProcessor {
             // reads or not.
             let reader_for_process = reader.read(path).await.map_err(reader_err)?;
 
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+            // Process into memory, so that nothing is written to the processed asset source if the
+            // asset fails validation.
+            let mut asset_bytes = Vec::new();
+            let validation_issues;
             let mut processed_meta = {
                 let mut context = ProcessContext::new(
                     self,
@@ -1185,7 +1320,7 @@ impl AssetProcessor {
                     reader_for_process,
                     &mut new_processed_info,
                 );
-                let process = processor.process(&mut context, settings, &mut *writer);
+                let process = processor.process(&mut context, settings, &mut asset_bytes);
                 #[cfg(feature = "trace")]
                 let process = {
                     let span = info_span!(
@@ -1195,16 +1330,23 @@ impl AssetProcessor {
                     );
                     process.instrument(span)
                 };
-                process.await?
+                let result = process.await;
+                validation_issues = core::mem::take(&mut context.validation_issues);
+                let errors = self.record_validation_issues(asset_path, validation_issues.clone());
+                let processed_meta = result?;
+                if !errors.is_empty() {
+                    return Err(ProcessError::ValidationFailed {
+                        path: asset_path.clone(),
+                        errors,
+                    });
+                }
+                processed_meta
             };
 
-            writer
-                .flush()
+            processed_writer
+                .write_bytes(path, &asset_bytes)
                 .await
-                .map_err(|e| ProcessError::AssetWriterError {
-                    path: asset_path.clone(),
-                    err: AssetWriterError::Io(e),
-                })?;
+                .map_err(writer_err)?;
 
             let full_hash = get_full_asset_hash(
                 new_hash,
@@ -1221,6 +1363,17 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+
+            if let Some((cache, key)) = &cache {
+                let cached = CachedProcessedAsset {
+                    asset_bytes,
+                    meta_bytes,
+                    validation_issues,
+                };
+                if let Err(err) = cache.put(key, &cached).await {
+                    warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+                }
+            }
         } else {
             // See the reasoning for processing why it's ok to do a second read here.
             let mut reader_for_copy = reader.read(path).await.map_err(reader_err)?;
@@ -1243,6 +1396,74 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Writes the processed asset stored in `cache` under `key` to the processed asset source, if
+    /// there is one and the process dependencies it was produced with have not changed. The
+    /// validation issues stored with it are recorded as if the asset had been processed.
+    ///
+    /// Returns the [`ProcessedInfo`] of the restored asset, or [`None`] if the asset must be
+    /// processed.
+    async fn restore_from_cache(
+        &self,
+        cache: &dyn ProcessedAssetCache,
+        key: &ProcessedAssetCacheKey,
+        asset_path: &AssetPath<'static>,
+        processed_writer: &dyn ErasedAssetWriter,
+    ) -> Result<Option<ProcessedInfo>, ProcessError> {
+        let cached = match cache.get(key).await {
+            Ok(Some(cached)) => cached,
+            Ok(None) => return Ok(None),
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                return Ok(None);
+            }
+        };
+        let Ok(ProcessedInfoMinimal {
+            processed_info: Some(processed_info),
+        }) = ron::de::from_bytes(&cached.meta_bytes)
+        else {
+            warn!("The processed asset cache entry for {asset_path} has invalid meta. Processing the asset instead.");
+            return Ok(None);
+        };
+
+        for dependency in &processed_info.process_dependencies {
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.processing_state.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                return Ok(None);
+            }
+        }
+
+        let errors = self.record_validation_issues(asset_path, cached.validation_issues);
+        if !errors.is_empty() {
+            return Err(ProcessError::ValidationFailed {
+                path: asset_path.clone(),
+                errors,
+            });
+        }
+
+        let path = asset_path.path();
+        let writer_err = |err| ProcessError::AssetWriterError {
+            path: asset_path.clone(),
+            err,
+        };
+        processed_writer
+            .write_bytes(path, &cached.asset_bytes)
+            .await
+            .map_err(writer_err)?;
+        processed_writer
+            .write_meta_bytes(path, &cached.meta_bytes)
+            .await
+            .map_err(writer_err)?;
+        debug!("Restored {} from the processed asset cache", asset_path);
+        Ok(Some(processed_info))
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         let log_factory = self
             .data
@@ -1346,7 +1567,11 @@ impl AssetProcessorData {
             sources,
             log_factory: Mutex::new(Some(Box::new(FileTransactionLogFactory::default()))),
             log: Default::default(),
+            cache: Default::default(),
             processors: Default::default(),
+            validators: Default::default(),
+            validation_issues: Default::default(),
+            fail_on_validation_errors: AtomicBool::new(false),
         }
     }
 
@@ -1372,6 +1597,41 @@ impl AssetProcessorData {
         Ok(())
     }
 
+    /// Sets the [`ProcessedAssetCache`] used to reuse processed assets across runs and machines.
+    ///
+    /// Assets that are processed after this call will be looked up in (and stored in) the cache.
+    /// By default, no cache is used.
+    pub fn set_cache(&self, cache: impl ProcessedAssetCache) {
+        *self.cache.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(cache));
+    }
+
+    /// Sets whether [`ValidationSeverity::Error`]s reported by [`AssetValidator`]s fail the
+    /// processing of the asset (with [`ProcessError::ValidationFailed`]). Assets that fail
+    /// validation are not written to the processed asset source.
+    ///
+    /// If any validators are registered, changing this setting reprocesses all assets.
+    ///
+    /// This is disabled by default: issues are only logged and collected into the
+    /// [`AssetProcessor::validation_report`].
+    pub fn set_fail_on_validation_errors(&self, fail: bool) {
+        self.fail_on_validation_errors
+            .store(fail, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if [`ValidationSeverity::Error`]s fail the processing of assets. See
+    /// [`AssetProcessorData::set_fail_on_validation_errors`].
+    pub fn fail_on_validation_errors(&self) -> bool {
+        self.fail_on_validation_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the [`ProcessedAssetCache`] used by the processor, if any.
+    pub fn cache(&self) -> Option<Arc<dyn ProcessedAssetCache>> {
+        self.cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
     /// Returns a future that will not finish until the path has been processed.
     pub async fn wait_until_processed(&self, path: AssetPath<'static>) -> ProcessStatus {
         self.processing_state.wait_until_processed(path).await
@@ -4,7 +4,7 @@ use crate::{
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, Reader, Writer,
     },
     meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
-    processor::AssetProcessor,
+    processor::{validate_loaded_asset, AssetProcessor, ValidationIssue},
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
     AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
@@ -33,6 +33,11 @@ pub trait Process: TypePath + Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor's output. Increment this whenever a change to
+    /// [`Process::process`] would produce different output for the same input, so that results
+    /// stored in a [`ProcessedAssetCache`](crate::processor::ProcessedAssetCache) by an
+    /// older version are not reused.
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -167,6 +172,12 @@ pub enum ProcessError {
     AssetTransformError(Box<dyn core::error::Error + Send + Sync + 'static>),
     #[error("Assets without extensions are not supported.")]
     ExtensionRequired,
+    #[error("The asset '{path}' failed validation: {}", errors.join("; "))]
+    #[from(ignore)]
+    ValidationFailed {
+        path: AssetPath<'static>,
+        errors: Vec<String>,
+    },
 }
 
 impl<Loader, Transformer, Saver> Process for LoadTransformAndSave<Loader, Transformer, Saver>
@@ -232,6 +243,8 @@ pub trait ErasedProcessor: Send + Sync {
     fn type_path(&self) -> &'static str;
     /// Returns the short type path of this processor.
     fn short_type_path(&self) -> &'static str;
+    /// Returns the [`Process::VERSION`] of the original [`Process`].
+    fn version(&self) -> u32;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self, processor_path_kind: MetaTypePathKind) -> Box<dyn AssetMetaDyn>;
 }
@@ -276,6 +289,10 @@ impl<P: Process> ErasedProcessor for P {
         P::short_type_path()
     }
 
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
+
     fn default_meta(&self, processor_path_kind: MetaTypePathKind) -> Box<dyn AssetMetaDyn> {
         let type_path = match processor_path_kind {
             MetaTypePathKind::Short => P::short_type_path(),
@@ -312,6 +329,8 @@ pub struct ProcessContext<'a> {
     processor: &'a AssetProcessor,
     path: &'a AssetPath<'static>,
     reader: Box<dyn Reader + 'a>,
+    /// The issues reported by [`AssetValidator`](crate::processor::AssetValidator)s for the assets loaded by this context.
+    pub(crate) validation_issues: Vec<ValidationIssue>,
 }
 
 impl<'a> ProcessContext<'a> {
@@ -326,6 +345,7 @@ impl<'a> ProcessContext<'a> {
             path,
             reader,
             new_processed_info,
+            validation_issues: Vec::new(),
         }
     }
 
@@ -333,6 +353,9 @@ impl<'a> ProcessContext<'a> {
     /// This will take the "load dependencies" (asset values used when loading with `L`]) and
     /// register them as "process dependencies" because they are asset values required to process the
     /// current asset.
+    ///
+    /// The loaded asset (and its labeled sub-assets) are checked by the registered
+    /// [`AssetValidator`](crate::processor::AssetValidator)s.
     pub async fn load_source_asset<L: AssetLoader>(
         &mut self,
         settings: &L::Settings,
@@ -358,6 +381,14 @@ impl<'a> ProcessContext<'a> {
                     path: path.to_owned(),
                 });
         }
+        let validators = self.processor.validators();
+        if !validators.is_empty() {
+            self.validation_issues.extend(validate_loaded_asset(
+                &validators,
+                self.path,
+                &loaded_asset,
+            ));
+        }
         Ok(loaded_asset)
     }
 
@@ -29,8 +29,10 @@ use crate::{
         AssetSourceId, AssetWatcher, PathStream, Reader,
     },
     processor::{
-        AssetProcessor, GetProcessorError, LoadTransformAndSave, LogEntry, Process, ProcessContext,
-        ProcessError, ProcessorState, ProcessorTransactionLog, ProcessorTransactionLogFactory,
+        AssetProcessor, AssetValidator, CachedProcessedAsset, GetProcessorError,
+        LoadTransformAndSave, LogEntry, Process, ProcessContext, ProcessError, ProcessStatus,
+        ProcessedAssetCache, ProcessedAssetCacheKey, ProcessorState, ProcessorTransactionLog,
+        ProcessorTransactionLogFactory, ValidationContext, ValidationSeverity,
     },
     saver::{tests::CoolTextSaver, AssetSaver},
     tests::{
@@ -1827,3 +1829,365 @@ fn write_default_meta_does_not_overwrite() {
         META_TEXT
     );
 }
+
+#[test]
+fn processed_asset_cache_is_shared_between_processors() {
+    /// An in-memory cache that can be shared between apps.
+    #[derive(Clone, Default)]
+    struct MemoryCache(Arc<Mutex<HashMap<ProcessedAssetCacheKey, CachedProcessedAsset>>>);
+
+    impl ProcessedAssetCache for MemoryCache {
+        fn get<'a>(
+            &'a self,
+            key: &'a ProcessedAssetCacheKey,
+        ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, BevyError>> {
+            Box::pin(async move {
+                Ok(self
+                    .0
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get(key)
+                    .cloned())
+            })
+        }
+
+        fn put<'a>(
+            &'a self,
+            key: &'a ProcessedAssetCacheKey,
+            asset: &'a CachedProcessedAsset,
+        ) -> BoxedFuture<'a, Result<(), BevyError>> {
+            Box::pin(async move {
+                self.0
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(*key, asset.clone());
+                Ok(())
+            })
+        }
+    }
+
+    #[derive(TypePath, Clone)]
+    struct CountedAddText(Arc<Mutex<u32>>);
+
+    impl MutateAsset<CoolText> for CountedAddText {
+        fn mutate(&self, asset: &mut CoolText) {
+            *self.0.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+            asset.text.push_str(" processed");
+            if !asset.embedded.is_empty() {
+                asset.text.push(' ');
+                asset.text.push_str(&asset.embedded);
+                asset.embedded.clear();
+            }
+        }
+    }
+
+    #[derive(TypePath)]
+    struct NoDepText;
+
+    impl AssetValidator for NoDepText {
+        type Asset = CoolText;
+
+        fn validate(&self, asset: &CoolText, context: &mut ValidationContext) {
+            if asset.text == "dep" {
+                context.warn("text is dep");
+            }
+        }
+    }
+
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<CountedAddText, CoolText>,
+        CoolTextSaver,
+    >;
+
+    let dep_path = Path::new("dep.cool.ron");
+    let root_path = Path::new("root.cool.ron");
+    let root_ron = ron::ser::to_string_pretty(
+        &CoolTextRon {
+            text: "root".into(),
+            dependencies: vec![],
+            embedded_dependencies: vec![dep_path.to_string_lossy().into_owned()],
+            sub_texts: vec![],
+        },
+        PrettyConfig::new().new_line("\n"),
+    )
+    .unwrap();
+
+    let cache = MemoryCache::default();
+    let count = Arc::new(Mutex::new(0));
+
+    // Process the same sources with two independent apps (as if on two machines) sharing a cache.
+    let process = |dep_text: &str| {
+        let AppWithProcessor {
+            mut app,
+            source_gate,
+            default_source_dirs:
+                ProcessingDirs {
+                    source: source_dir,
+                    processed: processed_dir,
+                    ..
+                },
+            ..
+        } = create_app_with_asset_processor(&[]);
+        app.world()
+            .resource::<AssetProcessor>()
+            .data()
+            .set_cache(cache.clone());
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_processor(CoolTextProcessor::new(
+                RootAssetTransformer::new(CountedAddText(count.clone())),
+                CoolTextSaver,
+            ))
+            .set_default_asset_processor::<CoolTextProcessor>("cool.ron")
+            .register_asset_validator(NoDepText);
+
+        let guard = source_gate.write_blocking();
+        source_dir.insert_asset_text(dep_path, &serialize_as_cool_text(dep_text));
+        source_dir.insert_asset_text(root_path, &root_ron);
+        run_app_until_finished_processing(&mut app, guard);
+
+        let report = app.world().resource::<AssetProcessor>().validation_report();
+        (
+            read_asset_as_string(&processed_dir, dep_path),
+            read_asset_as_string(&processed_dir, root_path),
+            read_meta_as_string(&processed_dir, root_path),
+            report.issues().to_vec(),
+        )
+    };
+
+    let first = process("dep");
+    assert_eq!(first.0, serialize_as_cool_text("dep processed"));
+    assert_eq!(
+        first.1,
+        serialize_as_cool_text("root processed dep processed")
+    );
+    assert_eq!(*count.lock().unwrap_or_else(PoisonError::into_inner), 2);
+    assert_eq!(
+        cache.0.lock().unwrap_or_else(PoisonError::into_inner).len(),
+        2
+    );
+    assert_eq!(first.3.len(), 1);
+    assert_eq!(first.3[0].path, AssetPath::from(dep_path));
+
+    // Everything is restored from the cache, including the processed meta and the validation
+    // issues.
+    let second = process("dep");
+    assert_eq!(second, first);
+    assert_eq!(*count.lock().unwrap_or_else(PoisonError::into_inner), 2);
+
+    // Changing the dependency invalidates both the dependency and the asset that embeds it, even
+    // though the source of the latter is unchanged.
+    let third = process("changed");
+    assert_eq!(
+        third.1,
+        serialize_as_cool_text("root processed changed processed")
+    );
+    assert_eq!(*count.lock().unwrap_or_else(PoisonError::into_inner), 4);
+    assert!(third.3.is_empty());
+}
+
+#[test]
+fn validators_report_issues_and_can_fail_processing() {
+    #[derive(TypePath)]
+    struct NonEmptyText;
+
+    impl AssetValidator for NonEmptyText {
+        type Asset = CoolText;
+
+        fn validate(&self, asset: &CoolText, context: &mut ValidationContext) {
+            if asset.text.is_empty() {
+                context.error("text is empty");
+            } else if asset.text.contains("todo") {
+                context.warn("text is not finished");
+            }
+        }
+    }
+
+    #[derive(TypePath)]
+    struct UnfinishedSubText;
+
+    impl AssetValidator for UnfinishedSubText {
+        type Asset = SubText;
+
+        fn validate(&self, asset: &SubText, context: &mut ValidationContext) {
+            if asset.text.contains("todo") {
+                context.warn("sub text is not finished");
+            }
+        }
+    }
+
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<AddText, CoolText>,
+        CoolTextSaver,
+    >;
+
+    let good_path = Path::new("good.cool.ron");
+    let good_ron = ron::ser::to_string_pretty(
+        &CoolTextRon {
+            text: "todo: good".into(),
+            dependencies: vec![],
+            embedded_dependencies: vec![],
+            sub_texts: vec!["todo_sub".into()],
+        },
+        PrettyConfig::new().new_line("\n"),
+    )
+    .unwrap();
+    let bad_path = Path::new("bad.cool.ron");
+
+    let process = |fail_on_validation_errors: bool| {
+        let AppWithProcessor {
+            mut app,
+            source_gate,
+            default_source_dirs:
+                ProcessingDirs {
+                    source: source_dir,
+                    processed: processed_dir,
+                    ..
+                },
+            ..
+        } = create_app_with_asset_processor(&[]);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_processor(CoolTextProcessor::new(
+                RootAssetTransformer::new(AddText("".into())),
+                CoolTextSaver,
+            ))
+            .set_default_asset_processor::<CoolTextProcessor>("cool.ron")
+            .register_asset_validator(NonEmptyText)
+            .register_asset_validator(UnfinishedSubText);
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        processor
+            .data()
+            .set_fail_on_validation_errors(fail_on_validation_errors);
+
+        let guard = source_gate.write_blocking();
+        source_dir.insert_asset_text(good_path, &good_ron);
+        source_dir.insert_asset_text(bad_path, &serialize_as_cool_text(""));
+        run_app_until_finished_processing(&mut app, guard);
+
+        let statuses = [good_path, bad_path]
+            .map(|path| bevy_tasks::block_on(processor.data().wait_until_processed(path.into())));
+        (processor.validation_report(), statuses, processed_dir)
+    };
+
+    let (report, statuses, processed_dir) = process(false);
+    assert!(processed_dir.get_asset(bad_path).is_some());
+    assert_eq!(
+        statuses,
+        [ProcessStatus::Processed, ProcessStatus::Processed]
+    );
+    let issues: Vec<_> = report
+        .issues()
+        .iter()
+        .map(|issue| {
+            (
+                issue.path.to_string(),
+                issue.severity,
+                issue.validator.as_ref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        issues,
+        [
+            (
+                "bad.cool.ron".to_string(),
+                ValidationSeverity::Error,
+                "NonEmptyText"
+            ),
+            (
+                "good.cool.ron".to_string(),
+                ValidationSeverity::Warning,
+                "NonEmptyText"
+            ),
+            (
+                "good.cool.ron#todo_sub".to_string(),
+                ValidationSeverity::Warning,
+                "UnfinishedSubText"
+            ),
+        ]
+    );
+    assert!(report.has_errors());
+    assert_eq!(
+        report.to_json(),
+        r#"{"issues":[{"path":"bad.cool.ron","validator":"NonEmptyText","severity":"error","message":"text is empty"},{"path":"good.cool.ron","validator":"NonEmptyText","severity":"warning","message":"text is not finished"},{"path":"good.cool.ron#todo_sub","validator":"UnfinishedSubText","severity":"warning","message":"sub text is not finished"}]}"#
+    );
+
+    let (report, statuses, processed_dir) = process(true);
+    assert_eq!(statuses, [ProcessStatus::Processed, ProcessStatus::Failed]);
+    assert_eq!(report.errors().count(), 1);
+    assert_eq!(report.warnings().count(), 2);
+    // Assets that fail validation are not written to the processed source.
+    assert!(processed_dir.get_asset(good_path).is_some());
+    assert!(processed_dir.get_asset(bad_path).is_none());
+    assert!(processed_dir.get_metadata(bad_path).is_none());
+}
+
+#[test]
+fn adding_a_validator_revalidates_unchanged_assets() {
+    #[derive(TypePath)]
+    struct NonEmptyText;
+
+    impl AssetValidator for NonEmptyText {
+        type Asset = CoolText;
+
+        fn validate(&self, asset: &CoolText, context: &mut ValidationContext) {
+            if asset.text.is_empty() {
+                context.error("text is empty");
+            }
+        }
+    }
+
+    type CoolTextProcessor = LoadTransformAndSave<
+        CoolTextLoader,
+        RootAssetTransformer<AddText, CoolText>,
+        CoolTextSaver,
+    >;
+
+    let AppWithProcessor {
+        mut app,
+        source_gate,
+        default_source_dirs:
+            ProcessingDirs {
+                source: source_dir,
+                processed: processed_dir,
+                source_event_sender,
+            },
+        ..
+    } = create_app_with_asset_processor(&[]);
+    app.init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .register_asset_processor(CoolTextProcessor::new(
+            RootAssetTransformer::new(AddText("".into())),
+            CoolTextSaver,
+        ))
+        .set_default_asset_processor::<CoolTextProcessor>("cool.ron");
+    let processor = app.world().resource::<AssetProcessor>().clone();
+
+    let path = Path::new("empty.cool.ron");
+    let guard = source_gate.write_blocking();
+    source_dir.insert_asset_text(path, &serialize_as_cool_text(""));
+    run_app_until_finished_processing(&mut app, guard);
+    assert!(processor.validation_report().issues().is_empty());
+    assert!(processed_dir.get_asset(path).is_some());
+
+    // The source asset is unchanged, but it must be processed again to be validated.
+    processor.register_validator(NonEmptyText);
+    processor.data().set_fail_on_validation_errors(true);
+    let guard = source_gate.write_blocking();
+    source_event_sender
+        .send_blocking(AssetSourceEvent::ModifiedAsset(path.to_path_buf()))
+        .unwrap();
+    run_app_until_finished_processing(&mut app, guard);
+
+    assert_eq!(
+        bevy_tasks::block_on(processor.data().wait_until_processed(path.into())),
+        ProcessStatus::Failed
+    );
+    assert_eq!(processor.validation_report().errors().count(), 1);
+}
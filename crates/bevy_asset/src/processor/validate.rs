@@ -0,0 +1,245 @@
+use crate::{server::write_escaped_string, Asset, AssetPath, ErasedLoadedAsset};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_reflect::TypePath;
+use core::{any::TypeId, fmt::Write};
+use serde::{Deserialize, Serialize};
+
+/// A rule that checks assets of a given type while they are processed, such as "textures that
+/// need mipmaps must have power-of-two dimensions" or "meshes must have normals".
+///
+/// Validators are registered with [`AssetProcessor::register_validator`] (or
+/// [`AssetApp::register_asset_validator`]). They run on every asset (and labeled sub-asset) of
+/// type [`AssetValidator::Asset`] loaded through [`ProcessContext::load_source_asset`], which
+/// includes every asset processed by [`LoadTransformAndSave`]. Assets that are copied without a
+/// processor are not validated.
+///
+/// Reported issues are logged, collected into the [`AssetProcessor::validation_report`], and fail
+/// the processing of the asset if [`AssetProcessorData::set_fail_on_validation_errors`] is enabled.
+///
+/// [`AssetProcessor::register_validator`]: crate::processor::AssetProcessor::register_validator
+/// [`AssetApp::register_asset_validator`]: crate::AssetApp::register_asset_validator
+/// [`ProcessContext::load_source_asset`]: crate::processor::ProcessContext::load_source_asset
+/// [`LoadTransformAndSave`]: crate::processor::LoadTransformAndSave
+/// [`AssetProcessor::validation_report`]: crate::processor::AssetProcessor::validation_report
+/// [`AssetProcessorData::set_fail_on_validation_errors`]: crate::processor::AssetProcessorData::set_fail_on_validation_errors
+pub trait AssetValidator: TypePath + Send + Sync + 'static {
+    /// The type of asset this validator checks.
+    type Asset: Asset;
+
+    /// Checks `asset`, reporting any issue to `context`.
+    fn validate(&self, asset: &Self::Asset, context: &mut ValidationContext);
+}
+
+/// A type-erased variant of [`AssetValidator`].
+pub trait ErasedAssetValidator: Send + Sync + 'static {
+    /// Returns the [`TypeId`] of the assets this validator checks.
+    fn asset_type_id(&self) -> TypeId;
+    /// Type-erased variant of [`AssetValidator::validate`]. Does nothing if `asset` is not of the
+    /// type returned by [`ErasedAssetValidator::asset_type_id`].
+    fn validate(&self, asset: &ErasedLoadedAsset, context: &mut ValidationContext);
+    /// Returns the type path of the original [`AssetValidator`].
+    fn type_path(&self) -> &'static str;
+    /// Returns the short type path of the original [`AssetValidator`].
+    fn short_type_path(&self) -> &'static str;
+}
+
+impl<V: AssetValidator> ErasedAssetValidator for V {
+    fn asset_type_id(&self) -> TypeId {
+        TypeId::of::<V::Asset>()
+    }
+
+    fn validate(&self, asset: &ErasedLoadedAsset, context: &mut ValidationContext) {
+        if let Some(asset) = asset.get::<V::Asset>() {
+            AssetValidator::validate(self, asset, context);
+        }
+    }
+
+    fn type_path(&self) -> &'static str {
+        V::type_path()
+    }
+
+    fn short_type_path(&self) -> &'static str {
+        V::short_type_path()
+    }
+}
+
+/// Collects the issues reported by an [`AssetValidator`] for a single asset.
+pub struct ValidationContext<'a> {
+    path: &'a AssetPath<'static>,
+    validator: &'static str,
+    issues: &'a mut Vec<ValidationIssue>,
+}
+
+impl<'a> ValidationContext<'a> {
+    pub(crate) fn new(
+        path: &'a AssetPath<'static>,
+        validator: &'static str,
+        issues: &'a mut Vec<ValidationIssue>,
+    ) -> Self {
+        Self {
+            path,
+            validator,
+            issues,
+        }
+    }
+
+    /// The path of the asset being validated. This includes the label of labeled sub-assets.
+    pub fn path(&self) -> &AssetPath<'static> {
+        self.path
+    }
+
+    /// Reports an issue that should be fixed, but does not prevent the asset from being used.
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Warning, message.into());
+    }
+
+    /// Reports an issue that makes the asset unusable (or incorrect) in game.
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Error, message.into());
+    }
+
+    fn report(&mut self, severity: ValidationSeverity, message: String) {
+        self.issues.push(ValidationIssue {
+            path: self.path.clone(),
+            validator: Cow::Borrowed(self.validator),
+            severity,
+            message,
+        });
+    }
+}
+
+/// How serious a [`ValidationIssue`] is.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum ValidationSeverity {
+    /// The asset can be used, but should be fixed.
+    Warning,
+    /// The asset is broken. This fails processing if
+    /// [`AssetProcessorData::set_fail_on_validation_errors`] is enabled.
+    ///
+    /// [`AssetProcessorData::set_fail_on_validation_errors`]: crate::processor::AssetProcessorData::set_fail_on_validation_errors
+    Error,
+}
+
+/// An issue reported by an [`AssetValidator`].
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// The path of the asset the issue was found in, including the label of labeled sub-assets.
+    pub path: AssetPath<'static>,
+    /// The short type path of the [`AssetValidator`] that reported the issue.
+    pub validator: Cow<'static, str>,
+    /// How serious the issue is.
+    pub severity: ValidationSeverity,
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl core::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let severity = match self.severity {
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Error => "error",
+        };
+        write!(
+            f,
+            "{severity} in '{}' ({}): {}",
+            self.path, self.validator, self.message
+        )
+    }
+}
+
+/// All the [`ValidationIssue`]s reported while processing assets, as returned by
+/// [`AssetProcessor::validation_report`](crate::processor::AssetProcessor::validation_report).
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    pub(crate) issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns every issue in this report, ordered by asset path.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Iterates over the issues with [`ValidationSeverity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// Iterates over the issues with [`ValidationSeverity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Warning)
+    }
+
+    /// Returns `true` if any issue is an error.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Returns `true` if no issue was reported.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Exports this report as JSON, for use by CI or other tools.
+    ///
+    /// The output is an object with an `issues` array. Each issue has a `path`, the `validator`
+    /// that reported it, a `severity` (`"warning"` or `"error"`) and a `message`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"issues\":[");
+        for (index, issue) in self.issues.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"path\":");
+            write_escaped_string(&mut json, &issue.path.to_string());
+            json.push_str(",\"validator\":");
+            write_escaped_string(&mut json, &issue.validator);
+            let severity = match issue.severity {
+                ValidationSeverity::Warning => "warning",
+                ValidationSeverity::Error => "error",
+            };
+            let _ = write!(json, ",\"severity\":\"{severity}\",\"message\":");
+            write_escaped_string(&mut json, &issue.message);
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+/// Runs every validator in `validators` that applies to `asset` or one of its labeled sub-assets.
+pub(crate) fn validate_loaded_asset(
+    validators: &[Arc<dyn ErasedAssetValidator>],
+    path: &AssetPath<'static>,
+    asset: &ErasedLoadedAsset,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut validate = |path: &AssetPath<'static>, asset: &ErasedLoadedAsset| {
+        for validator in validators
+            .iter()
+            .filter(|validator| validator.asset_type_id() == asset.asset_type_id())
+        {
+            let mut context =
+                ValidationContext::new(path, validator.short_type_path(), &mut issues);
+            validator.validate(asset, &mut context);
+        }
+    };
+    validate(path, asset);
+    let mut labels: Vec<_> = asset.iter_labels().collect();
+    labels.sort_unstable();
+    for label in labels {
+        if let Some(labeled) = asset.get_labeled(label) {
+            validate(&path.clone().with_label(label.to_string()), labeled);
+        }
+    }
+    issues
+}
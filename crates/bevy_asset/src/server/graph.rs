@@ -0,0 +1,319 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use core::fmt::Write;
+
+/// A snapshot of the dependency relationships between assets tracked by the
+/// [`AssetServer`](crate::AssetServer).
+///
+/// Obtained through [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph) or
+/// [`AssetServer::get_dependency_graph`](crate::AssetServer::get_dependency_graph). The graph is
+/// not kept in sync with the server: request a new one to observe later changes.
+///
+/// Labeled sub-assets are part of the graph. They are linked to the asset that produced them
+/// through [`AssetDependencyNode::labeled_assets`] and [`AssetDependencyNode::source_assets`],
+/// and the recursive queries on this type traverse those links as well as regular dependencies.
+///
+/// The graph can be exported with [`AssetDependencyGraph::to_dot`] (for Graphviz) or
+/// [`AssetDependencyGraph::to_json`] to inspect it with external tools.
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    pub(crate) nodes: HashMap<UntypedAssetId, AssetDependencyNode>,
+}
+
+/// A single asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug)]
+pub struct AssetDependencyNode {
+    /// The path of the asset, if it was loaded from one.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`LoadState`] of the asset when the graph was built.
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset when the graph was built.
+    pub dependency_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset when the graph was built.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The direct dependencies of the asset, as reported by its loader.
+    ///
+    /// This is empty until the asset has finished loading. Dependencies that are no longer
+    /// tracked by the server are still listed here, but have no node in the graph.
+    pub dependencies: HashSet<UntypedAssetId>,
+    /// The assets that directly depend on this asset.
+    pub dependents: HashSet<UntypedAssetId>,
+    /// The labeled sub-assets produced while loading this asset.
+    pub labeled_assets: HashSet<UntypedAssetId>,
+    /// If this is a labeled sub-asset, the asset(s) loaded from its unlabeled path.
+    pub source_assets: HashSet<UntypedAssetId>,
+}
+
+impl AssetDependencyGraph {
+    /// Returns the node for the given asset, if it is part of this graph.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Returns `true` if the given asset is part of this graph.
+    pub fn contains(&self, id: impl Into<UntypedAssetId>) -> bool {
+        self.nodes.contains_key(&id.into())
+    }
+
+    /// Iterates over every asset in this graph, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, &AssetDependencyNode)> {
+        self.nodes.iter().map(|(id, node)| (*id, node))
+    }
+
+    /// Returns the number of assets in this graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if this graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns every asset the given asset transitively depends on, including labeled sub-assets
+    /// of the asset and of its dependencies. The asset itself is not included.
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> HashSet<UntypedAssetId> {
+        self.traverse(id.into(), |node| {
+            node.dependencies.iter().chain(node.labeled_assets.iter())
+        })
+    }
+
+    /// Returns every asset that transitively depends on the given asset, including the assets
+    /// that produced it (and its dependents) as a labeled sub-asset. The asset itself is not
+    /// included.
+    pub fn recursive_dependents(&self, id: impl Into<UntypedAssetId>) -> HashSet<UntypedAssetId> {
+        self.traverse(id.into(), |node| {
+            node.dependents.iter().chain(node.source_assets.iter())
+        })
+    }
+
+    /// Returns the recursive dependencies of the given asset that have not finished loading yet.
+    ///
+    /// These are the assets preventing the given asset from reaching
+    /// [`RecursiveDependencyLoadState::Loaded`]. Failed dependencies are not included.
+    pub fn pending_dependencies(&self, id: impl Into<UntypedAssetId>) -> HashSet<UntypedAssetId> {
+        let mut pending = self.recursive_dependencies(id);
+        pending.retain(|id| {
+            self.nodes.get(id).is_some_and(|node| {
+                matches!(node.load_state, LoadState::NotLoaded | LoadState::Loading)
+            })
+        });
+        pending
+    }
+
+    /// Returns the part of this graph relevant to the given asset: the asset itself, its
+    /// recursive dependencies and its recursive dependents.
+    ///
+    /// Relationships with assets outside of the subgraph are removed from the returned nodes.
+    /// Returns [`None`] if the asset is not part of this graph.
+    pub fn subgraph(&self, id: impl Into<UntypedAssetId>) -> Option<AssetDependencyGraph> {
+        let id = id.into();
+        if !self.nodes.contains_key(&id) {
+            return None;
+        }
+        let mut members = self.recursive_dependencies(id);
+        members.extend(self.recursive_dependents(id));
+        members.insert(id);
+
+        let nodes = members
+            .iter()
+            .filter_map(|member| {
+                let mut node = self.nodes.get(member)?.clone();
+                node.dependencies.retain(|id| members.contains(id));
+                node.dependents.retain(|id| members.contains(id));
+                node.labeled_assets.retain(|id| members.contains(id));
+                node.source_assets.retain(|id| members.contains(id));
+                Some((*member, node))
+            })
+            .collect();
+        Some(AssetDependencyGraph { nodes })
+    }
+
+    /// Exports this graph in the Graphviz DOT format.
+    ///
+    /// Dependencies are drawn as solid edges and labeled sub-assets as dashed edges from the asset
+    /// that produced them. Nodes are colored by their [`LoadState`].
+    pub fn to_dot(&self) -> String {
+        let ids = self.sorted_ids();
+        let numbering = Self::numbering(&ids);
+        let mut dot = String::from("digraph assets {\n");
+        for (number, id) in ids.iter().enumerate() {
+            let node = &self.nodes[id];
+            let color = match node.load_state {
+                LoadState::NotLoaded => "gray",
+                LoadState::Loading => "orange",
+                LoadState::Loaded => "green",
+                LoadState::Failed(_) => "red",
+            };
+            let _ = write!(dot, "    n{number} [label=");
+            write_escaped_string(&mut dot, &self.label(*id));
+            let _ = writeln!(dot, ", color={color}];");
+        }
+        for (number, id) in ids.iter().enumerate() {
+            let node = &self.nodes[id];
+            for dependency in Self::sorted_numbers(&numbering, &node.dependencies) {
+                let _ = writeln!(dot, "    n{number} -> n{dependency};");
+            }
+            for labeled in Self::sorted_numbers(&numbering, &node.labeled_assets) {
+                let _ = writeln!(dot, "    n{number} -> n{labeled} [style=dashed];");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports this graph as JSON.
+    ///
+    /// The output is an object with a `nodes` array. Each node has a numeric `id` that is only
+    /// meaningful within this export, the `asset_id` and `path` of the asset, its load states,
+    /// and the `dependencies`, `dependents`, `labeled_assets` and `source_assets` of the asset as
+    /// arrays of node ids.
+    pub fn to_json(&self) -> String {
+        let ids = self.sorted_ids();
+        let numbering = Self::numbering(&ids);
+        let mut json = String::from("{\"nodes\":[");
+        for (number, id) in ids.iter().enumerate() {
+            let node = &self.nodes[id];
+            if number > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "{{\"id\":{number},\"asset_id\":");
+            write_escaped_string(&mut json, &format!("{id}"));
+            json.push_str(",\"path\":");
+            match &node.path {
+                Some(path) => write_escaped_string(&mut json, &format!("{path}")),
+                None => json.push_str("null"),
+            }
+            let _ = write!(
+                json,
+                ",\"load_state\":\"{}\",\"dependency_load_state\":\"{}\",\"recursive_dependency_load_state\":\"{}\"",
+                state_name(
+                    node.load_state.is_loaded(),
+                    node.load_state.is_loading(),
+                    node.load_state.is_failed()
+                ),
+                state_name(
+                    node.dependency_load_state.is_loaded(),
+                    node.dependency_load_state.is_loading(),
+                    node.dependency_load_state.is_failed()
+                ),
+                state_name(
+                    node.recursive_dependency_load_state.is_loaded(),
+                    node.recursive_dependency_load_state.is_loading(),
+                    node.recursive_dependency_load_state.is_failed()
+                ),
+            );
+            for (key, set) in [
+                ("dependencies", &node.dependencies),
+                ("dependents", &node.dependents),
+                ("labeled_assets", &node.labeled_assets),
+                ("source_assets", &node.source_assets),
+            ] {
+                let _ = write!(json, ",\"{key}\":[");
+                for (index, other) in Self::sorted_numbers(&numbering, set).enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    let _ = write!(json, "{other}");
+                }
+                json.push(']');
+            }
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
+
+    fn traverse<'a, I>(
+        &'a self,
+        start: UntypedAssetId,
+        mut neighbors: impl FnMut(&'a AssetDependencyNode) -> I,
+    ) -> HashSet<UntypedAssetId>
+    where
+        I: Iterator<Item = &'a UntypedAssetId>,
+    {
+        let mut visited = HashSet::default();
+        let mut queue = VecDeque::from([start]);
+        while let Some(id) = queue.pop_front() {
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            for next in neighbors(node) {
+                if *next != start && visited.insert(*next) {
+                    queue.push_back(*next);
+                }
+            }
+        }
+        visited
+    }
+
+    fn label(&self, id: UntypedAssetId) -> String {
+        match &self.nodes[&id].path {
+            Some(path) => format!("{path}"),
+            None => format!("{id}"),
+        }
+    }
+
+    /// Orders the nodes by path (falling back to their id) so exports are stable.
+    fn sorted_ids(&self) -> Vec<UntypedAssetId> {
+        let mut ids: Vec<_> = self.nodes.keys().copied().collect();
+        ids.sort_by_cached_key(|id| (self.nodes[id].path.as_ref().map(ToString::to_string), *id));
+        ids
+    }
+
+    fn numbering(ids: &[UntypedAssetId]) -> HashMap<UntypedAssetId, usize> {
+        ids.iter()
+            .enumerate()
+            .map(|(number, id)| (*id, number))
+            .collect()
+    }
+
+    fn sorted_numbers(
+        numbering: &HashMap<UntypedAssetId, usize>,
+        ids: &HashSet<UntypedAssetId>,
+    ) -> impl Iterator<Item = usize> {
+        let mut numbers: Vec<_> = ids
+            .iter()
+            .filter_map(|id| numbering.get(id).copied())
+            .collect();
+        numbers.sort_unstable();
+        numbers.into_iter()
+    }
+}
+
+fn state_name(loaded: bool, loading: bool, failed: bool) -> &'static str {
+    if loaded {
+        "Loaded"
+    } else if loading {
+        "Loading"
+    } else if failed {
+        "Failed"
+    } else {
+        "NotLoaded"
+    }
+}
+
+/// Writes `value` as a double-quoted string, escaping it so that it is valid in both DOT and JSON.
+pub(crate) fn write_escaped_string(output: &mut String, value: &str) {
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
@@ -1,8 +1,9 @@
+use super::{AssetDependencyGraph, AssetDependencyNode};
 use crate::{
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetIndex, AssetLoadError, AssetPath, DependencyLoadState,
     ErasedAssetIndex, ErasedLoadedAsset, Handle, InternalAssetEvent, LoadState,
-    RecursiveDependencyLoadState, StrongHandle, UntypedHandle,
+    RecursiveDependencyLoadState, StrongHandle, UntypedAssetId, UntypedHandle,
 };
 use alloc::{
     borrow::ToOwned,
@@ -19,6 +20,7 @@ use core::{
     task::Waker,
 };
 use crossbeam_channel::Sender;
+use std::path::PathBuf;
 use thiserror::Error;
 use tracing::warn;
 
@@ -29,6 +31,9 @@ pub(crate) struct AssetInfo {
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
     pub(crate) rec_dep_load_state: RecursiveDependencyLoadState,
+    /// Every direct dependency reported by the most recent load of this asset, regardless of
+    /// its current load state. Used to build an [`AssetDependencyGraph`].
+    dependencies: HashSet<ErasedAssetIndex>,
     loading_dependencies: HashSet<ErasedAssetIndex>,
     failed_dependencies: HashSet<ErasedAssetIndex>,
     loading_rec_dependencies: HashSet<ErasedAssetIndex>,
@@ -57,6 +62,7 @@ impl AssetInfo {
             load_state: LoadState::NotLoaded,
             dep_load_state: DependencyLoadState::NotLoaded,
             rec_dep_load_state: RecursiveDependencyLoadState::NotLoaded,
+            dependencies: HashSet::default(),
             loading_dependencies: HashSet::default(),
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
@@ -90,6 +96,9 @@ pub(crate) struct AssetInfos {
     /// Tracks living labeled assets for a given source asset.
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
+    /// Maps the (unlabeled) path of assets that were loaded from a localized variant to the path
+    /// of that variant. See [`AssetLocale`](crate::AssetLocale).
+    pub(crate) localized_paths: HashMap<AssetPath<'static>, PathBuf>,
     pub(crate) handle_providers: TypeIdMap<AssetHandleProvider>,
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, AssetIndex)>,
     pub(crate) dependency_failed_event_sender:
@@ -360,6 +369,70 @@ impl AssetInfos {
         Some(UntypedHandle::Strong(strong_handle))
     }
 
+    /// Builds a snapshot of the dependency relationships between every asset currently tracked.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        let mut nodes: HashMap<UntypedAssetId, AssetDependencyNode> = self
+            .infos
+            .iter()
+            .map(|(index, info)| {
+                let node = AssetDependencyNode {
+                    path: info.path.clone(),
+                    load_state: info.load_state.clone(),
+                    dependency_load_state: info.dep_load_state.clone(),
+                    recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                    dependencies: info.dependencies.iter().copied().map(Into::into).collect(),
+                    dependents: HashSet::default(),
+                    labeled_assets: HashSet::default(),
+                    source_assets: HashSet::default(),
+                };
+                ((*index).into(), node)
+            })
+            .collect();
+
+        let mut dependent_edges = Vec::new();
+        let mut labeled_edges = Vec::new();
+        for (index, info) in &self.infos {
+            let id = UntypedAssetId::from(*index);
+            for dependency in &info.dependencies {
+                dependent_edges.push((UntypedAssetId::from(*dependency), id));
+            }
+            if let Some(path) = &info.path
+                && path.label().is_some()
+            {
+                let source_path = path.without_label();
+                for source in self.get_path_indices(&source_path) {
+                    labeled_edges.push((UntypedAssetId::from(source), id));
+                }
+            }
+        }
+
+        for (dependency, dependent) in dependent_edges {
+            // Dependencies that are no longer tracked (e.g. dropped) have no node to update.
+            if let Some(node) = nodes.get_mut(&dependency) {
+                node.dependents.insert(dependent);
+            }
+        }
+        for (source, labeled) in labeled_edges {
+            if let Some(node) = nodes.get_mut(&source) {
+                node.labeled_assets.insert(labeled);
+            }
+            if let Some(node) = nodes.get_mut(&labeled) {
+                node.source_assets.insert(source);
+            }
+        }
+
+        AssetDependencyGraph { nodes }
+    }
+
+    /// Returns the unlabeled paths of every asset currently tracked. Paths of labeled assets are
+    /// returned as the path of the asset they were loaded from.
+    pub(crate) fn root_paths(&self) -> HashSet<AssetPath<'static>> {
+        self.path_to_index
+            .keys()
+            .map(|path| path.without_label().into_owned())
+            .collect()
+    }
+
     /// Returns `true` if the asset this path points to is still alive
     pub(crate) fn is_path_alive<'a>(&self, path: impl Into<AssetPath<'a>>) -> bool {
         self.get_path_indices(&path.into())
@@ -387,6 +460,7 @@ impl AssetInfos {
             &mut self.path_to_index,
             &mut self.loader_dependents,
             &mut self.living_labeled_assets,
+            &mut self.localized_paths,
             &mut self.pending_tasks,
             self.watching_for_changes,
             index,
@@ -424,7 +498,8 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_index.index, world);
-        let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loaded_asset.dependencies;
+        let mut loading_deps = dependencies.clone();
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
         let mut loading_rec_deps = loading_deps.clone();
@@ -520,6 +595,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_index)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -712,6 +788,7 @@ impl AssetInfos {
         path_to_id: &mut HashMap<AssetPath<'static>, TypeIdMap<AssetIndex>>,
         loader_dependents: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
         living_labeled_assets: &mut HashMap<AssetPath<'static>, HashSet<Box<str>>>,
+        localized_paths: &mut HashMap<AssetPath<'static>, PathBuf>,
         pending_tasks: &mut HashMap<ErasedAssetIndex, Task<()>>,
         watching_for_changes: bool,
         index: ErasedAssetIndex,
@@ -753,6 +830,17 @@ impl AssetInfos {
             }
         };
 
+        // The localized variant is shared by the asset and its labeled assets, so only forget it
+        // once none of them are left.
+        let base_path = path.without_label().into_owned();
+        if localized_paths.contains_key(&base_path)
+            && !path_to_id
+                .keys()
+                .any(|path| path.without_label() == base_path)
+        {
+            localized_paths.remove(&base_path);
+        }
+
         true
     }
 
@@ -771,6 +859,7 @@ impl AssetInfos {
                         &mut self.path_to_index,
                         &mut self.loader_dependents,
                         &mut self.living_labeled_assets,
+                        &mut self.localized_paths,
                         &mut self.pending_tasks,
                         self.watching_for_changes,
                         id,
@@ -1,3 +1,4 @@
+mod graph;
 mod info;
 mod loaders;
 
@@ -14,7 +15,7 @@ use crate::{
         MetaTransform, Settings,
     },
     path::AssetPath,
-    Asset, AssetEvent, AssetHandleProvider, AssetId, AssetIndex, AssetLoadFailedEvent,
+    Asset, AssetEvent, AssetHandleProvider, AssetId, AssetIndex, AssetLoadFailedEvent, AssetLocale,
     AssetMetaCheck, Assets, DeserializeMetaError, ErasedAssetIndex, ErasedLoadedAsset, Handle,
     LoadedUntypedAsset, UnapprovedPathMode, UntypedAssetId, UntypedAssetLoadFailedEvent,
     UntypedHandle, VisitAssetDependencies,
@@ -41,6 +42,8 @@ use core::{
 };
 use crossbeam_channel::{Receiver, Sender};
 use futures_lite::{FutureExt, StreamExt};
+pub(crate) use graph::write_escaped_string;
+pub use graph::{AssetDependencyGraph, AssetDependencyNode};
 use info::*;
 use loaders::*;
 use std::path::{Path, PathBuf};
@@ -77,6 +80,7 @@ pub(crate) struct AssetServerData {
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
     unapproved_path_mode: UnapprovedPathMode,
+    locale: RwLock<AssetLocale>,
 }
 
 /// The "asset mode" the server is currently in.
@@ -150,6 +154,7 @@ impl AssetServer {
                 loaders,
                 infos: RwLock::new(infos),
                 unapproved_path_mode,
+                locale: Default::default(),
             }),
         }
     }
@@ -749,8 +754,9 @@ impl AssetServer {
 
         let path = path.into_owned();
         let path_clone = path.clone();
+        let read_path = self.resolve_localized_path(&path).await;
         let (mut meta, loader, mut reader) = self
-            .get_meta_loader_and_reader(&path_clone, input_handle_type_id)
+            .get_meta_loader_and_reader(&path_clone, &read_path, input_handle_type_id)
             .await
             .inspect_err(|e| {
                 // if there was an input handle, a "load" operation has already started, so we must produce a "failure" event, if
@@ -1284,6 +1290,26 @@ impl AssetServer {
             .map(|i| i.rec_dep_load_state.clone())
     }
 
+    /// Returns a snapshot of the dependency relationships between every asset currently tracked
+    /// by this server, including labeled sub-assets.
+    ///
+    /// This is intended for debugging and tooling: it walks every tracked asset, so avoid calling
+    /// it every frame. See [`AssetDependencyGraph`] for the available queries and exports.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.read_infos().dependency_graph()
+    }
+
+    /// Returns the [`AssetDependencyGraph`] of the given asset `id`: the asset itself, everything
+    /// it transitively depends on and everything that transitively depends on it.
+    ///
+    /// Returns [`None`] if the asset is not tracked by this server.
+    pub fn get_dependency_graph(
+        &self,
+        id: impl Into<UntypedAssetId>,
+    ) -> Option<AssetDependencyGraph> {
+        self.dependency_graph().subgraph(id)
+    }
+
     /// Retrieves the main [`LoadState`] of a given asset `id`.
     ///
     /// This is the same as [`AssetServer::get_load_state`] except the result is unwrapped. If
@@ -1544,9 +1570,127 @@ impl AssetServer {
             .0
     }
 
+    /// Returns the current [`AssetLocale`] of this server.
+    pub fn locale(&self) -> AssetLocale {
+        self.data
+            .locale
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sets the [`AssetLocale`] used to pick localized variants of assets.
+    ///
+    /// Every loaded asset that resolves to a different file with the new locale is reloaded.
+    /// Assets loaded afterwards use the new locale. This is usually driven by inserting or
+    /// changing the [`AssetLocale`] resource.
+    pub fn set_locale(&self, locale: AssetLocale) {
+        {
+            let mut current = self
+                .data
+                .locale
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            if *current == locale {
+                return;
+            }
+            *current = locale.clone();
+        }
+
+        let paths = self.read_infos().root_paths();
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                for path in paths {
+                    let Ok(reader) = server.asset_reader(path.source()) else {
+                        continue;
+                    };
+                    let new_path = Self::locate_localized_path(reader, path.path(), &locale).await;
+                    let changed = {
+                        let infos = server.read_infos();
+                        let current_path = infos
+                            .localized_paths
+                            .get(&path)
+                            .map_or(path.path(), PathBuf::as_path);
+                        current_path != new_path
+                    };
+                    if changed {
+                        server.reload(path);
+                    }
+                }
+            })
+            .detach();
+    }
+
+    fn asset_reader<'a>(
+        &'a self,
+        source: &AssetSourceId<'_>,
+    ) -> Result<&'a dyn ErasedAssetReader, AssetLoadError> {
+        let source = self.get_source(source.clone())?;
+        Ok(match self.data.mode {
+            AssetServerMode::Unprocessed => source.reader(),
+            AssetServerMode::Processed => source.processed_reader()?,
+        })
+    }
+
+    /// Returns the first localized variant of `path` (as listed by [`AssetLocale::fallback_chain`])
+    /// that exists in `reader`, or `path` itself if there is none.
+    async fn locate_localized_path(
+        reader: &dyn ErasedAssetReader,
+        path: &Path,
+        locale: &AssetLocale,
+    ) -> PathBuf {
+        for candidate in locale.candidates(path) {
+            if reader.read(&candidate).await.is_ok() {
+                return candidate;
+            }
+        }
+        path.to_path_buf()
+    }
+
+    /// Returns the path that should be read to load `asset_path`, taking the current
+    /// [`AssetLocale`] into account, and records it so that locale changes and hot reloading of
+    /// localized variants can reload the asset.
+    pub(crate) async fn resolve_localized_path(&self, asset_path: &AssetPath<'_>) -> PathBuf {
+        let locale = self.locale();
+        if locale.locale().is_none() {
+            return asset_path.path().to_path_buf();
+        }
+        let Ok(reader) = self.asset_reader(asset_path.source()) else {
+            // Let the load itself report the missing source.
+            return asset_path.path().to_path_buf();
+        };
+        let read_path = Self::locate_localized_path(reader, asset_path.path(), &locale).await;
+
+        let base_path = asset_path.without_label().into_owned();
+        let mut infos = self.write_infos();
+        if infos.watching_for_changes {
+            // Adding or modifying any of the variants may change what this asset loads.
+            for candidate in locale.candidates(asset_path.path()) {
+                let candidate =
+                    AssetPath::from(candidate).with_source(asset_path.source().clone_owned());
+                infos
+                    .loader_dependents
+                    .entry(candidate)
+                    .or_default()
+                    .insert(base_path.clone());
+            }
+        }
+        if read_path != asset_path.path() {
+            infos.localized_paths.insert(base_path, read_path.clone());
+        } else {
+            infos.localized_paths.remove(&base_path);
+        }
+        read_path
+    }
+
+    /// Returns the meta, loader and reader for loading `asset_path`, reading the asset bytes (and
+    /// meta) from `read_path`. `read_path` only differs from the path of `asset_path` when loading
+    /// a localized variant of the asset.
     pub(crate) async fn get_meta_loader_and_reader<'a>(
         &'a self,
         asset_path: &'a AssetPath<'_>,
+        read_path: &'a Path,
         asset_type_id: Option<TypeId>,
     ) -> Result<
         (
@@ -1556,11 +1700,7 @@ impl AssetServer {
         ),
         AssetLoadError,
     > {
-        let source = self.get_source(asset_path.source())?;
-        let asset_reader = match self.data.mode {
-            AssetServerMode::Unprocessed => source.reader(),
-            AssetServerMode::Processed => source.processed_reader()?,
-        };
+        let asset_reader = self.asset_reader(asset_path.source())?;
         let read_meta = match &self.data.meta_check {
             AssetMetaCheck::Always => true,
             AssetMetaCheck::Paths(paths) => paths.contains(asset_path),
@@ -1574,7 +1714,7 @@ impl AssetServer {
         let mut meta_reader;
 
         let (meta, loader) = if read_meta {
-            match asset_reader.read_meta(asset_path.path()).await {
+            match asset_reader.read_meta(read_path).await {
                 Ok(new_meta_reader) => {
                     meta_reader = new_meta_reader;
                     let mut meta_bytes = vec![];
@@ -1642,7 +1782,7 @@ impl AssetServer {
             let meta = loader.default_meta();
             (meta, loader)
         };
-        let reader = asset_reader.read(asset_path.path()).await?;
+        let reader = asset_reader.read(read_path).await?;
         Ok((meta, loader, reader))
     }
 
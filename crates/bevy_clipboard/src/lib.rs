@@ -34,6 +34,7 @@ use bevy_asset::RenderAssetUsages;
 use bevy_ecs::resource::Resource;
 #[cfg(feature = "image")]
 use bevy_image::Image;
+use core::any::Any;
 #[cfg(feature = "image")]
 use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
 
@@ -202,6 +203,9 @@ pub struct Clipboard {
         target_arch = "wasm32"
     )))]
     text: String,
+    /// Application-specific data describing the text most recently placed on the clipboard,
+    /// stored along with that text.
+    metadata: Option<(String, Box<dyn Any + Send + Sync>)>,
 }
 
 #[cfg_attr(
@@ -221,6 +225,7 @@ impl Default for Clipboard {
                 target_arch = "wasm32"
             )))]
             text: String::new(),
+            metadata: None,
         }
     }
 }
@@ -297,6 +302,8 @@ impl Clipboard {
     ///
     /// Returns error if `text` failed to be stored on the clipboard.
     pub fn set_text<'a, T: Into<Cow<'a, str>>>(&mut self, text: T) -> Result<(), ClipboardError> {
+        self.metadata = None;
+
         #[cfg(all(any(unix, windows), feature = "system_clipboard"))]
         {
             self.system_clipboard
@@ -335,6 +342,41 @@ impl Clipboard {
         }
     }
 
+    /// Places the text onto the clipboard, along with application-specific metadata describing it,
+    /// such as its formatting.
+    ///
+    /// Only the text is placed on the system clipboard: the metadata is kept in this [`Clipboard`],
+    /// and can be read back with [`Clipboard::metadata`] for as long as the clipboard holds the same text.
+    /// This allows richer copy and paste within the application, while pasting into other applications
+    /// (or pasting after another application has replaced the clipboard contents) falls back to the plain text.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `text` failed to be stored on the clipboard.
+    pub fn set_text_with_metadata<'a, T: Into<Cow<'a, str>>>(
+        &mut self,
+        text: T,
+        metadata: impl Any + Send + Sync,
+    ) -> Result<(), ClipboardError> {
+        let text = text.into();
+        let stored = text.clone().into_owned();
+        self.set_text(text)?;
+        self.metadata = Some((stored, Box::new(metadata)));
+        Ok(())
+    }
+
+    /// Returns the metadata placed on the clipboard by [`Clipboard::set_text_with_metadata`],
+    /// if it has type `T` and `text` (as read from the clipboard) is the text it was placed with.
+    ///
+    /// A mismatch means the clipboard has since been overwritten, possibly by another application,
+    /// so the metadata no longer applies.
+    pub fn metadata<T: Any>(&self, text: &str) -> Option<&T> {
+        self.metadata
+            .as_ref()
+            .filter(|(stored, _)| stored == text)
+            .and_then(|(_, metadata)| metadata.downcast_ref())
+    }
+
     /// Places image data onto the clipboard.
     ///
     /// The image must contain initialized 2D pixel data in packed RGBA8 row-major order.
@@ -345,6 +387,7 @@ impl Clipboard {
     /// Returns an error if the image data is invalid or the clipboard write fails.
     #[cfg(feature = "image")]
     pub fn set_image(&mut self, image: &Image) -> Result<(), ClipboardError> {
+        self.metadata = None;
         self.system_clipboard
             .as_mut()
             .ok_or(ClipboardError::ClipboardNotSupported)
@@ -425,3 +468,32 @@ impl From<arboard::Error> for ClipboardError {
         }
     }
 }
+
+// Without `system_clipboard`, the clipboard is an in-process buffer, so this doesn't need a display.
+#[cfg(all(test, not(feature = "system_clipboard")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_round_trip() {
+        let mut clipboard = Clipboard::default();
+        clipboard.set_text_with_metadata("styled", 42_u32).unwrap();
+
+        assert_eq!(clipboard.metadata::<u32>("styled"), Some(&42));
+        // Wrong type
+        assert_eq!(clipboard.metadata::<i64>("styled"), None);
+        // The clipboard holds different text, e.g. copied by another application
+        assert_eq!(clipboard.metadata::<u32>("other"), None);
+    }
+
+    #[test]
+    fn set_text_invalidates_metadata() {
+        let mut clipboard = Clipboard::default();
+        clipboard.set_text_with_metadata("styled", 42_u32).unwrap();
+        clipboard.set_text("styled").unwrap();
+
+        assert_eq!(clipboard.metadata::<u32>("styled"), None);
+        let mut read = clipboard.fetch_text();
+        assert_eq!(read.poll_result().unwrap().unwrap(), "styled");
+    }
+}
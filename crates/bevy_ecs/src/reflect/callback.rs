@@ -0,0 +1,353 @@
+//! Reflectable references to named functions and systems, for callbacks stored in components.
+//!
+//! Components can't store closures or [`SystemId`]s in a form that survives serialization, since
+//! neither can be reflected. Instead, a component can store a [`SystemRef`] or a `FunctionRef`,
+//! which only hold a name, and resolve it when the callback is invoked: [`SystemRef`]s are
+//! looked up in the [`NamedSystems`] resource, and `FunctionRef`s in the
+//! [`AppFunctionRegistry`](crate::reflect::AppFunctionRegistry).
+
+use crate::{
+    entity::Entity,
+    resource::Resource,
+    system::{IntoSystem, RegisteredSystemError, SystemId, SystemInput},
+    world::World,
+};
+use alloc::borrow::Cow;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use {
+    bevy_reflect::{ReflectDeserialize, ReflectSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// A reference to a system registered in the [`NamedSystems`] resource, by name.
+///
+/// Unlike a [`SystemId`], a [`SystemRef`] can be reflected and serialized, so components that
+/// store callbacks can be authored in scenes and shown in inspectors.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, reflect::SystemRef};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct OnClick(SystemRef);
+///
+/// #[derive(Resource, Default)]
+/// struct Clicks(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Clicks>();
+/// world.register_named_system("count_click", |mut clicks: ResMut<Clicks>| clicks.0 += 1);
+///
+/// let on_click = OnClick(SystemRef::new("count_click"));
+/// on_click.0.run::<()>(&mut world).unwrap();
+/// assert_eq!(world.resource::<Clicks>().0, 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize, Deserialize),
+    serde(transparent),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SystemRef {
+    name: Cow<'static, str>,
+}
+
+impl SystemRef {
+    /// Creates a reference to the system registered as `name`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Returns the name of the referenced system.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`SystemId`] of the referenced system, if it is registered in `world`.
+    pub fn system_id<I: SystemInput + 'static, O: 'static>(
+        &self,
+        world: &World,
+    ) -> Option<SystemId<I, O>> {
+        world.get_resource::<NamedSystems>()?.get(&self.name)
+    }
+
+    /// Runs the referenced system, returning its output.
+    pub fn run<O: 'static>(&self, world: &mut World) -> Result<O, SystemRefError<(), O>> {
+        self.run_with(world, ())
+    }
+
+    /// Runs the referenced system with the given input, returning its output.
+    pub fn run_with<I, O>(
+        &self,
+        world: &mut World,
+        input: I::Inner<'_>,
+    ) -> Result<O, SystemRefError<I, O>>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self
+            .system_id(world)
+            .ok_or_else(|| SystemRefError::UnknownSystem(self.name.clone()))?;
+        world
+            .run_system_with(id, input)
+            .map_err(SystemRefError::Run)
+    }
+}
+
+/// An error returned when running a [`SystemRef`].
+#[derive(Error)]
+pub enum SystemRefError<I: SystemInput = (), O = ()> {
+    /// No system is registered with the name.
+    #[error("no system is registered with the name `{0}`")]
+    UnknownSystem(Cow<'static, str>),
+    /// The system failed to run.
+    #[error(transparent)]
+    Run(RegisteredSystemError<I, O>),
+}
+
+impl<I: SystemInput, O> core::fmt::Debug for SystemRefError<I, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownSystem(name) => f.debug_tuple("UnknownSystem").field(name).finish(),
+            Self::Run(error) => f.debug_tuple("Run").field(error).finish(),
+        }
+    }
+}
+
+/// A [`Resource`] mapping names to registered systems, used to resolve [`SystemRef`]s.
+///
+/// Systems are usually added with [`World::register_named_system`].
+#[derive(Resource, Default, Debug)]
+pub struct NamedSystems {
+    systems: HashMap<Cow<'static, str>, Entity>,
+}
+
+impl NamedSystems {
+    /// Names the system `id` as `name`, replacing the system previously named `name`.
+    ///
+    /// Returns the entity of the replaced system, if there was one.
+    pub fn insert<I: SystemInput, O>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        id: SystemId<I, O>,
+    ) -> Option<Entity> {
+        self.systems.insert(name.into(), id.entity())
+    }
+
+    /// Returns the [`SystemId`] of the system named `name`.
+    ///
+    /// The input and output types are not checked here: running a system with the wrong
+    /// types returns [`RegisteredSystemError::IncorrectType`].
+    pub fn get<I: SystemInput, O>(&self, name: &str) -> Option<SystemId<I, O>> {
+        self.systems.get(name).copied().map(SystemId::from_entity)
+    }
+
+    /// Removes the name `name`, returning the entity of the system it referred to.
+    ///
+    /// The system itself stays registered.
+    pub fn remove(&mut self, name: &str) -> Option<Entity> {
+        self.systems.remove(name)
+    }
+
+    /// Returns an iterator over the names and system entities.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.systems
+            .iter()
+            .map(|(name, entity)| (name.as_ref(), *entity))
+    }
+}
+
+impl World {
+    /// Registers `system` and names it `name` in the [`NamedSystems`] resource, so that it can
+    /// be run through a [`SystemRef`].
+    ///
+    /// If a system was already named `name`, the name now refers to `system`, and the previous
+    /// system stays registered.
+    pub fn register_named_system<I, O, M>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let id = self.register_system(system);
+        self.get_resource_or_init::<NamedSystems>().insert(name, id);
+        id
+    }
+}
+
+#[cfg(feature = "reflect_functions")]
+pub use function_ref::*;
+
+#[cfg(feature = "reflect_functions")]
+mod function_ref {
+    use crate::{reflect::AppFunctionRegistry, world::World};
+    use alloc::borrow::Cow;
+    use bevy_reflect::{
+        func::{ArgList, FunctionError, Return},
+        std_traits::ReflectDefault,
+        Reflect,
+    };
+    use thiserror::Error;
+
+    #[cfg(feature = "serialize")]
+    use {
+        bevy_reflect::{ReflectDeserialize, ReflectSerialize},
+        serde::{Deserialize, Serialize},
+    };
+
+    /// A reference to a function registered in the [`AppFunctionRegistry`], by name.
+    ///
+    /// Like [`SystemRef`](super::SystemRef), a [`FunctionRef`] can be reflected and serialized,
+    /// so it can be stored in components that are authored in scenes.
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, reflect::{AppFunctionRegistry, FunctionRef}};
+    /// # use bevy_reflect::func::ArgList;
+    /// fn double(value: i32) -> i32 {
+    ///     value * 2
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppFunctionRegistry>();
+    /// world
+    ///     .resource::<AppFunctionRegistry>()
+    ///     .write()
+    ///     .register_with_name("double", double)
+    ///     .unwrap();
+    ///
+    /// let function = FunctionRef::new("double");
+    /// let result = function.call(&world, ArgList::new().with_owned(21i32)).unwrap();
+    /// assert_eq!(result.unwrap_owned().try_take::<i32>().unwrap(), 42);
+    /// ```
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+    #[reflect(Clone, Debug, Default, PartialEq, Hash)]
+    #[cfg_attr(
+        feature = "serialize",
+        derive(Serialize, Deserialize),
+        serde(transparent),
+        reflect(Serialize, Deserialize)
+    )]
+    pub struct FunctionRef {
+        name: Cow<'static, str>,
+    }
+
+    impl FunctionRef {
+        /// Creates a reference to the function registered as `name`.
+        pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+            Self { name: name.into() }
+        }
+
+        /// Returns the name of the referenced function.
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        /// Returns `true` if the referenced function is registered in `world`.
+        pub fn is_registered(&self, world: &World) -> bool {
+            world
+                .get_resource::<AppFunctionRegistry>()
+                .is_some_and(|registry| registry.read().contains(&self.name))
+        }
+
+        /// Calls the referenced function with the given arguments, returning its result.
+        pub fn call<'a>(
+            &self,
+            world: &World,
+            args: ArgList<'a>,
+        ) -> Result<Return<'a>, FunctionRefError> {
+            let registry = world
+                .get_resource::<AppFunctionRegistry>()
+                .ok_or_else(|| FunctionRefError::UnknownFunction(self.name.clone()))?
+                .read();
+            let function = registry
+                .get(&self.name)
+                .ok_or_else(|| FunctionRefError::UnknownFunction(self.name.clone()))?;
+            Ok(function.call(args)?)
+        }
+    }
+
+    /// An error returned when calling a [`FunctionRef`].
+    #[derive(Error, Debug)]
+    pub enum FunctionRefError {
+        /// No function is registered with the name.
+        #[error("no function is registered with the name `{0}`")]
+        UnknownFunction(Cow<'static, str>),
+        /// The function returned an error.
+        #[error(transparent)]
+        Function(#[from] FunctionError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::Component,
+        prelude::*,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        system::In,
+    };
+    use bevy_reflect::{FromReflect, PartialReflect};
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct OnActivate(SystemRef);
+
+    #[derive(Resource, Default)]
+    struct Total(u32);
+
+    #[test]
+    fn run_system_ref() {
+        let mut world = World::new();
+        world.init_resource::<Total>();
+        world.register_named_system("add", |In(amount): In<u32>, mut total: ResMut<Total>| {
+            total.0 += amount;
+            total.0
+        });
+
+        let add = SystemRef::new("add");
+        assert_eq!(add.run_with::<In<u32>, u32>(&mut world, 2).unwrap(), 2);
+        assert_eq!(add.run_with::<In<u32>, u32>(&mut world, 3).unwrap(), 5);
+        assert!(matches!(
+            add.run::<()>(&mut world),
+            Err(SystemRefError::Run(RegisteredSystemError::IncorrectType(
+                ..
+            )))
+        ));
+        assert!(matches!(
+            SystemRef::new("missing").run::<()>(&mut world),
+            Err(SystemRefError::UnknownSystem(_))
+        ));
+    }
+
+    #[test]
+    fn reflect_system_ref_in_component() {
+        let mut world = World::new();
+        world.init_resource::<Total>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<OnActivate>();
+        world.register_named_system("increment", |mut total: ResMut<Total>| total.0 += 1);
+
+        // Round-trip through a dynamic value, as a scene would.
+        let dynamic = OnActivate(SystemRef::new("increment")).to_dynamic();
+        let on_activate = OnActivate::from_reflect(dynamic.as_partial_reflect()).unwrap();
+        let entity = world.spawn(on_activate).id();
+
+        let system = world.get::<OnActivate>(entity).unwrap().0.clone();
+        system.run::<()>(&mut world).unwrap();
+        assert_eq!(world.resource::<Total>().0, 1);
+    }
+}
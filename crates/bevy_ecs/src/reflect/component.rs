@@ -57,7 +57,10 @@
 //!
 //! [`get_type_registration`]: bevy_reflect::GetTypeRegistration::get_type_registration
 
-use super::from_reflect_with_fallback;
+use super::{
+    dynamic_component::{self, DynamicComponentType},
+    from_reflect_with_fallback,
+};
 use crate::{
     change_detection::Mut,
     component::{ComponentId, ComponentMutability},
@@ -78,7 +81,18 @@ use bevy_utils::prelude::DebugName;
 /// A [`ReflectComponent`] for type `T` can be obtained via
 /// [`bevy_reflect::TypeRegistration::data`].
 #[derive(Clone)]
-pub struct ReflectComponent(ReflectComponentFns);
+pub struct ReflectComponent {
+    pub(super) kind: ReflectComponentKind,
+}
+
+/// How a [`ReflectComponent`] operates on its component.
+#[derive(Clone)]
+pub(super) enum ReflectComponentKind {
+    /// A component with a Rust type, operated on through function pointers.
+    Typed(ReflectComponentFns),
+    /// A component created with [`ReflectComponent::dynamic`].
+    Dynamic(DynamicComponentType),
+}
 
 /// The raw function pointers needed to make up a [`ReflectComponent`].
 ///
@@ -144,7 +158,10 @@ impl ReflectComponentFns {
     /// This is useful if you want to start with the default implementation before overriding some
     /// of the functions to create a custom implementation.
     pub fn new<T: Component + FromReflect + TypePath>() -> Self {
-        <ReflectComponent as FromType<T>>::from_type().0
+        match <ReflectComponent as FromType<T>>::from_type().kind {
+            ReflectComponentKind::Typed(fns) => fns,
+            ReflectComponentKind::Dynamic(_) => unreachable!("`FromType` creates typed components"),
+        }
     }
 }
 
@@ -156,7 +173,10 @@ impl ReflectComponent {
         component: &dyn PartialReflect,
         registry: &TypeRegistry,
     ) {
-        (self.0.insert)(entity, component, registry);
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.insert(entity, component),
+            ReflectComponentKind::Typed(fns) => (fns.insert)(entity, component, registry),
+        }
     }
 
     /// Uses reflection to set the value of this [`Component`] type in the entity to the given value.
@@ -167,7 +187,10 @@ impl ReflectComponent {
     ///
     /// Will also panic if [`Component`] is immutable.
     pub fn apply<'a>(&self, entity: impl Into<EntityMut<'a>>, component: &dyn PartialReflect) {
-        (self.0.apply)(entity.into(), component);
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.apply(entity.into(), component),
+            ReflectComponentKind::Typed(fns) => (fns.apply)(entity.into(), component),
+        }
     }
 
     /// Uses reflection to set the value of this [`Component`] type in the entity to the given value or insert a new one if it does not exist.
@@ -183,22 +206,42 @@ impl ReflectComponent {
         map: &mut dyn EntityMapper,
         relationship_hook_mode: RelationshipHookMode,
     ) {
-        (self.0.apply_or_insert_mapped)(entity, component, registry, map, relationship_hook_mode);
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => {
+                dynamic.apply_or_insert_mapped(entity, component, map, relationship_hook_mode);
+            }
+            ReflectComponentKind::Typed(fns) => (fns.apply_or_insert_mapped)(
+                entity,
+                component,
+                registry,
+                map,
+                relationship_hook_mode,
+            ),
+        }
     }
 
     /// Removes this [`Component`] type from the entity. Does nothing if it doesn't exist.
     pub fn remove(&self, entity: &mut EntityWorldMut) {
-        (self.0.remove)(entity);
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.remove(entity),
+            ReflectComponentKind::Typed(fns) => (fns.remove)(entity),
+        }
     }
 
     /// Removes this [`Component`] from the entity and returns its previous value.
     pub fn take(&self, entity: &mut EntityWorldMut) -> Option<Box<dyn Reflect>> {
-        (self.0.take)(entity)
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.take(entity),
+            ReflectComponentKind::Typed(fns) => (fns.take)(entity),
+        }
     }
 
     /// Returns whether entity contains this [`Component`]
     pub fn contains<'w, 's>(&self, entity: impl Into<FilteredEntityRef<'w, 's>>) -> bool {
-        (self.0.contains)(entity.into())
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.contains(entity.into()),
+            ReflectComponentKind::Typed(fns) => (fns.contains)(entity.into()),
+        }
     }
 
     /// Gets the value of this [`Component`] type from the entity as a reflected reference.
@@ -206,7 +249,10 @@ impl ReflectComponent {
         &self,
         entity: impl Into<FilteredEntityRef<'w, 's>>,
     ) -> Option<&'w dyn Reflect> {
-        (self.0.reflect)(entity.into())
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.reflect(entity.into()),
+            ReflectComponentKind::Typed(fns) => (fns.reflect)(entity.into()),
+        }
     }
 
     /// Gets the value of this [`Component`] type from the entity as a mutable reflected reference.
@@ -218,7 +264,10 @@ impl ReflectComponent {
         &self,
         entity: impl Into<FilteredEntityMut<'w, 's>>,
     ) -> Option<Mut<'w, dyn Reflect>> {
-        (self.0.reflect_mut)(entity.into())
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.reflect_mut(entity.into()),
+            ReflectComponentKind::Typed(fns) => (fns.reflect_mut)(entity.into()),
+        }
     }
 
     /// # Safety
@@ -235,7 +284,12 @@ impl ReflectComponent {
         entity: UnsafeEntityCell<'a>,
     ) -> Option<Mut<'a, dyn Reflect>> {
         // SAFETY: safety requirements deferred to caller
-        unsafe { (self.0.reflect_unchecked_mut)(entity) }
+        unsafe {
+            match &self.kind {
+                ReflectComponentKind::Dynamic(dynamic) => dynamic.reflect_unchecked_mut(entity),
+                ReflectComponentKind::Typed(fns) => (fns.reflect_unchecked_mut)(entity),
+            }
+        }
     }
 
     /// Gets the value of this [`Component`] type from entity from `source_world` and [applies](Self::apply()) it to the value of this [`Component`] type in entity in `destination_world`.
@@ -251,18 +305,29 @@ impl ReflectComponent {
         destination_entity: Entity,
         registry: &TypeRegistry,
     ) {
-        (self.0.copy)(
-            source_world,
-            destination_world,
-            source_entity,
-            destination_entity,
-            registry,
-        );
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.copy(
+                source_world,
+                destination_world,
+                source_entity,
+                destination_entity,
+            ),
+            ReflectComponentKind::Typed(fns) => (fns.copy)(
+                source_world,
+                destination_world,
+                source_entity,
+                destination_entity,
+                registry,
+            ),
+        }
     }
 
     /// Register the type of this [`Component`] in [`World`], returning its [`ComponentId`].
     pub fn register_component(&self, world: &mut World) -> ComponentId {
-        (self.0.register_component)(world)
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.register_component(world),
+            ReflectComponentKind::Typed(fns) => (fns.register_component)(world),
+        }
     }
 
     /// Create a custom implementation of [`ReflectComponent`].
@@ -277,7 +342,9 @@ impl ReflectComponent {
     ///
     /// See [`ReflectComponentFns`] for more information.
     pub fn new(fns: ReflectComponentFns) -> Self {
-        Self(fns)
+        Self {
+            kind: ReflectComponentKind::Typed(fns),
+        }
     }
 
     /// The underlying function pointers implementing methods on `ReflectComponent`.
@@ -294,15 +361,35 @@ impl ReflectComponent {
     /// use `fn_pointers` to get the underlying [`ReflectComponentFns`]
     /// and copy the subset of function pointers you care about.
     ///
+    /// # Panics
+    ///
+    /// Panics for [dynamic](Self::dynamic) components, which are not implemented with function
+    /// pointers. Use [`try_fn_pointers`](Self::try_fn_pointers) if the component may be dynamic.
+    ///
     /// [`TypeRegistration::data::<ReflectComponent>`]: bevy_reflect::TypeRegistration::data
     /// [`TypeRegistry::get`]: bevy_reflect::TypeRegistry::get
     pub fn fn_pointers(&self) -> &ReflectComponentFns {
-        &self.0
+        self.try_fn_pointers()
+            .expect("dynamic components don't have function pointers")
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectComponent`, or [`None`]
+    /// for [dynamic](Self::dynamic) components.
+    ///
+    /// See [`fn_pointers`](Self::fn_pointers) for more information.
+    pub fn try_fn_pointers(&self) -> Option<&ReflectComponentFns> {
+        match &self.kind {
+            ReflectComponentKind::Typed(fns) => Some(fns),
+            ReflectComponentKind::Dynamic(_) => None,
+        }
     }
 
     /// Calls a dynamic version of [`Component::map_entities`].
     pub fn map_entities(&self, component: &mut dyn Reflect, func: &mut dyn EntityMapper) {
-        (self.0.map_entities)(component, func);
+        match &self.kind {
+            ReflectComponentKind::Typed(fns) => (fns.map_entities)(component, func),
+            ReflectComponentKind::Dynamic(_) => dynamic_component::map_entities(component, func),
+        }
     }
 }
 
@@ -310,7 +397,7 @@ impl<C: Component + Reflect + TypePath> FromType<C> for ReflectComponent {
     fn from_type() -> Self {
         // TODO: Currently we panic if a component is immutable and you use
         // reflection to mutate it. Perhaps the mutation methods should be fallible?
-        ReflectComponent(ReflectComponentFns {
+        ReflectComponent::new(ReflectComponentFns {
             insert: |entity, reflected_component, registry| {
                 let component = entity.world_scope(|world| {
                     from_reflect_with_fallback::<C>(reflected_component, world, registry)
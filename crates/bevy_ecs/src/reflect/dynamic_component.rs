@@ -0,0 +1,756 @@
+//! Definitions for dynamic components: components whose type is only described by a [`TypeInfo`],
+//! such as types defined at runtime by scripts or loaded from a schema.
+//!
+//! Dynamic components are registered with [`ReflectComponent::dynamic`], and stored in the
+//! [`World`] as [`DynamicComponent`] values.
+
+use crate::{
+    change_detection::Mut,
+    component::{ComponentCloneBehavior, ComponentDescriptor, ComponentId, StorageType},
+    entity::{ComponentCloneCtx, Entity, EntityMapper, SourceComponent},
+    reflect::{component::ReflectComponentKind, ReflectComponent},
+    relationship::RelationshipHookMode,
+    resource::Resource,
+    world::{
+        unsafe_world_cell::UnsafeEntityCell, EntityMut, EntityWorldMut, FilteredEntityMut,
+        FilteredEntityRef, World, WorldId,
+    },
+};
+use alloc::boxed::Box;
+use bevy_platform::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+use bevy_ptr::{OwningPtr, Ptr};
+use bevy_reflect::{
+    ApplyError, DynamicTyped, PartialReflect, Reflect, ReflectCloneError, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+};
+use core::{alloc::Layout, any::Any, fmt, mem::ManuallyDrop};
+
+/// The value of a dynamic component, as stored in the [`World`].
+///
+/// A dynamic component has no Rust type of its own: its value is a dynamic type, such as a
+/// [`DynamicStruct`](bevy_reflect::structs::DynamicStruct), which represents the [`TypeInfo`]
+/// the component was registered with. [`DynamicComponent`] reflects as this value, so reflecting
+/// the component (with [`ReflectComponent::reflect`] for example) behaves as if it was a value of
+/// the represented type.
+///
+/// See [`ReflectComponent::dynamic`] to register a dynamic component.
+#[derive(TypePath)]
+pub struct DynamicComponent {
+    type_info: &'static TypeInfo,
+    value: Box<dyn PartialReflect>,
+}
+
+impl DynamicComponent {
+    /// Creates a component of the type described by `type_info` from a reflected value.
+    ///
+    /// Returns `None` if the value is not a struct, tuple struct or enum matching the kind of
+    /// `type_info`.
+    pub fn new(type_info: &'static TypeInfo, value: &dyn PartialReflect) -> Option<Self> {
+        let value: Box<dyn PartialReflect> = match (type_info, value.reflect_ref()) {
+            (TypeInfo::Struct(_), ReflectRef::Struct(value)) => {
+                let mut value = value.to_dynamic_struct();
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            (TypeInfo::TupleStruct(_), ReflectRef::TupleStruct(value)) => {
+                let mut value = value.to_dynamic_tuple_struct();
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            (TypeInfo::Enum(_), ReflectRef::Enum(value)) => {
+                let mut value = value.to_dynamic_enum();
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            _ => return None,
+        };
+        Some(Self { type_info, value })
+    }
+
+    /// Returns the [`TypeInfo`] of the component.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+
+    /// Returns the value of the component.
+    pub fn value(&self) -> &dyn PartialReflect {
+        &*self.value
+    }
+
+    /// Returns the value of the component mutably.
+    pub fn value_mut(&mut self) -> &mut dyn PartialReflect {
+        &mut *self.value
+    }
+
+    /// Returns the value of the component.
+    pub fn into_value(self) -> Box<dyn PartialReflect> {
+        self.value
+    }
+}
+
+impl Clone for DynamicComponent {
+    fn clone(&self) -> Self {
+        Self {
+            type_info: self.type_info,
+            value: self.value.to_dynamic(),
+        }
+    }
+}
+
+impl fmt::Debug for DynamicComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.debug(f)
+    }
+}
+
+impl DynamicTyped for DynamicComponent {
+    fn reflect_type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+}
+
+impl PartialReflect for DynamicComponent {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(self.type_info)
+    }
+
+    fn into_partial_reflect(self: Box<Self>) -> Box<dyn PartialReflect> {
+        self
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn try_into_reflect(self: Box<Self>) -> Result<Box<dyn Reflect>, Box<dyn PartialReflect>> {
+        Ok(self)
+    }
+
+    fn try_as_reflect(&self) -> Option<&dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), ApplyError> {
+        self.value.try_apply(value)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        self.value.reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.value.reflect_mut()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        self.value.reflect_owned()
+    }
+
+    fn to_dynamic(&self) -> Box<dyn PartialReflect> {
+        self.value.to_dynamic()
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        self.value.reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
+        self.value.reflect_partial_eq(value)
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.debug(f)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+}
+
+impl Reflect for DynamicComponent {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+}
+
+/// A [`Resource`] mapping the [`ComponentId`]s of the [dynamic](ReflectComponent::dynamic)
+/// components registered in a [`World`] to the type paths of their types.
+///
+/// It is used by [`component_registration`] to find the registration of dynamic components.
+#[derive(Resource, Default, Debug)]
+pub struct DynamicComponentTypePaths {
+    type_paths: HashMap<ComponentId, &'static str>,
+}
+
+impl DynamicComponentTypePaths {
+    /// Returns the type path of the dynamic component `component_id`.
+    pub fn type_path(&self, component_id: ComponentId) -> Option<&'static str> {
+        self.type_paths.get(&component_id).copied()
+    }
+}
+
+/// The state of a [`ReflectComponent`] created with [`ReflectComponent::dynamic`].
+///
+/// Dynamic components are registered lazily in each [`World`] they are used in, so their
+/// [`ComponentId`]s are stored per [`WorldId`].
+#[derive(Clone)]
+pub(super) struct DynamicComponentType {
+    type_info: &'static TypeInfo,
+    component_ids: Arc<RwLock<HashMap<WorldId, ComponentId>>>,
+}
+
+impl DynamicComponentType {
+    pub(super) fn new(type_info: &'static TypeInfo) -> Self {
+        Self {
+            type_info,
+            component_ids: Arc::default(),
+        }
+    }
+
+    fn component_id(&self, world_id: WorldId) -> Option<ComponentId> {
+        self.component_ids
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&world_id)
+            .copied()
+    }
+
+    pub(super) fn register_component(&self, world: &mut World) -> ComponentId {
+        if let Some(id) = self.component_id(world.id()) {
+            return id;
+        }
+        // SAFETY:
+        // - `drop_dynamic_component` drops a `DynamicComponent`, which has the given layout.
+        // - `DynamicComponent` is `Send + Sync`.
+        // - There is no relationship accessor.
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                self.type_info.type_path(),
+                StorageType::Table,
+                Layout::new::<DynamicComponent>(),
+                Some(drop_dynamic_component),
+                true,
+                ComponentCloneBehavior::Custom(clone_dynamic_component),
+                None,
+            )
+        };
+        let id = world.register_component_with_descriptor(descriptor);
+        world
+            .get_resource_or_init::<DynamicComponentTypePaths>()
+            .type_paths
+            .insert(id, self.type_info.type_path());
+        self.component_ids
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(world.id(), id);
+        id
+    }
+
+    fn new_component(&self, component: &dyn PartialReflect) -> DynamicComponent {
+        DynamicComponent::new(self.type_info, component).unwrap_or_else(|| {
+            panic!(
+                "Cannot create dynamic component `{}` from a value of type `{}`",
+                self.type_info.type_path(),
+                component.reflect_type_path()
+            )
+        })
+    }
+
+    fn insert_component(
+        &self,
+        entity: &mut EntityWorldMut,
+        component: DynamicComponent,
+        relationship_hook_mode: RelationshipHookMode,
+    ) {
+        let id = entity.world_scope(|world| self.register_component(world));
+        OwningPtr::make(component, |ptr| {
+            // SAFETY:
+            // - `id` was registered in the world of `entity` above.
+            // - `ptr` points to a `DynamicComponent`, as described by the component `id`.
+            unsafe {
+                entity.insert_by_id_with_caller(
+                    id,
+                    ptr,
+                    crate::bundle::InsertMode::Replace,
+                    crate::change_detection::MaybeLocation::caller(),
+                    relationship_hook_mode,
+                );
+            }
+        });
+    }
+
+    pub(super) fn insert(&self, entity: &mut EntityWorldMut, component: &dyn PartialReflect) {
+        let component = self.new_component(component);
+        self.insert_component(entity, component, RelationshipHookMode::Run);
+    }
+
+    pub(super) fn apply(&self, mut entity: EntityMut, component: &dyn PartialReflect) {
+        let id = self.component_id(entity.as_unsafe_entity_cell().world().id());
+        let entity_id = entity.id();
+        let mut value = id
+            .and_then(|id| entity.get_mut_by_id(id).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Entity {entity_id} does not have dynamic component `{}`",
+                    self.type_info.type_path()
+                )
+            });
+        // SAFETY: dynamic components are stored as `DynamicComponent`s.
+        unsafe { value.as_mut().deref_mut::<DynamicComponent>() }.apply(component);
+    }
+
+    pub(super) fn apply_or_insert_mapped(
+        &self,
+        entity: &mut EntityWorldMut,
+        component: &dyn PartialReflect,
+        mapper: &mut dyn EntityMapper,
+        relationship_hook_mode: RelationshipHookMode,
+    ) {
+        let id = self.component_id(entity.world().id());
+        if let Some(mut value) = id.and_then(|id| entity.get_mut_by_id(id).ok()) {
+            // SAFETY: dynamic components are stored as `DynamicComponent`s.
+            let value = unsafe { value.as_mut().deref_mut::<DynamicComponent>() };
+            value.apply(component);
+            map_reflected_entities(value.value_mut(), mapper);
+        } else {
+            let mut value = self.new_component(component);
+            map_reflected_entities(value.value_mut(), mapper);
+            self.insert_component(entity, value, relationship_hook_mode);
+        }
+    }
+
+    pub(super) fn remove(&self, entity: &mut EntityWorldMut) {
+        if let Some(id) = self.component_id(entity.world().id()) {
+            entity.remove_by_id(id);
+        }
+    }
+
+    pub(super) fn take(&self, entity: &mut EntityWorldMut) -> Option<Box<dyn Reflect>> {
+        let id = self.component_id(entity.world().id())?;
+        let ptr = entity.get_by_id(id).ok()?;
+        // SAFETY: dynamic components are stored as `DynamicComponent`s.
+        let component = unsafe { ptr.deref::<DynamicComponent>() }.clone();
+        entity.remove_by_id(id);
+        Some(Box::new(component))
+    }
+
+    pub(super) fn contains(&self, entity: FilteredEntityRef) -> bool {
+        self.component_id(entity.world_id())
+            .is_some_and(|id| entity.contains_id(id))
+    }
+
+    pub(super) fn reflect<'w>(&self, entity: FilteredEntityRef<'w, '_>) -> Option<&'w dyn Reflect> {
+        let ptr = entity.get_by_id(self.component_id(entity.world_id())?)?;
+        // SAFETY: dynamic components are stored as `DynamicComponent`s.
+        Some(unsafe { ptr.deref::<DynamicComponent>() })
+    }
+
+    pub(super) fn reflect_mut<'w>(
+        &self,
+        mut entity: FilteredEntityMut<'w, '_>,
+    ) -> Option<Mut<'w, dyn Reflect>> {
+        let id = self.component_id(entity.as_unsafe_entity_cell().world().id())?;
+        let value = entity.into_mut_by_id(id)?;
+        Some(value.map_unchanged(|ptr| {
+            // SAFETY: dynamic components are stored as `DynamicComponent`s.
+            unsafe { ptr.deref_mut::<DynamicComponent>() as &mut dyn Reflect }
+        }))
+    }
+
+    /// # Safety
+    /// `entity` must have mutable access to the component.
+    pub(super) unsafe fn reflect_unchecked_mut<'a>(
+        &self,
+        entity: UnsafeEntityCell<'a>,
+    ) -> Option<Mut<'a, dyn Reflect>> {
+        let id = self.component_id(entity.world().id())?;
+        // SAFETY: the caller ensures `entity` may access the component mutably.
+        let value = unsafe { entity.get_mut_by_id(id) }.ok()?;
+        Some(value.map_unchanged(|ptr| {
+            // SAFETY: dynamic components are stored as `DynamicComponent`s.
+            unsafe { ptr.deref_mut::<DynamicComponent>() as &mut dyn Reflect }
+        }))
+    }
+
+    pub(super) fn copy(
+        &self,
+        source_world: &World,
+        destination_world: &mut World,
+        source_entity: Entity,
+        destination_entity: Entity,
+    ) {
+        let component = self
+            .reflect(source_world.entity(source_entity).into())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Entity {source_entity} does not have dynamic component `{}`",
+                    self.type_info.type_path()
+                )
+            })
+            .as_partial_reflect();
+        self.insert(
+            &mut destination_world.entity_mut(destination_entity),
+            component,
+        );
+    }
+}
+
+impl ReflectComponent {
+    /// Creates a [`ReflectComponent`] for a dynamic component: a component of a type described
+    /// by `type_info` which has no Rust type of its own, such as a
+    /// [runtime type](bevy_reflect::Type::runtime).
+    ///
+    /// The component is registered in each [`World`] it is used in with a
+    /// [`ComponentDescriptor`] named after its type path, and its values are stored as
+    /// [`DynamicComponent`]s. They can be inserted from any value of the same kind as
+    /// `type_info` (struct, tuple struct or enum), such as a deserialized dynamic value.
+    ///
+    /// When mapping the entities of a dynamic component, every [`Entity`] it contains is mapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+    /// # use bevy_reflect::{structs::{DynamicStruct, StructInfo}, NamedField, Type, TypeInfo, TypePathTable};
+    /// let type_info: &'static TypeInfo = Box::leak(Box::new(TypeInfo::Struct(StructInfo::from_type(
+    ///     Type::runtime(TypePathTable::from_path("my_game::Health")),
+    ///     &[NamedField::new::<f32>("value")],
+    /// ))));
+    /// let reflect_component = ReflectComponent::dynamic(type_info);
+    ///
+    /// let mut world = World::new();
+    /// let mut health = DynamicStruct::default();
+    /// health.insert("value", 10.0f32);
+    /// let mut entity = world.spawn_empty();
+    /// reflect_component.insert(&mut entity, &health, &Default::default());
+    ///
+    /// let value = reflect_component.reflect(entity.as_readonly()).unwrap();
+    /// assert_eq!(value.reflect_type_info().type_path(), "my_game::Health");
+    /// ```
+    pub fn dynamic(type_info: &'static TypeInfo) -> Self {
+        Self {
+            kind: ReflectComponentKind::Dynamic(DynamicComponentType::new(type_info)),
+        }
+    }
+
+    /// Returns `true` if this was created with [`ReflectComponent::dynamic`].
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self.kind, ReflectComponentKind::Dynamic(_))
+    }
+
+    /// Returns the [`ComponentId`] of this component in `world`, if it is a
+    /// [dynamic](Self::dynamic) component registered in `world`.
+    ///
+    /// Dynamic components have no [`TypeId`](core::any::TypeId), so this can be used to find
+    /// the registration of a component from its [`ComponentId`].
+    pub fn dynamic_component_id(&self, world: &World) -> Option<ComponentId> {
+        match &self.kind {
+            ReflectComponentKind::Dynamic(dynamic) => dynamic.component_id(world.id()),
+            ReflectComponentKind::Typed(_) => None,
+        }
+    }
+}
+
+/// Returns the registration of the type of the component `component_id` of `world`.
+///
+/// Unlike looking up the [`TypeId`](core::any::TypeId) of the component in the registry, this
+/// also finds the registration of [dynamic](ReflectComponent::dynamic) components.
+pub fn component_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    world: &World,
+    component_id: ComponentId,
+) -> Option<&'r TypeRegistration> {
+    match world.components().get_info(component_id)?.type_id() {
+        Some(type_id) => type_registry.get(type_id),
+        None => type_registry.get_with_type_path(
+            world
+                .get_resource::<DynamicComponentTypePaths>()?
+                .type_path(component_id)?,
+        ),
+    }
+}
+
+/// Returns the [`ComponentId`] of the component type of `registration` in `world`, if it has
+/// been registered in `world`.
+///
+/// This is the counterpart of [`component_registration`].
+pub fn registration_component_id(
+    registration: &TypeRegistration,
+    world: &World,
+) -> Option<ComponentId> {
+    match registration.data::<ReflectComponent>() {
+        Some(reflect_component) if reflect_component.is_dynamic() => {
+            reflect_component.dynamic_component_id(world)
+        }
+        _ => world.components().get_valid_id(registration.type_id()),
+    }
+}
+
+/// Maps every [`Entity`] contained in the reflected `value`.
+pub(super) fn map_entities(value: &mut dyn Reflect, mapper: &mut dyn EntityMapper) {
+    map_reflected_entities(value.as_partial_reflect_mut(), mapper);
+}
+
+fn map_reflected_entities(value: &mut dyn PartialReflect, mapper: &mut dyn EntityMapper) {
+    if let Some(entity) = value.try_downcast_mut::<Entity>() {
+        *entity = mapper.get_mapped(*entity);
+        return;
+    }
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_at_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                map_reflected_entities(value.get_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                map_reflected_entities(value.get_mut(index).unwrap(), mapper);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                map_reflected_entities(value.field_at_mut(index).unwrap(), mapper);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// # Safety
+/// `ptr` must point to a [`DynamicComponent`].
+unsafe fn drop_dynamic_component(ptr: OwningPtr<'_>) {
+    // SAFETY: ensured by the caller.
+    unsafe { ptr.drop_as::<DynamicComponent>() };
+}
+
+fn clone_dynamic_component(source: &SourceComponent, ctx: &mut ComponentCloneCtx) {
+    // SAFETY: dynamic components are stored as `DynamicComponent`s.
+    let mut component = unsafe { source.ptr().deref::<DynamicComponent>() }.clone();
+    map_reflected_entities(component.value_mut(), ctx.entity_mapper());
+    let component = ManuallyDrop::new(component);
+    // SAFETY:
+    // - The source and target components are the same dynamic component.
+    // - `component` is a new value which is never dropped, so its ownership is passed to the
+    //   target.
+    unsafe { ctx.write_target_component_ptr(Ptr::from(&*component)) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{component_registration, registration_component_id, DynamicComponent};
+    use crate::{
+        entity::{Entity, EntityHashMap},
+        query::QueryBuilder,
+        reflect::ReflectComponent,
+        world::{FilteredEntityMut, FilteredEntityRef, World},
+    };
+    use alloc::boxed::Box;
+    use bevy_reflect::{
+        structs::{DynamicStruct, StructInfo},
+        NamedField, PartialReflect, Type, TypeInfo, TypePathTable, TypeRegistration, TypeRegistry,
+    };
+
+    fn runtime_struct() -> &'static TypeInfo {
+        let fields = [
+            NamedField::new::<f32>("value"),
+            NamedField::new::<Entity>("target"),
+        ];
+        let ty = Type::runtime(TypePathTable::from_path("my_game::Health"));
+        Box::leak(Box::new(TypeInfo::Struct(StructInfo::from_type(
+            ty, &fields,
+        ))))
+    }
+
+    fn health(value: f32, target: Entity) -> DynamicStruct {
+        let mut health = DynamicStruct::default();
+        health.insert("value", value);
+        health.insert("target", target);
+        health
+    }
+
+    fn field<T: PartialReflect + Copy>(component: &dyn PartialReflect, name: &str) -> T {
+        let bevy_reflect::ReflectRef::Struct(component) = component.reflect_ref() else {
+            panic!("expected a struct");
+        };
+        *component
+            .field(name)
+            .unwrap()
+            .try_downcast_ref::<T>()
+            .unwrap()
+    }
+
+    #[test]
+    fn dynamic_component() {
+        let type_info = runtime_struct();
+        let reflect_component = ReflectComponent::dynamic(type_info);
+        let registry = TypeRegistry::default();
+        let mut world = World::new();
+
+        let id = reflect_component.register_component(&mut world);
+        assert_eq!(reflect_component.register_component(&mut world), id);
+
+        let target = world.spawn_empty().id();
+        let mut entity = world.spawn_empty();
+        reflect_component.insert(&mut entity, &health(10.0, target), &registry);
+        let entity = entity.id();
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(id)
+            .build();
+        let components = query
+            .iter(&world)
+            .map(|entity| {
+                assert!(reflect_component.contains(entity));
+                reflect_component.reflect(entity).unwrap()
+            })
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(components.len(), 1);
+        assert_eq!(
+            components[0].reflect_type_info().type_path(),
+            "my_game::Health"
+        );
+        assert_eq!(
+            field::<f32>(components[0].as_partial_reflect(), "value"),
+            10.0
+        );
+
+        reflect_component.apply(world.entity_mut(entity), &health(20.0, target));
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(id)
+            .build();
+        for entity in query.iter_mut(&mut world) {
+            let mut component = reflect_component.reflect_mut(entity).unwrap();
+            assert_eq!(field::<f32>(component.as_partial_reflect(), "value"), 20.0);
+            component.apply(&health(30.0, target));
+        }
+
+        let clone = world.entity_mut(entity).clone_and_spawn();
+        let component = reflect_component.reflect(world.entity(clone)).unwrap();
+        assert_eq!(field::<f32>(component.as_partial_reflect(), "value"), 30.0);
+
+        let mut component = reflect_component
+            .take(&mut world.entity_mut(entity))
+            .unwrap();
+        assert!(!reflect_component.contains(world.entity(entity)));
+        assert!(component.is::<DynamicComponent>());
+
+        let mapped = world.spawn_empty().id();
+        let mut mapper = EntityHashMap::default();
+        mapper.insert(target, mapped);
+        reflect_component.map_entities(&mut *component, &mut mapper);
+        assert_eq!(
+            field::<Entity>(component.as_partial_reflect(), "target"),
+            mapped
+        );
+
+        reflect_component.remove(&mut world.entity_mut(clone));
+        assert!(!reflect_component.contains(world.entity(clone)));
+    }
+
+    #[test]
+    fn dynamic_component_per_world() {
+        let reflect_component = ReflectComponent::dynamic(runtime_struct());
+        let registry = TypeRegistry::default();
+        let mut source = World::new();
+        let mut destination = World::new();
+        destination.spawn_empty();
+
+        let target = source.spawn_empty().id();
+        let source_entity = source.spawn_empty().id();
+        reflect_component.insert(
+            &mut source.entity_mut(source_entity),
+            &health(1.0, target),
+            &registry,
+        );
+        let destination_entity = destination.spawn_empty().id();
+        reflect_component.copy(
+            &source,
+            &mut destination,
+            source_entity,
+            destination_entity,
+            &registry,
+        );
+
+        let component = reflect_component
+            .reflect(destination.entity(destination_entity))
+            .unwrap();
+        assert_eq!(field::<f32>(component.as_partial_reflect(), "value"), 1.0);
+        assert!(DynamicComponent::new(runtime_struct(), &1.0f32).is_none());
+    }
+    #[test]
+    fn dynamic_component_registration() {
+        let type_info = runtime_struct();
+        let reflect_component = ReflectComponent::dynamic(type_info);
+        assert!(reflect_component.try_fn_pointers().is_none());
+
+        let mut registry = TypeRegistry::default();
+        let mut registration = TypeRegistration::from_type_info(type_info);
+        registration.insert(reflect_component.clone());
+        registry.add_registration(registration);
+
+        let mut world = World::new();
+        let registration = registry.get_with_type_path("my_game::Health").unwrap();
+        assert_eq!(registration_component_id(registration, &world), None);
+
+        let id = reflect_component.register_component(&mut world);
+        assert_eq!(registration_component_id(registration, &world), Some(id));
+        let found = component_registration(&registry, &world, id).unwrap();
+        assert_eq!(found.type_info().type_path(), "my_game::Health");
+        assert!(component_registration(&registry, &World::new(), id).is_none());
+    }
+}
@@ -364,7 +364,7 @@ fn insert_reflect_with_registry_ref(
         .get_represented_type_info()
         .expect("component should represent a type.");
     let type_path = type_info.type_path();
-    let Some(type_registration) = type_registry.get(type_info.type_id()) else {
+    let Some(type_registration) = type_registry.get_with_type(type_info.ty()) else {
         panic!("`{type_path}` should be registered in type registry via `App::register_type<{type_path}>`");
     };
 
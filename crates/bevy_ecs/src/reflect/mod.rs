@@ -12,22 +12,32 @@ use bevy_reflect::{
 };
 
 mod bundle;
+mod callback;
 mod component;
+mod dynamic_component;
 mod entity_commands;
 mod event;
 mod from_world;
 mod map_entities;
 mod message;
+mod reflected_world;
 mod resource;
 
 use bevy_utils::prelude::DebugName;
 pub use bundle::{ReflectBundle, ReflectBundleFns};
+#[cfg(feature = "reflect_functions")]
+pub use callback::{FunctionRef, FunctionRefError};
+pub use callback::{NamedSystems, SystemRef, SystemRefError};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use dynamic_component::{
+    component_registration, registration_component_id, DynamicComponent, DynamicComponentTypePaths,
+};
 pub use entity_commands::ReflectCommandExt;
 pub use event::{ReflectEvent, ReflectEventFns};
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::ReflectMapEntities;
 pub use message::{ReflectMessage, ReflectMessageFns};
+pub use reflected_world::{DynamicQuery, DynamicQueryItem, ReflectedWorld, ReflectedWorldError};
 pub use resource::ReflectResource;
 
 /// A [`Resource`] storing [`TypeRegistry`] for
@@ -0,0 +1,725 @@
+//! A [`World`] access layer that only deals in type paths and reflected values.
+//!
+//! [`ReflectedWorld`] wraps a [`World`] and a [`TypeRegistry`], and exposes the operations a
+//! scripting language needs without any Rust types: components are named by their type path
+//! (or short type path), values are passed as `&dyn PartialReflect` and returned as
+//! `Box<dyn PartialReflect>`, and queries are built at runtime as [`DynamicQuery`]s.
+
+use crate::{
+    component::{ComponentId, ComponentInfo},
+    entity::Entity,
+    query::{QueryBuilder, QueryState},
+    reflect::{
+        dynamic_component::{registration_component_id, DynamicComponent},
+        AppTypeRegistry, ReflectBundle, ReflectComponent, ReflectFromWorld,
+    },
+    world::{FilteredEntityRef, World},
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use bevy_reflect::{
+    std_traits::ReflectDefault, ApplyError, PartialReflect, ReflectFromReflect, ReflectPath,
+    TypeRegistration, TypeRegistry, TypeRegistryArc,
+};
+use thiserror::Error;
+
+/// A [`World`] whose components are accessed by type path, with reflected values.
+///
+/// This is a stable surface for scripting languages and other runtime tooling to wrap: every
+/// operation takes type paths and [`PartialReflect`] values, and returns owned
+/// `Box<dyn PartialReflect>` values, so bindings don't need to know about any component type.
+///
+/// Types are looked up by their full [type path](bevy_reflect::TypePath::type_path) first, then by
+/// their short type path. Both regular and [dynamic](ReflectComponent::dynamic) components are
+/// supported.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, reflect::{AppTypeRegistry, ReflectedWorld}};
+/// # use bevy_reflect::{structs::DynamicStruct, Reflect};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Health>();
+///
+/// let mut world = ReflectedWorld::new(&mut world);
+/// let mut health = DynamicStruct::default();
+/// health.insert("current", 5.0f32);
+/// health.insert("max", 10.0f32);
+/// let entity = world.spawn([("Health", &health as _)]).unwrap();
+///
+/// world.set_path(entity, "Health", ".current", &8.0f32).unwrap();
+/// let current = world.get_path(entity, "Health", ".current").unwrap();
+/// assert_eq!(current.try_downcast_ref::<f32>(), Some(&8.0));
+///
+/// let mut query = world.query(&["Health"]).unwrap();
+/// let items = query.results(world.world());
+/// assert_eq!(items[0].entity, entity);
+/// ```
+pub struct ReflectedWorld<'w> {
+    world: &'w mut World,
+    type_registry: TypeRegistryArc,
+}
+
+/// An error returned by [`ReflectedWorld`] operations.
+#[derive(Error, Debug)]
+pub enum ReflectedWorldError {
+    /// No type is registered with the given type path.
+    #[error("no type is registered with the path `{0}`")]
+    UnknownType(String),
+    /// The type is not a component, or is not a component or bundle when inserting.
+    #[error("the type `{0}` is not a reflected component or bundle")]
+    NotAComponent(String),
+    /// The value can't be converted to the type it is inserted as.
+    #[error("the value of type `{received}` can't be converted to `{expected}`")]
+    InvalidValue {
+        /// The type path of the inserted type.
+        expected: String,
+        /// The type path of the value.
+        received: String,
+    },
+    /// The entity doesn't exist.
+    #[error("the entity {0} does not exist")]
+    NoSuchEntity(Entity),
+    /// The entity doesn't have the component.
+    #[error("the entity {entity} has no component `{type_path}`")]
+    MissingComponent {
+        /// The entity.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// The component is immutable, so its fields can't be set.
+    #[error("the component `{0}` is immutable")]
+    ImmutableComponent(String),
+    /// The path doesn't lead to a value in the component.
+    #[error("the path `{path}` is not valid: {message}")]
+    InvalidPath {
+        /// The path.
+        path: String,
+        /// The reason the path could not be accessed.
+        message: String,
+    },
+    /// The value couldn't be applied to the value at a path.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+    /// No function is registered with the given name.
+    #[cfg(feature = "reflect_functions")]
+    #[error("no function is registered with the name `{0}`")]
+    UnknownFunction(String),
+    /// A function returned an error.
+    #[cfg(feature = "reflect_functions")]
+    #[error(transparent)]
+    Function(#[from] bevy_reflect::func::FunctionError),
+}
+
+/// A query built at runtime from component type paths, with [`ReflectedWorld::query`].
+///
+/// The query is tied to the [`World`] it was built for.
+pub struct DynamicQuery {
+    state: QueryState<FilteredEntityRef<'static, 'static>>,
+    components: Vec<ReflectComponent>,
+}
+
+/// An entity matched by a [`DynamicQuery`], with copies of its fetched components.
+#[derive(Debug)]
+pub struct DynamicQueryItem {
+    /// The matched entity.
+    pub entity: Entity,
+    /// The values of the fetched components, in the order they were passed to the query.
+    pub components: Vec<Box<dyn PartialReflect>>,
+}
+
+impl<'w> ReflectedWorld<'w> {
+    /// Wraps `world`, using its [`AppTypeRegistry`] to look up types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `world` has no [`AppTypeRegistry`] resource.
+    pub fn new(world: &'w mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        Self::with_type_registry(world, type_registry)
+    }
+
+    /// Wraps `world`, using `type_registry` to look up types.
+    pub fn with_type_registry(world: &'w mut World, type_registry: TypeRegistryArc) -> Self {
+        Self {
+            world,
+            type_registry,
+        }
+    }
+
+    /// Returns the wrapped world.
+    pub fn world(&self) -> &World {
+        self.world
+    }
+
+    /// Returns the wrapped world mutably.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.world
+    }
+
+    /// Returns the registry used to look up types.
+    pub fn type_registry(&self) -> &TypeRegistryArc {
+        &self.type_registry
+    }
+
+    /// Spawns an entity with the given components and bundles, each given by its type path
+    /// and value.
+    ///
+    /// If any value can't be inserted, the entity is despawned and the error is returned.
+    pub fn spawn<'a>(
+        &mut self,
+        bundle: impl IntoIterator<Item = (&'a str, &'a dyn PartialReflect)>,
+    ) -> Result<Entity, ReflectedWorldError> {
+        let entity = self.world.spawn_empty().id();
+        for (type_path, value) in bundle {
+            if let Err(error) = self.insert(entity, type_path, value) {
+                self.world.despawn(entity);
+                return Err(error);
+            }
+        }
+        Ok(entity)
+    }
+
+    /// Inserts a component or bundle of type `type_path` into `entity`, replacing the
+    /// existing values.
+    ///
+    /// `value` doesn't need to be of the concrete type: it is converted using the reflected
+    /// [`FromReflect`](bevy_reflect::FromReflect), `Default` or [`FromWorld`](crate::world::FromWorld)
+    /// of the type.
+    pub fn insert(
+        &mut self,
+        entity: Entity,
+        type_path: &str,
+        value: &dyn PartialReflect,
+    ) -> Result<(), ReflectedWorldError> {
+        let type_registry = self.type_registry.read();
+        let registration = registration(&type_registry, type_path)?;
+        let reflect_component = registration.data::<ReflectComponent>();
+        let reflect_bundle = registration.data::<ReflectBundle>();
+        if reflect_component.is_none() && reflect_bundle.is_none() {
+            return Err(ReflectedWorldError::NotAComponent(type_path.to_owned()));
+        }
+        if !can_convert(registration, value, self.world) {
+            return Err(ReflectedWorldError::InvalidValue {
+                expected: registration.type_info().type_path().to_owned(),
+                received: value.reflect_type_path().to_owned(),
+            });
+        }
+
+        let mut entity_mut = self
+            .world
+            .get_entity_mut(entity)
+            .map_err(|_| ReflectedWorldError::NoSuchEntity(entity))?;
+        match (reflect_component, reflect_bundle) {
+            (Some(reflect_component), _) => {
+                reflect_component.insert(&mut entity_mut, value, &type_registry);
+            }
+            (None, Some(reflect_bundle)) => {
+                reflect_bundle.insert(&mut entity_mut, value, &type_registry);
+            }
+            (None, None) => unreachable!("checked above"),
+        }
+        Ok(())
+    }
+
+    /// Removes the component of type `type_path` from `entity`, returning its value if it had
+    /// one.
+    pub fn remove(
+        &mut self,
+        entity: Entity,
+        type_path: &str,
+    ) -> Result<Option<Box<dyn PartialReflect>>, ReflectedWorldError> {
+        let type_registry = self.type_registry.read();
+        let reflect_component = reflect_component(&type_registry, type_path)?;
+        let mut entity_mut = self
+            .world
+            .get_entity_mut(entity)
+            .map_err(|_| ReflectedWorldError::NoSuchEntity(entity))?;
+        Ok(reflect_component
+            .take(&mut entity_mut)
+            .map(PartialReflect::into_partial_reflect))
+    }
+
+    /// Returns a copy of the component of type `type_path` of `entity`.
+    pub fn get(
+        &self,
+        entity: Entity,
+        type_path: &str,
+    ) -> Result<Box<dyn PartialReflect>, ReflectedWorldError> {
+        self.get_path(entity, type_path, "")
+    }
+
+    /// Returns a copy of the value at `path` in the component of type `type_path` of `entity`.
+    ///
+    /// `path` uses the syntax of [`GetPath`](bevy_reflect::GetPath), such as `.translation.x`.
+    pub fn get_path(
+        &self,
+        entity: Entity,
+        type_path: &str,
+        path: &str,
+    ) -> Result<Box<dyn PartialReflect>, ReflectedWorldError> {
+        let type_registry = self.type_registry.read();
+        let reflect_component = reflect_component(&type_registry, type_path)?;
+        let entity_ref = self
+            .world
+            .get_entity(entity)
+            .map_err(|_| ReflectedWorldError::NoSuchEntity(entity))?;
+        let component = reflect_component.reflect(entity_ref).ok_or_else(|| {
+            ReflectedWorldError::MissingComponent {
+                entity,
+                type_path: type_path.to_owned(),
+            }
+        })?;
+        let value = path
+            .reflect_element(component.as_partial_reflect())
+            .map_err(|error| invalid_path(path, error))?;
+        Ok(to_owned_value(value))
+    }
+
+    /// Applies `value` to the value at `path` in the component of type `type_path` of `entity`.
+    ///
+    /// An empty `path` applies `value` to the whole component.
+    pub fn set_path(
+        &mut self,
+        entity: Entity,
+        type_path: &str,
+        path: &str,
+        value: &dyn PartialReflect,
+    ) -> Result<(), ReflectedWorldError> {
+        let type_registry = self.type_registry.read();
+        let registration = registration(&type_registry, type_path)?;
+        let reflect_component = registration
+            .data::<ReflectComponent>()
+            .ok_or_else(|| ReflectedWorldError::NotAComponent(type_path.to_owned()))?;
+        let component_id = component_id_of(self.world, registration, reflect_component);
+        if !self
+            .world
+            .components()
+            .get_info(component_id)
+            .is_some_and(ComponentInfo::mutable)
+        {
+            return Err(ReflectedWorldError::ImmutableComponent(
+                type_path.to_owned(),
+            ));
+        }
+
+        let entity_mut = self
+            .world
+            .get_entity_mut(entity)
+            .map_err(|_| ReflectedWorldError::NoSuchEntity(entity))?;
+        let mut component = reflect_component.reflect_mut(entity_mut).ok_or_else(|| {
+            ReflectedWorldError::MissingComponent {
+                entity,
+                type_path: type_path.to_owned(),
+            }
+        })?;
+        path.reflect_element_mut(component.as_partial_reflect_mut())
+            .map_err(|error| invalid_path(path, error))?
+            .try_apply(value)?;
+        Ok(())
+    }
+
+    /// Builds a query for the entities that have all the components in `components`, fetching
+    /// them in order.
+    pub fn query(&mut self, components: &[&str]) -> Result<DynamicQuery, ReflectedWorldError> {
+        self.query_filtered(components, &[], &[])
+    }
+
+    /// Builds a query for the entities that have all the components in `components` and
+    /// `with`, and none of the components in `without`. Only the components in `components`
+    /// are fetched, in order.
+    pub fn query_filtered(
+        &mut self,
+        components: &[&str],
+        with: &[&str],
+        without: &[&str],
+    ) -> Result<DynamicQuery, ReflectedWorldError> {
+        let type_registry = self.type_registry.read();
+        let mut resolve = |type_path: &str| {
+            let registration = registration(&type_registry, type_path)?;
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .ok_or_else(|| ReflectedWorldError::NotAComponent(type_path.to_owned()))?;
+            Ok::<_, ReflectedWorldError>((
+                component_id_of(self.world, registration, reflect_component),
+                reflect_component.clone(),
+            ))
+        };
+        let fetched = components
+            .iter()
+            .map(|type_path| resolve(type_path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let with = with
+            .iter()
+            .map(|type_path| resolve(type_path).map(|(id, _)| id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let without = without
+            .iter()
+            .map(|type_path| resolve(type_path).map(|(id, _)| id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut builder = QueryBuilder::<FilteredEntityRef>::new(self.world);
+        for (id, _) in &fetched {
+            builder.ref_id(*id);
+        }
+        for id in with {
+            builder.with_id(id);
+        }
+        for id in without {
+            builder.without_id(id);
+        }
+        Ok(DynamicQuery {
+            state: builder.build(),
+            components: fetched
+                .into_iter()
+                .map(|(_, reflect_component)| reflect_component)
+                .collect(),
+        })
+    }
+
+    /// Calls the function registered as `name` in the [`AppFunctionRegistry`] of the world
+    /// with the given arguments, returning its result.
+    ///
+    /// If the function returns a reference, a copy of the referenced value is returned.
+    ///
+    /// [`AppFunctionRegistry`]: crate::reflect::AppFunctionRegistry
+    #[cfg(feature = "reflect_functions")]
+    pub fn call(
+        &mut self,
+        name: &str,
+        args: impl IntoIterator<Item = Box<dyn PartialReflect>>,
+    ) -> Result<Box<dyn PartialReflect>, ReflectedWorldError> {
+        use bevy_reflect::func::{ArgList, Return};
+
+        let function_registry = self
+            .world
+            .get_resource::<crate::reflect::AppFunctionRegistry>()
+            .ok_or_else(|| ReflectedWorldError::UnknownFunction(name.to_owned()))?
+            .read();
+        let function = function_registry
+            .get(name)
+            .ok_or_else(|| ReflectedWorldError::UnknownFunction(name.to_owned()))?;
+        let mut arg_list = ArgList::new();
+        for arg in args {
+            arg_list.push_boxed(arg);
+        }
+        Ok(match function.call(arg_list)? {
+            Return::Owned(value) => value,
+            Return::Ref(value) => to_owned_value(value),
+            Return::Mut(value) => to_owned_value(value),
+        })
+    }
+}
+
+impl DynamicQuery {
+    /// Returns the entities matched by the query, with copies of their fetched components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `world` is not the world the query was built for.
+    pub fn results(&mut self, world: &World) -> Vec<DynamicQueryItem> {
+        let components = &self.components;
+        self.state
+            .iter(world)
+            .map(|entity| DynamicQueryItem {
+                entity: entity.id(),
+                components: components
+                    .iter()
+                    .map(|reflect_component| {
+                        let component = reflect_component
+                            .reflect(entity)
+                            .expect("the query fetches all the components");
+                        to_owned_value(component.as_partial_reflect())
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Returns the entities matched by the query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `world` is not the world the query was built for.
+    pub fn entities(&mut self, world: &World) -> Vec<Entity> {
+        self.state.iter(world).map(|entity| entity.id()).collect()
+    }
+}
+
+fn registration<'r>(
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<&'r TypeRegistration, ReflectedWorldError> {
+    type_registry
+        .get_with_type_path(type_path)
+        .or_else(|| type_registry.get_with_short_type_path(type_path))
+        .ok_or_else(|| ReflectedWorldError::UnknownType(type_path.to_owned()))
+}
+
+/// Returns the [`ComponentId`] of a component type, registering it if needed.
+fn component_id_of(
+    world: &mut World,
+    registration: &TypeRegistration,
+    reflect_component: &ReflectComponent,
+) -> ComponentId {
+    registration_component_id(registration, world)
+        .unwrap_or_else(|| reflect_component.register_component(world))
+}
+
+fn reflect_component<'r>(
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<&'r ReflectComponent, ReflectedWorldError> {
+    registration(type_registry, type_path)?
+        .data::<ReflectComponent>()
+        .ok_or_else(|| ReflectedWorldError::NotAComponent(type_path.to_owned()))
+}
+
+/// Returns `true` if inserting `value` as the type of `registration` won't panic.
+///
+/// This mirrors the fallbacks of [`from_reflect_with_fallback`](crate::reflect::from_reflect_with_fallback):
+/// a value created with `Default` or `FromWorld` must also accept `value` being applied to it.
+fn can_convert(
+    registration: &TypeRegistration,
+    value: &dyn PartialReflect,
+    world: &mut World,
+) -> bool {
+    if registration
+        .data::<ReflectComponent>()
+        .is_some_and(ReflectComponent::is_dynamic)
+    {
+        return DynamicComponent::new(registration.type_info(), value).is_some();
+    }
+    if registration
+        .data::<ReflectFromReflect>()
+        .is_some_and(|from_reflect| from_reflect.from_reflect(value).is_some())
+    {
+        return true;
+    }
+    let mut fallback = if let Some(reflect_default) = registration.data::<ReflectDefault>() {
+        reflect_default.default()
+    } else if let Some(reflect_from_world) = registration.data::<ReflectFromWorld>() {
+        reflect_from_world.from_world(world)
+    } else {
+        return false;
+    };
+    fallback.try_apply(value).is_ok()
+}
+
+fn invalid_path(path: &str, error: impl core::fmt::Display) -> ReflectedWorldError {
+    ReflectedWorldError::InvalidPath {
+        path: path.to_owned(),
+        message: alloc::format!("{error}"),
+    }
+}
+
+fn to_owned_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    value
+        .reflect_clone()
+        .map(PartialReflect::into_partial_reflect)
+        .unwrap_or_else(|_| value.to_dynamic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{component::Component, prelude::*};
+    use bevy_reflect::{structs::DynamicStruct, Reflect};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name(String);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    #[component(immutable)]
+    struct Team(u8);
+
+    #[derive(Bundle, Reflect)]
+    #[reflect(Bundle)]
+    struct Unit {
+        health: Health,
+        name: Name,
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Name>();
+            registry.register::<Team>();
+            registry.register::<Unit>();
+        }
+        world
+    }
+
+    #[test]
+    fn spawn_get_and_set() {
+        let mut world = world();
+        let mut reflected = ReflectedWorld::new(&mut world);
+
+        let mut health = DynamicStruct::default();
+        health.insert("current", 3.0f32);
+        let name = Name("Ferris".into());
+        let entity = reflected
+            .spawn([("Health", &health as _), ("Name", &name as _)])
+            .unwrap();
+
+        let value = reflected.get(entity, "Health").unwrap();
+        assert_eq!(
+            value.try_downcast_ref::<Health>(),
+            Some(&Health {
+                current: 3.0,
+                max: 0.0
+            })
+        );
+
+        reflected
+            .set_path(entity, "Health", ".max", &10.0f32)
+            .unwrap();
+        let max = reflected.get_path(entity, "Health", ".max").unwrap();
+        assert_eq!(max.try_downcast_ref::<f32>(), Some(&10.0));
+
+        assert!(matches!(
+            reflected.set_path(entity, "Health", ".missing", &1.0f32),
+            Err(ReflectedWorldError::InvalidPath { .. })
+        ));
+        assert!(matches!(
+            reflected.get(entity, "Team"),
+            Err(ReflectedWorldError::MissingComponent { .. })
+        ));
+        assert!(matches!(
+            reflected.get(entity, "Unknown"),
+            Err(ReflectedWorldError::UnknownType(_))
+        ));
+
+        reflected.insert(entity, "Team", &Team(1)).unwrap();
+        assert!(matches!(
+            reflected.set_path(entity, "Team", ".0", &2u8),
+            Err(ReflectedWorldError::ImmutableComponent(_))
+        ));
+
+        let removed = reflected.remove(entity, "Name").unwrap().unwrap();
+        assert_eq!(removed.try_downcast_ref::<Name>(), Some(&name));
+        assert!(world.get::<Name>(entity).is_none());
+        assert_eq!(world.get::<Health>(entity).unwrap().max, 10.0);
+    }
+
+    #[test]
+    fn spawn_bundles_and_reject_invalid_values() {
+        let mut world = world();
+        let mut reflected = ReflectedWorld::new(&mut world);
+
+        let unit = Unit {
+            health: Health {
+                current: 1.0,
+                max: 2.0,
+            },
+            name: Name("Bevy".into()),
+        };
+        let entity = reflected.spawn([("Unit", &unit as _)]).unwrap();
+        assert_eq!(world.get::<Name>(entity), Some(&Name("Bevy".into())));
+
+        let mut reflected = ReflectedWorld::new(&mut world);
+        let entities = reflected.world().entities().len();
+        let error = reflected
+            .spawn([("Health", &Health::default() as _), ("Name", &5u32 as _)])
+            .unwrap_err();
+        assert!(matches!(error, ReflectedWorldError::InvalidValue { .. }));
+        assert_eq!(reflected.world().entities().len(), entities);
+    }
+
+    #[test]
+    fn reject_mismatched_values_for_default_components() {
+        let mut world = world();
+        let entity = world.spawn_empty().id();
+        let mut reflected = ReflectedWorld::new(&mut world);
+
+        // `Health` can be created with `Default`, but a `u32` can't be applied to it.
+        let error = reflected.insert(entity, "Health", &5u32).unwrap_err();
+        assert!(matches!(error, ReflectedWorldError::InvalidValue { .. }));
+        assert!(world.get::<Health>(entity).is_none());
+
+        // A partial value is applied on top of the default.
+        let mut health = DynamicStruct::default();
+        health.insert("max", 4.0f32);
+        let mut reflected = ReflectedWorld::new(&mut world);
+        reflected.insert(entity, "Health", &health).unwrap();
+        assert_eq!(
+            world.get::<Health>(entity),
+            Some(&Health {
+                current: 0.0,
+                max: 4.0
+            })
+        );
+    }
+
+    #[test]
+    fn dynamic_query() {
+        let mut world = world();
+        let a = world.spawn((Health::default(), Name("a".into()))).id();
+        world.spawn((Health::default(), Name("b".into()), Team(0)));
+        world.spawn(Name("c".into()));
+
+        let mut reflected = ReflectedWorld::new(&mut world);
+        let mut query = reflected
+            .query_filtered(&["Name", "Health"], &[], &["Team"])
+            .unwrap();
+        let items = query.results(reflected.world());
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].entity, a);
+        assert_eq!(
+            items[0].components[0].try_downcast_ref::<Name>(),
+            Some(&Name("a".into()))
+        );
+        assert!(items[0].components[1]
+            .try_downcast_ref::<Health>()
+            .is_some());
+
+        let mut query = reflected.query(&["Name"]).unwrap();
+        assert_eq!(query.entities(reflected.world()).len(), 3);
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn call_function() {
+        use crate::reflect::AppFunctionRegistry;
+
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let mut world = world();
+        world.init_resource::<AppFunctionRegistry>();
+        world
+            .resource::<AppFunctionRegistry>()
+            .write()
+            .register_with_name("add", add)
+            .unwrap();
+
+        let mut reflected = ReflectedWorld::new(&mut world);
+        let result = reflected
+            .call("add", [Box::new(2i32) as _, Box::new(3i32) as _])
+            .unwrap();
+        assert_eq!(result.try_downcast_ref::<i32>(), Some(&5));
+        assert!(matches!(
+            reflected.call("sub", []),
+            Err(ReflectedWorldError::UnknownFunction(_))
+        ));
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     component::{Component, ComponentId, Mutable},
     entity::{ContainsEntity, Entity, EntityEquivalent, EntityLocation},
     query::Access,
-    world::{unsafe_world_cell::UnsafeEntityCell, EntityMut, EntityRef, Mut, Ref},
+    world::{unsafe_world_cell::UnsafeEntityCell, EntityMut, EntityRef, Mut, Ref, WorldId},
 };
 
 use bevy_ptr::Ptr;
@@ -91,6 +91,12 @@ impl<'w, 's> FilteredEntityRef<'w, 's> {
         self.entity.archetype()
     }
 
+    /// Returns the [`WorldId`] of the [`World`](crate::world::World) the current entity belongs to.
+    #[inline]
+    pub fn world_id(&self) -> WorldId {
+        self.entity.world().id()
+    }
+
     /// Returns a reference to the underlying [`Access`].
     #[inline]
     pub fn access(&self) -> &Access {
@@ -616,6 +622,22 @@ impl<'w, 's> FilteredEntityMut<'w, 's> {
             .flatten()
     }
 
+    /// Consumes self and gets a [`MutUntyped<'w>`] of the component of the given [`ComponentId`]
+    /// with the world `'w` lifetime for the current entity.
+    ///
+    /// **You should prefer to use the typed API [`Self::into_mut`] where possible and only
+    /// use this in cases where the actual component types are not known at
+    /// compile time.**
+    #[inline]
+    pub fn into_mut_by_id(self, component_id: ComponentId) -> Option<MutUntyped<'w>> {
+        self.access
+            .has_write(component_id)
+            // SAFETY: We have write access, and self is consumed so no other reference to the
+            // component can be created through it.
+            .then(|| unsafe { self.entity.get_mut_by_id(component_id).ok() })
+            .flatten()
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     #[inline]
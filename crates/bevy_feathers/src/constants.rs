@@ -59,3 +59,34 @@ pub mod size {
     /// Extra-small font size
     pub const EXTRA_SMALL_FONT: FontSize = FontSize::Px(11.0);
 }
+
+/// Timing of animated changes to widget styles
+pub mod transitions {
+    use core::time::Duration;
+
+    use bevy_math::curve::EaseFunction;
+    use bevy_ui::{Transition, TransitionProperty};
+
+    /// Duration of color changes, such as when hovering or pressing a widget
+    pub const COLOR_DURATION: Duration = Duration::from_millis(120);
+
+    /// Duration of moving parts, such as the slide of a toggle switch
+    pub const MOTION_DURATION: Duration = Duration::from_millis(150);
+
+    /// Easing used for all widget transitions
+    pub const EASING: EaseFunction = EaseFunction::CubicOut;
+
+    /// Animates the background and border colors of a widget.
+    pub fn colors() -> Transition {
+        Transition::new(TransitionProperty::BackgroundColor, COLOR_DURATION, EASING).with_property(
+            TransitionProperty::BorderColor,
+            COLOR_DURATION,
+            EASING,
+        )
+    }
+
+    /// Animates the background and border colors and the layout of a widget.
+    pub fn colors_and_motion() -> Transition {
+        colors().with_property(TransitionProperty::Node, MOTION_DURATION, EASING)
+    }
+}
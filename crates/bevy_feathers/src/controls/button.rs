@@ -20,7 +20,7 @@ use bevy_ui::{px, AlignItems, InteractionDisabled, JustifyContent, Node, Pressed
 use bevy_ui_widgets::Button;
 
 use crate::{
-    constants::{fonts, size},
+    constants::{fonts, size, transitions},
     cursor::EntityCursor,
     focus::FocusIndicator,
     font_styles::InheritableFont,
@@ -96,6 +96,7 @@ impl FeathersButton {
             TabIndex(0)
             FocusIndicator
             ThemeBackgroundColor(tokens::BUTTON_BG)
+            template_value(transitions::colors())
             InheritableThemeTextColor(tokens::BUTTON_TEXT)
             InheritableFont {
                 font: fonts::REGULAR,
@@ -27,7 +27,7 @@ use bevy_ui::{
 use bevy_ui_widgets::{ActivateOnPress, Checkbox};
 
 use crate::{
-    constants::{fonts, size},
+    constants::{fonts, size, transitions},
     cursor::EntityCursor,
     focus::FocusIndicator,
     font_styles::InheritableFont,
@@ -93,6 +93,7 @@ impl FeathersCheckbox {
                 CheckboxOutline
                 ThemeBackgroundColor(tokens::CHECKBOX_BG)
                 ThemeBorderColor(tokens::CHECKBOX_BORDER)
+                template_value(transitions::colors())
                 FocusIndicator
                 Children [(
                     // Cheesy checkmark: rotated node with L-shaped border.
@@ -110,6 +111,7 @@ impl FeathersCheckbox {
                     UiTransform::from_rotation(Rot2::FRAC_PI_4)
                     CheckboxMark
                     ThemeBorderColor(tokens::CHECKBOX_MARK)
+                    template_value(transitions::colors())
                 )]),
                 {props.caption}
             ]
@@ -0,0 +1,390 @@
+use bevy_app::{Plugin, PreUpdate};
+use bevy_camera::visibility::Visibility;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    lifecycle::{Add, RemovedComponents},
+    observer::On,
+    query::{Added, Changed, Has, Or, With},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_input_focus::tab_navigation::TabIndex;
+use bevy_math::Rot2;
+use bevy_picking::{hover::Hovered, PickingSystems};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_scene::prelude::*;
+use bevy_text::FontWeight;
+use bevy_ui::{
+    percent, px, AlignItems, Display, FlexDirection, JustifyContent, Node, Overflow, PositionType,
+    Selected, UiRect, UiTransform,
+};
+use bevy_ui_widgets::{
+    DataTable, DataTableBody, DataTableHeader, DataTableHeaderCell, DataTableResizeHandle,
+    DataTableSort, SortDirection, VirtualListRow,
+};
+use bevy_window::SystemCursorIcon;
+
+use crate::{
+    constants::{fonts, icons, size},
+    cursor::EntityCursor,
+    display::icon,
+    focus::FocusIndicator,
+    font_styles::InheritableFont,
+    theme::{InheritableThemeTextColor, ThemeBackgroundColor, ThemeBorderColor},
+    tokens,
+};
+
+/// A data table. The table should be given a [`DataTable`] component with its columns, and
+/// contain a [`FeathersDataTableHeader`] and a [`FeathersDataTableBody`].
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersDataTable;
+
+impl FeathersDataTable {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                border: px(1),
+            }
+            FeathersDataTable
+            DataTable
+            ThemeBackgroundColor(tokens::DATATABLE_BG)
+            ThemeBorderColor(tokens::DATATABLE_BORDER)
+            InheritableThemeTextColor(tokens::DATATABLE_TEXT)
+            InheritableFont {
+                font: fonts::REGULAR,
+                font_size: size::COMPACT_FONT,
+                weight: FontWeight::NORMAL,
+            }
+        }
+    }
+}
+
+/// The header row of a [`FeathersDataTable`], which should contain a
+/// [`FeathersDataTableHeaderCell`] for each column.
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersDataTableHeader;
+
+impl FeathersDataTableHeader {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                height: size::ROW_HEIGHT,
+                border: UiRect::bottom(px(1)),
+            }
+            FeathersDataTableHeader
+            DataTableHeader
+            ThemeBackgroundColor(tokens::DATATABLE_HEADER_BG)
+            ThemeBorderColor(tokens::DATATABLE_BORDER)
+        }
+    }
+}
+
+/// A cell of a [`FeathersDataTableHeader`]. The cell shows the sort direction when the table is
+/// sorted by its column, and has a handle on its right edge for resizing the column.
+///
+/// This is spawnable by inheriting it as a "scene component" with optional
+/// [`FeathersDataTableHeaderCellProps`].
+#[derive(SceneComponent, Default, Clone)]
+#[scene(FeathersDataTableHeaderCellProps)]
+pub struct FeathersDataTableHeaderCell;
+
+/// Props used to construct a [`FeathersDataTableHeaderCell`] scene.
+pub struct FeathersDataTableHeaderCellProps {
+    /// Label for this column
+    pub caption: Box<dyn SceneList>,
+    /// The index of the column
+    pub column: usize,
+}
+
+impl Default for FeathersDataTableHeaderCellProps {
+    fn default() -> Self {
+        Self {
+            caption: Box::new(bsn_list!()),
+            column: 0,
+        }
+    }
+}
+
+impl FeathersDataTableHeaderCell {
+    fn scene(props: FeathersDataTableHeaderCellProps) -> impl Scene {
+        bsn! {
+            Node {
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                column_gap: px(4),
+                padding: UiRect::horizontal(px(6)),
+                overflow: Overflow::clip(),
+            }
+            FeathersDataTableHeaderCell
+            DataTableHeaderCell {
+                column: {props.column},
+            }
+            Hovered
+            EntityCursor::System(SystemCursorIcon::Pointer)
+            ThemeBackgroundColor(tokens::DATATABLE_HEADER_BG)
+            InheritableThemeTextColor(tokens::DATATABLE_HEADER_TEXT)
+            InheritableFont {
+                font: fonts::REGULAR,
+                font_size: size::COMPACT_FONT,
+                weight: FontWeight::MEDIUM,
+            }
+            Children [
+                {props.caption},
+                Node {
+                    flex_grow: 1.0,
+                },
+                (
+                    Node {
+                        width: px(12),
+                        height: px(12),
+                        display: Display::Flex,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                    }
+                    DataTableSortIndicator
+                    Visibility::Hidden
+                    Children [
+                        :icon(icons::CHEVRON_DOWN)
+                    ]
+                ),
+                (
+                    Node {
+                        position_type: PositionType::Absolute,
+                        right: px(0),
+                        top: px(0),
+                        width: px(4),
+                        height: percent(100),
+                    }
+                    DataTableResizeHandle {
+                        column: {props.column},
+                    }
+                    Hovered
+                    EntityCursor::System(SystemCursorIcon::ColResize)
+                    ThemeBackgroundColor(tokens::DATATABLE_HEADER_BG)
+                )
+            ]
+        }
+    }
+}
+
+/// Marker for the icon that shows the sort direction of a [`FeathersDataTableHeaderCell`].
+#[derive(Component, Default, Clone, Reflect)]
+#[reflect(Component, Clone, Default)]
+struct DataTableSortIndicator;
+
+/// The scrolling body of a [`FeathersDataTable`]. Its [`VirtualList`](bevy_ui_widgets::VirtualList)
+/// should be given the number of rows of the table.
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersDataTableBody;
+
+impl FeathersDataTableBody {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                flex_grow: 1.0,
+                min_height: px(0),
+                overflow: Overflow::scroll_y(),
+            }
+            FeathersDataTableBody
+            DataTableBody
+            TabIndex(0)
+            FocusIndicator
+        }
+    }
+}
+
+/// Marker for a row of a [`FeathersDataTableBody`].
+#[derive(Component, Default, Clone, Reflect)]
+#[reflect(Component, Clone, Default)]
+struct FeathersDataTableRow;
+
+/// Gives the rows of a [`FeathersDataTableBody`] their theme components when they are spawned.
+fn data_table_on_add_row(
+    add: On<Add, VirtualListRow>,
+    q_row: Query<&ChildOf>,
+    q_body: Query<(), With<FeathersDataTableBody>>,
+    mut commands: Commands,
+) {
+    if q_row
+        .get(add.entity)
+        .is_ok_and(|ChildOf(body)| q_body.contains(*body))
+    {
+        commands.entity(add.entity).insert((
+            FeathersDataTableRow,
+            Hovered::default(),
+            ThemeBackgroundColor(tokens::DATATABLE_BG),
+        ));
+    }
+}
+
+fn update_data_table_row_styles(
+    q_rows: Query<
+        (Entity, Has<Selected>, &Hovered, &ThemeBackgroundColor),
+        (
+            With<FeathersDataTableRow>,
+            Or<(Changed<Hovered>, Added<Selected>)>,
+        ),
+    >,
+    mut commands: Commands,
+) {
+    for (row_ent, selected, hovered, bg_color) in q_rows.iter() {
+        set_row_colors(row_ent, selected, hovered.0, bg_color, &mut commands);
+    }
+}
+
+fn update_data_table_row_styles_remove(
+    q_rows: Query<
+        (Entity, Has<Selected>, &Hovered, &ThemeBackgroundColor),
+        With<FeathersDataTableRow>,
+    >,
+    mut removed_selected: RemovedComponents<Selected>,
+    mut commands: Commands,
+) {
+    removed_selected.read().for_each(|ent| {
+        if let Ok((row_ent, selected, hovered, bg_color)) = q_rows.get(ent) {
+            set_row_colors(row_ent, selected, hovered.0, bg_color, &mut commands);
+        }
+    });
+}
+
+fn set_row_colors(
+    row_ent: Entity,
+    selected: bool,
+    hovered: bool,
+    bg_color: &ThemeBackgroundColor,
+    commands: &mut Commands,
+) {
+    let bg_token = match (selected, hovered) {
+        (true, _) => tokens::DATATABLE_ROW_BG_SELECTED,
+        (false, true) => tokens::DATATABLE_ROW_BG_HOVER,
+        (false, false) => tokens::DATATABLE_BG,
+    };
+
+    // Change background color
+    if bg_color.0 != bg_token {
+        commands
+            .entity(row_ent)
+            .insert(ThemeBackgroundColor(bg_token));
+    }
+}
+
+fn update_data_table_header_styles(
+    q_cells: Query<
+        (
+            Entity,
+            &DataTableHeaderCell,
+            &Hovered,
+            &ThemeBackgroundColor,
+        ),
+        (With<FeathersDataTableHeaderCell>, Changed<Hovered>),
+    >,
+    q_handles: Query<
+        (Entity, &Hovered, &ThemeBackgroundColor),
+        (With<DataTableResizeHandle>, Changed<Hovered>),
+    >,
+    q_parents: Query<&ChildOf>,
+    q_table: Query<&DataTable>,
+    mut commands: Commands,
+) {
+    for (cell_ent, cell, hovered, bg_color) in q_cells.iter() {
+        let sortable = q_parents
+            .iter_ancestors(cell_ent)
+            .find_map(|ancestor| q_table.get(ancestor).ok())
+            .and_then(|table| table.columns.get(cell.column))
+            .is_some_and(|column| column.sortable);
+        let bg_token = match hovered.0 && sortable {
+            true => tokens::DATATABLE_HEADER_BG_HOVER,
+            false => tokens::DATATABLE_HEADER_BG,
+        };
+        if bg_color.0 != bg_token {
+            commands
+                .entity(cell_ent)
+                .insert(ThemeBackgroundColor(bg_token));
+        }
+    }
+
+    for (handle_ent, hovered, bg_color) in q_handles.iter() {
+        let bg_token = match hovered.0 {
+            true => tokens::DATATABLE_RESIZE_HANDLE,
+            false => tokens::DATATABLE_HEADER_BG,
+        };
+        if bg_color.0 != bg_token {
+            commands
+                .entity(handle_ent)
+                .insert(ThemeBackgroundColor(bg_token));
+        }
+    }
+}
+
+/// Shows the sort direction on the header cell of the column the table is sorted by.
+fn update_data_table_sort_indicators(
+    q_cells: Query<
+        (Entity, &DataTableHeaderCell, Ref<Children>),
+        With<FeathersDataTableHeaderCell>,
+    >,
+    q_parents: Query<&ChildOf>,
+    q_table: Query<Ref<DataTableSort>>,
+    mut q_indicator: Query<(&mut Visibility, &mut UiTransform), With<DataTableSortIndicator>>,
+) {
+    for (cell_ent, cell, children) in q_cells.iter() {
+        let Some(sort) = q_parents
+            .iter_ancestors(cell_ent)
+            .find_map(|ancestor| q_table.get(ancestor).ok())
+        else {
+            continue;
+        };
+        if !sort.is_changed() && !children.is_changed() {
+            continue;
+        }
+        let direction = sort
+            .0
+            .filter(|sort| sort.column == cell.column)
+            .map(|sort| sort.direction);
+        for child in children.iter() {
+            let Ok((mut visibility, mut transform)) = q_indicator.get_mut(*child) else {
+                continue;
+            };
+            visibility.set_if_neq(match direction {
+                Some(_) => Visibility::Inherited,
+                None => Visibility::Hidden,
+            });
+            // The chevron points down for descending order, and up for ascending order.
+            transform.rotation = match direction {
+                Some(SortDirection::Ascending) => Rot2::turn_fraction(0.5),
+                _ => Rot2::turn_fraction(0.0),
+            };
+        }
+    }
+}
+
+/// Plugin which registers the observers and systems for the data table styles.
+pub struct DataTablePlugin;
+
+impl Plugin for DataTablePlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_observer(data_table_on_add_row).add_systems(
+            PreUpdate,
+            (
+                update_data_table_row_styles,
+                update_data_table_row_styles_remove,
+                update_data_table_header_styles,
+                update_data_table_sort_indicators,
+            )
+                .in_set(PickingSystems::Last),
+        );
+    }
+}
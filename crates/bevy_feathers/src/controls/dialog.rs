@@ -0,0 +1,164 @@
+use bevy_color::{Alpha, Srgba};
+use bevy_ecs::hierarchy::Children;
+use bevy_scene::prelude::*;
+use bevy_text::FontWeight;
+use bevy_ui::widget::Text;
+use bevy_ui::{
+    percent, px, AlignItems, BoxShadow, Display, FlexDirection, GlobalZIndex, JustifyContent, Node,
+    PositionType,
+};
+use bevy_ui_widgets::{Dialog, DialogButton, DialogResponse};
+
+use crate::{
+    constants::{fonts, size},
+    controls::{ButtonVariant, FeathersButton},
+    font_styles::InheritableFont,
+    rounded_corners::RoundedCorners,
+    theme::{InheritableThemeTextColor, ThemeBackgroundColor, ThemeBorderColor, ThemedText},
+    tokens,
+};
+
+/// A modal dialog. This spawns a backdrop covering the whole window, which blocks interaction
+/// with the UI underneath, with the dialog panel centered on top of it.
+///
+/// The dialog doesn't close itself: observe [`DialogResult`](bevy_ui_widgets::DialogResult) and
+/// [`DialogDismiss`](bevy_ui_widgets::DialogDismiss) on this entity and despawn it.
+///
+/// This is spawnable by inheriting it as a "scene component" with optional [`FeathersDialogProps`].
+#[derive(SceneComponent, Default, Clone)]
+#[scene(FeathersDialogProps)]
+pub struct FeathersDialog;
+
+/// Props used to construct a [`FeathersDialog`] scene.
+pub struct FeathersDialogProps {
+    /// Title of the dialog.
+    pub title: Box<dyn SceneList>,
+    /// Content of the dialog, displayed below the title.
+    pub body: Box<dyn SceneList>,
+    /// Buttons of the dialog, displayed in a row at the bottom. These should have a
+    /// [`DialogButton`] component.
+    pub buttons: Box<dyn SceneList>,
+}
+
+impl Default for FeathersDialogProps {
+    fn default() -> Self {
+        Self {
+            title: Box::new(bsn_list!()),
+            body: Box::new(bsn_list!()),
+            buttons: Box::new(bsn_list!()),
+        }
+    }
+}
+
+impl FeathersDialog {
+    fn scene(props: FeathersDialogProps) -> impl Scene {
+        bsn! {
+            Node {
+                position_type: PositionType::Absolute,
+                left: px(0),
+                top: px(0),
+                width: percent(100),
+                height: percent(100),
+                display: Display::Flex,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+            }
+            FeathersDialog
+            Dialog
+            ThemeBackgroundColor(tokens::DIALOG_BACKDROP)
+            GlobalZIndex(200)
+            Children [
+                (
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        min_width: px(300),
+                        max_width: percent(80),
+                        border: px(1),
+                        padding: px(12),
+                        row_gap: px(12),
+                        border_radius: {RoundedCorners::All.to_border_radius(6.0)},
+                    }
+                    ThemeBackgroundColor(tokens::DIALOG_BG)
+                    ThemeBorderColor(tokens::DIALOG_BORDER)
+                    BoxShadow::new(
+                        Srgba::BLACK.with_alpha(0.9).into(),
+                        px(0),
+                        px(0),
+                        px(1),
+                        px(8),
+                    )
+                    InheritableThemeTextColor(tokens::DIALOG_TEXT)
+                    InheritableFont {
+                        font: fonts::REGULAR,
+                        font_size: size::MEDIUM_FONT,
+                        weight: FontWeight::NORMAL,
+                    }
+                    Children [
+                        (
+                            Node {
+                                display: Display::Flex,
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                            }
+                            InheritableThemeTextColor(tokens::DIALOG_TITLE_TEXT)
+                            InheritableFont {
+                                font: fonts::BOLD,
+                                font_size: size::MEDIUM_FONT,
+                                weight: FontWeight::BOLD,
+                            }
+                            Children [ {props.title} ]
+                        ),
+                        (
+                            Node {
+                                display: Display::Flex,
+                                flex_direction: FlexDirection::Column,
+                                row_gap: px(4),
+                            }
+                            Children [ {props.body} ]
+                        ),
+                        (
+                            Node {
+                                display: Display::Flex,
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::End,
+                                column_gap: px(8),
+                            }
+                            Children [ {props.buttons} ]
+                        ),
+                    ]
+                ),
+            ]
+        }
+    }
+}
+
+/// A confirmation dialog with a title, a message, and "Cancel" and "OK" buttons, which emit a
+/// [`DialogResult`](bevy_ui_widgets::DialogResult) with [`DialogResponse::Cancel`] and
+/// [`DialogResponse::Confirm`] respectively.
+pub fn confirm_dialog(title: impl Into<String>, message: impl Into<String>) -> impl Scene {
+    let title: String = title.into();
+    let message: String = message.into();
+    bsn! {
+        :FeathersDialog {
+            @title: {bsn! { Text(title) ThemedText }},
+            @body: {bsn! { Text(message) ThemedText }},
+            @buttons: {bsn_list! [
+                (
+                    :FeathersButton {
+                        @caption: {bsn! { Text("Cancel") ThemedText }},
+                    }
+                    DialogButton(DialogResponse::Cancel)
+                ),
+                (
+                    :FeathersButton {
+                        @caption: {bsn! { Text("OK") ThemedText }},
+                        @variant: ButtonVariant::Primary,
+                    }
+                    DialogButton(DialogResponse::Confirm)
+                ),
+            ]},
+        }
+    }
+}
@@ -3,7 +3,7 @@ use bevy_ecs::{
     hierarchy::Children,
     lifecycle::RemovedComponents,
     query::{Added, Has, Or, With},
-    schedule::IntoScheduleConfigs,
+    schedule::{common_conditions::resource_changed, IntoScheduleConfigs},
     system::{Query, Res},
 };
 use bevy_input_focus::tab_navigation::TabIndex;
@@ -121,6 +121,36 @@ fn update_toggle_styles_remove(
         });
 }
 
+fn update_toggle_styles_theme(
+    mut q_toggle: Query<
+        (
+            Has<InteractionDisabled>,
+            Has<Checked>,
+            &mut UiTransform,
+            &Children,
+        ),
+        With<FeathersDisclosureToggle>,
+    >,
+    mut q_icon: Query<&mut ImageNode>,
+    theme: Res<UiTheme>,
+) {
+    for (disabled, checked, mut transform, children) in q_toggle.iter_mut() {
+        let Some(child_id) = children.first() else {
+            continue;
+        };
+        let Ok(mut icon_child) = q_icon.get_mut(*child_id) else {
+            continue;
+        };
+        set_toggle_styles(
+            disabled,
+            checked,
+            transform.as_mut(),
+            &mut icon_child,
+            &theme,
+        );
+    }
+}
+
 fn set_toggle_styles(
     disabled: bool,
     checked: bool,
@@ -156,7 +186,12 @@ impl Plugin for DisclosureTogglePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             PreUpdate,
-            (update_toggle_styles, update_toggle_styles_remove).in_set(PickingSystems::Last),
+            (
+                update_toggle_styles,
+                update_toggle_styles_remove,
+                update_toggle_styles_theme.run_if(resource_changed::<UiTheme>),
+            )
+                .in_set(PickingSystems::Last),
         );
     }
 }
@@ -0,0 +1,1466 @@
+use alloc::borrow::Cow;
+
+use bevy_app::{Plugin, PreUpdate};
+use bevy_color::{Color, LinearRgba, Srgba};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::{Component, ComponentId, ComponentInfo},
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::Children,
+    observer::On,
+    query::{Changed, Or, With},
+    reflect::{registration_component_id, AppTypeRegistry, ReflectComponent},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, SystemChangeTick},
+    world::{Ref, World},
+};
+use bevy_input_focus::InputFocus;
+use bevy_log::warn;
+use bevy_picking::PickingSystems;
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{
+    enums::{DynamicEnum, DynamicVariant, VariantInfo},
+    std_traits::ReflectDefault,
+    structs::DynamicStruct,
+    tuple::DynamicTuple,
+    Access, ParsedPath, PartialReflect, ReflectMut, ReflectPath, ReflectRef, Type, TypeInfo,
+    TypePath, TypeRegistry,
+};
+use bevy_scene::prelude::*;
+use bevy_text::{EditableText, TextEdit, TextEditChange};
+use bevy_ui::{
+    percent, px, widget::Text, AlignItems, Checked, Display, FlexDirection, JustifyContent, Node,
+    UiRect,
+};
+use bevy_ui_widgets::{Activate, SliderValue, ValueChange};
+
+use crate::{
+    constants::{icons, size},
+    controls::{
+        ButtonVariant, ColorChannel, ColorSwatchValue, FeathersButton, FeathersCheckbox,
+        FeathersColorSlider, FeathersColorSwatch, FeathersDisclosureToggle, FeathersMenu,
+        FeathersMenuButton, FeathersMenuItem, FeathersMenuPopup, FeathersNumberInput,
+        FeathersTextInput, FeathersTextInputContainer, FeathersToolButton, NumberFormat,
+        NumberInputValue, SliderBaseColor, UpdateNumberInput,
+    },
+    display::{icon, label, label_dim, label_small},
+    theme::ThemedText,
+    tokens,
+};
+
+/// A property editor which displays the fields of a reflected component or resource as editable
+/// feathers controls.
+///
+/// The inspector walks the reflected value of its [`InspectorTarget`] and picks a control for
+/// each field:
+/// * numeric fields use a [`FeathersNumberInput`].
+/// * `bool` fields use a [`FeathersCheckbox`].
+/// * `String` fields use a [`FeathersTextInput`].
+/// * enums use a [`FeathersMenu`] listing the variants. Switching to another variant fills its
+///   fields with their [`Default`] values, so the field types must register [`ReflectDefault`].
+/// * [`Color`], [`Srgba`] and [`LinearRgba`] use a [`FeathersColorSwatch`] and a set of
+///   [`FeathersColorSlider`]s.
+/// * nested structs, tuples, lists and arrays are shown in collapsible groups. Lists also have
+///   buttons for adding and removing elements.
+///
+/// Edits are written back through the target's [`ReflectComponent`], so the target type must be
+/// registered in the [`AppTypeRegistry`] with `#[reflect(Component)]` or `#[reflect(Resource)]`.
+/// Changes made to the target by other systems are displayed as they happen.
+///
+/// This is spawnable by inheriting it as a "scene component" with optional [`FeathersInspectorProps`].
+/// The target can be changed afterwards by inserting a new [`InspectorTarget`].
+#[derive(SceneComponent, Default, Clone)]
+#[scene(FeathersInspectorProps)]
+#[require(InspectorTarget, InspectorState, InspectorDirty)]
+pub struct FeathersInspector;
+
+/// Props used to construct a [`FeathersInspector`] scene.
+#[derive(Default, Clone)]
+pub struct FeathersInspectorProps {
+    /// The value being inspected.
+    pub target: InspectorTarget,
+}
+
+impl FeathersInspector {
+    fn scene(props: FeathersInspectorProps) -> impl Scene {
+        bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: px(4),
+            }
+            FeathersInspector
+            template_value(props.target)
+        }
+    }
+}
+
+/// Identifies the reflected value displayed by a [`FeathersInspector`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub enum InspectorTarget {
+    /// Nothing is inspected.
+    #[default]
+    None,
+    /// A component on an entity. The component type is identified by its type path, which must
+    /// have a registration in the [`AppTypeRegistry`].
+    Component {
+        /// The entity which owns the component.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: Cow<'static, str>,
+    },
+    /// A resource. The resource type is identified by its type path, which must have a
+    /// registration in the [`AppTypeRegistry`].
+    Resource {
+        /// The type path of the resource.
+        type_path: Cow<'static, str>,
+    },
+}
+
+impl InspectorTarget {
+    /// Inspect the component `C` on `entity`.
+    pub fn component<C: Component + TypePath>(entity: Entity) -> Self {
+        Self::Component {
+            entity,
+            type_path: C::type_path().into(),
+        }
+    }
+
+    /// Inspect the resource `R`.
+    pub fn resource<R: Resource + TypePath>() -> Self {
+        Self::Resource {
+            type_path: R::type_path().into(),
+        }
+    }
+}
+
+/// Remembers which groups of an inspector have been expanded by the user.
+#[derive(Component, Default, Clone)]
+struct InspectorState {
+    expanded: HashSet<String>,
+}
+
+/// Marks an inspector whose controls need to be rebuilt.
+#[derive(Component, Default, Clone)]
+struct InspectorDirty;
+
+/// Links a control within an inspector to the field it edits.
+#[derive(Component, Clone)]
+struct InspectorField {
+    /// The inspector which owns this control.
+    inspector: Entity,
+    /// Path from the inspected value to the field.
+    path: ParsedPath,
+    kind: FieldKind,
+}
+
+impl Default for InspectorField {
+    fn default() -> Self {
+        Self {
+            inspector: Entity::PLACEHOLDER,
+            path: ParsedPath(Vec::new()),
+            kind: FieldKind::Group,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum FieldKind {
+    /// A number input.
+    Number,
+    /// A checkbox.
+    Bool,
+    /// A text input.
+    Text,
+    /// A color swatch.
+    Swatch,
+    /// A color slider editing one channel.
+    Channel(ColorChannel),
+    /// The menu of an enum field, displaying the variant it was built for.
+    Enum(String),
+    /// A menu item which selects an enum variant.
+    Variant(&'static str),
+    /// The container of a group that cannot change shape.
+    Group,
+    /// The container of a list or array group, with the length it was built for.
+    Len(usize),
+    /// The disclosure toggle of a group.
+    Expand,
+    /// A button which appends an element to a list.
+    ListAdd,
+    /// A button which removes an element from a list.
+    ListRemove(usize),
+    /// The root of the inspector, recording whether the target existed when it was built.
+    Root(bool),
+}
+
+/// Looks up the entity holding the inspected value, along with its reflection data.
+fn resolve_target<'r>(
+    world: &World,
+    target: &InspectorTarget,
+    type_registry: &'r TypeRegistry,
+) -> Option<(Entity, &'r ReflectComponent, ComponentId)> {
+    let type_path = match target {
+        InspectorTarget::None => return None,
+        InspectorTarget::Component { type_path, .. } | InspectorTarget::Resource { type_path } => {
+            type_path
+        }
+    };
+    let registration = type_registry.get_with_type_path(type_path)?;
+    let reflect_component = registration.data::<ReflectComponent>()?;
+    let component_id = registration_component_id(registration, world)?;
+    let entity = match target {
+        InspectorTarget::Component { entity, .. } => *entity,
+        _ => world.resource_entities().get(component_id)?,
+    };
+    Some((entity, reflect_component, component_id))
+}
+
+/// Runs `edit` on the value inspected by `inspector`.
+fn modify_target(
+    world: &mut World,
+    inspector: Entity,
+    edit: impl FnOnce(&mut dyn PartialReflect, &TypeRegistry),
+) {
+    let Some(target) = world.get::<InspectorTarget>(inspector).cloned() else {
+        return;
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let Some((entity, reflect_component, component_id)) =
+        resolve_target(world, &target, &type_registry)
+    else {
+        return;
+    };
+    if !world
+        .components()
+        .get_info(component_id)
+        .is_some_and(ComponentInfo::mutable)
+    {
+        warn!("Inspector target {:?} is immutable", target);
+        return;
+    }
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let Some(mut value) = reflect_component.reflect_mut(&mut entity_mut) else {
+        return;
+    };
+    edit(value.as_partial_reflect_mut(), &type_registry);
+}
+
+/// Queues `edit` to run on the field edited by the `field` control.
+fn edit_field(
+    commands: &mut Commands,
+    field: &InspectorField,
+    edit: impl FnOnce(&mut dyn PartialReflect, &TypeRegistry) + Send + 'static,
+) {
+    let inspector = field.inspector;
+    let path = field.path.clone();
+    commands.queue(move |world: &mut World| {
+        modify_target(world, inspector, |root, type_registry| {
+            match (&path).reflect_element_mut(root) {
+                Ok(value) => edit(value, type_registry),
+                Err(err) => warn!("Inspector field {} not found: {}", path, err),
+            }
+        });
+    });
+}
+
+/// Builds the controls for a reflected value.
+struct InspectorBuilder<'a> {
+    inspector: Entity,
+    expanded: &'a HashSet<String>,
+}
+
+impl InspectorBuilder<'_> {
+    fn field(&self, path: &[Access<'static>], kind: FieldKind) -> InspectorField {
+        InspectorField {
+            inspector: self.inspector,
+            path: ParsedPath::from(path.to_vec()),
+            kind,
+        }
+    }
+
+    /// Builds one row per field of a struct-like value, or a single row for any other value.
+    fn build_fields(
+        &self,
+        path: &[Access<'static>],
+        value: &dyn PartialReflect,
+    ) -> Vec<Box<dyn Scene>> {
+        let child = |access: Access<'static>| {
+            let mut path = path.to_vec();
+            path.push(access);
+            path
+        };
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => (0..value.field_len())
+                .filter_map(|index| {
+                    let name = value.name_at(index)?.to_string();
+                    let field = value.field_at(index)?;
+                    Some(self.build_value(&child(Access::FieldIndex(index)), name, field))
+                })
+                .collect(),
+            ReflectRef::TupleStruct(value) => (0..value.field_len())
+                .filter_map(|index| {
+                    let field = value.field(index)?;
+                    Some(self.build_value(
+                        &child(Access::TupleIndex(index)),
+                        index.to_string(),
+                        field,
+                    ))
+                })
+                .collect(),
+            ReflectRef::Tuple(value) => (0..value.field_len())
+                .filter_map(|index| {
+                    let field = value.field(index)?;
+                    Some(self.build_value(
+                        &child(Access::TupleIndex(index)),
+                        index.to_string(),
+                        field,
+                    ))
+                })
+                .collect(),
+            ReflectRef::Enum(value) => (0..value.field_len())
+                .filter_map(|index| {
+                    let field = value.field_at(index)?;
+                    let (access, name) = match value.name_at(index) {
+                        Some(name) => (Access::FieldIndex(index), name.to_string()),
+                        None => (Access::TupleIndex(index), index.to_string()),
+                    };
+                    Some(self.build_value(&child(access), name, field))
+                })
+                .collect(),
+            _ => vec![self.build_value(path, String::new(), value)],
+        }
+    }
+
+    /// Builds a row, or a group of rows, which edits a single value.
+    fn build_value(
+        &self,
+        path: &[Access<'static>],
+        caption: String,
+        value: &dyn PartialReflect,
+    ) -> Box<dyn Scene> {
+        if read_color(value).is_some() {
+            return self.color_row(path, caption);
+        }
+        if let Some(format) = number_format(value) {
+            let field = self.field(path, FieldKind::Number);
+            return Box::new(field_row(
+                caption,
+                bsn_list! {
+                    :FeathersNumberInput {
+                        @number_format: format,
+                    }
+                    Node {
+                        flex_grow: 1.0,
+                    }
+                    template_value(field)
+                    on(inspector_on_f32)
+                    on(inspector_on_f64)
+                    on(inspector_on_i32)
+                    on(inspector_on_i64)
+                },
+            ));
+        }
+        if value.try_downcast_ref::<bool>().is_some() {
+            let field = self.field(path, FieldKind::Bool);
+            return Box::new(field_row(
+                caption,
+                bsn_list! {
+                    :FeathersCheckbox
+                    template_value(field)
+                    on(inspector_on_bool)
+                },
+            ));
+        }
+        if value.try_downcast_ref::<String>().is_some() {
+            let field = self.field(path, FieldKind::Text);
+            return Box::new(field_row(
+                caption,
+                bsn_list! {
+                    :FeathersTextInputContainer
+                    Node {
+                        flex_grow: 1.0,
+                    }
+                    Children [
+                        :FeathersTextInput
+                        template_value(field)
+                        on(inspector_on_text_change)
+                    ]
+                },
+            ));
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(struct_value) => {
+                if let Some(axes) = vector_axes(struct_value) {
+                    return self.vector_row(path, caption, &axes);
+                }
+                self.group(path, caption, FieldKind::Group, None, || {
+                    self.build_fields(path, value)
+                })
+            }
+            ReflectRef::TupleStruct(tuple_struct) if tuple_struct.field_len() == 1 => {
+                // Newtypes are edited in place.
+                let mut inner = path.to_vec();
+                inner.push(Access::TupleIndex(0));
+                self.build_value(&inner, caption, tuple_struct.field(0).unwrap())
+            }
+            ReflectRef::TupleStruct(_) | ReflectRef::Tuple(_) => {
+                self.group(path, caption, FieldKind::Group, None, || {
+                    self.build_fields(path, value)
+                })
+            }
+            ReflectRef::Enum(enum_value) => self.enum_row(path, caption, value, enum_value),
+            ReflectRef::List(list) => {
+                let len = list.len();
+                self.group(
+                    path,
+                    caption,
+                    FieldKind::Len(len),
+                    Some(format!("{len} items")),
+                    || {
+                        let mut rows = list
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| self.list_item(path, index, item))
+                            .collect::<Vec<_>>();
+                        let add = self.field(path, FieldKind::ListAdd);
+                        rows.push(Box::new(bsn! {
+                            :FeathersButton {
+                                @caption: {bsn! { Text("Add item") ThemedText }},
+                            }
+                            template_value(add)
+                            on(inspector_on_activate)
+                        }));
+                        rows
+                    },
+                )
+            }
+            ReflectRef::Array(array) => {
+                let len = array.len();
+                self.group(
+                    path,
+                    caption,
+                    FieldKind::Len(len),
+                    Some(format!("{len} items")),
+                    || {
+                        array
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| {
+                                let mut item_path = path.to_vec();
+                                item_path.push(Access::ListIndex(index));
+                                self.build_value(&item_path, index.to_string(), item)
+                            })
+                            .collect()
+                    },
+                )
+            }
+            ReflectRef::Map(map) => {
+                let summary = format!("{} entries", map.len());
+                Box::new(field_row(caption, bsn_list! { :label_dim(summary) }))
+            }
+            ReflectRef::Set(set) => {
+                let summary = format!("{} entries", set.len());
+                Box::new(field_row(caption, bsn_list! { :label_dim(summary) }))
+            }
+            _ => {
+                let summary = value.reflect_short_type_path().to_string();
+                Box::new(field_row(caption, bsn_list! { :label_dim(summary) }))
+            }
+        }
+    }
+
+    /// Builds a collapsible group. The body is only built when the group is expanded.
+    fn group(
+        &self,
+        path: &[Access<'static>],
+        caption: String,
+        kind: FieldKind,
+        summary: Option<String>,
+        body: impl FnOnce() -> Vec<Box<dyn Scene>>,
+    ) -> Box<dyn Scene> {
+        let container = self.field(path, kind);
+        let toggle = self.field(path, FieldKind::Expand);
+        let rows = if self.expanded.contains(&toggle.path.to_string()) {
+            body()
+        } else {
+            Vec::new()
+        };
+        let summary = summary.unwrap_or_default();
+        Box::new(bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: px(4),
+            }
+            template_value(container)
+            Children [
+                (
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: px(6),
+                        min_height: size::ROW_HEIGHT,
+                    }
+                    Children [
+                        (
+                            :FeathersDisclosureToggle
+                            template_value(toggle)
+                            on(inspector_on_bool)
+                        ),
+                        :label(caption),
+                        (
+                            Node {
+                                flex_grow: 1.0,
+                            }
+                        ),
+                        :label_dim(summary),
+                    ]
+                ),
+                (
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        row_gap: px(4),
+                        padding: UiRect::left(px(18)),
+                    }
+                    Children [
+                        {rows}
+                    ]
+                ),
+            ]
+        })
+    }
+
+    /// Builds a list element with a button for removing it.
+    fn list_item(
+        &self,
+        path: &[Access<'static>],
+        index: usize,
+        item: &dyn PartialReflect,
+    ) -> Box<dyn Scene> {
+        let mut item_path = path.to_vec();
+        item_path.push(Access::ListIndex(index));
+        let rows = vec![self.build_value(&item_path, index.to_string(), item)];
+        let remove = self.field(path, FieldKind::ListRemove(index));
+        Box::new(bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Start,
+                column_gap: px(4),
+            }
+            Children [
+                (
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        flex_grow: 1.0,
+                    }
+                    Children [
+                        {rows}
+                    ]
+                ),
+                (
+                    :FeathersToolButton {
+                        @variant: ButtonVariant::Plain,
+                    }
+                    template_value(remove)
+                    on(inspector_on_activate)
+                    Children [
+                        :icon(icons::X)
+                    ]
+                ),
+            ]
+        })
+    }
+
+    /// Builds a menu for choosing an enum variant, followed by the fields of the current variant.
+    fn enum_row(
+        &self,
+        path: &[Access<'static>],
+        caption: String,
+        value: &dyn PartialReflect,
+        enum_value: &dyn bevy_reflect::enums::Enum,
+    ) -> Box<dyn Scene> {
+        let variant_name = enum_value.variant_name().to_string();
+        let menu = self.field(path, FieldKind::Enum(variant_name.clone()));
+        let items = match value.get_represented_type_info() {
+            Some(TypeInfo::Enum(info)) => info
+                .variant_names()
+                .iter()
+                .map(|&name| {
+                    let item = self.field(path, FieldKind::Variant(name));
+                    Box::new(bsn! {
+                        :FeathersMenuItem {
+                            @caption: {bsn! { Text(name) ThemedText }},
+                        }
+                        template_value(item)
+                        on(inspector_on_activate)
+                    }) as Box<dyn Scene>
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let fields = self.build_fields(path, value);
+        let header = Box::new(field_row(
+            caption,
+            bsn_list! {
+                :FeathersMenu
+                Node {
+                    flex_grow: 1.0,
+                }
+                template_value(menu)
+                Children [
+                    (
+                        :FeathersMenuButton {
+                            @caption: {bsn! { Text(variant_name) ThemedText }},
+                        }
+                        Node {
+                            flex_grow: 1.0,
+                        }
+                    ),
+                    (
+                        :FeathersMenuPopup
+                        Children [
+                            {items}
+                        ]
+                    ),
+                ]
+            },
+        )) as Box<dyn Scene>;
+        Box::new(bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: px(4),
+            }
+            Children [
+                {vec![header]},
+                (
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        row_gap: px(4),
+                        padding: UiRect::left(px(18)),
+                    }
+                    Children [
+                        {fields}
+                    ]
+                ),
+            ]
+        })
+    }
+
+    /// Builds a swatch and a set of RGBA sliders for a color value.
+    fn color_row(&self, path: &[Access<'static>], caption: String) -> Box<dyn Scene> {
+        let swatch = self.field(path, FieldKind::Swatch);
+        let sliders = [
+            ColorChannel::Red,
+            ColorChannel::Green,
+            ColorChannel::Blue,
+            ColorChannel::Alpha,
+        ]
+        .into_iter()
+        .map(|channel| {
+            let slider = self.field(path, FieldKind::Channel(channel));
+            Box::new(bsn! {
+                :FeathersColorSlider {
+                    @channel: channel,
+                }
+                template_value(slider)
+                on(inspector_on_f32)
+            }) as Box<dyn Scene>
+        })
+        .collect::<Vec<_>>();
+        let mut rows = vec![Box::new(field_row(
+            caption,
+            bsn_list! {
+                :FeathersColorSwatch
+                Node {
+                    flex_grow: 1.0,
+                }
+                template_value(swatch)
+            },
+        )) as Box<dyn Scene>];
+        rows.extend(sliders);
+        Box::new(bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: px(4),
+            }
+            Children [
+                {rows}
+            ]
+        })
+    }
+
+    /// Builds a row of number inputs for a vector-like struct such as `Vec3` or `Quat`.
+    fn vector_row(
+        &self,
+        path: &[Access<'static>],
+        caption: String,
+        axes: &[(&'static str, NumberFormat)],
+    ) -> Box<dyn Scene> {
+        let inputs = axes
+            .iter()
+            .enumerate()
+            .map(|(index, &(axis, format))| {
+                let mut axis_path = path.to_vec();
+                axis_path.push(Access::FieldIndex(index));
+                let field = self.field(&axis_path, FieldKind::Number);
+                let sigil = match index {
+                    0 => tokens::TEXT_INPUT_X_AXIS,
+                    1 => tokens::TEXT_INPUT_Y_AXIS,
+                    2 => tokens::TEXT_INPUT_Z_AXIS,
+                    _ => tokens::TEXT_INPUT_BG,
+                };
+                Box::new(bsn! {
+                    :FeathersNumberInput {
+                        @sigil_color: sigil,
+                        @label_text: axis,
+                        @number_format: format,
+                    }
+                    Node {
+                        flex_grow: 1.0,
+                        flex_basis: px(0),
+                    }
+                    template_value(field)
+                    on(inspector_on_f32)
+                    on(inspector_on_f64)
+                    on(inspector_on_i32)
+                    on(inspector_on_i64)
+                }) as Box<dyn Scene>
+            })
+            .collect::<Vec<_>>();
+        Box::new(field_row(caption, inputs))
+    }
+}
+
+/// A row containing a caption and the controls for editing a field.
+fn field_row(caption: String, controls: impl SceneList) -> impl Scene {
+    bsn! {
+        Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: px(6),
+            min_height: size::ROW_HEIGHT,
+        }
+        Children [
+            (
+                Node {
+                    width: percent(35),
+                    flex_shrink: 0.0,
+                }
+                Children [
+                    :label_small(caption)
+                ]
+            ),
+            (
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Stretch,
+                    column_gap: px(4),
+                    flex_grow: 1.0,
+                }
+                Children [
+                    {controls}
+                ]
+            ),
+        ]
+    }
+}
+
+/// If `value` is a struct whose fields are the numeric axes `x`, `y`, `z` and `w` (in that order),
+/// returns the axis captions and number formats.
+fn vector_axes(
+    value: &dyn bevy_reflect::structs::Struct,
+) -> Option<Vec<(&'static str, NumberFormat)>> {
+    const AXES: [(&str, &str); 4] = [("x", "X"), ("y", "Y"), ("z", "Z"), ("w", "W")];
+    if value.field_len() < 2 || value.field_len() > AXES.len() {
+        return None;
+    }
+    (0..value.field_len())
+        .map(|index| {
+            let (name, axis) = AXES[index];
+            if value.name_at(index)? != name {
+                return None;
+            }
+            Some((axis, number_format(value.field_at(index)?)?))
+        })
+        .collect()
+}
+
+/// Returns the number format used to edit `value`, if it is a primitive number.
+fn number_format(value: &dyn PartialReflect) -> Option<NumberFormat> {
+    let ty = value.get_represented_type_info()?.ty();
+    if ty.is::<f32>() {
+        Some(NumberFormat::F32)
+    } else if ty.is::<f64>() {
+        Some(NumberFormat::F64)
+    } else if ty.is::<i8>() || ty.is::<i16>() || ty.is::<i32>() || ty.is::<u8>() || ty.is::<u16>() {
+        Some(NumberFormat::I32)
+    } else if ty.is::<i64>()
+        || ty.is::<u32>()
+        || ty.is::<u64>()
+        || ty.is::<isize>()
+        || ty.is::<usize>()
+    {
+        Some(NumberFormat::I64)
+    } else {
+        None
+    }
+}
+
+/// Reads a primitive number as a [`NumberInputValue`] in the format returned by [`number_format`].
+fn read_number(value: &dyn PartialReflect) -> Option<NumberInputValue> {
+    macro_rules! read {
+        ($($ty:ty => $variant:ident),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return Some(NumberInputValue::$variant((*value).try_into().ok()?));
+                }
+            )*
+        };
+    }
+    read!(f32 => F32, f64 => F64, i8 => I32, i16 => I32, i32 => I32, u8 => I32, u16 => I32);
+    read!(i64 => I64, u32 => I64, u64 => I64, isize => I64, usize => I64);
+    None
+}
+
+/// Writes a [`NumberInputValue`] to a primitive number, returning `false` if the value is out of
+/// range for the field type.
+fn write_number(value: &mut dyn PartialReflect, number: NumberInputValue) -> bool {
+    let (int, float) = match number {
+        NumberInputValue::F32(value) => (None, value as f64),
+        NumberInputValue::F64(value) => (None, value),
+        NumberInputValue::I32(value) => (Some(value as i64), value as f64),
+        NumberInputValue::I64(value) => (Some(value), value as f64),
+    };
+    if let Some(field) = value.try_downcast_mut::<f32>() {
+        *field = float as f32;
+        return true;
+    }
+    if let Some(field) = value.try_downcast_mut::<f64>() {
+        *field = float;
+        return true;
+    }
+    macro_rules! write_int {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(field) = value.try_downcast_mut::<$ty>() {
+                    let Some(int) = int.and_then(|int| <$ty>::try_from(int).ok()) else {
+                        return false;
+                    };
+                    *field = int;
+                    return true;
+                }
+            )*
+        };
+    }
+    write_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    false
+}
+
+fn read_color(value: &dyn PartialReflect) -> Option<Color> {
+    if let Some(color) = value.try_downcast_ref::<Color>() {
+        Some(*color)
+    } else if let Some(color) = value.try_downcast_ref::<Srgba>() {
+        Some((*color).into())
+    } else {
+        value
+            .try_downcast_ref::<LinearRgba>()
+            .map(|color| (*color).into())
+    }
+}
+
+fn write_color(value: &mut dyn PartialReflect, color: Color) {
+    if let Some(field) = value.try_downcast_mut::<Color>() {
+        *field = color;
+    } else if let Some(field) = value.try_downcast_mut::<Srgba>() {
+        *field = color.into();
+    } else if let Some(field) = value.try_downcast_mut::<LinearRgba>() {
+        *field = color.into();
+    }
+}
+
+fn channel_value(color: Color, channel: ColorChannel) -> f32 {
+    let color = color.to_srgba();
+    match channel {
+        ColorChannel::Red => color.red,
+        ColorChannel::Green => color.green,
+        ColorChannel::Blue => color.blue,
+        _ => color.alpha,
+    }
+}
+
+fn with_channel(color: Color, channel: ColorChannel, value: f32) -> Color {
+    let mut color = color.to_srgba();
+    match channel {
+        ColorChannel::Red => color.red = value,
+        ColorChannel::Green => color.green = value,
+        ColorChannel::Blue => color.blue = value,
+        _ => color.alpha = value,
+    }
+    color.into()
+}
+
+/// Returns the [`Default`] value for a type, if it is registered with [`ReflectDefault`].
+fn default_value(type_registry: &TypeRegistry, ty: &Type) -> Option<Box<dyn PartialReflect>> {
+    let reflect_default = type_registry.get_with_type(ty)?.data::<ReflectDefault>()?;
+    Some(reflect_default.default().into_partial_reflect())
+}
+
+/// Constructs the variant `name` of an enum, with each field set to its default value.
+fn default_variant(
+    type_registry: &TypeRegistry,
+    value: &dyn PartialReflect,
+    name: &str,
+) -> Option<DynamicEnum> {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return None;
+    };
+    let variant = match info.variant(name)? {
+        VariantInfo::Unit(_) => DynamicVariant::Unit,
+        VariantInfo::Tuple(variant) => {
+            let mut tuple = DynamicTuple::default();
+            for field in variant.iter() {
+                tuple.insert_boxed(default_value(type_registry, field.ty())?);
+            }
+            DynamicVariant::Tuple(tuple)
+        }
+        VariantInfo::Struct(variant) => {
+            let mut dynamic_struct = DynamicStruct::default();
+            for field in variant.iter() {
+                dynamic_struct
+                    .insert_boxed(field.name(), default_value(type_registry, field.ty())?);
+            }
+            DynamicVariant::Struct(dynamic_struct)
+        }
+    };
+    Some(DynamicEnum::new(name, variant))
+}
+
+fn inspector_on_f32(
+    change: On<ValueChange<f32>>,
+    q_fields: Query<&InspectorField>,
+    mut commands: Commands,
+) {
+    let Ok(field) = q_fields.get(change.source) else {
+        return;
+    };
+    let new_value = change.value;
+    match field.kind {
+        FieldKind::Number => edit_number(&mut commands, field, NumberInputValue::F32(new_value)),
+        FieldKind::Channel(channel) => edit_field(&mut commands, field, move |value, _| {
+            if let Some(color) = read_color(value) {
+                write_color(value, with_channel(color, channel, new_value));
+            }
+        }),
+        _ => {}
+    }
+}
+
+fn inspector_on_f64(
+    change: On<ValueChange<f64>>,
+    q_fields: Query<&InspectorField>,
+    mut commands: Commands,
+) {
+    if let Ok(field) = q_fields.get(change.source) {
+        edit_number(&mut commands, field, NumberInputValue::F64(change.value));
+    }
+}
+
+fn inspector_on_i32(
+    change: On<ValueChange<i32>>,
+    q_fields: Query<&InspectorField>,
+    mut commands: Commands,
+) {
+    if let Ok(field) = q_fields.get(change.source) {
+        edit_number(&mut commands, field, NumberInputValue::I32(change.value));
+    }
+}
+
+fn inspector_on_i64(
+    change: On<ValueChange<i64>>,
+    q_fields: Query<&InspectorField>,
+    mut commands: Commands,
+) {
+    if let Ok(field) = q_fields.get(change.source) {
+        edit_number(&mut commands, field, NumberInputValue::I64(change.value));
+    }
+}
+
+fn edit_number(commands: &mut Commands, field: &InspectorField, number: NumberInputValue) {
+    if !matches!(field.kind, FieldKind::Number) {
+        return;
+    }
+    edit_field(commands, field, move |value, _| {
+        if !write_number(value, number) {
+            warn!("Value {} is out of range for the inspected field", number);
+        }
+    });
+}
+
+fn inspector_on_bool(
+    change: On<ValueChange<bool>>,
+    mut q_fields: Query<&InspectorField>,
+    mut q_state: Query<&mut InspectorState>,
+    mut commands: Commands,
+) {
+    let Ok(field) = q_fields.get_mut(change.source) else {
+        return;
+    };
+    let checked = change.value;
+    match field.kind {
+        FieldKind::Bool => edit_field(&mut commands, field, move |value, _| {
+            if let Some(value) = value.try_downcast_mut::<bool>() {
+                *value = checked;
+            }
+        }),
+        FieldKind::Expand => {
+            let Ok(mut state) = q_state.get_mut(field.inspector) else {
+                return;
+            };
+            let key = field.path.to_string();
+            if checked {
+                state.expanded.insert(key);
+            } else {
+                state.expanded.remove(&key);
+            }
+            commands.entity(field.inspector).insert(InspectorDirty);
+        }
+        _ => {}
+    }
+}
+
+fn inspector_on_text_change(
+    change: On<TextEditChange>,
+    q_fields: Query<(&InspectorField, &EditableText)>,
+    mut commands: Commands,
+) {
+    let Ok((field, editable_text)) = q_fields.get(change.event_target()) else {
+        return;
+    };
+    let text = editable_text.value().to_string();
+    edit_field(&mut commands, field, move |value, _| {
+        if let Some(value) = value.try_downcast_mut::<String>() {
+            *value = text;
+        }
+    });
+}
+
+fn inspector_on_activate(
+    activate: On<Activate>,
+    q_fields: Query<&InspectorField>,
+    mut commands: Commands,
+) {
+    let Ok(field) = q_fields.get(activate.entity) else {
+        return;
+    };
+    match field.kind {
+        FieldKind::Variant(name) => {
+            edit_field(&mut commands, field, move |value, type_registry| {
+                let Some(variant) = default_variant(type_registry, value, name) else {
+                    warn!(
+                        "Cannot switch {} to variant {}: a field has no reflected default",
+                        value.reflect_type_path(),
+                        name
+                    );
+                    return;
+                };
+                if let Err(err) = value.try_apply(&variant) {
+                    warn!("Cannot switch to variant {}: {}", name, err);
+                }
+            });
+        }
+        FieldKind::ListAdd => edit_field(&mut commands, field, |value, type_registry| {
+            let item_ty = match value.get_represented_type_info() {
+                Some(TypeInfo::List(info)) => Some(info.item_ty()),
+                _ => None,
+            };
+            let ReflectMut::List(list) = value.reflect_mut() else {
+                return;
+            };
+            let item = item_ty
+                .and_then(|ty| default_value(type_registry, &ty))
+                .or_else(|| Some(list.get(list.len().checked_sub(1)?)?.to_dynamic()));
+            match item {
+                Some(item) => list.push(item),
+                None => warn!(
+                    "Cannot add an item to {}: the item type has no reflected default",
+                    list.reflect_type_path()
+                ),
+            }
+        }),
+        FieldKind::ListRemove(index) => edit_field(&mut commands, field, move |value, _| {
+            if let ReflectMut::List(list) = value.reflect_mut()
+                && index < list.len()
+            {
+                list.remove(index);
+            }
+        }),
+        _ => return,
+    }
+    commands.entity(field.inspector).insert(InspectorDirty);
+}
+
+/// Rebuilds the controls of inspectors whose target has changed, or whose layout is out of date.
+fn build_inspectors(
+    world: &World,
+    q_inspectors: Query<
+        (Entity, &InspectorState),
+        (
+            With<FeathersInspector>,
+            Or<(Changed<InspectorTarget>, With<InspectorDirty>)>,
+        ),
+    >,
+    q_targets: Query<&InspectorTarget>,
+    mut commands: Commands,
+) {
+    if q_inspectors.is_empty() {
+        return;
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    for (inspector, state) in q_inspectors.iter() {
+        let Ok(target) = q_targets.get(inspector) else {
+            continue;
+        };
+        let builder = InspectorBuilder {
+            inspector,
+            expanded: &state.expanded,
+        };
+        let value = resolve_target(world, target, &type_registry).and_then(
+            |(entity, reflect_component, _)| {
+                reflect_component.reflect(world.get_entity(entity).ok()?)
+            },
+        );
+        let root = builder.field(&[], FieldKind::Root(value.is_some()));
+        let rows = match value {
+            Some(value) => builder.build_fields(&[], value.as_partial_reflect()),
+            None => vec![Box::new(label_dim("No value")) as Box<dyn Scene>],
+        };
+        commands
+            .entity(inspector)
+            .remove::<InspectorDirty>()
+            .despawn_related::<Children>()
+            .queue_spawn_related_scenes::<Children>(bsn_list! {
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Stretch,
+                    row_gap: px(4),
+                }
+                template_value(root)
+                Children [
+                    {rows}
+                ]
+            });
+    }
+}
+
+/// Updates the controls of inspectors when the inspected values change, and flags inspectors
+/// whose layout no longer matches the shape of the value.
+fn sync_inspectors(
+    world: &World,
+    q_fields: Query<(Entity, Ref<InspectorField>)>,
+    q_inspectors: Query<(&InspectorTarget, &InspectorState), With<FeathersInspector>>,
+    focus: Res<InputFocus>,
+    ticks: SystemChangeTick,
+    mut commands: Commands,
+) {
+    if q_fields.is_empty() {
+        return;
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut targets = HashMap::<Entity, Option<(&dyn PartialReflect, bool)>>::default();
+    for (control, field) in q_fields.iter() {
+        let Ok((target, state)) = q_inspectors.get(field.inspector) else {
+            continue;
+        };
+        let resolved = *targets.entry(field.inspector).or_insert_with(|| {
+            let (entity, reflect_component, component_id) =
+                resolve_target(world, target, &type_registry)?;
+            let entity_ref = world.get_entity(entity).ok()?;
+            let changed = entity_ref
+                .get_change_ticks_by_id(component_id)
+                .is_some_and(|t| t.is_changed(ticks.last_run(), ticks.this_run()));
+            let value = reflect_component.reflect(entity_ref)?;
+            Some((value.as_partial_reflect(), changed))
+        });
+
+        if let FieldKind::Root(present) = field.kind {
+            if present != resolved.is_some() {
+                commands.entity(field.inspector).insert(InspectorDirty);
+            }
+            continue;
+        }
+        let Some((root, changed)) = resolved else {
+            continue;
+        };
+        if !changed && !field.is_added() {
+            continue;
+        }
+        let Ok(value) = (&field.path).reflect_element(root) else {
+            // The field no longer exists, for example because a list became shorter.
+            commands.entity(field.inspector).insert(InspectorDirty);
+            continue;
+        };
+
+        match &field.kind {
+            FieldKind::Number => {
+                if let Some(number) = read_number(value) {
+                    commands.trigger(UpdateNumberInput {
+                        entity: control,
+                        value: number,
+                    });
+                }
+            }
+            FieldKind::Bool => {
+                if let Some(checked) = value.try_downcast_ref::<bool>()
+                    && world.entity(control).contains::<Checked>() != *checked
+                {
+                    if *checked {
+                        commands.entity(control).insert(Checked);
+                    } else {
+                        commands.entity(control).remove::<Checked>();
+                    }
+                }
+            }
+            FieldKind::Text => {
+                if let Some(text) = value.try_downcast_ref::<String>()
+                    && focus.get() != Some(control)
+                    && world
+                        .get::<EditableText>(control)
+                        .is_some_and(|editable_text| editable_text.value() != text.as_str())
+                {
+                    let text = text.clone();
+                    commands.queue(move |world: &mut World| {
+                        if let Some(mut editable_text) = world.get_mut::<EditableText>(control) {
+                            editable_text.queue_edit(TextEdit::SelectAll);
+                            editable_text.queue_edit(TextEdit::Insert(text.into()));
+                        }
+                    });
+                }
+            }
+            FieldKind::Swatch => {
+                if let Some(color) = read_color(value) {
+                    commands.entity(control).insert(ColorSwatchValue(color));
+                }
+            }
+            FieldKind::Channel(channel) => {
+                if let Some(color) = read_color(value) {
+                    commands.entity(control).insert((
+                        SliderValue(channel_value(color, *channel)),
+                        SliderBaseColor(color),
+                    ));
+                }
+            }
+            FieldKind::Enum(variant_name) => {
+                if let ReflectRef::Enum(enum_value) = value.reflect_ref()
+                    && enum_value.variant_name() != variant_name
+                {
+                    commands.entity(field.inspector).insert(InspectorDirty);
+                }
+            }
+            FieldKind::Len(len) => {
+                let current_len = match value.reflect_ref() {
+                    ReflectRef::List(list) => list.len(),
+                    ReflectRef::Array(array) => array.len(),
+                    _ => *len,
+                };
+                if current_len != *len {
+                    commands.entity(field.inspector).insert(InspectorDirty);
+                }
+            }
+            FieldKind::Expand => {
+                if field.is_added() && state.expanded.contains(&field.path.to_string()) {
+                    commands.entity(control).insert(Checked);
+                }
+            }
+            FieldKind::Variant(_)
+            | FieldKind::Group
+            | FieldKind::ListAdd
+            | FieldKind::ListRemove(_)
+            | FieldKind::Root(_) => {}
+        }
+    }
+}
+
+/// Plugin which registers the systems for building and updating [`FeathersInspector`] widgets.
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PreUpdate,
+            (build_inspectors, sync_inspectors)
+                .chain()
+                .in_set(PickingSystems::Last),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct NoDefault(u8);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Shape {
+        Empty,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+        Custom(NoDefault),
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u8,
+        items: Vec<u32>,
+    }
+
+    #[test]
+    fn number_formats() {
+        assert!(matches!(number_format(&1.0f32), Some(NumberFormat::F32)));
+        assert!(matches!(number_format(&1u8), Some(NumberFormat::I32)));
+        assert!(matches!(number_format(&1u64), Some(NumberFormat::I64)));
+        assert!(number_format(&String::new()).is_none());
+
+        assert_eq!(read_number(&7u8), Some(NumberInputValue::I32(7)));
+        assert_eq!(read_number(&7u64), Some(NumberInputValue::I64(7)));
+        assert_eq!(read_number(&u64::MAX), None);
+    }
+
+    #[test]
+    fn write_number_checks_range() {
+        let mut byte = 5u8;
+        assert!(!write_number(&mut byte, NumberInputValue::I32(256)));
+        assert!(!write_number(&mut byte, NumberInputValue::I32(-1)));
+        assert!(!write_number(&mut byte, NumberInputValue::F32(1.0)));
+        assert_eq!(byte, 5);
+        assert!(write_number(&mut byte, NumberInputValue::I32(255)));
+        assert_eq!(byte, 255);
+
+        let mut long = 5u64;
+        assert!(!write_number(&mut long, NumberInputValue::I64(-1)));
+        assert_eq!(long, 5);
+        assert!(write_number(&mut long, NumberInputValue::I64(i64::MAX)));
+        assert_eq!(long, i64::MAX as u64);
+
+        let mut float = 0.0f32;
+        assert!(write_number(&mut float, NumberInputValue::I32(3)));
+        assert_eq!(float, 3.0);
+    }
+
+    #[test]
+    fn default_variants() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Shape>();
+
+        let mut shape = Shape::Empty;
+        let variant = default_variant(&type_registry, &shape, "Circle").unwrap();
+        shape.apply(&variant);
+        assert_eq!(shape, Shape::Circle(0.0));
+
+        let variant = default_variant(&type_registry, &shape, "Rect").unwrap();
+        shape.apply(&variant);
+        assert_eq!(
+            shape,
+            Shape::Rect {
+                width: 0.0,
+                height: 0.0
+            }
+        );
+
+        let variant = default_variant(&type_registry, &shape, "Empty").unwrap();
+        shape.apply(&variant);
+        assert_eq!(shape, Shape::Empty);
+
+        // `NoDefault` doesn't register `ReflectDefault`.
+        assert!(default_variant(&type_registry, &shape, "Custom").is_none());
+        assert!(default_variant(&type_registry, &shape, "Missing").is_none());
+        assert!(default_variant(&type_registry, &1.0f32, "Circle").is_none());
+    }
+
+    fn setup_inspector() -> (World, Entity, Entity) {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Stats>();
+        world.insert_resource(type_registry);
+        world.init_resource::<InputFocus>();
+        let target = world
+            .spawn(Stats {
+                health: 10,
+                items: vec![1, 2],
+            })
+            .id();
+        let inspector = world
+            .spawn((
+                FeathersInspector,
+                InspectorTarget::component::<Stats>(target),
+            ))
+            .id();
+        (world, target, inspector)
+    }
+
+    #[test]
+    fn edits_write_back_to_target() {
+        let (mut world, target, inspector) = setup_inspector();
+        let field = InspectorField {
+            inspector,
+            path: ParsedPath::parse("health").unwrap(),
+            kind: FieldKind::Number,
+        };
+
+        world
+            .run_system_once(move |mut commands: Commands| {
+                edit_number(&mut commands, &field, NumberInputValue::I32(42));
+                // Out of range for `u8`, so ignored.
+                edit_number(&mut commands, &field, NumberInputValue::I32(1000));
+            })
+            .unwrap();
+        assert_eq!(world.get::<Stats>(target).unwrap().health, 42);
+    }
+
+    #[test]
+    fn list_length_change_marks_dirty() {
+        let (mut world, target, inspector) = setup_inspector();
+        world.entity_mut(inspector).remove::<InspectorDirty>();
+        world.spawn(InspectorField {
+            inspector,
+            path: ParsedPath::parse("items").unwrap(),
+            kind: FieldKind::Len(2),
+        });
+
+        world.run_system_once(sync_inspectors).unwrap();
+        assert!(!world.entity(inspector).contains::<InspectorDirty>());
+
+        world.get_mut::<Stats>(target).unwrap().items.push(3);
+        world.run_system_once(sync_inspectors).unwrap();
+        assert!(world.entity(inspector).contains::<InspectorDirty>());
+    }
+}
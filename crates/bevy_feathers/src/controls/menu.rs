@@ -25,7 +25,7 @@ use bevy_ui_widgets::{
 };
 
 use crate::{
-    constants::{fonts, icons, size},
+    constants::{fonts, icons, size, transitions},
     controls::{ButtonVariant, FeathersButton},
     cursor::EntityCursor,
     display::icon,
@@ -272,6 +272,7 @@ impl FeathersMenuItem {
             EntityCursor::System(bevy_window::SystemCursorIcon::Pointer)
             TabIndex(0)
             ThemeBackgroundColor(tokens::MENU_BG) // Same as menu
+            template_value(transitions::colors())
             InheritableThemeTextColor(tokens::MENUITEM_TEXT)
             InheritableFont {
                 font: fonts::REGULAR,
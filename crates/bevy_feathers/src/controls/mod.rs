@@ -6,13 +6,18 @@ mod checkbox;
 mod color_plane;
 mod color_slider;
 mod color_swatch;
+mod data_table;
+mod dialog;
 mod disclosure_toggle;
+mod inspector;
 mod menu;
 mod number_input;
 mod radio;
 mod slider;
+mod tabs;
 mod text_input;
 mod toggle_switch;
+mod tree_view;
 mod virtual_keyboard;
 
 pub use button::*;
@@ -20,13 +25,18 @@ pub use checkbox::*;
 pub use color_plane::*;
 pub use color_slider::*;
 pub use color_swatch::*;
+pub use data_table::*;
+pub use dialog::*;
 pub use disclosure_toggle::*;
+pub use inspector::*;
 pub use menu::*;
 pub use number_input::*;
 pub use radio::*;
 pub use slider::*;
+pub use tabs::*;
 pub use text_input::*;
 pub use toggle_switch::*;
+pub use tree_view::*;
 pub use virtual_keyboard::*;
 
 use crate::alpha_pattern::AlphaPatternPlugin;
@@ -44,12 +54,18 @@ impl Plugin for ControlsPlugin {
             ColorPlanePlugin,
             ColorSliderPlugin,
             ColorSwatchPlugin,
+            DataTablePlugin,
             DisclosureTogglePlugin,
+            InspectorPlugin,
             MenuPlugin,
             RadioPlugin,
             SliderPlugin,
+        ))
+        .add_plugins((
+            TabsPlugin,
             TextInputPlugin,
             ToggleSwitchPlugin,
+            TreeViewPlugin,
         ));
     }
 }
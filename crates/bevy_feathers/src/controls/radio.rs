@@ -25,7 +25,7 @@ use bevy_ui::{
 use bevy_ui_widgets::{ActivateOnPress, RadioButton};
 
 use crate::{
-    constants::{fonts, size},
+    constants::{fonts, size, transitions},
     cursor::EntityCursor,
     focus::FocusIndicator,
     font_styles::InheritableFont,
@@ -94,6 +94,7 @@ impl FeathersRadio {
                 RadioOutline
                 FocusIndicator
                 ThemeBorderColor(tokens::RADIO_BORDER)
+                template_value(transitions::colors())
                 Children [(
                     // Cheesy checkmark: rotated node with L-shaped border.
                     Node {
@@ -103,6 +104,7 @@ impl FeathersRadio {
                     }
                     RadioMark
                     ThemeBackgroundColor(tokens::RADIO_MARK)
+                    template_value(transitions::colors())
                 )]),
                 {props.caption}
             ]
@@ -11,7 +11,7 @@ use bevy_ecs::{
     lifecycle::RemovedComponents,
     query::{Added, Changed, Has, Or, Spawned, With},
     reflect::ReflectComponent,
-    schedule::IntoScheduleConfigs,
+    schedule::{common_conditions::resource_changed, IntoScheduleConfigs},
     system::{Commands, Query, Res},
 };
 use bevy_input_focus::tab_navigation::TabIndex;
@@ -271,6 +271,33 @@ fn update_slider_styles_remove(
         });
 }
 
+fn update_slider_styles_theme(
+    mut q_sliders: Query<
+        (
+            Entity,
+            Has<InteractionDisabled>,
+            Has<Pressed>,
+            &Hovered,
+            &mut BackgroundGradient,
+        ),
+        With<FeathersSlider>,
+    >,
+    theme: Res<UiTheme>,
+    mut commands: Commands,
+) {
+    for (slider_ent, disabled, pressed, hovered, mut gradient) in q_sliders.iter_mut() {
+        set_slider_styles(
+            slider_ent,
+            &theme,
+            disabled,
+            pressed,
+            hovered.0,
+            gradient.as_mut(),
+            &mut commands,
+        );
+    }
+}
+
 fn set_slider_styles(
     slider_ent: Entity,
     theme: &Res<'_, UiTheme>,
@@ -376,6 +403,7 @@ impl Plugin for SliderPlugin {
             (
                 update_slider_styles,
                 update_slider_styles_remove,
+                update_slider_styles_theme.run_if(resource_changed::<UiTheme>),
                 update_slider_pos,
             )
                 .in_set(PickingSystems::Last),
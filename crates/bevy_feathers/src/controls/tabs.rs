@@ -0,0 +1,260 @@
+use bevy_app::{Plugin, PreUpdate};
+use bevy_ecs::{
+    entity::Entity,
+    hierarchy::Children,
+    lifecycle::RemovedComponents,
+    query::{Added, Changed, Has, Or, With},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+    template::EntityTemplate,
+};
+use bevy_input_focus::tab_navigation::TabIndex;
+use bevy_picking::{hover::Hovered, PickingSystems};
+use bevy_scene::prelude::*;
+use bevy_text::FontWeight;
+use bevy_ui::{
+    px, AlignItems, Display, FlexDirection, InteractionDisabled, JustifyContent, Node, Selected,
+    UiRect,
+};
+use bevy_ui_widgets::{Tab, TabGroup, TabPanel};
+use bevy_window::SystemCursorIcon;
+
+use crate::{
+    constants::{fonts, size, transitions},
+    cursor::EntityCursor,
+    focus::FocusIndicator,
+    font_styles::InheritableFont,
+    rounded_corners::RoundedCorners,
+    theme::{InheritableThemeTextColor, ThemeBackgroundColor, ThemeBorderColor},
+    tokens,
+};
+
+/// A row of tabs, such as the tabs at the top of an editor pane. The [`FeathersTab`]s should be
+/// spawned as children of this entity.
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersTabGroup;
+
+impl FeathersTabGroup {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::End,
+                column_gap: px(2),
+                padding: UiRect::horizontal(px(4)),
+                border: UiRect::bottom(px(1)),
+            }
+            FeathersTabGroup
+            TabGroup
+            ThemeBorderColor(tokens::TAB_BORDER)
+        }
+    }
+}
+
+/// A tab in a [`FeathersTabGroup`].
+///
+/// This is spawnable by inheriting it as a "scene component" with [`FeathersTabProps`].
+#[derive(SceneComponent, Default, Clone)]
+#[scene(FeathersTabProps)]
+pub struct FeathersTab;
+
+/// Props used to construct a [`FeathersTab`] scene.
+pub struct FeathersTabProps {
+    /// Label for this tab
+    pub caption: Box<dyn SceneList>,
+    /// The [`FeathersTabPanel`] shown when this tab is active.
+    pub panel: EntityTemplate,
+}
+
+impl Default for FeathersTabProps {
+    fn default() -> Self {
+        Self {
+            caption: Box::new(bsn_list!()),
+            panel: EntityTemplate::default(),
+        }
+    }
+}
+
+impl FeathersTab {
+    fn scene(props: FeathersTabProps) -> impl Scene {
+        bsn! {
+            Node {
+                height: size::ROW_HEIGHT,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: px(4),
+                padding: UiRect::horizontal(px(10)),
+                border: UiRect {
+                    left: px(1),
+                    top: px(1),
+                    right: px(1),
+                },
+                border_radius: {RoundedCorners::Top.to_border_radius(4.0)},
+            }
+            FeathersTab
+            Tab { panel: #{props.panel} }
+            Hovered
+            EntityCursor::System(SystemCursorIcon::Pointer)
+            TabIndex(0)
+            FocusIndicator
+            ThemeBackgroundColor(tokens::TAB_BG)
+            ThemeBorderColor(tokens::TAB_BG)
+            template_value(transitions::colors())
+            InheritableThemeTextColor(tokens::TAB_TEXT)
+            InheritableFont {
+                font: fonts::REGULAR,
+                font_size: size::MEDIUM_FONT,
+                weight: FontWeight::NORMAL,
+            }
+            Children [ {props.caption} ]
+        }
+    }
+}
+
+/// The panel of a [`FeathersTab`]. It is only displayed while its tab is active.
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersTabPanel;
+
+impl FeathersTabPanel {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: px(4),
+                padding: px(6),
+            }
+            FeathersTabPanel
+            TabPanel
+            ThemeBackgroundColor(tokens::TAB_PANEL_BG)
+        }
+    }
+}
+
+fn update_tab_styles(
+    q_tabs: Query<
+        (
+            Entity,
+            Has<Selected>,
+            Has<InteractionDisabled>,
+            &Hovered,
+            &ThemeBackgroundColor,
+            &InheritableThemeTextColor,
+        ),
+        (
+            With<FeathersTab>,
+            Or<(
+                Changed<Hovered>,
+                Added<Selected>,
+                Added<InteractionDisabled>,
+            )>,
+        ),
+    >,
+    mut commands: Commands,
+) {
+    for (tab_ent, selected, disabled, hovered, bg_color, font_color) in q_tabs.iter() {
+        set_tab_colors(
+            tab_ent,
+            selected,
+            disabled,
+            hovered.0,
+            bg_color,
+            font_color,
+            &mut commands,
+        );
+    }
+}
+
+fn update_tab_styles_remove(
+    q_tabs: Query<
+        (
+            Entity,
+            Has<Selected>,
+            Has<InteractionDisabled>,
+            &Hovered,
+            &ThemeBackgroundColor,
+            &InheritableThemeTextColor,
+        ),
+        With<FeathersTab>,
+    >,
+    mut removed_selected: RemovedComponents<Selected>,
+    mut removed_disabled: RemovedComponents<InteractionDisabled>,
+    mut commands: Commands,
+) {
+    removed_selected
+        .read()
+        .chain(removed_disabled.read())
+        .for_each(|ent| {
+            if let Ok((tab_ent, selected, disabled, hovered, bg_color, font_color)) =
+                q_tabs.get(ent)
+            {
+                set_tab_colors(
+                    tab_ent,
+                    selected,
+                    disabled,
+                    hovered.0,
+                    bg_color,
+                    font_color,
+                    &mut commands,
+                );
+            }
+        });
+}
+
+fn set_tab_colors(
+    tab_ent: Entity,
+    selected: bool,
+    disabled: bool,
+    hovered: bool,
+    bg_color: &ThemeBackgroundColor,
+    font_color: &InheritableThemeTextColor,
+    commands: &mut Commands,
+) {
+    // The active tab has the same background as the panel, and a border which joins the border
+    // under the tab group.
+    let (bg_token, border_token) = match (selected, disabled, hovered) {
+        (true, _, _) => (tokens::TAB_PANEL_BG, tokens::TAB_BORDER),
+        (false, false, true) => (tokens::TAB_BG_HOVER, tokens::TAB_BG_HOVER),
+        (false, _, _) => (tokens::TAB_BG, tokens::TAB_BG),
+    };
+
+    let font_color_token = match (selected, disabled) {
+        (_, true) => tokens::TAB_TEXT_DISABLED,
+        (true, false) => tokens::TAB_TEXT_SELECTED,
+        (false, false) => tokens::TAB_TEXT,
+    };
+
+    // Change background and border color
+    if bg_color.0 != bg_token {
+        commands.entity(tab_ent).insert((
+            ThemeBackgroundColor(bg_token),
+            ThemeBorderColor(border_token),
+        ));
+    }
+
+    // Change font color
+    if font_color.0 != font_color_token {
+        commands
+            .entity(tab_ent)
+            .insert(InheritableThemeTextColor(font_color_token));
+    }
+}
+
+/// Plugin which registers the systems for updating the tab styles.
+pub struct TabsPlugin;
+
+impl Plugin for TabsPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(
+            PreUpdate,
+            (update_tab_styles, update_tab_styles_remove).in_set(PickingSystems::Last),
+        );
+    }
+}
@@ -24,7 +24,7 @@ use bevy_ui::{
 use bevy_ui_widgets::{ActivateOnPress, Checkbox};
 
 use crate::{
-    constants::size,
+    constants::{size, transitions},
     cursor::EntityCursor,
     focus::FocusIndicator,
     theme::{ThemeBackgroundColor, ThemeBorderColor},
@@ -56,6 +56,7 @@ impl FeathersToggleSwitch {
             FeathersToggleSwitch
             ThemeBackgroundColor(tokens::SWITCH_BG)
             ThemeBorderColor(tokens::SWITCH_BORDER)
+            template_value(transitions::colors())
             AccessibilityNode(accesskit::Node::new(Role::Switch))
             Hovered
             EntityCursor::System(bevy_window::SystemCursorIcon::Pointer)
@@ -74,6 +75,7 @@ impl FeathersToggleSwitch {
                 ToggleSwitchSlide
                 ThemeBackgroundColor(tokens::SWITCH_SLIDE_BG)
                 ThemeBorderColor(tokens::SWITCH_SLIDE_BORDER)
+                template_value(transitions::colors_and_motion())
             )]
         }
     }
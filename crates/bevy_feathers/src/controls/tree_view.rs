@@ -0,0 +1,329 @@
+use bevy_app::{Plugin, PostUpdate, PreUpdate};
+use bevy_camera::visibility::Visibility;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    lifecycle::RemovedComponents,
+    observer::On,
+    query::{Added, Changed, Has, Or, With},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_input_focus::{tab_navigation::TabIndex, InputFocus, InputFocusVisible};
+use bevy_math::Rot2;
+use bevy_picking::{
+    events::{Click, Pointer},
+    hover::Hovered,
+    PickingSystems,
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_scene::prelude::*;
+use bevy_text::FontWeight;
+use bevy_ui::{
+    px, AlignItems, Display, FlexDirection, JustifyContent, Node, Selected, UiRect, UiSystems,
+    UiTransform,
+};
+use bevy_ui_widgets::{Expanded, TreeChildren, TreeDepth, TreeItem, TreeView, ValueChange};
+use bevy_window::SystemCursorIcon;
+
+use crate::{
+    constants::{fonts, icons, size},
+    cursor::EntityCursor,
+    display::icon,
+    font_styles::InheritableFont,
+    theme::{InheritableThemeTextColor, ThemeBackgroundColor},
+    tokens,
+};
+
+/// Width of the indentation for each level of nesting in a tree view.
+const INDENT: f32 = 12.0;
+
+/// A tree view, such as an outliner. The [`FeathersTreeItem`]s of the tree should be spawned as
+/// UI children of this entity, in the order they appear in the tree, and linked to their parent
+/// item (or to the tree view itself) with a [`TreeParent`](bevy_ui_widgets::TreeParent).
+///
+/// This is spawnable by inheriting it as a "scene component".
+#[derive(SceneComponent, Default, Clone)]
+pub struct FeathersTreeView;
+
+impl FeathersTreeView {
+    fn scene() -> impl Scene {
+        bsn! {
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+            }
+            FeathersTreeView
+            TreeView
+            ThemeBackgroundColor(tokens::TREEVIEW_BG)
+        }
+    }
+}
+
+/// An item in a [`FeathersTreeView`]. Items that have children show a chevron which expands or
+/// collapses the item when clicked, and are indented according to their [`TreeDepth`].
+///
+/// This is spawnable by inheriting it as a "scene component" with optional [`FeathersTreeItemProps`].
+#[derive(SceneComponent, Default, Clone)]
+#[scene(FeathersTreeItemProps)]
+pub struct FeathersTreeItem;
+
+/// Props used to construct a [`FeathersTreeItem`] scene.
+pub struct FeathersTreeItemProps {
+    /// Label for this tree item
+    pub caption: Box<dyn SceneList>,
+}
+
+impl Default for FeathersTreeItemProps {
+    fn default() -> Self {
+        Self {
+            caption: Box::new(bsn_list!()),
+        }
+    }
+}
+
+impl FeathersTreeItem {
+    fn scene(props: FeathersTreeItemProps) -> impl Scene {
+        bsn! {
+            Node {
+                height: size::ROW_HEIGHT,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                column_gap: px(4),
+                padding: UiRect::horizontal(px(4)),
+            }
+            FeathersTreeItem
+            TreeItem
+            Hovered
+            TabIndex(0)
+            ThemeBackgroundColor(tokens::TREEVIEW_BG)
+            InheritableThemeTextColor(tokens::TREEITEM_TEXT)
+            InheritableFont {
+                font: fonts::REGULAR,
+                font_size: size::MEDIUM_FONT,
+                weight: FontWeight::NORMAL,
+            }
+            Children [
+                (
+                    Node {
+                        width: px(12),
+                        height: px(12),
+                        display: Display::Flex,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                    }
+                    FeathersTreeItemChevron
+                    EntityCursor::System(SystemCursorIcon::Pointer)
+                    Children [
+                        :icon(icons::CHEVRON_RIGHT)
+                    ]
+                ),
+                {props.caption}
+            ]
+        }
+    }
+}
+
+/// Marker for the chevron that expands or collapses a [`FeathersTreeItem`].
+#[derive(Component, Default, Clone, Reflect)]
+#[reflect(Component, Clone, Default)]
+struct FeathersTreeItemChevron;
+
+/// Clicking the chevron of an item expands or collapses it, without changing the selection.
+fn tree_item_chevron_on_click(
+    mut click: On<Pointer<Click>>,
+    q_chevron: Query<&ChildOf, With<FeathersTreeItemChevron>>,
+    q_item: Query<Has<Expanded>, With<TreeItem>>,
+    mut commands: Commands,
+) {
+    let Ok(parent) = q_chevron.get(click.entity) else {
+        return;
+    };
+    let Ok(expanded) = q_item.get(parent.parent()) else {
+        return;
+    };
+    click.propagate(false);
+    commands.trigger(ValueChange {
+        source: parent.parent(),
+        value: !expanded,
+        is_final: true,
+    });
+}
+
+/// Updates the indentation and the chevron of items when the structure of the tree or the
+/// expanded state of an item changes.
+fn update_tree_item_layout(
+    mut q_items: Query<
+        (
+            &TreeDepth,
+            Has<Expanded>,
+            Has<TreeChildren>,
+            &mut Node,
+            &Children,
+        ),
+        With<FeathersTreeItem>,
+    >,
+    q_changed: Query<
+        Entity,
+        (
+            With<FeathersTreeItem>,
+            Or<(Changed<TreeDepth>, Changed<TreeChildren>, Added<Expanded>)>,
+        ),
+    >,
+    mut removed_expanded: RemovedComponents<Expanded>,
+    mut removed_children: RemovedComponents<TreeChildren>,
+    mut q_chevron: Query<(&mut UiTransform, &mut Visibility), With<FeathersTreeItemChevron>>,
+) {
+    let changed = q_changed
+        .iter()
+        .chain(removed_expanded.read())
+        .chain(removed_children.read())
+        .collect::<Vec<_>>();
+    for entity in changed {
+        let Ok((depth, expanded, has_children, mut node, children)) = q_items.get_mut(entity)
+        else {
+            continue;
+        };
+        let indent = px(4.0 + depth.0 as f32 * INDENT);
+        if node.padding.left != indent {
+            node.padding.left = indent;
+        }
+
+        let Some(Ok((mut transform, mut visibility))) =
+            children.first().map(|child| q_chevron.get_mut(*child))
+        else {
+            continue;
+        };
+        // The chevron keeps its space when hidden, so that leaf items line up with their siblings.
+        let chevron_visibility = match has_children {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+        visibility.set_if_neq(chevron_visibility);
+        transform.rotation = match expanded {
+            true => Rot2::turn_fraction(0.25),
+            false => Rot2::turn_fraction(0.0),
+        };
+    }
+}
+
+fn update_tree_item_styles(
+    q_items: Query<
+        (Entity, Has<Selected>, &Hovered, &ThemeBackgroundColor),
+        (
+            With<FeathersTreeItem>,
+            Or<(Changed<Hovered>, Added<Selected>)>,
+        ),
+    >,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    for (item_ent, selected, hovered, bg_color) in q_items.iter() {
+        set_tree_item_colors(
+            item_ent,
+            selected,
+            hovered.0,
+            Some(item_ent) == focus.get() && focus_visible.0,
+            bg_color,
+            &mut commands,
+        );
+    }
+}
+
+fn update_tree_item_styles_remove(
+    q_items: Query<
+        (Entity, Has<Selected>, &Hovered, &ThemeBackgroundColor),
+        With<FeathersTreeItem>,
+    >,
+    mut removed_selected: RemovedComponents<Selected>,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    removed_selected.read().for_each(|ent| {
+        if let Ok((item_ent, selected, hovered, bg_color)) = q_items.get(ent) {
+            set_tree_item_colors(
+                item_ent,
+                selected,
+                hovered.0,
+                Some(item_ent) == focus.get() && focus_visible.0,
+                bg_color,
+                &mut commands,
+            );
+        }
+    });
+}
+
+fn update_tree_item_styles_focus_changed(
+    q_items: Query<
+        (Entity, Has<Selected>, &Hovered, &ThemeBackgroundColor),
+        With<FeathersTreeItem>,
+    >,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    if focus.is_changed() || focus_visible.is_changed() {
+        for (item_ent, selected, hovered, bg_color) in q_items.iter() {
+            set_tree_item_colors(
+                item_ent,
+                selected,
+                hovered.0,
+                Some(item_ent) == focus.get() && focus_visible.0,
+                bg_color,
+                &mut commands,
+            );
+        }
+    }
+}
+
+fn set_tree_item_colors(
+    item_ent: Entity,
+    selected: bool,
+    hovered: bool,
+    focused: bool,
+    bg_color: &ThemeBackgroundColor,
+    commands: &mut Commands,
+) {
+    let bg_token = match (selected, focused, hovered) {
+        (true, _, _) => tokens::TREEITEM_BG_SELECTED,
+        (false, true, _) => tokens::TREEITEM_BG_FOCUSED,
+        (false, false, true) => tokens::TREEITEM_BG_HOVER,
+        (false, false, false) => tokens::TREEVIEW_BG,
+    };
+
+    // Change background color
+    if bg_color.0 != bg_token {
+        commands
+            .entity(item_ent)
+            .insert(ThemeBackgroundColor(bg_token));
+    }
+}
+
+/// Plugin which registers the observers and systems for the tree view and tree item styles.
+pub struct TreeViewPlugin;
+
+impl Plugin for TreeViewPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_observer(tree_item_chevron_on_click)
+            .add_systems(
+                PreUpdate,
+                (
+                    update_tree_item_styles,
+                    update_tree_item_styles_remove,
+                    update_tree_item_styles_focus_changed,
+                )
+                    .in_set(PickingSystems::Last),
+            )
+            .add_systems(
+                PostUpdate,
+                update_tree_item_layout.in_set(UiSystems::Propagate),
+            );
+    }
+}
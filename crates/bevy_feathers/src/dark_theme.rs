@@ -249,6 +249,51 @@ pub fn create_dark_theme() -> ThemeProps {
                 tokens::MENUITEM_TEXT_DISABLED,
                 palette::WHITE.with_alpha(0.5),
             ),
+            // Tree view
+            (tokens::TREEVIEW_BG, palette::GRAY_1),
+            (tokens::TREEITEM_BG_HOVER, palette::GRAY_1.lighter(0.05)),
+            (tokens::TREEITEM_BG_FOCUSED, palette::GRAY_1.lighter(0.1)),
+            (
+                tokens::TREEITEM_BG_SELECTED,
+                palette::ACCENT.with_alpha(0.5),
+            ),
+            (tokens::TREEITEM_TEXT, palette::WHITE),
+            // Data table
+            (tokens::DATATABLE_BG, palette::GRAY_1),
+            (tokens::DATATABLE_BORDER, palette::WARM_GRAY_1),
+            (tokens::DATATABLE_TEXT, palette::LIGHT_GRAY_1),
+            (tokens::DATATABLE_HEADER_BG, palette::GRAY_2),
+            (
+                tokens::DATATABLE_HEADER_BG_HOVER,
+                palette::GRAY_2.lighter(0.05),
+            ),
+            (tokens::DATATABLE_HEADER_TEXT, palette::WHITE),
+            (tokens::DATATABLE_RESIZE_HANDLE, palette::ACCENT),
+            (
+                tokens::DATATABLE_ROW_BG_HOVER,
+                palette::GRAY_1.lighter(0.05),
+            ),
+            (
+                tokens::DATATABLE_ROW_BG_SELECTED,
+                palette::ACCENT.with_alpha(0.5),
+            ),
+            // Tabs
+            (tokens::TAB_BG, Color::NONE),
+            (tokens::TAB_BG_HOVER, palette::GRAY_2),
+            (tokens::TAB_TEXT, palette::LIGHT_GRAY_2),
+            (tokens::TAB_TEXT_SELECTED, palette::WHITE),
+            (
+                tokens::TAB_TEXT_DISABLED,
+                palette::LIGHT_GRAY_2.with_alpha(0.5),
+            ),
+            (tokens::TAB_BORDER, palette::WARM_GRAY_1),
+            (tokens::TAB_PANEL_BG, palette::GRAY_1),
+            // Dialog
+            (tokens::DIALOG_BACKDROP, palette::BLACK.with_alpha(0.5)),
+            (tokens::DIALOG_BG, palette::GRAY_1),
+            (tokens::DIALOG_BORDER, palette::WARM_GRAY_1),
+            (tokens::DIALOG_TEXT, palette::LIGHT_GRAY_1),
+            (tokens::DIALOG_TITLE_TEXT, palette::WHITE),
             // Text Input
             (tokens::TEXT_INPUT_BG, palette::GRAY_1),
             (tokens::TEXT_INPUT_LABEL_BG, palette::GRAY_3),
@@ -0,0 +1,202 @@
+//! The `bevy_feathers` high-contrast theme.
+use crate::{palette, tokens};
+use bevy_color::{Alpha, Color, Luminance};
+use bevy_platform::collections::HashMap;
+
+use crate::theme::ThemeProps;
+
+/// Create a [`ThemeProps`] object and populate it with the colors for the high-contrast theme.
+pub fn create_high_contrast_theme() -> ThemeProps {
+    ThemeProps {
+        color: HashMap::from([
+            (tokens::WINDOW_BG, palette::BLACK),
+            (tokens::FOCUS_RING, palette::YELLOW),
+            (tokens::TEXT_MAIN, palette::WHITE),
+            (tokens::TEXT_DIM, palette::LIGHT_GRAY_1),
+            // Button (normal)
+            (tokens::BUTTON_BG, palette::BLACK),
+            (tokens::BUTTON_BG_HOVER, palette::GRAY_3),
+            (tokens::BUTTON_BG_PRESSED, palette::GRAY_3),
+            (tokens::BUTTON_BG_DISABLED, palette::GRAY_2),
+            // Button (primary)
+            (tokens::BUTTON_PRIMARY_BG, palette::YELLOW),
+            (tokens::BUTTON_PRIMARY_BG_HOVER, palette::YELLOW.darker(0.1)),
+            (
+                tokens::BUTTON_PRIMARY_BG_PRESSED,
+                palette::YELLOW.darker(0.2),
+            ),
+            (tokens::BUTTON_PRIMARY_BG_DISABLED, palette::GRAY_2),
+            // Button (plain)
+            (tokens::BUTTON_PLAIN_BG, Color::NONE),
+            (tokens::BUTTON_PLAIN_BG_HOVER, palette::GRAY_3),
+            (tokens::BUTTON_PLAIN_BG_PRESSED, palette::GRAY_3),
+            (tokens::BUTTON_PLAIN_BG_DISABLED, palette::GRAY_2),
+            // Button text
+            (tokens::BUTTON_TEXT, palette::WHITE),
+            (tokens::BUTTON_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::BUTTON_PRIMARY_TEXT, palette::BLACK),
+            (tokens::BUTTON_PRIMARY_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            // Slider
+            (tokens::SLIDER_BG, palette::BLACK),
+            (tokens::SLIDER_BG_HOVER, palette::GRAY_3),
+            (tokens::SLIDER_BG_PRESSED, palette::GRAY_3),
+            (tokens::SLIDER_BG_DISABLED, palette::GRAY_2),
+            (tokens::SLIDER_BAR, palette::YELLOW.darker(0.4)),
+            (tokens::SLIDER_BAR_HOVER, palette::YELLOW.darker(0.35)),
+            (tokens::SLIDER_BAR_PRESSED, palette::YELLOW.darker(0.3)),
+            (tokens::SLIDER_BAR_DISABLED, palette::GRAY_2),
+            (tokens::SLIDER_TEXT, palette::WHITE),
+            (tokens::SLIDER_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            // Scrollbar
+            (tokens::SCROLLBAR_BG, palette::BLACK),
+            (tokens::SCROLLBAR_THUMB, palette::YELLOW),
+            (tokens::SCROLLBAR_THUMB_HOVER, palette::YELLOW),
+            // Checkbox
+            (tokens::CHECKBOX_BG, palette::BLACK),
+            (tokens::CHECKBOX_BG_HOVER, palette::GRAY_3),
+            (tokens::CHECKBOX_BG_PRESSED, palette::GRAY_3),
+            (tokens::CHECKBOX_BG_DISABLED, palette::GRAY_2),
+            (tokens::CHECKBOX_BG_CHECKED, palette::YELLOW),
+            (tokens::CHECKBOX_BG_CHECKED_HOVER, palette::YELLOW),
+            (tokens::CHECKBOX_BG_CHECKED_PRESSED, palette::YELLOW),
+            (tokens::CHECKBOX_BG_CHECKED_DISABLED, palette::GRAY_2),
+            (tokens::CHECKBOX_BORDER, palette::WHITE),
+            (tokens::CHECKBOX_BORDER_HOVER, palette::WHITE),
+            (tokens::CHECKBOX_BORDER_PRESSED, palette::WHITE),
+            (tokens::CHECKBOX_BORDER_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::CHECKBOX_BORDER_CHECKED, palette::YELLOW),
+            (tokens::CHECKBOX_BORDER_CHECKED_HOVER, palette::YELLOW),
+            (tokens::CHECKBOX_BORDER_CHECKED_PRESSED, palette::YELLOW),
+            (
+                tokens::CHECKBOX_BORDER_CHECKED_DISABLED,
+                palette::LIGHT_GRAY_2,
+            ),
+            (tokens::CHECKBOX_MARK, palette::BLACK),
+            (tokens::CHECKBOX_MARK_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::CHECKBOX_TEXT, palette::WHITE),
+            (tokens::CHECKBOX_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            // Radio
+            (tokens::RADIO_BORDER, palette::WHITE),
+            (tokens::RADIO_BORDER_HOVER, palette::WHITE),
+            (tokens::RADIO_BORDER_PRESSED, palette::WHITE),
+            (tokens::RADIO_BORDER_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::RADIO_BORDER_CHECKED, palette::YELLOW),
+            (tokens::RADIO_BORDER_CHECKED_HOVER, palette::YELLOW),
+            (tokens::RADIO_BORDER_CHECKED_PRESSED, palette::YELLOW),
+            (tokens::RADIO_BORDER_CHECKED_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::RADIO_MARK, palette::YELLOW),
+            (tokens::RADIO_MARK_HOVER, palette::YELLOW),
+            (tokens::RADIO_MARK_PRESSED, palette::YELLOW),
+            (tokens::RADIO_MARK_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::RADIO_TEXT, palette::WHITE),
+            (tokens::RADIO_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            // Toggle Switch
+            (tokens::SWITCH_BG, palette::BLACK),
+            (tokens::SWITCH_BG_HOVER, palette::GRAY_3),
+            (tokens::SWITCH_BG_PRESSED, palette::GRAY_3),
+            (tokens::SWITCH_BG_DISABLED, palette::GRAY_2),
+            (tokens::SWITCH_BG_CHECKED, palette::YELLOW),
+            (tokens::SWITCH_BG_CHECKED_HOVER, palette::YELLOW),
+            (tokens::SWITCH_BG_CHECKED_PRESSED, palette::YELLOW),
+            (tokens::SWITCH_BG_CHECKED_DISABLED, palette::GRAY_2),
+            (tokens::SWITCH_BORDER, palette::WHITE),
+            (tokens::SWITCH_BORDER_HOVER, palette::WHITE),
+            (tokens::SWITCH_BORDER_PRESSED, palette::WHITE),
+            (tokens::SWITCH_BORDER_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::SWITCH_BORDER_CHECKED, palette::YELLOW),
+            (tokens::SWITCH_BORDER_CHECKED_HOVER, palette::YELLOW),
+            (tokens::SWITCH_BORDER_CHECKED_PRESSED, palette::YELLOW),
+            (
+                tokens::SWITCH_BORDER_CHECKED_DISABLED,
+                palette::LIGHT_GRAY_2,
+            ),
+            (tokens::SWITCH_SLIDE_BG, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_HOVER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_PRESSED, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_DISABLED, palette::GRAY_2),
+            (tokens::SWITCH_SLIDE_BG_CHECKED, palette::BLACK),
+            (tokens::SWITCH_SLIDE_BG_CHECKED_HOVER, palette::BLACK),
+            (tokens::SWITCH_SLIDE_BG_CHECKED_PRESSED, palette::BLACK),
+            (tokens::SWITCH_SLIDE_BG_CHECKED_DISABLED, palette::GRAY_2),
+            (tokens::SWITCH_SLIDE_BORDER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BORDER_HOVER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BORDER_PRESSED, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BORDER_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED, palette::BLACK),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED_HOVER, palette::BLACK),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED_PRESSED, palette::BLACK),
+            (
+                tokens::SWITCH_SLIDE_BORDER_CHECKED_DISABLED,
+                palette::LIGHT_GRAY_2,
+            ),
+            (tokens::COLOR_PLANE_BG, palette::BLACK),
+            // Menus
+            (tokens::MENU_BG, palette::BLACK),
+            (tokens::MENU_BORDER, palette::WHITE),
+            (tokens::MENUITEM_BG_HOVER, palette::GRAY_3),
+            (tokens::MENUITEM_BG_PRESSED, palette::GRAY_3),
+            (tokens::MENUITEM_BG_FOCUSED, palette::GRAY_3),
+            (tokens::MENUITEM_TEXT, palette::WHITE),
+            (tokens::MENUITEM_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            // Tree view
+            (tokens::TREEVIEW_BG, palette::BLACK),
+            (tokens::TREEITEM_BG_HOVER, palette::GRAY_3),
+            (tokens::TREEITEM_BG_FOCUSED, palette::GRAY_3),
+            (tokens::TREEITEM_BG_SELECTED, palette::ACCENT),
+            (tokens::TREEITEM_TEXT, palette::WHITE),
+            // Data table
+            (tokens::DATATABLE_BG, palette::BLACK),
+            (tokens::DATATABLE_BORDER, palette::WHITE),
+            (tokens::DATATABLE_TEXT, palette::WHITE),
+            (tokens::DATATABLE_HEADER_BG, palette::BLACK),
+            (tokens::DATATABLE_HEADER_BG_HOVER, palette::GRAY_3),
+            (tokens::DATATABLE_HEADER_TEXT, palette::WHITE),
+            (tokens::DATATABLE_RESIZE_HANDLE, palette::YELLOW),
+            (tokens::DATATABLE_ROW_BG_HOVER, palette::GRAY_3),
+            (tokens::DATATABLE_ROW_BG_SELECTED, palette::ACCENT),
+            // Tabs
+            (tokens::TAB_BG, Color::NONE),
+            (tokens::TAB_BG_HOVER, palette::GRAY_3),
+            (tokens::TAB_TEXT, palette::LIGHT_GRAY_1),
+            (tokens::TAB_TEXT_SELECTED, palette::WHITE),
+            (tokens::TAB_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::TAB_BORDER, palette::WHITE),
+            (tokens::TAB_PANEL_BG, palette::BLACK),
+            // Dialog
+            (tokens::DIALOG_BACKDROP, palette::BLACK.with_alpha(0.7)),
+            (tokens::DIALOG_BG, palette::BLACK),
+            (tokens::DIALOG_BORDER, palette::WHITE),
+            (tokens::DIALOG_TEXT, palette::WHITE),
+            (tokens::DIALOG_TITLE_TEXT, palette::WHITE),
+            // Text Input
+            (tokens::TEXT_INPUT_BG, palette::BLACK),
+            (tokens::TEXT_INPUT_LABEL_BG, palette::GRAY_3),
+            (tokens::TEXT_INPUT_TEXT, palette::WHITE),
+            (tokens::TEXT_INPUT_TEXT_DISABLED, palette::LIGHT_GRAY_2),
+            (tokens::TEXT_INPUT_CURSOR, palette::YELLOW),
+            (tokens::TEXT_INPUT_SELECTION, palette::ACCENT),
+            (tokens::TEXT_INPUT_SELECTION_UNFOCUSED, palette::TRANSPARENT),
+            (tokens::TEXT_INPUT_X_AXIS, palette::X_AXIS),
+            (tokens::TEXT_INPUT_Y_AXIS, palette::Y_AXIS),
+            (tokens::TEXT_INPUT_Z_AXIS, palette::Z_AXIS),
+            // Pane
+            (tokens::PANE_HEADER_BG, palette::BLACK),
+            (tokens::PANE_HEADER_BORDER, palette::WHITE),
+            (tokens::PANE_HEADER_TEXT, palette::WHITE),
+            (tokens::PANE_HEADER_DIVIDER, palette::WHITE),
+            (tokens::PANE_BODY_BG, palette::BLACK),
+            // Subpane
+            (tokens::SUBPANE_HEADER_BG, palette::BLACK),
+            (tokens::SUBPANE_HEADER_BORDER, palette::WHITE),
+            (tokens::SUBPANE_HEADER_TEXT, palette::WHITE),
+            (tokens::SUBPANE_BODY_BG, palette::BLACK),
+            (tokens::SUBPANE_BODY_BORDER, palette::WHITE),
+            // Group
+            (tokens::GROUP_HEADER_BG, palette::BLACK),
+            (tokens::GROUP_HEADER_BORDER, palette::WHITE),
+            (tokens::GROUP_HEADER_TEXT, palette::WHITE),
+            (tokens::GROUP_BODY_BG, palette::BLACK),
+            (tokens::GROUP_BODY_BORDER, palette::WHITE),
+        ]),
+    }
+}
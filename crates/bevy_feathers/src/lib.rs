@@ -31,7 +31,7 @@ extern crate alloc;
 use bevy_app::{
     HierarchyPropagatePlugin, Plugin, PluginGroup, PluginGroupBuilder, PostUpdate, PropagateSet,
 };
-use bevy_asset::embedded_asset;
+use bevy_asset::{embedded_asset, AssetApp};
 use bevy_ecs::{query::With, schedule::IntoScheduleConfigs};
 use bevy_input_focus::tab_navigation::TabNavigationPlugin;
 use bevy_text::{TextColor, TextFont};
@@ -42,7 +42,7 @@ use crate::{
     alpha_pattern::{AlphaPatternMaterial, AlphaPatternResource},
     controls::ControlsPlugin,
     cursor::{CursorIconPlugin, DefaultCursor, EntityCursor},
-    theme::{ThemedText, UiTheme},
+    theme::{ThemeProps, ThemePropsLoader, ThemedText, UiTheme},
 };
 
 mod alpha_pattern;
@@ -54,6 +54,8 @@ pub mod dark_theme;
 pub mod display;
 pub mod focus;
 pub mod font_styles;
+pub mod high_contrast_theme;
+pub mod light_theme;
 pub mod palette;
 pub mod rounded_corners;
 pub mod theme;
@@ -64,7 +66,9 @@ pub struct FeathersCorePlugin;
 
 impl Plugin for FeathersCorePlugin {
     fn build(&self, app: &mut bevy_app::App) {
-        app.init_resource::<UiTheme>();
+        app.init_resource::<UiTheme>()
+            .init_asset::<ThemeProps>()
+            .init_asset_loader::<ThemePropsLoader>();
 
         // Embedded font
         embedded_asset!(app, "assets/fonts/FiraSans-Bold.ttf");
@@ -102,12 +106,18 @@ impl Plugin for FeathersCorePlugin {
             bevy_window::SystemCursorIcon::Default,
         )));
 
-        app.add_systems(PostUpdate, theme::update_theme)
-            .add_observer(theme::on_changed_background)
-            .add_observer(theme::on_changed_border)
-            .add_observer(theme::on_changed_font_color)
-            .add_observer(theme::on_changed_text_color)
-            .add_observer(font_styles::on_changed_font);
+        app.add_systems(
+            PostUpdate,
+            (theme::update_theme_from_source, theme::update_theme)
+                .chain()
+                // Let transitions animate theme changes, rather than displaying the new colors for a frame.
+                .before(UiSystems::Prepare),
+        )
+        .add_observer(theme::on_changed_background)
+        .add_observer(theme::on_changed_border)
+        .add_observer(theme::on_changed_font_color)
+        .add_observer(theme::on_changed_text_color)
+        .add_observer(font_styles::on_changed_font);
 
         app.init_resource::<AlphaPatternResource>();
     }
@@ -0,0 +1,313 @@
+//! The `bevy_feathers` light theme.
+use crate::{palette, tokens};
+use bevy_color::{Alpha, Color, Luminance};
+use bevy_platform::collections::HashMap;
+
+use crate::theme::ThemeProps;
+
+/// Create a [`ThemeProps`] object and populate it with the colors for the light theme.
+pub fn create_light_theme() -> ThemeProps {
+    ThemeProps {
+        color: HashMap::from([
+            (tokens::WINDOW_BG, palette::SILVER_0),
+            (tokens::FOCUS_RING, palette::ACCENT.with_alpha(0.5)),
+            (tokens::TEXT_MAIN, palette::DARK_GRAY_1),
+            (tokens::TEXT_DIM, palette::DARK_GRAY_2),
+            // Button (normal)
+            (tokens::BUTTON_BG, palette::SILVER_3),
+            (tokens::BUTTON_BG_HOVER, palette::SILVER_3.darker(0.05)),
+            (tokens::BUTTON_BG_PRESSED, palette::SILVER_3.darker(0.1)),
+            (tokens::BUTTON_BG_DISABLED, palette::SILVER_2),
+            // Button (primary)
+            (tokens::BUTTON_PRIMARY_BG, palette::ACCENT),
+            (
+                tokens::BUTTON_PRIMARY_BG_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::BUTTON_PRIMARY_BG_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (tokens::BUTTON_PRIMARY_BG_DISABLED, palette::SILVER_2),
+            // Button (plain)
+            (tokens::BUTTON_PLAIN_BG, Color::NONE),
+            (tokens::BUTTON_PLAIN_BG_HOVER, palette::SILVER_2),
+            (tokens::BUTTON_PLAIN_BG_PRESSED, palette::SILVER_3),
+            (tokens::BUTTON_PLAIN_BG_DISABLED, Color::NONE),
+            // Button text
+            (tokens::BUTTON_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::BUTTON_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            (tokens::BUTTON_PRIMARY_TEXT, palette::WHITE),
+            (
+                tokens::BUTTON_PRIMARY_TEXT_DISABLED,
+                palette::WHITE.with_alpha(0.5),
+            ),
+            // Slider
+            (tokens::SLIDER_BG, palette::SILVER_2),
+            (tokens::SLIDER_BG_HOVER, palette::SILVER_2.darker(0.05)),
+            (tokens::SLIDER_BG_PRESSED, palette::SILVER_2.darker(0.1)),
+            (tokens::SLIDER_BG_DISABLED, palette::SILVER_2),
+            (tokens::SLIDER_BAR, palette::ACCENT.lighter(0.25)),
+            (tokens::SLIDER_BAR_HOVER, palette::ACCENT.lighter(0.2)),
+            (tokens::SLIDER_BAR_PRESSED, palette::ACCENT.lighter(0.15)),
+            (tokens::SLIDER_BAR_DISABLED, palette::SILVER_3),
+            (tokens::SLIDER_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::SLIDER_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            // Scrollbar
+            (tokens::SCROLLBAR_BG, palette::SILVER_2),
+            (tokens::SCROLLBAR_THUMB, palette::ACCENT),
+            (tokens::SCROLLBAR_THUMB_HOVER, palette::ACCENT.darker(0.1)),
+            // Checkbox
+            (tokens::CHECKBOX_BG, palette::SILVER_3),
+            (tokens::CHECKBOX_BG_HOVER, palette::SILVER_3),
+            (tokens::CHECKBOX_BG_PRESSED, palette::SILVER_3),
+            (
+                tokens::CHECKBOX_BG_DISABLED,
+                palette::SILVER_1.with_alpha(0.5),
+            ),
+            (tokens::CHECKBOX_BG_CHECKED, palette::ACCENT),
+            (
+                tokens::CHECKBOX_BG_CHECKED_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::CHECKBOX_BG_CHECKED_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (
+                tokens::CHECKBOX_BG_CHECKED_DISABLED,
+                palette::SILVER_1.with_alpha(0.5),
+            ),
+            (tokens::CHECKBOX_BORDER, palette::SILVER_3),
+            (
+                tokens::CHECKBOX_BORDER_HOVER,
+                palette::SILVER_3.darker(0.05),
+            ),
+            (
+                tokens::CHECKBOX_BORDER_PRESSED,
+                palette::SILVER_3.darker(0.1),
+            ),
+            (
+                tokens::CHECKBOX_BORDER_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::CHECKBOX_BORDER_CHECKED, palette::ACCENT),
+            (
+                tokens::CHECKBOX_BORDER_CHECKED_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::CHECKBOX_BORDER_CHECKED_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (
+                tokens::CHECKBOX_BORDER_CHECKED_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::CHECKBOX_MARK, palette::WHITE),
+            (tokens::CHECKBOX_MARK_DISABLED, palette::DARK_GRAY_2),
+            (tokens::CHECKBOX_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::CHECKBOX_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            // Radio
+            (tokens::RADIO_BORDER, palette::SILVER_3),
+            (tokens::RADIO_BORDER_HOVER, palette::SILVER_3.darker(0.05)),
+            (tokens::RADIO_BORDER_PRESSED, palette::SILVER_3.darker(0.1)),
+            (
+                tokens::RADIO_BORDER_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::RADIO_BORDER_CHECKED, palette::ACCENT),
+            (
+                tokens::RADIO_BORDER_CHECKED_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::RADIO_BORDER_CHECKED_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (
+                tokens::RADIO_BORDER_CHECKED_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::RADIO_MARK, palette::ACCENT),
+            (tokens::RADIO_MARK_HOVER, palette::ACCENT.darker(0.05)),
+            (tokens::RADIO_MARK_PRESSED, palette::ACCENT.darker(0.1)),
+            (
+                tokens::RADIO_MARK_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::RADIO_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::RADIO_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            // Toggle Switch
+            (tokens::SWITCH_BG, palette::SILVER_3),
+            (tokens::SWITCH_BG_HOVER, palette::SILVER_3.darker(0.05)),
+            (tokens::SWITCH_BG_PRESSED, palette::SILVER_3.darker(0.1)),
+            (
+                tokens::SWITCH_BG_DISABLED,
+                palette::SILVER_1.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_BG_CHECKED, palette::ACCENT),
+            (
+                tokens::SWITCH_BG_CHECKED_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::SWITCH_BG_CHECKED_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (
+                tokens::SWITCH_BG_CHECKED_DISABLED,
+                palette::SILVER_1.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_BORDER, palette::SILVER_3),
+            (tokens::SWITCH_BORDER_HOVER, palette::SILVER_3.darker(0.05)),
+            (tokens::SWITCH_BORDER_PRESSED, palette::SILVER_3.darker(0.1)),
+            (
+                tokens::SWITCH_BORDER_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_BORDER_CHECKED, palette::ACCENT),
+            (
+                tokens::SWITCH_BORDER_CHECKED_HOVER,
+                palette::ACCENT.darker(0.05),
+            ),
+            (
+                tokens::SWITCH_BORDER_CHECKED_PRESSED,
+                palette::ACCENT.darker(0.1),
+            ),
+            (
+                tokens::SWITCH_BORDER_CHECKED_DISABLED,
+                palette::SILVER_3.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_SLIDE_BG, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_HOVER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_PRESSED, palette::WHITE),
+            (
+                tokens::SWITCH_SLIDE_BG_DISABLED,
+                palette::SILVER_1.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_SLIDE_BG_CHECKED, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_CHECKED_HOVER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BG_CHECKED_PRESSED, palette::WHITE),
+            (
+                tokens::SWITCH_SLIDE_BG_CHECKED_DISABLED,
+                palette::DARK_GRAY_2.with_alpha(0.3),
+            ),
+            (tokens::SWITCH_SLIDE_BORDER, palette::WARM_GRAY_2),
+            (tokens::SWITCH_SLIDE_BORDER_HOVER, palette::WARM_GRAY_2),
+            (tokens::SWITCH_SLIDE_BORDER_PRESSED, palette::WARM_GRAY_2),
+            (
+                tokens::SWITCH_SLIDE_BORDER_DISABLED,
+                palette::SILVER_2.with_alpha(0.5),
+            ),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED_HOVER, palette::WHITE),
+            (tokens::SWITCH_SLIDE_BORDER_CHECKED_PRESSED, palette::WHITE),
+            (
+                tokens::SWITCH_SLIDE_BORDER_CHECKED_DISABLED,
+                palette::DARK_GRAY_2.with_alpha(0.3),
+            ),
+            (tokens::COLOR_PLANE_BG, palette::SILVER_1),
+            // Menus
+            (tokens::MENU_BG, palette::SILVER_1),
+            (tokens::MENU_BORDER, palette::WARM_GRAY_2),
+            (tokens::MENUITEM_BG_HOVER, palette::SILVER_1.darker(0.05)),
+            (tokens::MENUITEM_BG_PRESSED, palette::SILVER_1.darker(0.1)),
+            (tokens::MENUITEM_BG_FOCUSED, palette::SILVER_1.darker(0.1)),
+            (tokens::MENUITEM_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::MENUITEM_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            // Tree view
+            (tokens::TREEVIEW_BG, palette::SILVER_1),
+            (tokens::TREEITEM_BG_HOVER, palette::SILVER_1.darker(0.05)),
+            (tokens::TREEITEM_BG_FOCUSED, palette::SILVER_1.darker(0.1)),
+            (
+                tokens::TREEITEM_BG_SELECTED,
+                palette::ACCENT.with_alpha(0.25),
+            ),
+            (tokens::TREEITEM_TEXT, palette::DARK_GRAY_1),
+            // Data table
+            (tokens::DATATABLE_BG, palette::SILVER_1),
+            (tokens::DATATABLE_BORDER, palette::WARM_GRAY_2),
+            (tokens::DATATABLE_TEXT, palette::DARK_GRAY_1),
+            (tokens::DATATABLE_HEADER_BG, palette::SILVER_2),
+            (
+                tokens::DATATABLE_HEADER_BG_HOVER,
+                palette::SILVER_2.darker(0.05),
+            ),
+            (tokens::DATATABLE_HEADER_TEXT, palette::DARK_GRAY_1),
+            (tokens::DATATABLE_RESIZE_HANDLE, palette::ACCENT),
+            (
+                tokens::DATATABLE_ROW_BG_HOVER,
+                palette::SILVER_1.darker(0.05),
+            ),
+            (
+                tokens::DATATABLE_ROW_BG_SELECTED,
+                palette::ACCENT.with_alpha(0.25),
+            ),
+            // Tabs
+            (tokens::TAB_BG, Color::NONE),
+            (tokens::TAB_BG_HOVER, palette::SILVER_2),
+            (tokens::TAB_TEXT, palette::DARK_GRAY_2),
+            (tokens::TAB_TEXT_SELECTED, palette::DARK_GRAY_1),
+            (
+                tokens::TAB_TEXT_DISABLED,
+                palette::DARK_GRAY_2.with_alpha(0.5),
+            ),
+            (tokens::TAB_BORDER, palette::WARM_GRAY_2),
+            (tokens::TAB_PANEL_BG, palette::SILVER_1),
+            // Dialog
+            (tokens::DIALOG_BACKDROP, palette::BLACK.with_alpha(0.3)),
+            (tokens::DIALOG_BG, palette::SILVER_1),
+            (tokens::DIALOG_BORDER, palette::WARM_GRAY_2),
+            (tokens::DIALOG_TEXT, palette::DARK_GRAY_1),
+            (tokens::DIALOG_TITLE_TEXT, palette::DARK_GRAY_1),
+            // Text Input
+            (tokens::TEXT_INPUT_BG, palette::WHITE),
+            (tokens::TEXT_INPUT_LABEL_BG, palette::SILVER_3),
+            (tokens::TEXT_INPUT_TEXT, palette::DARK_GRAY_1),
+            (
+                tokens::TEXT_INPUT_TEXT_DISABLED,
+                palette::DARK_GRAY_1.with_alpha(0.5),
+            ),
+            (tokens::TEXT_INPUT_CURSOR, palette::ACCENT),
+            (tokens::TEXT_INPUT_SELECTION, palette::ACCENT.lighter(0.3)),
+            (tokens::TEXT_INPUT_SELECTION_UNFOCUSED, palette::TRANSPARENT),
+            (tokens::TEXT_INPUT_X_AXIS, palette::X_AXIS),
+            (tokens::TEXT_INPUT_Y_AXIS, palette::Y_AXIS),
+            (tokens::TEXT_INPUT_Z_AXIS, palette::Z_AXIS),
+            // Pane
+            (tokens::PANE_HEADER_BG, palette::SILVER_0),
+            (tokens::PANE_HEADER_BORDER, palette::WARM_GRAY_2),
+            (tokens::PANE_HEADER_TEXT, palette::DARK_GRAY_1),
+            (tokens::PANE_HEADER_DIVIDER, palette::WARM_GRAY_2),
+            (tokens::PANE_BODY_BG, palette::SILVER_1),
+            // Subpane
+            (tokens::SUBPANE_HEADER_BG, palette::SILVER_2),
+            (tokens::SUBPANE_HEADER_BORDER, palette::SILVER_3),
+            (tokens::SUBPANE_HEADER_TEXT, palette::DARK_GRAY_1),
+            (tokens::SUBPANE_BODY_BG, palette::SILVER_1),
+            (tokens::SUBPANE_BODY_BORDER, palette::SILVER_2),
+            // Group
+            (tokens::GROUP_HEADER_BG, palette::SILVER_2),
+            (tokens::GROUP_HEADER_BORDER, palette::SILVER_3),
+            (tokens::GROUP_HEADER_TEXT, palette::DARK_GRAY_1),
+            (tokens::GROUP_BODY_BG, palette::SILVER_2),
+            (tokens::GROUP_BODY_BORDER, palette::SILVER_3),
+        ]),
+    }
+}
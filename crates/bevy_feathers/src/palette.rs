@@ -29,3 +29,19 @@ pub const X_AXIS: Color = Color::oklcha(0.5232, 0.1404, 13.84, 1.0);
 pub const Y_AXIS: Color = Color::oklcha(0.5866, 0.1543, 129.84, 1.0);
 /// <div style="background-color: #2160A3; width: 10px; padding: 10px; border: 1px solid;"></div> - for Z-axis inputs and drag handles
 pub const Z_AXIS: Color = Color::oklcha(0.4847, 0.1249, 253.08, 1.0);
+/// <div style="background-color: #F3F3F5; width: 10px; padding: 10px; border: 1px solid;"></div> - window background (light)
+pub const SILVER_0: Color = Color::oklcha(0.9647, 0.0027, 286.35, 1.0);
+/// <div style="background-color: #FAFAFB; width: 10px; padding: 10px; border: 1px solid;"></div> - pane background (light)
+pub const SILVER_1: Color = Color::oklcha(0.9854, 0.0013, 286.38, 1.0);
+/// <div style="background-color: #E8E8EC; width: 10px; padding: 10px; border: 1px solid;"></div> - item background (light)
+pub const SILVER_2: Color = Color::oklcha(0.9321, 0.0054, 286.30, 1.0);
+/// <div style="background-color: #DCDCE1; width: 10px; padding: 10px; border: 1px solid;"></div> - item background (light, active)
+pub const SILVER_3: Color = Color::oklcha(0.8959, 0.0068, 286.26, 1.0);
+/// <div style="background-color: #C4C4C6; width: 10px; padding: 10px; border: 1px solid;"></div> - border (light)
+pub const WARM_GRAY_2: Color = Color::oklcha(0.8209, 0.0028, 286.34, 1.0);
+/// <div style="background-color: #26262B; width: 10px; padding: 10px; border: 1px solid;"></div> - bright label text (light)
+pub const DARK_GRAY_1: Color = Color::oklcha(0.2707, 0.0092, 285.77, 1.0);
+/// <div style="background-color: #6B6B72; width: 10px; padding: 10px; border: 1px solid;"></div> - dim label text (light)
+pub const DARK_GRAY_2: Color = Color::oklcha(0.5302, 0.0108, 286.02, 1.0);
+/// <div style="background-color: #FFD60A; width: 10px; padding: 10px; border: 1px solid;"></div> - call-to-action and selection color (high contrast)
+pub const YELLOW: Color = Color::oklcha(0.8849, 0.1805, 94.78, 1.0);
@@ -1,22 +1,38 @@
 //! A framework for theming.
+//!
+//! The current theme is held in the [`UiTheme`] resource. Feathers comes with a
+//! [dark](crate::dark_theme), a [light](crate::light_theme) and a
+//! [high-contrast](crate::high_contrast_theme) theme, and themes can also be loaded
+//! from `.theme.ron` asset files (see [`ThemePropsLoader`] and [`UiThemeSource`]).
+//!
+//! Changing the theme at runtime updates the colors of all themed entities in place.
+use alloc::collections::BTreeMap;
+
 use bevy_app::{Propagate, PropagateOver};
-use bevy_color::{palettes, Color};
+use bevy_asset::{io::Reader, Asset, AssetEvent, AssetLoader, Assets, Handle, LoadContext};
+use bevy_color::{palettes, Color, HexColorError, Srgba};
 use bevy_ecs::{
     change_detection::DetectChanges,
     component::Component,
+    entity::Entity,
     lifecycle::Insert,
+    message::MessageReader,
     observer::On,
     query::Changed,
     reflect::{ReflectComponent, ReflectResource},
     resource::Resource,
-    system::{Commands, Query, Res},
+    system::{Commands, Query, Res, ResMut},
 };
 use bevy_log::warn_once;
 use bevy_platform::collections::HashMap;
-use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_reflect::{prelude::ReflectDefault, Reflect, TypePath};
 use bevy_text::TextColor;
 use bevy_ui::{BackgroundColor, BorderColor};
+use serde::Deserialize;
 use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::{dark_theme, high_contrast_theme, light_theme};
 
 /// A design token for the theme. This serves as the lookup key for the theme properties.
 #[derive(Clone, PartialEq, Eq, Hash, Reflect, Default)]
@@ -47,7 +63,9 @@ impl core::fmt::Debug for ThemeToken {
 }
 
 /// A collection of properties that make up a theme.
-#[derive(Default, Clone, Reflect, Debug)]
+///
+/// Themes can be loaded from asset files by the [`ThemePropsLoader`].
+#[derive(Asset, Default, Clone, Reflect, Debug)]
 #[reflect(Default, Debug)]
 pub struct ThemeProps {
     /// Map of design tokens to colors.
@@ -55,7 +73,10 @@ pub struct ThemeProps {
     // Other style property types to be added later.
 }
 
-/// The currently selected user interface theme. Overwriting this resource changes the theme.
+/// The currently selected user interface theme. Overwriting this resource changes the theme,
+/// for example with `UiTheme(create_light_theme())`.
+///
+/// If a [`UiThemeSource`] is present, this is overwritten whenever its asset is (re)loaded.
 #[derive(Resource, Default, Reflect, Debug)]
 #[reflect(Resource, Default, Debug)]
 pub struct UiTheme(pub ThemeProps);
@@ -83,6 +104,133 @@ impl UiTheme {
     }
 }
 
+/// Sets the [`UiTheme`] to a [`ThemeProps`] asset, and updates it whenever the asset is hot reloaded.
+///
+/// Remove this resource before overwriting [`UiTheme`] directly, or the theme will be replaced
+/// the next time the asset changes.
+#[derive(Resource, Clone, Debug)]
+pub struct UiThemeSource(pub Handle<ThemeProps>);
+
+/// One of the themes built into Feathers, used as the base of a theme file.
+#[derive(Deserialize, Clone, Copy, Debug)]
+enum ThemeBase {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+/// The contents of a `.theme.ron` file.
+#[derive(Deserialize)]
+struct ThemeFile {
+    /// Built-in theme to start from. Tokens not listed in `color` keep their colors from this theme.
+    #[serde(default)]
+    base: Option<ThemeBase>,
+    /// Map of design tokens to hex colors, such as `"#206EC9"` or `"#00000080"`.
+    #[serde(default)]
+    color: BTreeMap<String, String>,
+}
+
+/// An [`AssetLoader`] for [`ThemeProps`], reading `.theme.ron` files.
+///
+/// Theme files can start from one of the built-in themes and override individual tokens:
+///
+/// ```ron
+/// (
+///     base: Some(Dark),
+///     color: {
+///         "feathers.window.bg": "#101014",
+///         "feathers.button.bg.primary": "#C92065",
+///     },
+/// )
+/// ```
+///
+/// The base may be `Dark`, `Light` or `HighContrast`. Without a base, only the listed tokens are set.
+#[derive(Default, TypePath)]
+pub struct ThemePropsLoader;
+
+/// Possible errors that can be produced by [`ThemePropsLoader`].
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ThemePropsLoaderError {
+    /// An [IO](std::io) Error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The file is not valid RON, or doesn't match the theme file format.
+    #[error(transparent)]
+    Ron(#[from] ron::error::SpannedError),
+    /// A color is not a valid hex color.
+    #[error("Invalid color for theme token {token}: {error}")]
+    InvalidColor {
+        /// The token whose color is invalid.
+        token: String,
+        /// The reason the color is invalid.
+        error: HexColorError,
+    },
+}
+
+impl AssetLoader for ThemePropsLoader {
+    type Asset = ThemeProps;
+    type Settings = ();
+    type Error = ThemePropsLoaderError;
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<ThemeProps, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        parse_theme(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
+
+/// Builds the [`ThemeProps`] described by the contents of a `.theme.ron` file.
+fn parse_theme(bytes: &[u8]) -> Result<ThemeProps, ThemePropsLoaderError> {
+    let file: ThemeFile = ron::de::from_bytes(bytes)?;
+
+    let mut props = match file.base {
+        Some(ThemeBase::Dark) => dark_theme::create_dark_theme(),
+        Some(ThemeBase::Light) => light_theme::create_light_theme(),
+        Some(ThemeBase::HighContrast) => high_contrast_theme::create_high_contrast_theme(),
+        None => ThemeProps::default(),
+    };
+    for (token, color) in file.color {
+        let color = Srgba::hex(&color).map_err(|error| ThemePropsLoaderError::InvalidColor {
+            token: token.clone(),
+            error,
+        })?;
+        props
+            .color
+            .insert(ThemeToken::new(SmolStr::new(token)), color.into());
+    }
+    Ok(props)
+}
+
+/// Copies the [`UiThemeSource`] asset into the [`UiTheme`] when it is loaded or modified.
+pub(crate) fn update_theme_from_source(
+    source: Option<Res<UiThemeSource>>,
+    mut events: MessageReader<AssetEvent<ThemeProps>>,
+    assets: Res<Assets<ThemeProps>>,
+    mut theme: ResMut<UiTheme>,
+) {
+    let Some(source) = source else {
+        events.clear();
+        return;
+    };
+    let asset_changed = events
+        .read()
+        .any(|event| event.is_loaded_with_dependencies(&source.0) || event.is_modified(&source.0));
+    if (asset_changed || source.is_changed())
+        && let Some(props) = assets.get(&source.0)
+    {
+        theme.0 = props.clone();
+    }
+}
+
 /// Component which causes the background color of an entity to be set based on a theme color.
 #[derive(Component, Clone, Default)]
 #[require(BackgroundColor)]
@@ -130,7 +278,9 @@ pub(crate) fn update_theme(
     mut q_background: Query<(&mut BackgroundColor, &ThemeBackgroundColor)>,
     mut q_border: Query<(&mut BorderColor, &ThemeBorderColor)>,
     mut q_text_color: Query<(&mut TextColor, &ThemeTextColor)>,
+    q_inherited_text_color: Query<(Entity, &InheritableThemeTextColor)>,
     theme: Res<UiTheme>,
+    mut commands: Commands,
 ) {
     if theme.is_changed() {
         // Update all background colors
@@ -147,6 +297,13 @@ pub(crate) fn update_theme(
         for (mut text_color, theme_text_color) in q_text_color.iter_mut() {
             text_color.0 = theme.color(&theme_text_color.0);
         }
+
+        // Update all inherited text colors
+        for (entity, theme_text_color) in q_inherited_text_color.iter() {
+            commands
+                .entity(entity)
+                .insert(Propagate(TextColor(theme.color(&theme_text_color.0))));
+        }
     }
 }
 
@@ -201,3 +358,79 @@ pub(crate) fn on_changed_font_color(
             .insert(Propagate(TextColor(color)));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{schedule::Schedule, world::World};
+
+    use crate::tokens;
+
+    #[test]
+    fn parse_base_and_overrides() {
+        let props = parse_theme(
+            br##"(
+                base: Some(Dark),
+                color: { "feathers.window.bg": "#101014" },
+            )"##,
+        )
+        .unwrap();
+        let dark = dark_theme::create_dark_theme();
+        assert_eq!(props.color.len(), dark.color.len());
+        assert_eq!(
+            props.color[&tokens::WINDOW_BG],
+            Srgba::hex("101014").unwrap().into()
+        );
+        assert_eq!(
+            props.color[&tokens::BUTTON_BG],
+            dark.color[&tokens::BUTTON_BG]
+        );
+
+        let props = parse_theme(br##"(color: { "custom.token": "#00000080" })"##).unwrap();
+        assert_eq!(props.color.len(), 1);
+        assert_eq!(
+            props.color[&ThemeToken::new_static("custom.token")],
+            Srgba::new(0.0, 0.0, 0.0, 128.0 / 255.0).into()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_color() {
+        let result = parse_theme(br##"(color: { "feathers.window.bg": "#12345" })"##);
+        assert!(matches!(
+            result,
+            Err(ThemePropsLoaderError::InvalidColor { token, .. }) if token == "feathers.window.bg"
+        ));
+        assert!(matches!(
+            parse_theme(b"(base: Some(Sepia))"),
+            Err(ThemePropsLoaderError::Ron(_))
+        ));
+    }
+
+    #[test]
+    fn switching_theme_updates_existing_entities() {
+        let mut world = World::new();
+        world.insert_resource(UiTheme(dark_theme::create_dark_theme()));
+        let background = world.spawn(ThemeBackgroundColor(tokens::WINDOW_BG)).id();
+        let text = world
+            .spawn(InheritableThemeTextColor(tokens::TEXT_MAIN))
+            .id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_theme);
+        schedule.run(&mut world);
+
+        let background_color = |world: &World| world.get::<BackgroundColor>(background).unwrap().0;
+        let text_color = |world: &World| world.get::<Propagate<TextColor>>(text).unwrap().0 .0;
+        let dark = world.resource::<UiTheme>().color(&tokens::WINDOW_BG);
+        assert_eq!(background_color(&world), dark);
+
+        let light = light_theme::create_light_theme();
+        world.insert_resource(UiTheme(light.clone()));
+        schedule.run(&mut world);
+
+        // The same entities are updated in place.
+        assert_eq!(background_color(&world), light.color[&tokens::WINDOW_BG]);
+        assert_eq!(text_color(&world), light.color[&tokens::TEXT_MAIN]);
+        assert_ne!(background_color(&world), dark);
+    }
+}
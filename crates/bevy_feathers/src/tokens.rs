@@ -306,6 +306,76 @@ pub const MENUITEM_TEXT: ThemeToken = ThemeToken::new_static("feathers.menuitem.
 pub const MENUITEM_TEXT_DISABLED: ThemeToken =
     ThemeToken::new_static("feathers.menuitem.text.disabled");
 
+// Tree view
+
+/// Tree view background
+pub const TREEVIEW_BG: ThemeToken = ThemeToken::new_static("feathers.treeview.bg");
+/// Tree item background (hovered)
+pub const TREEITEM_BG_HOVER: ThemeToken = ThemeToken::new_static("feathers.treeitem.bg.hover");
+/// Tree item background (focused)
+pub const TREEITEM_BG_FOCUSED: ThemeToken = ThemeToken::new_static("feathers.treeitem.bg.focused");
+/// Tree item background (selected)
+pub const TREEITEM_BG_SELECTED: ThemeToken =
+    ThemeToken::new_static("feathers.treeitem.bg.selected");
+/// Tree item text
+pub const TREEITEM_TEXT: ThemeToken = ThemeToken::new_static("feathers.treeitem.text");
+
+// Data table
+
+/// Data table background
+pub const DATATABLE_BG: ThemeToken = ThemeToken::new_static("feathers.datatable.bg");
+/// Data table border
+pub const DATATABLE_BORDER: ThemeToken = ThemeToken::new_static("feathers.datatable.border");
+/// Data table text
+pub const DATATABLE_TEXT: ThemeToken = ThemeToken::new_static("feathers.datatable.text");
+/// Data table header background
+pub const DATATABLE_HEADER_BG: ThemeToken = ThemeToken::new_static("feathers.datatable.header.bg");
+/// Data table header background (hovered)
+pub const DATATABLE_HEADER_BG_HOVER: ThemeToken =
+    ThemeToken::new_static("feathers.datatable.header.bg.hover");
+/// Data table header text
+pub const DATATABLE_HEADER_TEXT: ThemeToken =
+    ThemeToken::new_static("feathers.datatable.header.text");
+/// Data table column resize handle (hovered)
+pub const DATATABLE_RESIZE_HANDLE: ThemeToken =
+    ThemeToken::new_static("feathers.datatable.resize.handle");
+/// Data table row background (hovered)
+pub const DATATABLE_ROW_BG_HOVER: ThemeToken =
+    ThemeToken::new_static("feathers.datatable.row.bg.hover");
+/// Data table row background (selected)
+pub const DATATABLE_ROW_BG_SELECTED: ThemeToken =
+    ThemeToken::new_static("feathers.datatable.row.bg.selected");
+
+// Tabs
+
+/// Tab background
+pub const TAB_BG: ThemeToken = ThemeToken::new_static("feathers.tab.bg");
+/// Tab background (hovered)
+pub const TAB_BG_HOVER: ThemeToken = ThemeToken::new_static("feathers.tab.bg.hover");
+/// Tab text
+pub const TAB_TEXT: ThemeToken = ThemeToken::new_static("feathers.tab.text");
+/// Tab text (active tab)
+pub const TAB_TEXT_SELECTED: ThemeToken = ThemeToken::new_static("feathers.tab.text.selected");
+/// Tab text (disabled)
+pub const TAB_TEXT_DISABLED: ThemeToken = ThemeToken::new_static("feathers.tab.text.disabled");
+/// Border under the tab group, and around the active tab
+pub const TAB_BORDER: ThemeToken = ThemeToken::new_static("feathers.tab.border");
+/// Tab panel background
+pub const TAB_PANEL_BG: ThemeToken = ThemeToken::new_static("feathers.tab.panel.bg");
+
+// Dialog
+
+/// Dialog backdrop, covering the UI underneath the dialog
+pub const DIALOG_BACKDROP: ThemeToken = ThemeToken::new_static("feathers.dialog.backdrop");
+/// Dialog background
+pub const DIALOG_BG: ThemeToken = ThemeToken::new_static("feathers.dialog.bg");
+/// Dialog border
+pub const DIALOG_BORDER: ThemeToken = ThemeToken::new_static("feathers.dialog.border");
+/// Dialog text
+pub const DIALOG_TEXT: ThemeToken = ThemeToken::new_static("feathers.dialog.text");
+/// Dialog title text
+pub const DIALOG_TITLE_TEXT: ThemeToken = ThemeToken::new_static("feathers.dialog.title.text");
+
 // Text Input
 
 /// Background for text input
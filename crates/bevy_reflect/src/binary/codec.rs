@@ -0,0 +1,364 @@
+use super::BinaryError;
+use crate::{FromReflect, FromType, PartialReflect, Reflect, TypePath};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::String,
+    vec::Vec,
+};
+use core::{any::TypeId, time::Duration};
+
+/// A type that can be written to and read from the [binary reflection format](super) directly.
+///
+/// The binary format handles structs, enums, lists and the other [reflection kinds] by walking
+/// their fields, but [opaque] types have no fields to walk: they are only supported if they
+/// implement this trait. Primitives, strings and [`Duration`] are supported out of the box;
+/// other opaque types can opt in by implementing this trait and registering
+/// [`ReflectBinaryCodec`] for the type (usually with `#[reflect(BinaryCodec)]`).
+///
+/// Implementations typically encode their data using the implementations for primitives:
+///
+/// ```
+/// # use bevy_reflect::binary::{BinaryCodec, BinaryError};
+/// struct Handle(u32);
+///
+/// impl BinaryCodec for Handle {
+///     fn encode(&self, output: &mut Vec<u8>) {
+///         self.0.encode(output);
+///     }
+///
+///     fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+///         u32::decode(input).map(Handle)
+///     }
+/// }
+/// ```
+///
+/// [reflection kinds]: crate::ReflectKind
+/// [opaque]: crate::ReflectKind::Opaque
+pub trait BinaryCodec: Sized {
+    /// Appends the encoded value to `output`.
+    fn encode(&self, output: &mut Vec<u8>);
+
+    /// Decodes a value from the start of `input`, advancing it past the decoded bytes.
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError>;
+}
+
+/// Type data for [opaque] types that implement [`BinaryCodec`].
+///
+/// A `ReflectBinaryCodec` for type `T` can be obtained via [`FromType::from_type`].
+///
+/// [opaque]: crate::ReflectKind::Opaque
+#[derive(Clone)]
+pub struct ReflectBinaryCodec {
+    encode: fn(&dyn PartialReflect, &mut Vec<u8>) -> Result<(), BinaryError>,
+    decode: fn(&mut &[u8]) -> Result<Box<dyn Reflect>, BinaryError>,
+}
+
+impl ReflectBinaryCodec {
+    /// Appends the encoded `value` to `output`.
+    ///
+    /// Returns [`BinaryError::MismatchedType`] if `value` can't be converted to the type this
+    /// type data was created for.
+    pub fn encode(
+        &self,
+        value: &dyn PartialReflect,
+        output: &mut Vec<u8>,
+    ) -> Result<(), BinaryError> {
+        (self.encode)(value, output)
+    }
+
+    /// Decodes a value from the start of `input`, advancing it past the decoded bytes.
+    pub fn decode(&self, input: &mut &[u8]) -> Result<Box<dyn Reflect>, BinaryError> {
+        (self.decode)(input)
+    }
+}
+
+impl<T: BinaryCodec + FromReflect + TypePath> FromType<T> for ReflectBinaryCodec {
+    fn from_type() -> Self {
+        Self {
+            encode: |value, output| {
+                if let Some(value) = value.try_downcast_ref::<T>() {
+                    value.encode(output);
+                } else {
+                    T::from_reflect(value)
+                        .ok_or_else(|| BinaryError::MismatchedType {
+                            expected: T::type_path(),
+                            received: value.reflect_type_path().to_owned(),
+                        })?
+                        .encode(output);
+                }
+                Ok(())
+            },
+            decode: |input| Ok(Box::new(T::decode(input)?)),
+        }
+    }
+}
+
+/// Invokes `$macro` with the list of opaque types with built-in support.
+macro_rules! with_builtin_types {
+    ($macro:ident) => {
+        $macro!(
+            bool,
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            usize,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            isize,
+            f32,
+            f64,
+            char,
+            String,
+            Cow<'static, str>,
+            Duration,
+        )
+    };
+}
+
+/// Encodes `value` if its declared type, identified by `type_id`, is one of the opaque types
+/// with built-in support.
+///
+/// Returns `None` if the type isn't supported.
+pub(super) fn encode_builtin(
+    type_id: TypeId,
+    value: &dyn PartialReflect,
+    output: &mut Vec<u8>,
+) -> Option<Result<(), BinaryError>> {
+    macro_rules! encode {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    let Some(value) = value.try_downcast_ref::<$ty>() else {
+                        return Some(Err(BinaryError::MismatchedType {
+                            expected: <$ty>::type_path(),
+                            received: value.reflect_type_path().to_owned(),
+                        }));
+                    };
+                    value.encode(output);
+                    return Some(Ok(()));
+                }
+            )*
+        };
+    }
+
+    with_builtin_types!(encode);
+    None
+}
+
+/// Decodes a value of the opaque type identified by `type_id` if it has built-in support.
+///
+/// Returns `None` if the type isn't supported.
+pub(super) fn decode_builtin(
+    type_id: TypeId,
+    input: &mut &[u8],
+) -> Option<Result<Box<dyn PartialReflect>, BinaryError>> {
+    macro_rules! decode {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Some(
+                        <$ty>::decode(input).map(|value| Box::new(value) as Box<dyn PartialReflect>),
+                    );
+                }
+            )*
+        };
+    }
+
+    with_builtin_types!(decode);
+    None
+}
+
+pub(super) fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], BinaryError> {
+    if input.len() < len {
+        return Err(BinaryError::UnexpectedEnd);
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+/// Writes `value` as a LEB128 variable-length integer.
+pub(super) fn write_varint(output: &mut Vec<u8>, mut value: u128) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Reads a LEB128 variable-length integer of at most `bits` bits.
+pub(super) fn read_varint(input: &mut &[u8], bits: u32) -> Result<u128, BinaryError> {
+    let mut value = 0u128;
+    let mut shift = 0;
+    loop {
+        let [byte] = take(input, 1)? else {
+            unreachable!("exactly one byte was taken");
+        };
+        let chunk = u128::from(byte & 0x7f);
+        if shift >= bits || (shift > 0 && chunk >> (bits - shift) != 0) {
+            return Err(BinaryError::InvalidData("integer out of range"));
+        }
+        value |= chunk << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+pub(super) fn write_len(output: &mut Vec<u8>, len: usize) {
+    write_varint(output, len as u128);
+}
+
+pub(super) fn read_len(input: &mut &[u8]) -> Result<usize, BinaryError> {
+    Ok(read_varint(input, usize::BITS)? as usize)
+}
+
+impl BinaryCodec for bool {
+    fn encode(&self, output: &mut Vec<u8>) {
+        output.push(u8::from(*self));
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        match take(input, 1)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(BinaryError::InvalidData("invalid boolean")),
+        }
+    }
+}
+
+impl BinaryCodec for u8 {
+    fn encode(&self, output: &mut Vec<u8>) {
+        output.push(*self);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        Ok(take(input, 1)?[0])
+    }
+}
+
+impl BinaryCodec for i8 {
+    fn encode(&self, output: &mut Vec<u8>) {
+        output.push(*self as u8);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        Ok(take(input, 1)?[0] as i8)
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl BinaryCodec for $ty {
+                fn encode(&self, output: &mut Vec<u8>) {
+                    write_varint(output, *self as u128);
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+                    Ok(read_varint(input, <$ty>::BITS)? as $ty)
+                }
+            }
+        )*
+    };
+}
+
+// Signed integers are zigzag-encoded so that small negative numbers stay small.
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {
+        $(
+            impl BinaryCodec for $ty {
+                fn encode(&self, output: &mut Vec<u8>) {
+                    let value = *self as i128;
+                    write_varint(output, ((value << 1) ^ (value >> 127)) as u128);
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+                    let value = read_varint(input, <$ty>::BITS)?;
+                    Ok(((value >> 1) as i128 ^ -((value & 1) as i128)) as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u16, u32, u64, u128, usize);
+impl_signed!(i16, i32, i64, i128, isize);
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl BinaryCodec for $ty {
+                fn encode(&self, output: &mut Vec<u8>) {
+                    output.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+                    let bytes = take(input, size_of::<$ty>())?;
+                    Ok(<$ty>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl BinaryCodec for char {
+    fn encode(&self, output: &mut Vec<u8>) {
+        (*self as u32).encode(output);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        char::from_u32(u32::decode(input)?).ok_or(BinaryError::InvalidData("invalid char"))
+    }
+}
+
+impl BinaryCodec for String {
+    fn encode(&self, output: &mut Vec<u8>) {
+        write_len(output, self.len());
+        output.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        let len = read_len(input)?;
+        let bytes = take(input, len)?;
+        core::str::from_utf8(bytes)
+            .map(ToOwned::to_owned)
+            .map_err(|_| BinaryError::InvalidData("invalid UTF-8 string"))
+    }
+}
+
+impl BinaryCodec for Cow<'static, str> {
+    fn encode(&self, output: &mut Vec<u8>) {
+        write_len(output, self.len());
+        output.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        String::decode(input).map(Cow::Owned)
+    }
+}
+
+impl BinaryCodec for Duration {
+    fn encode(&self, output: &mut Vec<u8>) {
+        self.as_secs().encode(output);
+        self.subsec_nanos().encode(output);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+        let secs = u64::decode(input)?;
+        let nanos = u32::decode(input)?;
+        if nanos >= 1_000_000_000 {
+            return Err(BinaryError::InvalidData("invalid duration"));
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+}
@@ -0,0 +1,660 @@
+//! A compact binary format for reflected values that doesn't rely on serde.
+//!
+//! The [`serde`](crate::serde) integration writes field names, variant names and type paths
+//! so that it can work with any self-describing format. This format instead relies on the
+//! [`TypeRegistry`] on both ends: values are written as raw field data in declaration order,
+//! without any names, which makes payloads much smaller and faster to decode.
+//!
+//! To detect data written for a different version of a type, each top-level type is declared
+//! once per stream, along with a [schema fingerprint](schema_fingerprint) derived from its
+//! [`TypeInfo`] (and the [`TypeInfo`] of its fields, recursively). Later values of the same type
+//! only refer to that declaration. When reading, the fingerprint is compared to the one of the
+//! registered type, and [`BinaryError::SchemaMismatch`] is returned if they differ.
+//!
+//! Values are written with a [`BinaryWriter`] and read back as dynamic values with a
+//! [`BinaryReader`]:
+//!
+//! ```
+//! # use bevy_reflect::{binary::{BinaryReader, BinaryWriter}, FromReflect, Reflect, TypeRegistry};
+//! #[derive(Reflect, PartialEq, Debug)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//! }
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Player>();
+//!
+//! let mut writer = BinaryWriter::new(&registry);
+//! writer.write(&Player { name: "Ferris".into(), health: 10 }).unwrap();
+//! writer.write(&Player { name: "Bevy".into(), health: 7 }).unwrap();
+//! let bytes = writer.into_bytes();
+//!
+//! let mut reader = BinaryReader::new(&registry, &bytes);
+//! let first = Player::from_reflect(&*reader.read().unwrap()).unwrap();
+//! let second = Player::from_reflect(&*reader.read().unwrap()).unwrap();
+//! assert_eq!(first, Player { name: "Ferris".into(), health: 10 });
+//! assert_eq!(second, Player { name: "Bevy".into(), health: 7 });
+//! assert!(reader.is_empty());
+//! ```
+//!
+//! # Format
+//!
+//! A stream is a sequence of values, each prefixed by the index of its type declaration. If the
+//! index is the number of types declared so far, it is followed by the declaration itself: the
+//! type path and the 8-byte little-endian fingerprint.
+//!
+//! The data of a value depends on its [kind](crate::ReflectKind):
+//! - structs, tuple structs and tuples: their fields, in order. Fields marked with
+//!   `#[reflect(skip_serializing)]` are left out.
+//! - enums: the index of the variant, followed by its fields.
+//! - lists, maps and sets: the number of elements, followed by the elements (key first for maps).
+//!   Since the reader rejects collections with more elements than bytes left in the input,
+//!   collections of values that take no space (such as `Vec<()>`) can't be read back if they are
+//!   longer than the rest of the stream.
+//! - arrays: their elements.
+//! - opaque types: their [`BinaryCodec`] encoding.
+//!
+//! Lengths, indices and integers are written as variable-length integers, with signed integers
+//! zigzag-encoded; floats are written as little-endian bytes.
+
+mod codec;
+mod reader;
+mod writer;
+
+pub use codec::{BinaryCodec, ReflectBinaryCodec};
+pub use reader::BinaryReader;
+pub use writer::BinaryWriter;
+
+use crate::{
+    enums::VariantInfo, serde::SerializationData, type_info::Type, TypeInfo, TypeRegistry,
+};
+use alloc::{string::String, vec::Vec};
+use thiserror::Error;
+
+/// An error returned when writing or reading the binary reflection format.
+#[derive(Error, Debug)]
+pub enum BinaryError {
+    /// The input ended in the middle of a value.
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    /// The input contains bytes that don't form a valid value.
+    #[error("invalid data: {0}")]
+    InvalidData(&'static str),
+    /// A type declared in the input, or used by a value, is not registered.
+    #[error("no registration found for type `{0}`")]
+    UnregisteredType(String),
+    /// A value has no [represented type](crate::PartialReflect::get_represented_type_info).
+    #[error("the value of type `{0}` does not represent any type")]
+    MissingTypeInfo(String),
+    /// An [opaque](crate::ReflectKind::Opaque) type has no [`BinaryCodec`] support.
+    #[error("the opaque type `{0}` has no `ReflectBinaryCodec` registration")]
+    UnsupportedType(&'static str),
+    /// A value doesn't match the type it is declared as.
+    #[error("expected a value of type `{expected}` but found a value of type `{received}`")]
+    MismatchedType {
+        /// The declared type.
+        expected: &'static str,
+        /// The type of the value.
+        received: String,
+    },
+    /// An array doesn't have the length of its declared type.
+    #[error("expected {expected} elements for `{type_path}` but found {received}")]
+    MismatchedLength {
+        /// The declared array type.
+        type_path: &'static str,
+        /// The length of the declared array type.
+        expected: usize,
+        /// The length of the array.
+        received: usize,
+    },
+    /// A struct-like value is missing one of the fields of its declared type.
+    #[error("missing field `{field}` on `{type_path}`")]
+    MissingField {
+        /// The declared type.
+        type_path: &'static str,
+        /// The name or index of the field.
+        field: String,
+    },
+    /// An enum value has a variant its declared type doesn't have.
+    #[error("unknown variant `{variant}` for enum `{type_path}`")]
+    UnknownVariant {
+        /// The declared enum type.
+        type_path: &'static str,
+        /// The name of the variant.
+        variant: String,
+    },
+    /// The input refers to a variant its declared type doesn't have.
+    #[error("invalid variant index {index} for enum `{type_path}`")]
+    InvalidVariantIndex {
+        /// The declared enum type.
+        type_path: &'static str,
+        /// The index of the variant.
+        index: usize,
+    },
+    /// The input refers to a type declaration that doesn't exist.
+    #[error("invalid type declaration index {0}")]
+    InvalidDeclarationIndex(usize),
+    /// The input was written for a different version of a type.
+    #[error(
+        "the schema of `{type_path}` doesn't match: the data was written with fingerprint {found:#018x} but the registered type has fingerprint {expected:#018x}"
+    )]
+    SchemaMismatch {
+        /// The type path of the declared type.
+        type_path: String,
+        /// The fingerprint of the registered type.
+        expected: u64,
+        /// The fingerprint found in the input.
+        found: u64,
+    },
+}
+
+/// Returns the schema fingerprint of a type, as written in [binary](self) streams.
+///
+/// The fingerprint is a hash of the type path and the layout of the type: the names and
+/// fingerprints of its fields, the names and fields of its variants, the fingerprints of its
+/// elements, and so on. Field types are looked up in `registry`; types that aren't registered,
+/// opaque types and recursive occurrences of a type only contribute their type path.
+///
+/// The hash is stable across platforms and program runs, so it can be stored alongside
+/// persisted data.
+pub fn schema_fingerprint(type_info: &TypeInfo, registry: &TypeRegistry) -> u64 {
+    let mut hasher = SchemaHasher::default();
+    hasher.hash_info(type_info, registry, &mut Vec::new());
+    hasher.0
+}
+
+/// A 64-bit FNV-1a hasher, whose output only depends on the bytes written to it.
+struct SchemaHasher(u64);
+
+impl Default for SchemaHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl SchemaHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write(value.as_bytes());
+    }
+
+    // Types are compared and looked up as `Type`s rather than `TypeId`s, since all runtime types
+    // share the same `TypeId`.
+    fn hash_type(&mut self, ty: &Type, registry: &TypeRegistry, visiting: &mut Vec<Type>) {
+        match registry.get_with_type(ty) {
+            Some(registration) => self.hash_info(registration.type_info(), registry, visiting),
+            None => self.write_str(ty.path()),
+        }
+    }
+
+    fn hash_info(&mut self, info: &TypeInfo, registry: &TypeRegistry, visiting: &mut Vec<Type>) {
+        self.write_str(info.type_path());
+        if visiting.contains(info.ty()) {
+            return;
+        }
+        visiting.push(*info.ty());
+
+        let serialization_data = registry
+            .get_with_type(info.ty())
+            .and_then(|registration| registration.data::<SerializationData>());
+        let is_skipped =
+            |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        match info {
+            TypeInfo::Struct(info) => {
+                self.write(&[0]);
+                for (index, field) in info.iter().enumerate() {
+                    if !is_skipped(index) {
+                        self.write_str(field.name());
+                        self.hash_type(field.ty(), registry, visiting);
+                    }
+                }
+            }
+            TypeInfo::TupleStruct(info) => {
+                self.write(&[1]);
+                for (index, field) in info.iter().enumerate() {
+                    if !is_skipped(index) {
+                        self.hash_type(field.ty(), registry, visiting);
+                    }
+                }
+            }
+            TypeInfo::Tuple(info) => {
+                self.write(&[2]);
+                for field in info.iter() {
+                    self.hash_type(field.ty(), registry, visiting);
+                }
+            }
+            TypeInfo::List(info) => {
+                self.write(&[3]);
+                self.hash_type(&info.item_ty(), registry, visiting);
+            }
+            TypeInfo::Array(info) => {
+                self.write(&[4]);
+                self.write_u64(info.capacity() as u64);
+                self.hash_type(&info.item_ty(), registry, visiting);
+            }
+            TypeInfo::Map(info) => {
+                self.write(&[5]);
+                self.hash_type(&info.key_ty(), registry, visiting);
+                self.hash_type(&info.value_ty(), registry, visiting);
+            }
+            TypeInfo::Set(info) => {
+                self.write(&[6]);
+                self.hash_type(&info.value_ty(), registry, visiting);
+            }
+            TypeInfo::Enum(info) => {
+                self.write(&[7]);
+                for variant in info.iter() {
+                    self.write_str(variant.name());
+                    match variant {
+                        VariantInfo::Struct(variant) => {
+                            self.write(&[0]);
+                            for field in variant.iter() {
+                                self.write_str(field.name());
+                                self.hash_type(field.ty(), registry, visiting);
+                            }
+                        }
+                        VariantInfo::Tuple(variant) => {
+                            self.write(&[1]);
+                            for field in variant.iter() {
+                                self.hash_type(field.ty(), registry, visiting);
+                            }
+                        }
+                        VariantInfo::Unit(_) => self.write(&[2]),
+                    }
+                }
+            }
+            TypeInfo::Opaque(_) => self.write(&[8]),
+        }
+
+        visiting.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        structs::{DynamicStruct, StructInfo},
+        FromReflect, NamedField, PartialReflect, Reflect, TypePathTable, TypeRegistration,
+    };
+    use alloc::{boxed::Box, string::ToString, vec};
+    use bevy_platform::collections::HashMap;
+    use core::any::TypeId;
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Empty,
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Level {
+        name: String,
+        id: u64,
+        offset: i32,
+        shapes: Vec<Shape>,
+        tags: HashMap<String, bool>,
+        children: Vec<Level>,
+        position: (f32, char),
+        grid: [u8; 3],
+        #[reflect(skip_serializing, default = "default_cache")]
+        cache: usize,
+    }
+
+    fn default_cache() -> usize {
+        42
+    }
+
+    fn level() -> Level {
+        Level {
+            name: "First".to_string(),
+            id: 1 << 40,
+            offset: -3,
+            shapes: vec![
+                Shape::Circle { radius: 1.5 },
+                Shape::Rect(2.0, 3.0),
+                Shape::Empty,
+            ],
+            tags: HashMap::from_iter([("boss".to_string(), true)]),
+            children: vec![Level {
+                name: "Root".to_string(),
+                id: 0,
+                offset: 0,
+                shapes: Vec::new(),
+                tags: HashMap::default(),
+                children: Vec::new(),
+                position: (0.0, 'r'),
+                grid: [0; 3],
+                cache: 42,
+            }],
+            position: (-1.25, 'é'),
+            grid: [1, 2, 3],
+            cache: 7,
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Level>();
+        registry
+    }
+
+    fn runtime_struct(type_path: &'static str, fields: &[NamedField]) -> &'static TypeInfo {
+        let ty = Type::runtime(TypePathTable::from_path(type_path));
+        Box::leak(Box::new(TypeInfo::Struct(StructInfo::from_type(
+            ty, fields,
+        ))))
+    }
+
+    #[test]
+    fn should_round_trip_values() {
+        let registry = registry();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&level()).unwrap();
+        writer.write(&Shape::Rect(-1.0, 0.5)).unwrap();
+        writer.write(&level()).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BinaryReader::new(&registry, &bytes);
+        let expected = Level {
+            cache: 42,
+            ..level()
+        };
+        let value = reader.read().unwrap();
+        assert_eq!(Level::from_reflect(&*value), Some(expected.clone()));
+        let value = reader.read().unwrap();
+        assert_eq!(Shape::from_reflect(&*value), Some(Shape::Rect(-1.0, 0.5)));
+        let value = reader.read().unwrap();
+        assert_eq!(Level::from_reflect(&*value), Some(expected));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn should_declare_types_once() {
+        let registry = registry();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&Shape::Empty).unwrap();
+        let declared_len = writer.as_bytes().len();
+        writer.write(&Shape::Empty).unwrap();
+
+        // The declaration index followed by the variant index.
+        assert_eq!(writer.as_bytes().len() - declared_len, 2);
+        assert_eq!(
+            declared_len,
+            2 + "bevy_reflect::binary::tests::Shape".len() + 8 + 1
+        );
+    }
+
+    #[test]
+    fn should_encode_integers_compactly() {
+        let mut output = Vec::new();
+        0u32.encode(&mut output);
+        127u64.encode(&mut output);
+        (-1i32).encode(&mut output);
+        assert_eq!(output, [0, 127, 1]);
+
+        let mut output = Vec::new();
+        for value in [i64::MIN, -64, 63, i64::MAX] {
+            value.encode(&mut output);
+        }
+        u128::MAX.encode(&mut output);
+        let mut input = output.as_slice();
+        for value in [i64::MIN, -64, 63, i64::MAX] {
+            assert_eq!(i64::decode(&mut input).unwrap(), value);
+        }
+        assert_eq!(u128::decode(&mut input).unwrap(), u128::MAX);
+        assert!(input.is_empty());
+
+        let mut input = [0xff, 0xff, 0x04].as_slice();
+        assert!(matches!(
+            u16::decode(&mut input),
+            Err(BinaryError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn should_reject_mismatched_schema() {
+        mod v1 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Save {
+                pub score: u32,
+            }
+        }
+
+        mod v2 {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[type_path = "game"]
+            pub struct Save {
+                pub score: u32,
+                pub lives: u8,
+            }
+        }
+
+        let mut old_registry = TypeRegistry::new();
+        old_registry.register::<v1::Save>();
+        let mut new_registry = TypeRegistry::new();
+        new_registry.register::<v2::Save>();
+
+        let mut writer = BinaryWriter::new(&old_registry);
+        writer.write(&v1::Save { score: 10 }).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BinaryReader::new(&new_registry, &bytes);
+        let error = reader.read().unwrap_err();
+        let BinaryError::SchemaMismatch {
+            type_path,
+            expected,
+            found,
+        } = error
+        else {
+            panic!("expected a schema mismatch, found {error:?}");
+        };
+        assert_eq!(type_path, "game::Save");
+        assert_eq!(
+            expected,
+            schema_fingerprint(
+                new_registry
+                    .get_type_info(TypeId::of::<v2::Save>())
+                    .unwrap(),
+                &new_registry
+            )
+        );
+        assert_eq!(
+            found,
+            schema_fingerprint(
+                old_registry
+                    .get_type_info(TypeId::of::<v1::Save>())
+                    .unwrap(),
+                &old_registry
+            )
+        );
+    }
+
+    #[test]
+    fn should_use_registered_codecs_for_opaque_types() {
+        #[derive(Reflect, Clone, PartialEq, Debug)]
+        #[reflect(opaque, Clone, PartialEq, Debug)]
+        struct Handle(u32);
+
+        #[derive(Reflect)]
+        struct Sprite {
+            handle: Handle,
+        }
+
+        impl BinaryCodec for Handle {
+            fn encode(&self, output: &mut Vec<u8>) {
+                self.0.encode(output);
+            }
+
+            fn decode(input: &mut &[u8]) -> Result<Self, BinaryError> {
+                u32::decode(input).map(Handle)
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Sprite>();
+
+        let mut writer = BinaryWriter::new(&registry);
+        let error = writer.write(&Sprite { handle: Handle(3) }).unwrap_err();
+        assert!(matches!(error, BinaryError::UnsupportedType(_)));
+        assert!(writer.as_bytes().is_empty());
+
+        registry.register_type_data::<Handle, ReflectBinaryCodec>();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&Sprite { handle: Handle(3) }).unwrap();
+        let bytes = writer.into_bytes();
+
+        let value = BinaryReader::new(&registry, &bytes).read().unwrap();
+        let handle = value
+            .reflect_ref()
+            .as_struct()
+            .unwrap()
+            .field("handle")
+            .unwrap();
+        assert_eq!(handle.try_downcast_ref::<Handle>(), Some(&Handle(3)));
+    }
+
+    #[test]
+    fn should_reject_truncated_input() {
+        let registry = registry();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&level()).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BinaryReader::new(&registry, &bytes[..bytes.len() - 1]);
+        assert!(matches!(reader.read(), Err(BinaryError::UnexpectedEnd)));
+    }
+
+    #[test]
+    fn should_reject_oversized_collections() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Vec<()>>();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&Vec::<()>::new()).unwrap();
+        let mut bytes = writer.into_bytes();
+
+        // Replace the element count with one that is far larger than the input.
+        bytes.pop();
+        bytes.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x0f]);
+        let mut reader = BinaryReader::new(&registry, &bytes);
+        assert!(matches!(reader.read(), Err(BinaryError::InvalidData(_))));
+    }
+
+    #[test]
+    fn should_round_trip_runtime_types() {
+        let inner = runtime_struct("my_crate::Inner", &[NamedField::new::<f32>("value")]);
+        let outer = runtime_struct(
+            "my_crate::Outer",
+            &[NamedField::from_type("inner", *inner.ty(), Some(inner))],
+        );
+        let mut registry = TypeRegistry::new();
+        registry.add_registration(TypeRegistration::from_type_info(inner));
+        registry.add_registration(TypeRegistration::from_type_info(outer));
+
+        let mut inner_value = DynamicStruct::default();
+        inner_value.set_represented_type(Some(inner));
+        inner_value.insert("value", 2.5f32);
+        let mut outer_value = DynamicStruct::default();
+        outer_value.set_represented_type(Some(outer));
+        outer_value.insert("inner", inner_value);
+
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&outer_value).unwrap();
+        let bytes = writer.into_bytes();
+
+        let value = BinaryReader::new(&registry, &bytes).read().unwrap();
+        let inner_read = value
+            .reflect_ref()
+            .as_struct()
+            .unwrap()
+            .field("inner")
+            .unwrap();
+        assert_eq!(
+            inner_read
+                .get_represented_type_info()
+                .map(TypeInfo::type_path),
+            Some("my_crate::Inner")
+        );
+        assert!(value.reflect_partial_eq(&outer_value).unwrap());
+    }
+
+    #[test]
+    fn should_distinguish_runtime_types() {
+        fn runtime_registry(inner_field: NamedField) -> (TypeRegistry, &'static TypeInfo) {
+            let inner = runtime_struct("my_crate::Inner", &[inner_field]);
+            let outer = runtime_struct(
+                "my_crate::Outer",
+                &[NamedField::from_type("inner", *inner.ty(), Some(inner))],
+            );
+            let mut registry = TypeRegistry::new();
+            registry.add_registration(TypeRegistration::from_type_info(inner));
+            registry.add_registration(TypeRegistration::from_type_info(outer));
+            (registry, outer)
+        }
+
+        // The layout of a nested runtime type is part of the fingerprint.
+        let (registry, outer) = runtime_registry(NamedField::new::<f32>("value"));
+        let (other_registry, other_outer) = runtime_registry(NamedField::new::<u32>("value"));
+        assert_ne!(
+            schema_fingerprint(outer, &registry),
+            schema_fingerprint(other_outer, &other_registry)
+        );
+
+        // Each runtime type gets its own declaration.
+        let inner = registry.get_with_type_path("my_crate::Inner").unwrap();
+        let mut inner_value = DynamicStruct::default();
+        inner_value.set_represented_type(Some(inner.type_info()));
+        inner_value.insert("value", 1.0f32);
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&inner_value).unwrap();
+        let mut outer_value = DynamicStruct::default();
+        outer_value.set_represented_type(Some(outer));
+        outer_value.insert_boxed("inner", inner_value.to_dynamic());
+        writer.write(&outer_value).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BinaryReader::new(&registry, &bytes);
+        let first = reader.read().unwrap();
+        let second = reader.read().unwrap();
+        assert_eq!(
+            first.get_represented_type_info().map(TypeInfo::type_path),
+            Some("my_crate::Inner")
+        );
+        assert_eq!(
+            second.get_represented_type_info().map(TypeInfo::type_path),
+            Some("my_crate::Outer")
+        );
+    }
+
+    #[test]
+    fn should_write_dynamic_values() {
+        let registry = registry();
+        let dynamic = Shape::Circle { radius: 2.0 }.to_dynamic();
+        let mut writer = BinaryWriter::new(&registry);
+        writer.write(&*dynamic).unwrap();
+        let bytes = writer.into_bytes();
+
+        let value = BinaryReader::new(&registry, &bytes).read().unwrap();
+        assert!(value.reflect_partial_eq(&*dynamic).unwrap());
+    }
+}
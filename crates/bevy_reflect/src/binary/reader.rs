@@ -0,0 +1,242 @@
+use super::{
+    codec::{decode_builtin, read_len, take},
+    schema_fingerprint, BinaryCodec, BinaryError, ReflectBinaryCodec,
+};
+use crate::{
+    array::DynamicArray,
+    enums::{DynamicEnum, DynamicVariant, VariantInfo},
+    list::DynamicList,
+    map::{DynamicMap, Map},
+    serde::SerializationData,
+    set::{DynamicSet, Set},
+    structs::DynamicStruct,
+    tuple::DynamicTuple,
+    tuple_struct::DynamicTupleStruct,
+    type_info::Type,
+    PartialReflect, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+
+/// Reads reflected values from a [binary](super) stream.
+///
+/// Values are returned as dynamic values representing their type, except for [opaque] values,
+/// which are returned as concrete values. Use [`FromReflect`](crate::FromReflect) or
+/// [`ReflectFromReflect`](crate::ReflectFromReflect) to convert them to concrete values.
+///
+/// [opaque]: crate::ReflectKind::Opaque
+pub struct BinaryReader<'a, 'b> {
+    registry: &'a TypeRegistry,
+    input: &'b [u8],
+    declarations: Vec<&'a TypeRegistration>,
+}
+
+impl<'a, 'b> BinaryReader<'a, 'b> {
+    /// Creates a reader for `input`, using `registry` to look up the types declared in it.
+    pub fn new(registry: &'a TypeRegistry, input: &'b [u8]) -> Self {
+        Self {
+            registry,
+            input,
+            declarations: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if all values have been read.
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Reads the next value.
+    ///
+    /// The schema fingerprint of each type is checked once, when its declaration is read.
+    /// Returns [`BinaryError::SchemaMismatch`] if the type was registered with a different
+    /// layout than the one the stream was written with.
+    ///
+    /// If an error is returned, the reader is left unchanged.
+    pub fn read(&mut self) -> Result<Box<dyn PartialReflect>, BinaryError> {
+        let mut input = self.input;
+        let index = read_len(&mut input)?;
+        let registration = if let Some(registration) = self.declarations.get(index) {
+            *registration
+        } else if index == self.declarations.len() {
+            let type_path = String::decode(&mut input)?;
+            let found = u64::from_le_bytes(take(&mut input, 8)?.try_into().unwrap());
+            let registration = self
+                .registry
+                .get_with_type_path(&type_path)
+                .ok_or_else(|| BinaryError::UnregisteredType(type_path.clone()))?;
+            let expected = schema_fingerprint(registration.type_info(), self.registry);
+            if found != expected {
+                return Err(BinaryError::SchemaMismatch {
+                    type_path,
+                    expected,
+                    found,
+                });
+            }
+            registration
+        } else {
+            return Err(BinaryError::InvalidDeclarationIndex(index));
+        };
+
+        let value = read_value(registration, self.registry, &mut input)?;
+        if index == self.declarations.len() {
+            self.declarations.push(registration);
+        }
+        self.input = input;
+        Ok(value)
+    }
+}
+
+/// Reads a value declared as `ty`, such as a field or an element.
+fn read_field(
+    ty: &Type,
+    registry: &TypeRegistry,
+    input: &mut &[u8],
+) -> Result<Box<dyn PartialReflect>, BinaryError> {
+    // Fast path for primitives, which don't need a registration.
+    if let Some(result) = decode_builtin(ty.id(), input) {
+        return result;
+    }
+    let registration = registry
+        .get_with_type(ty)
+        .ok_or_else(|| BinaryError::UnregisteredType(ty.path().to_owned()))?;
+    read_value(registration, registry, input)
+}
+
+/// Reads the number of elements of a list, map or set.
+///
+/// Each element is expected to take at least one byte, so that a corrupted length can't make
+/// the reader loop (and allocate) far beyond the end of the input.
+fn read_count(input: &mut &[u8]) -> Result<usize, BinaryError> {
+    let count = read_len(input)?;
+    if count > input.len() {
+        return Err(BinaryError::InvalidData(
+            "collection length exceeds the remaining input",
+        ));
+    }
+    Ok(count)
+}
+
+fn read_value(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    input: &mut &[u8],
+) -> Result<Box<dyn PartialReflect>, BinaryError> {
+    let type_info = registration.type_info();
+    let serialization_data = registration.data::<SerializationData>();
+    let skipped_default = |index: usize| {
+        serialization_data
+            .filter(|data| data.is_field_skipped(index))
+            .map(|data| data.generate_default(index))
+    };
+
+    Ok(match type_info {
+        TypeInfo::Struct(info) => {
+            let mut value = DynamicStruct::default();
+            value.set_represented_type(Some(type_info));
+            for (index, field) in info.iter().enumerate() {
+                match skipped_default(index) {
+                    Some(Some(default)) => {
+                        value.insert_boxed(field.name(), default.into_partial_reflect());
+                    }
+                    Some(None) => {}
+                    None => {
+                        value.insert_boxed(field.name(), read_field(field.ty(), registry, input)?);
+                    }
+                }
+            }
+            Box::new(value)
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut value = DynamicTupleStruct::default();
+            value.set_represented_type(Some(type_info));
+            for (index, field) in info.iter().enumerate() {
+                match skipped_default(index) {
+                    Some(Some(default)) => value.insert_boxed(default.into_partial_reflect()),
+                    Some(None) => {}
+                    None => value.insert_boxed(read_field(field.ty(), registry, input)?),
+                }
+            }
+            Box::new(value)
+        }
+        TypeInfo::Tuple(info) => {
+            let mut value = DynamicTuple::default();
+            value.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                value.insert_boxed(read_field(field.ty(), registry, input)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::List(info) => {
+            let mut value = DynamicList::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..read_count(input)? {
+                value.push_box(read_field(&info.item_ty(), registry, input)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Array(info) => {
+            let items = (0..info.capacity())
+                .map(|_| read_field(&info.item_ty(), registry, input))
+                .collect::<Result<Box<_>, _>>()?;
+            let mut value = DynamicArray::new(items);
+            value.set_represented_type(Some(type_info));
+            Box::new(value)
+        }
+        TypeInfo::Map(info) => {
+            let mut value = DynamicMap::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..read_count(input)? {
+                let key = read_field(&info.key_ty(), registry, input)?;
+                value.insert_boxed(key, read_field(&info.value_ty(), registry, input)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Set(info) => {
+            let mut value = DynamicSet::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..read_count(input)? {
+                value.insert_boxed(read_field(&info.value_ty(), registry, input)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Enum(info) => {
+            let index = read_len(input)?;
+            let variant = info
+                .variant_at(index)
+                .ok_or(BinaryError::InvalidVariantIndex {
+                    type_path: type_info.type_path(),
+                    index,
+                })?;
+            let dynamic_variant = match variant {
+                VariantInfo::Struct(variant) => {
+                    let mut fields = DynamicStruct::default();
+                    for field in variant.iter() {
+                        fields.insert_boxed(field.name(), read_field(field.ty(), registry, input)?);
+                    }
+                    DynamicVariant::Struct(fields)
+                }
+                VariantInfo::Tuple(variant) => {
+                    let mut fields = DynamicTuple::default();
+                    for field in variant.iter() {
+                        fields.insert_boxed(read_field(field.ty(), registry, input)?);
+                    }
+                    DynamicVariant::Tuple(fields)
+                }
+                VariantInfo::Unit(_) => DynamicVariant::Unit,
+            };
+            let mut value = DynamicEnum::new_with_index(index, variant.name(), dynamic_variant);
+            value.set_represented_type(Some(type_info));
+            Box::new(value)
+        }
+        TypeInfo::Opaque(_) => {
+            if let Some(result) = decode_builtin(type_info.type_id(), input) {
+                return result;
+            }
+            registration
+                .data::<ReflectBinaryCodec>()
+                .ok_or(BinaryError::UnsupportedType(type_info.type_path()))?
+                .decode(input)?
+                .into_partial_reflect()
+        }
+    })
+}
@@ -0,0 +1,230 @@
+use super::{
+    codec::{encode_builtin, write_len},
+    schema_fingerprint, BinaryError, ReflectBinaryCodec,
+};
+use crate::{
+    enums::VariantInfo, serde::SerializationData, type_info::Type, PartialReflect, ReflectRef,
+    TypeInfo, TypeRegistration, TypeRegistry,
+};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+
+/// Writes reflected values to a [binary](super) stream.
+///
+/// The type of each value written is declared in the stream the first time it is used, so
+/// writing many values with the same writer produces a smaller output than writing them with
+/// separate writers.
+pub struct BinaryWriter<'a> {
+    registry: &'a TypeRegistry,
+    output: Vec<u8>,
+    // Keyed by type path, since all runtime types share the same `TypeId`.
+    declarations: HashMap<&'static str, usize>,
+}
+
+impl<'a> BinaryWriter<'a> {
+    /// Creates a writer with an empty output, using `registry` to look up the types of the
+    /// written values.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            output: Vec::new(),
+            declarations: HashMap::default(),
+        }
+    }
+
+    /// Appends `value` to the output.
+    ///
+    /// `value` is written as the type it [represents](PartialReflect::get_represented_type_info),
+    /// so dynamic values can be written as long as they represent a registered type.
+    ///
+    /// If an error is returned, the output is left unchanged.
+    pub fn write(&mut self, value: &dyn PartialReflect) -> Result<(), BinaryError> {
+        let type_info = value
+            .get_represented_type_info()
+            .ok_or_else(|| BinaryError::MissingTypeInfo(value.reflect_type_path().to_owned()))?;
+        let registration = self
+            .registry
+            .get_with_type(type_info.ty())
+            .ok_or_else(|| BinaryError::UnregisteredType(type_info.type_path().to_owned()))?;
+
+        let start = self.output.len();
+        let declared = self.declarations.len();
+        let index = *self
+            .declarations
+            .entry(type_info.type_path())
+            .or_insert(declared);
+        write_len(&mut self.output, index);
+        if index == declared {
+            write_str(&mut self.output, type_info.type_path());
+            let fingerprint = schema_fingerprint(type_info, self.registry);
+            self.output.extend_from_slice(&fingerprint.to_le_bytes());
+        }
+
+        if let Err(error) = write_value(value, registration, self.registry, &mut self.output) {
+            self.output.truncate(start);
+            if index == declared {
+                self.declarations.remove(type_info.type_path());
+            }
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Returns the output written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Returns the output, consuming the writer.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.output
+    }
+}
+
+fn write_str(output: &mut Vec<u8>, value: &str) {
+    write_len(output, value.len());
+    output.extend_from_slice(value.as_bytes());
+}
+
+/// Writes a value declared as `ty`, such as a field or an element.
+fn write_field(
+    value: &dyn PartialReflect,
+    ty: &Type,
+    registry: &TypeRegistry,
+    output: &mut Vec<u8>,
+) -> Result<(), BinaryError> {
+    // Fast path for primitives, which don't need a registration.
+    if let Some(result) = encode_builtin(ty.id(), value, output) {
+        return result;
+    }
+    let registration = registry
+        .get_with_type(ty)
+        .ok_or_else(|| BinaryError::UnregisteredType(ty.path().to_owned()))?;
+    write_value(value, registration, registry, output)
+}
+
+fn write_value(
+    value: &dyn PartialReflect,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    output: &mut Vec<u8>,
+) -> Result<(), BinaryError> {
+    let type_info = registration.type_info();
+    let type_path = type_info.type_path();
+    let missing_field = |field: String| BinaryError::MissingField { type_path, field };
+    let serialization_data = registration.data::<SerializationData>();
+    let is_skipped =
+        |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+    match (type_info, value.reflect_ref()) {
+        (TypeInfo::Struct(info), ReflectRef::Struct(value)) => {
+            for (index, field) in info.iter().enumerate() {
+                if is_skipped(index) {
+                    continue;
+                }
+                let field_value = value
+                    .field(field.name())
+                    .ok_or_else(|| missing_field(field.name().to_owned()))?;
+                write_field(field_value, field.ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::TupleStruct(info), ReflectRef::TupleStruct(value)) => {
+            for (index, field) in info.iter().enumerate() {
+                if is_skipped(index) {
+                    continue;
+                }
+                let field_value = value
+                    .field(index)
+                    .ok_or_else(|| missing_field(index.to_string()))?;
+                write_field(field_value, field.ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::Tuple(info), ReflectRef::Tuple(value)) => {
+            for (index, field) in info.iter().enumerate() {
+                let field_value = value
+                    .field(index)
+                    .ok_or_else(|| missing_field(index.to_string()))?;
+                write_field(field_value, field.ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::List(info), ReflectRef::List(value)) => {
+            write_len(output, value.len());
+            for item in value.iter() {
+                write_field(item, &info.item_ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::Array(info), ReflectRef::Array(value)) => {
+            if value.len() != info.capacity() {
+                return Err(BinaryError::MismatchedLength {
+                    type_path,
+                    expected: info.capacity(),
+                    received: value.len(),
+                });
+            }
+            for item in value.iter() {
+                write_field(item, &info.item_ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::Map(info), ReflectRef::Map(value)) => {
+            write_len(output, value.len());
+            for (key, value) in value.iter() {
+                write_field(key, &info.key_ty(), registry, output)?;
+                write_field(value, &info.value_ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::Set(info), ReflectRef::Set(value)) => {
+            write_len(output, value.len());
+            for value in value.iter() {
+                write_field(value, &info.value_ty(), registry, output)?;
+            }
+        }
+        (TypeInfo::Enum(info), ReflectRef::Enum(value)) => {
+            let index =
+                info.index_of(value.variant_name())
+                    .ok_or_else(|| BinaryError::UnknownVariant {
+                        type_path,
+                        variant: value.variant_name().to_owned(),
+                    })?;
+            write_len(output, index);
+            match info.variant_at(index) {
+                Some(VariantInfo::Struct(variant)) => {
+                    for field in variant.iter() {
+                        let field_value = value
+                            .field(field.name())
+                            .ok_or_else(|| missing_field(field.name().to_owned()))?;
+                        write_field(field_value, field.ty(), registry, output)?;
+                    }
+                }
+                Some(VariantInfo::Tuple(variant)) => {
+                    for field in variant.iter() {
+                        let field_value = value
+                            .field_at(field.index())
+                            .ok_or_else(|| missing_field(field.index().to_string()))?;
+                        write_field(field_value, field.ty(), registry, output)?;
+                    }
+                }
+                Some(VariantInfo::Unit(_)) | None => {}
+            }
+        }
+        (TypeInfo::Opaque(_), _) => {
+            if let Some(result) = encode_builtin(type_info.type_id(), value, output) {
+                return result;
+            }
+            registration
+                .data::<ReflectBinaryCodec>()
+                .ok_or(BinaryError::UnsupportedType(type_path))?
+                .encode(value, output)?;
+        }
+        _ => {
+            return Err(BinaryError::MismatchedType {
+                expected: type_path,
+                received: value.reflect_type_path().to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,739 @@
+//! Structural diffing and patching of reflected values.
+//!
+//! [`reflect_diff`] compares two values and returns a [`ReflectDiff`]: the list of
+//! [`DiffChange`]s that turn the first value into the second one. Each change is located by the
+//! [`ParsedPath`] of the value it affects, so only the fields that actually differ are recorded.
+//!
+//! A [`ReflectDiff`] can be applied to a value with [`ReflectDiff::apply`], and serialized with a
+//! [`TypeRegistry`](crate::TypeRegistry) using [`ReflectDiffSerializer`] and
+//! [`ReflectDiffDeserializer`].
+//!
+//! ```
+//! # use bevy_reflect::{diff::{reflect_diff, DiffChange}, Reflect};
+//! #[derive(Reflect, PartialEq, Debug)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//!     inventory: Vec<String>,
+//! }
+//!
+//! let before = Player {
+//!     name: "Ferris".into(),
+//!     health: 10,
+//!     inventory: vec!["sword".into()],
+//! };
+//! let mut after = Player {
+//!     name: "Ferris".into(),
+//!     health: 7,
+//!     inventory: vec!["sword".into(), "shield".into()],
+//! };
+//!
+//! let diff = reflect_diff(&before, &after);
+//! let paths: Vec<String> = diff.changes().iter().map(|change| change.path().to_string()).collect();
+//! assert_eq!(paths, [".health", ".inventory"]);
+//! assert!(matches!(diff.changes()[1], DiffChange::ListPush { .. }));
+//!
+//! let mut patched = before;
+//! diff.apply(&mut patched).unwrap();
+//! assert_eq!(patched, after);
+//! ```
+
+mod serde;
+
+pub use self::serde::{ReflectDiffDeserializer, ReflectDiffSerializer};
+
+use crate::{
+    enums::VariantType, Access, ApplyError, ParsedPath, PartialReflect, ReflectKind, ReflectMut,
+    ReflectPath, ReflectRef,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use thiserror::Error;
+
+/// The differences between two reflected values, as returned by [`reflect_diff`].
+///
+/// Changes are ordered so that applying them in order, with [`ReflectDiff::apply`], turns the
+/// first value into the second one.
+#[derive(Debug, Default)]
+pub struct ReflectDiff {
+    changes: Vec<DiffChange>,
+}
+
+/// A single change in a [`ReflectDiff`].
+#[derive(Debug)]
+pub enum DiffChange {
+    /// The value at `path` was replaced by `value`.
+    ///
+    /// This is used for values that can't be compared field by field, such as opaque values,
+    /// enums that changed variant, or values whose type or shape changed.
+    Replace {
+        /// The location of the value.
+        path: ParsedPath,
+        /// The new value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// The list at `path` was truncated to `len` elements.
+    ListTruncate {
+        /// The location of the list.
+        path: ParsedPath,
+        /// The new length of the list.
+        len: usize,
+    },
+    /// `value` was pushed to the end of the list at `path`.
+    ListPush {
+        /// The location of the list.
+        path: ParsedPath,
+        /// The new element.
+        value: Box<dyn PartialReflect>,
+    },
+    /// An entry was inserted into the map at `path`.
+    MapInsert {
+        /// The location of the map.
+        path: ParsedPath,
+        /// The key of the new entry.
+        key: Box<dyn PartialReflect>,
+        /// The value of the new entry.
+        value: Box<dyn PartialReflect>,
+    },
+    /// An entry was removed from the map at `path`.
+    MapRemove {
+        /// The location of the map.
+        path: ParsedPath,
+        /// The key of the removed entry.
+        key: Box<dyn PartialReflect>,
+    },
+    /// The value of an entry of the map at `path` changed.
+    ///
+    /// Since map entries can't be located by a [`ParsedPath`], the changes to the value are
+    /// stored in a nested [`ReflectDiff`], relative to the value.
+    MapModify {
+        /// The location of the map.
+        path: ParsedPath,
+        /// The key of the modified entry.
+        key: Box<dyn PartialReflect>,
+        /// The changes to the value of the entry.
+        diff: ReflectDiff,
+    },
+    /// `value` was inserted into the set at `path`.
+    SetInsert {
+        /// The location of the set.
+        path: ParsedPath,
+        /// The new value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// `value` was removed from the set at `path`.
+    SetRemove {
+        /// The location of the set.
+        path: ParsedPath,
+        /// The removed value.
+        value: Box<dyn PartialReflect>,
+    },
+}
+
+/// An error returned when applying a [`ReflectDiff`] to a value that doesn't match it.
+#[derive(Error, Debug)]
+pub enum DiffApplyError {
+    /// The target has no value at the path of a change.
+    #[error("the path `{path}` is not valid for the target: {message}")]
+    InvalidPath {
+        /// The path of the change.
+        path: ParsedPath,
+        /// The reason the path could not be accessed.
+        message: String,
+    },
+    /// The value at the path of a change is not of the expected kind.
+    #[error("expected a {expected} at `{path}` but found a {received}")]
+    MismatchedKind {
+        /// The path of the change.
+        path: ParsedPath,
+        /// The kind of value the change applies to.
+        expected: ReflectKind,
+        /// The kind of the value in the target.
+        received: ReflectKind,
+    },
+    /// The map at the path of a [`DiffChange::MapModify`] has no entry for its key.
+    #[error("the map at `{path}` has no entry for key `{key:?}`")]
+    MissingMapKey {
+        /// The path of the map.
+        path: ParsedPath,
+        /// The missing key.
+        key: Box<dyn PartialReflect>,
+    },
+    /// A value could not be applied to the target.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+/// Returns the changes that turn `a` into `b`.
+///
+/// Structs, tuple structs, tuples, enums (that keep the same variant), lists and arrays are
+/// compared field by field (or element by element), and maps and sets entry by entry. Other
+/// values are compared with [`PartialReflect::reflect_partial_eq`], and replaced entirely if they
+/// are not equal (or can't be compared).
+///
+/// The values stored in the changes are [dynamic](PartialReflect::to_dynamic) copies of the
+/// values of `b`.
+pub fn reflect_diff(a: &dyn PartialReflect, b: &dyn PartialReflect) -> ReflectDiff {
+    let mut diff = ReflectDiff::default();
+    diff_values(&mut Vec::new(), a, b, &mut diff.changes);
+    diff
+}
+
+impl ReflectDiff {
+    /// Returns the changes of this diff, in the order they are applied.
+    pub fn changes(&self) -> &[DiffChange] {
+        &self.changes
+    }
+
+    /// Returns the changes of this diff, in the order they are applied.
+    pub fn into_changes(self) -> Vec<DiffChange> {
+        self.changes
+    }
+
+    /// Returns the number of changes in this diff. Nested [`DiffChange::MapModify`] diffs count
+    /// as a single change.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the compared values were equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the changes of this diff to `target`.
+    ///
+    /// Applying the diff returned by [`reflect_diff(a, b)`](reflect_diff) to a value equal to
+    /// `a` makes it equal to `b`. If an error is returned, the changes before the failing one
+    /// have already been applied.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        for change in &self.changes {
+            change.apply(target)?;
+        }
+        Ok(())
+    }
+}
+
+impl Clone for ReflectDiff {
+    fn clone(&self) -> Self {
+        Self {
+            changes: self.changes.iter().map(DiffChange::clone).collect(),
+        }
+    }
+}
+
+impl DiffChange {
+    /// Returns the location of the value this change applies to.
+    pub fn path(&self) -> &ParsedPath {
+        match self {
+            DiffChange::Replace { path, .. }
+            | DiffChange::ListTruncate { path, .. }
+            | DiffChange::ListPush { path, .. }
+            | DiffChange::MapInsert { path, .. }
+            | DiffChange::MapRemove { path, .. }
+            | DiffChange::MapModify { path, .. }
+            | DiffChange::SetInsert { path, .. }
+            | DiffChange::SetRemove { path, .. } => path,
+        }
+    }
+
+    fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        let path = self.path();
+        let element =
+            path.reflect_element_mut(target)
+                .map_err(|error| DiffApplyError::InvalidPath {
+                    path: path.clone(),
+                    message: error.to_string(),
+                })?;
+        let mismatched_kind =
+            |expected: ReflectKind, received: &ReflectMut| DiffApplyError::MismatchedKind {
+                path: path.clone(),
+                expected,
+                received: received.kind(),
+            };
+
+        if let DiffChange::Replace { value, .. } = self {
+            element.try_apply(value.as_partial_reflect())?;
+            return Ok(());
+        }
+
+        match (self, element.reflect_mut()) {
+            (DiffChange::Replace { .. }, _) => unreachable!("replacements are applied above"),
+            (DiffChange::ListTruncate { len, .. }, ReflectMut::List(list)) => {
+                while list.len() > *len {
+                    list.pop();
+                }
+            }
+            (DiffChange::ListPush { value, .. }, ReflectMut::List(list)) => {
+                list.push(value.to_dynamic());
+            }
+            (DiffChange::MapInsert { key, value, .. }, ReflectMut::Map(map)) => {
+                map.insert_boxed(key.to_dynamic(), value.to_dynamic());
+            }
+            (DiffChange::MapRemove { key, .. }, ReflectMut::Map(map)) => {
+                map.remove(key.as_partial_reflect());
+            }
+            (DiffChange::MapModify { key, diff, .. }, ReflectMut::Map(map)) => {
+                let value = map.get_mut(key.as_partial_reflect()).ok_or_else(|| {
+                    DiffApplyError::MissingMapKey {
+                        path: path.clone(),
+                        key: key.to_dynamic(),
+                    }
+                })?;
+                diff.apply(value)?;
+            }
+            (DiffChange::SetInsert { value, .. }, ReflectMut::Set(set)) => {
+                set.insert_boxed(value.to_dynamic());
+            }
+            (DiffChange::SetRemove { value, .. }, ReflectMut::Set(set)) => {
+                set.remove(value.as_partial_reflect());
+            }
+            (DiffChange::ListTruncate { .. } | DiffChange::ListPush { .. }, received) => {
+                return Err(mismatched_kind(ReflectKind::List, &received));
+            }
+            (
+                DiffChange::MapInsert { .. }
+                | DiffChange::MapRemove { .. }
+                | DiffChange::MapModify { .. },
+                received,
+            ) => return Err(mismatched_kind(ReflectKind::Map, &received)),
+            (DiffChange::SetInsert { .. } | DiffChange::SetRemove { .. }, received) => {
+                return Err(mismatched_kind(ReflectKind::Set, &received));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clone for DiffChange {
+    fn clone(&self) -> Self {
+        match self {
+            DiffChange::Replace { path, value } => DiffChange::Replace {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            DiffChange::ListTruncate { path, len } => DiffChange::ListTruncate {
+                path: path.clone(),
+                len: *len,
+            },
+            DiffChange::ListPush { path, value } => DiffChange::ListPush {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            DiffChange::MapInsert { path, key, value } => DiffChange::MapInsert {
+                path: path.clone(),
+                key: key.to_dynamic(),
+                value: value.to_dynamic(),
+            },
+            DiffChange::MapRemove { path, key } => DiffChange::MapRemove {
+                path: path.clone(),
+                key: key.to_dynamic(),
+            },
+            DiffChange::MapModify { path, key, diff } => DiffChange::MapModify {
+                path: path.clone(),
+                key: key.to_dynamic(),
+                diff: diff.clone(),
+            },
+            DiffChange::SetInsert { path, value } => DiffChange::SetInsert {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            DiffChange::SetRemove { path, value } => DiffChange::SetRemove {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+        }
+    }
+}
+
+fn diff_values(
+    path: &mut Vec<Access<'static>>,
+    a: &dyn PartialReflect,
+    b: &dyn PartialReflect,
+    changes: &mut Vec<DiffChange>,
+) {
+    let compared = same_type(a, b)
+        && match (a.reflect_ref(), b.reflect_ref()) {
+            (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+                let names = (0..b.field_len()).filter_map(|index| b.name_at(index));
+                diff_named_fields(
+                    path,
+                    a.field_len(),
+                    names,
+                    |name| a.field(name),
+                    |name| b.field(name),
+                    changes,
+                )
+            }
+            (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => diff_indexed(
+                path,
+                (a.field_len(), b.field_len()),
+                |index| a.field(index),
+                |index| b.field(index),
+                Access::TupleIndex,
+                changes,
+            ),
+            (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => diff_indexed(
+                path,
+                (a.field_len(), b.field_len()),
+                |index| a.field(index),
+                |index| b.field(index),
+                Access::TupleIndex,
+                changes,
+            ),
+            (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+                a.variant_name() == b.variant_name()
+                    && match b.variant_type() {
+                        VariantType::Struct => {
+                            let names = (0..b.field_len()).filter_map(|index| b.name_at(index));
+                            diff_named_fields(
+                                path,
+                                a.field_len(),
+                                names,
+                                |name| a.field(name),
+                                |name| b.field(name),
+                                changes,
+                            )
+                        }
+                        VariantType::Tuple => diff_indexed(
+                            path,
+                            (a.field_len(), b.field_len()),
+                            |index| a.field_at(index),
+                            |index| b.field_at(index),
+                            Access::TupleIndex,
+                            changes,
+                        ),
+                        VariantType::Unit => true,
+                    }
+            }
+            (ReflectRef::Array(a), ReflectRef::Array(b)) => diff_indexed(
+                path,
+                (a.len(), b.len()),
+                |index| a.get(index),
+                |index| b.get(index),
+                Access::ListIndex,
+                changes,
+            ),
+            (ReflectRef::List(a), ReflectRef::List(b)) => {
+                let common = a.len().min(b.len());
+                diff_indexed(
+                    path,
+                    (common, common),
+                    |index| a.get(index),
+                    |index| b.get(index),
+                    Access::ListIndex,
+                    changes,
+                );
+                if b.len() < a.len() {
+                    changes.push(DiffChange::ListTruncate {
+                        path: to_parsed_path(path),
+                        len: b.len(),
+                    });
+                }
+                for value in b.iter().skip(common) {
+                    changes.push(DiffChange::ListPush {
+                        path: to_parsed_path(path),
+                        value: value.to_dynamic(),
+                    });
+                }
+                true
+            }
+            (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+                for (key, b_value) in b.iter() {
+                    match a.get(key) {
+                        Some(a_value) => {
+                            let diff = reflect_diff(a_value, b_value);
+                            if !diff.is_empty() {
+                                changes.push(DiffChange::MapModify {
+                                    path: to_parsed_path(path),
+                                    key: key.to_dynamic(),
+                                    diff,
+                                });
+                            }
+                        }
+                        None => changes.push(DiffChange::MapInsert {
+                            path: to_parsed_path(path),
+                            key: key.to_dynamic(),
+                            value: b_value.to_dynamic(),
+                        }),
+                    }
+                }
+                for (key, _) in a.iter() {
+                    if b.get(key).is_none() {
+                        changes.push(DiffChange::MapRemove {
+                            path: to_parsed_path(path),
+                            key: key.to_dynamic(),
+                        });
+                    }
+                }
+                true
+            }
+            (ReflectRef::Set(a), ReflectRef::Set(b)) => {
+                for value in b.iter() {
+                    if !a.contains(value) {
+                        changes.push(DiffChange::SetInsert {
+                            path: to_parsed_path(path),
+                            value: value.to_dynamic(),
+                        });
+                    }
+                }
+                for value in a.iter() {
+                    if !b.contains(value) {
+                        changes.push(DiffChange::SetRemove {
+                            path: to_parsed_path(path),
+                            value: value.to_dynamic(),
+                        });
+                    }
+                }
+                true
+            }
+            _ => {
+                if a.reflect_partial_eq(b) != Some(true) {
+                    changes.push(replace(path, b));
+                }
+                true
+            }
+        };
+
+    if !compared {
+        changes.push(replace(path, b));
+    }
+}
+
+/// Compares the fields of two values field by field, using `access` to locate each field.
+///
+/// Returns `false`, without recording any change, if the values don't have the same number of
+/// fields.
+fn diff_indexed<'a>(
+    path: &mut Vec<Access<'static>>,
+    (a_len, b_len): (usize, usize),
+    a_field: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+    b_field: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+    access: fn(usize) -> Access<'static>,
+    changes: &mut Vec<DiffChange>,
+) -> bool {
+    if a_len != b_len {
+        return false;
+    }
+    for index in 0..b_len {
+        let (Some(a), Some(b)) = (a_field(index), b_field(index)) else {
+            continue;
+        };
+        path.push(access(index));
+        diff_values(path, a, b, changes);
+        path.pop();
+    }
+    true
+}
+
+/// Compares the named fields of two values, using [`Access::Field`] to locate each field.
+///
+/// Returns `false`, without recording any change, if the values don't have the same fields.
+fn diff_named_fields<'a, 'n>(
+    path: &mut Vec<Access<'static>>,
+    a_len: usize,
+    b_names: impl Iterator<Item = &'n str>,
+    a_field: impl Fn(&str) -> Option<&'a dyn PartialReflect>,
+    b_field: impl Fn(&str) -> Option<&'a dyn PartialReflect>,
+    changes: &mut Vec<DiffChange>,
+) -> bool {
+    let mut fields = Vec::new();
+    for name in b_names {
+        let (Some(a), Some(b)) = (a_field(name), b_field(name)) else {
+            return false;
+        };
+        fields.push((name, a, b));
+    }
+    if fields.len() != a_len {
+        return false;
+    }
+    for (name, a, b) in fields {
+        path.push(Access::Field(Cow::Owned(name.to_owned())));
+        diff_values(path, a, b, changes);
+        path.pop();
+    }
+    true
+}
+
+fn same_type(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        _ => a.reflect_kind() == b.reflect_kind(),
+    }
+}
+
+fn replace(path: &[Access<'static>], value: &dyn PartialReflect) -> DiffChange {
+    DiffChange::Replace {
+        path: to_parsed_path(path),
+        value: value.to_dynamic(),
+    }
+}
+
+fn to_parsed_path(path: &[Access<'static>]) -> ParsedPath {
+    ParsedPath::from(path.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Reflect, TypeRegistry};
+    use ::serde::de::DeserializeSeed;
+    use alloc::{string::String, vec};
+    use bevy_platform::collections::{HashMap, HashSet};
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Level {
+        name: String,
+        spawn: (f32, f32),
+        enemies: Vec<Enemy>,
+        scores: HashMap<String, u32>,
+        tags: HashSet<String>,
+        difficulty: Difficulty,
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Enemy {
+        health: u32,
+        loot: Option<String>,
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    enum Difficulty {
+        Easy,
+        Custom { speed: f32, lives: u8 },
+    }
+
+    fn before() -> Level {
+        Level {
+            name: "intro".into(),
+            spawn: (0.0, 1.0),
+            enemies: vec![
+                Enemy {
+                    health: 10,
+                    loot: None,
+                },
+                Enemy {
+                    health: 20,
+                    loot: Some("key".into()),
+                },
+            ],
+            scores: HashMap::from_iter([("alice".into(), 10), ("bob".into(), 5)]),
+            tags: HashSet::from_iter(["tutorial".into()]),
+            difficulty: Difficulty::Custom {
+                speed: 1.0,
+                lives: 3,
+            },
+        }
+    }
+
+    fn after() -> Level {
+        Level {
+            name: "intro".into(),
+            spawn: (0.0, 2.0),
+            enemies: vec![Enemy {
+                health: 15,
+                loot: None,
+            }],
+            scores: HashMap::from_iter([("alice".into(), 12), ("carol".into(), 7)]),
+            tags: HashSet::from_iter(["outdoor".into()]),
+            difficulty: Difficulty::Custom {
+                speed: 1.0,
+                lives: 5,
+            },
+        }
+    }
+
+    #[test]
+    fn should_diff_only_changed_fields() {
+        let diff = reflect_diff(&before(), &after());
+        let mut paths: Vec<String> = diff
+            .changes()
+            .iter()
+            .map(|change| change.path().to_string())
+            .collect();
+        paths.sort();
+        paths.dedup();
+        assert_eq!(
+            paths,
+            [
+                ".difficulty.lives",
+                ".enemies",
+                ".enemies[0].health",
+                ".scores",
+                ".spawn.1",
+                ".tags"
+            ]
+        );
+        assert!(diff
+            .changes()
+            .iter()
+            .any(|change| matches!(change, DiffChange::ListTruncate { len: 1, .. })));
+        assert!(reflect_diff(&before(), &before()).is_empty());
+    }
+
+    #[test]
+    fn should_apply_diff() {
+        let diff = reflect_diff(&before(), &after());
+        let mut level = before();
+        diff.apply(&mut level).unwrap();
+        assert_eq!(level, after());
+
+        let mut level = before();
+        reflect_diff(&before(), &level).apply(&mut level).unwrap();
+        assert_eq!(level, before());
+    }
+
+    #[test]
+    fn should_replace_changed_variant() {
+        let mut level = before();
+        let mut target = before();
+        target.difficulty = Difficulty::Easy;
+
+        let diff = reflect_diff(&level, &target);
+        assert_eq!(diff.len(), 1);
+        assert!(matches!(
+            &diff.changes()[0],
+            DiffChange::Replace { path, .. } if path.to_string() == ".difficulty"
+        ));
+
+        diff.apply(&mut level).unwrap();
+        assert_eq!(level, target);
+    }
+
+    #[test]
+    fn should_fail_on_mismatched_target() {
+        let diff = reflect_diff(&before(), &after());
+        let mut enemy = Enemy {
+            health: 1,
+            loot: None,
+        };
+        let result = diff.apply(&mut enemy);
+        assert!(matches!(result, Err(DiffApplyError::InvalidPath { .. })));
+    }
+
+    #[test]
+    fn should_serialize_diff() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<HashSet<String>>();
+
+        let diff = reflect_diff(&before(), &after());
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(deserialized.len(), diff.len());
+
+        let mut level = before();
+        deserialized.apply(&mut level).unwrap();
+        assert_eq!(level, after());
+    }
+}
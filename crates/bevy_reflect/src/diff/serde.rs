@@ -0,0 +1,282 @@
+use crate::{
+    diff::{DiffChange, ReflectDiff},
+    serde::{ReflectDeserializer, ReflectSerializer},
+    ParsedPath, TypeRegistry,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Formatter};
+use serde::{
+    de::{DeserializeSeed, EnumAccess, Error as _, SeqAccess, VariantAccess, Visitor},
+    ser::{SerializeSeq, SerializeTupleVariant},
+    Deserializer, Serialize, Serializer,
+};
+
+const VARIANTS: &[&str] = &[
+    "Replace",
+    "ListTruncate",
+    "ListPush",
+    "MapInsert",
+    "MapRemove",
+    "MapModify",
+    "SetInsert",
+    "SetRemove",
+];
+
+/// A serializer for [`ReflectDiff`].
+///
+/// A diff is serialized as a sequence of changes. The path of each change is serialized as a
+/// string, and its values with a [`ReflectSerializer`], so their types must be registered in the
+/// [`TypeRegistry`].
+pub struct ReflectDiffSerializer<'a> {
+    diff: &'a ReflectDiff,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    /// Creates a serializer for `diff`.
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl Serialize for ReflectDiffSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.diff.len()))?;
+        for change in self.diff.changes() {
+            seq.serialize_element(&ChangeSerializer {
+                change,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct ChangeSerializer<'a> {
+    change: &'a DiffChange,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for ChangeSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        let (index, len) = match self.change {
+            DiffChange::Replace { .. } => (0, 2),
+            DiffChange::ListTruncate { .. } => (1, 2),
+            DiffChange::ListPush { .. } => (2, 2),
+            DiffChange::MapInsert { .. } => (3, 3),
+            DiffChange::MapRemove { .. } => (4, 2),
+            DiffChange::MapModify { .. } => (5, 3),
+            DiffChange::SetInsert { .. } => (6, 2),
+            DiffChange::SetRemove { .. } => (7, 2),
+        };
+        let mut state = serializer.serialize_tuple_variant(
+            "DiffChange",
+            index,
+            VARIANTS[index as usize],
+            len,
+        )?;
+        state.serialize_field(&self.change.path().to_string())?;
+        match self.change {
+            DiffChange::Replace { value: v, .. }
+            | DiffChange::ListPush { value: v, .. }
+            | DiffChange::SetInsert { value: v, .. }
+            | DiffChange::SetRemove { value: v, .. } => {
+                state.serialize_field(&ReflectSerializer::new(v.as_partial_reflect(), registry))?;
+            }
+            DiffChange::ListTruncate { len, .. } => state.serialize_field(len)?,
+            DiffChange::MapInsert { key, value: v, .. } => {
+                state
+                    .serialize_field(&ReflectSerializer::new(key.as_partial_reflect(), registry))?;
+                state.serialize_field(&ReflectSerializer::new(v.as_partial_reflect(), registry))?;
+            }
+            DiffChange::MapRemove { key, .. } => state
+                .serialize_field(&ReflectSerializer::new(key.as_partial_reflect(), registry))?,
+            DiffChange::MapModify { key, diff, .. } => {
+                state
+                    .serialize_field(&ReflectSerializer::new(key.as_partial_reflect(), registry))?;
+                state.serialize_field(&ReflectDiffSerializer::new(diff, registry))?;
+            }
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for [`ReflectDiff`]s serialized with a [`ReflectDiffSerializer`].
+///
+/// The values of the changes are deserialized with a [`ReflectDeserializer`], so they are
+/// returned as dynamic types.
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    /// Creates a deserializer using the types registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of reflected changes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut changes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(change) = seq.next_element_seed(ChangeDeserializer {
+            registry: self.registry,
+        })? {
+            changes.push(change);
+        }
+        Ok(ReflectDiff { changes })
+    }
+}
+
+struct ChangeDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ChangeDeserializer<'_> {
+    type Value = DiffChange;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum("DiffChange", VARIANTS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for ChangeDeserializer<'_> {
+    type Value = DiffChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a reflected change")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (index, variant) = data.variant_seed(VariantIdentifier)?;
+        let len = match index {
+            3 | 5 => 3,
+            _ => 2,
+        };
+        variant.tuple_variant(
+            len,
+            ChangeFieldsVisitor {
+                index,
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct ChangeFieldsVisitor<'a> {
+    index: usize,
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for ChangeFieldsVisitor<'_> {
+    type Value = DiffChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "the fields of `{}`", VARIANTS[self.index])
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let registry = self.registry;
+        let path: String = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let path = ParsedPath::parse(&path)
+            .map_err(|error| A::Error::custom(format_args!("invalid path `{path}`: {error}")))?;
+        let value = |seq: &mut A| {
+            seq.next_element_seed(ReflectDeserializer::new(registry))?
+                .ok_or_else(|| A::Error::custom("missing reflected value"))
+        };
+
+        Ok(match VARIANTS[self.index] {
+            "Replace" => DiffChange::Replace {
+                path,
+                value: value(&mut seq)?,
+            },
+            "ListTruncate" => DiffChange::ListTruncate {
+                path,
+                len: seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &"a list length"))?,
+            },
+            "ListPush" => DiffChange::ListPush {
+                path,
+                value: value(&mut seq)?,
+            },
+            "MapInsert" => DiffChange::MapInsert {
+                path,
+                key: value(&mut seq)?,
+                value: value(&mut seq)?,
+            },
+            "MapRemove" => DiffChange::MapRemove {
+                path,
+                key: value(&mut seq)?,
+            },
+            "MapModify" => DiffChange::MapModify {
+                path,
+                key: value(&mut seq)?,
+                diff: seq
+                    .next_element_seed(ReflectDiffDeserializer::new(registry))?
+                    .ok_or_else(|| A::Error::invalid_length(2, &"a nested diff"))?,
+            },
+            "SetInsert" => DiffChange::SetInsert {
+                path,
+                value: value(&mut seq)?,
+            },
+            _ => DiffChange::SetRemove {
+                path,
+                value: value(&mut seq)?,
+            },
+        })
+    }
+}
+
+/// Deserializes the name (or index) of a [`DiffChange`] variant into its index in [`VARIANTS`].
+struct VariantIdentifier;
+
+impl<'de> DeserializeSeed<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a change variant")
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, index: u64) -> Result<Self::Value, E> {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < VARIANTS.len())
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Self::Value, E> {
+        VARIANTS
+            .iter()
+            .position(|variant| *variant == name)
+            .ok_or_else(|| E::unknown_variant(name, VARIANTS))
+    }
+}
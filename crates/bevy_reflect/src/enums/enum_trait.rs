@@ -166,6 +166,14 @@ impl EnumInfo {
     ///
     /// * `variants`: The variants of this enum in the order they are defined
     pub fn new<TEnum: Enum + TypePath>(variants: &[VariantInfo]) -> Self {
+        Self::from_type(Type::of::<TEnum>(), variants)
+    }
+
+    /// Create a new [`EnumInfo`] for the given [`Type`].
+    ///
+    /// This is used to describe [runtime types](Type::runtime), which are represented by a
+    /// [`DynamicEnum`].
+    pub fn from_type(ty: Type, variants: &[VariantInfo]) -> Self {
         let variant_indices = variants
             .iter()
             .enumerate()
@@ -175,7 +183,7 @@ impl EnumInfo {
         let variant_names = variants.iter().map(VariantInfo::name).collect();
 
         Self {
-            ty: Type::of::<TEnum>(),
+            ty,
             generics: Generics::new(),
             variants: variants.to_vec().into_boxed_slice(),
             variant_names,
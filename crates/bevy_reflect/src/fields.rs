@@ -7,11 +7,27 @@ use alloc::borrow::Cow;
 use bevy_platform::sync::Arc;
 use core::fmt::{Display, Formatter};
 
+/// The [`TypeInfo`] of a field, either computed on demand or stored directly.
+#[derive(Clone, Copy, Debug)]
+enum FieldTypeInfo {
+    Lazy(fn() -> Option<&'static TypeInfo>),
+    Value(Option<&'static TypeInfo>),
+}
+
+impl FieldTypeInfo {
+    fn get(&self) -> Option<&'static TypeInfo> {
+        match self {
+            FieldTypeInfo::Lazy(f) => f(),
+            FieldTypeInfo::Value(type_info) => *type_info,
+        }
+    }
+}
+
 /// The named field of a reflected struct.
 #[derive(Clone, Debug)]
 pub struct NamedField {
     name: &'static str,
-    type_info: fn() -> Option<&'static TypeInfo>,
+    type_info: FieldTypeInfo,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "reflect_documentation")]
@@ -23,7 +39,7 @@ impl NamedField {
     pub fn new<T: PartialReflect + MaybeTyped + TypePath>(name: &'static str) -> Self {
         Self {
             name,
-            type_info: T::maybe_type_info,
+            type_info: FieldTypeInfo::Lazy(T::maybe_type_info),
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "reflect_documentation")]
@@ -31,6 +47,20 @@ impl NamedField {
         }
     }
 
+    /// Create a new [`NamedField`] from the [`Type`] of the field and its [`TypeInfo`], if any.
+    ///
+    /// This is used for fields of [runtime types](Type::runtime), or fields whose type is one.
+    pub fn from_type(name: &'static str, ty: Type, type_info: Option<&'static TypeInfo>) -> Self {
+        Self {
+            name,
+            type_info: FieldTypeInfo::Value(type_info),
+            ty,
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            #[cfg(feature = "reflect_documentation")]
+            docs: None,
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "reflect_documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -56,7 +86,7 @@ impl NamedField {
     /// Returns `None` if the field does not contain static type information,
     /// such as for dynamic types.
     pub fn type_info(&self) -> Option<&'static TypeInfo> {
-        (self.type_info)()
+        self.type_info.get()
     }
 
     impl_type_methods!(ty);
@@ -74,7 +104,7 @@ impl NamedField {
 #[derive(Clone, Debug)]
 pub struct UnnamedField {
     index: usize,
-    type_info: fn() -> Option<&'static TypeInfo>,
+    type_info: FieldTypeInfo,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "reflect_documentation")]
@@ -86,7 +116,7 @@ impl UnnamedField {
     pub fn new<T: PartialReflect + MaybeTyped + TypePath>(index: usize) -> Self {
         Self {
             index,
-            type_info: T::maybe_type_info,
+            type_info: FieldTypeInfo::Lazy(T::maybe_type_info),
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "reflect_documentation")]
@@ -94,6 +124,20 @@ impl UnnamedField {
         }
     }
 
+    /// Create a new [`UnnamedField`] from the [`Type`] of the field and its [`TypeInfo`], if any.
+    ///
+    /// This is used for fields of [runtime types](Type::runtime), or fields whose type is one.
+    pub fn from_type(index: usize, ty: Type, type_info: Option<&'static TypeInfo>) -> Self {
+        Self {
+            index,
+            type_info: FieldTypeInfo::Value(type_info),
+            ty,
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            #[cfg(feature = "reflect_documentation")]
+            docs: None,
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "reflect_documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -119,7 +163,7 @@ impl UnnamedField {
     /// Returns `None` if the field does not contain static type information,
     /// such as for dynamic types.
     pub fn type_info(&self) -> Option<&'static TypeInfo> {
-        (self.type_info)()
+        self.type_info.get()
     }
 
     impl_type_methods!(ty);
@@ -596,6 +596,7 @@ extern crate alloc;
 extern crate self as bevy_reflect;
 
 pub mod array;
+pub mod diff;
 mod error;
 mod fields;
 mod from_reflect;
@@ -645,14 +646,17 @@ mod impls {
 }
 
 pub mod attributes;
+pub mod binary;
 pub mod convert;
 pub mod enums;
 mod generics;
 pub mod serde;
+pub mod snapshot;
 pub mod std_traits;
 #[cfg(feature = "debug_stack")]
 mod type_info_stack;
 pub mod utility;
+pub mod validation;
 
 /// The reflect prelude.
 ///
@@ -8,7 +8,7 @@ pub(super) fn try_get_registration<E: Error>(
     ty: Type,
     registry: &TypeRegistry,
 ) -> Result<&TypeRegistration, E> {
-    let registration = registry.get(ty.id()).ok_or_else(|| {
+    let registration = registry.get_with_type(&ty).ok_or_else(|| {
         make_custom_error(format_args!("no registration found for type `{ty:?}`"))
     })?;
     Ok(registration)
@@ -0,0 +1,1273 @@
+//! Snapshots of a [`TypeRegistry`], for checking that data stays compatible across versions.
+//!
+//! A [`RegistrySnapshot`] records the layout of every registered type: the type path, the
+//! fields of structs, the variants of enums, the element types of collections, and the names
+//! of the registered [type data](crate::TypeData). It can be written to a stable, line-based
+//! text format with [`ToString`] and read back with [`str::parse`], so a snapshot of the shipped
+//! version of a game can be committed and compared against the current registry.
+//!
+//! [`RegistrySnapshot::diff`] compares two snapshots and classifies each [`SnapshotChange`] as
+//! compatible or breaking, which makes it suitable for a CI check:
+//!
+//! ```
+//! # use bevy_reflect::{snapshot::RegistrySnapshot, Reflect, TypeRegistry};
+//! mod v1 {
+//! #   use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+//!     #[derive(Reflect, Default)]
+//!     #[reflect(Default)]
+//!     #[type_path = "game"]
+//!     pub struct Save {
+//!         pub score: u32,
+//!     }
+//! }
+//!
+//! mod v2 {
+//! #   use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+//!     #[derive(Reflect, Default)]
+//!     #[reflect(Default)]
+//!     #[type_path = "game"]
+//!     pub struct Save {
+//!         pub score: u32,
+//!         pub lives: u8,
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::empty();
+//! registry.register::<v1::Save>();
+//! let shipped = RegistrySnapshot::new(&registry).to_string();
+//!
+//! let mut registry = TypeRegistry::empty();
+//! registry.register::<v2::Save>();
+//! let current = RegistrySnapshot::new(&registry);
+//!
+//! let shipped: RegistrySnapshot = shipped.parse().unwrap();
+//! let diff = shipped.diff(&current);
+//! assert!(diff.is_compatible(), "{diff}");
+//! assert!(diff.to_string().contains("compatible: added field `lives` to `game::Save`"));
+//! ```
+//!
+//! # Compatibility
+//!
+//! A change is breaking if data written for the old type can't be loaded as the new type:
+//! removing a type, a field, a variant or type data, changing the type of a field or the kind of
+//! a type, or adding a field that has no default value.
+//!
+//! Added fields are considered to have a default value if their struct registers
+//! [`ReflectDefault`] (with `#[reflect(Default)]`), since [`FromReflect`](crate::FromReflect)
+//! then takes missing fields from the default value of the struct. Fields with their own
+//! `#[reflect(default)]` attribute are not visible to the registry, so adding them is reported
+//! as breaking. Renaming a field is reported as a removed field and an added field.
+//!
+//! Fields marked with `#[reflect(skip_serializing)]` are not part of the snapshot.
+
+use crate::{
+    enums::VariantInfo, serde::SerializationData, std_traits::ReflectDefault, TypeInfo,
+    TypeRegistration, TypeRegistry,
+};
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+use disqualified::ShortName;
+use thiserror::Error;
+
+/// The layout of the types of a [`TypeRegistry`], keyed by type path.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegistrySnapshot {
+    types: BTreeMap<String, TypeSnapshot>,
+}
+
+/// The layout of a single type in a [`RegistrySnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeSnapshot {
+    /// The fields, variants or elements of the type.
+    pub layout: TypeLayout,
+    /// The names of the [type data](crate::TypeData) registered for the type, sorted.
+    ///
+    /// Type data is named by its type path if its type is registered, and by its short name
+    /// (such as `ReflectDefault`) otherwise, since [`core::any::type_name`] can differ between
+    /// compilers.
+    pub type_data: Vec<String>,
+}
+
+/// The shape of a type in a [`TypeSnapshot`]. Types are referred to by their type path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeLayout {
+    /// A struct with named fields.
+    Struct(Vec<FieldSnapshot>),
+    /// A tuple struct, with the types of its fields.
+    TupleStruct(Vec<String>),
+    /// A tuple, with the types of its fields.
+    Tuple(Vec<String>),
+    /// A list.
+    List {
+        /// The type of the elements.
+        item: String,
+    },
+    /// A fixed-size array.
+    Array {
+        /// The type of the elements.
+        item: String,
+        /// The number of elements.
+        capacity: usize,
+    },
+    /// A map.
+    Map {
+        /// The type of the keys.
+        key: String,
+        /// The type of the values.
+        value: String,
+    },
+    /// A set.
+    Set {
+        /// The type of the values.
+        value: String,
+    },
+    /// An enum, with its variants.
+    Enum(Vec<VariantSnapshot>),
+    /// An opaque type.
+    Opaque,
+}
+
+/// A named field of a struct or struct variant in a [`TypeSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldSnapshot {
+    /// The name of the field.
+    pub name: String,
+    /// The type path of the field.
+    pub type_path: String,
+}
+
+/// A variant of an enum in a [`TypeSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantSnapshot {
+    /// The name of the variant.
+    pub name: String,
+    /// The fields of the variant.
+    pub layout: VariantLayout,
+}
+
+/// The fields of a [`VariantSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VariantLayout {
+    /// A struct variant, with named fields.
+    Struct(Vec<FieldSnapshot>),
+    /// A tuple variant, with the types of its fields.
+    Tuple(Vec<String>),
+    /// A unit variant.
+    Unit,
+}
+
+/// An error returned when parsing a [`RegistrySnapshot`] from text.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("line {line}: {message}")]
+pub struct SnapshotParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// A description of the error.
+    pub message: String,
+}
+
+/// The differences between two [`RegistrySnapshot`]s, as returned by [`RegistrySnapshot::diff`].
+///
+/// Its [`Display`] implementation lists the changes, one per line, prefixed by whether they
+/// are breaking.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    changes: Vec<SnapshotChange>,
+}
+
+/// A single change in a [`SnapshotDiff`].
+///
+/// Changes inside an enum variant have a `variant`; other changes apply to the type itself.
+/// Fields of tuple structs and tuple variants are named by their index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotChange {
+    /// A type was registered.
+    TypeAdded {
+        /// The type path of the type.
+        type_path: String,
+    },
+    /// A type is no longer registered. This is a breaking change.
+    TypeRemoved {
+        /// The type path of the type.
+        type_path: String,
+    },
+    /// The kind of a type or variant changed, or the type of the elements of a collection
+    /// changed. This is a breaking change.
+    LayoutChanged {
+        /// The type path of the type.
+        type_path: String,
+        /// The variant that changed.
+        variant: Option<String>,
+        /// A description of the old layout.
+        old: String,
+        /// A description of the new layout.
+        new: String,
+    },
+    /// A field was added. This is a breaking change unless the field is `optional`.
+    FieldAdded {
+        /// The type path of the type.
+        type_path: String,
+        /// The variant the field was added to.
+        variant: Option<String>,
+        /// The name of the field.
+        field: String,
+        /// Whether data without the field can still be loaded.
+        optional: bool,
+    },
+    /// A field was removed. This is a breaking change.
+    FieldRemoved {
+        /// The type path of the type.
+        type_path: String,
+        /// The variant the field was removed from.
+        variant: Option<String>,
+        /// The name of the field.
+        field: String,
+    },
+    /// The type of a field changed. This is a breaking change.
+    FieldTypeChanged {
+        /// The type path of the type.
+        type_path: String,
+        /// The variant of the field.
+        variant: Option<String>,
+        /// The name of the field.
+        field: String,
+        /// The old type path of the field.
+        old: String,
+        /// The new type path of the field.
+        new: String,
+    },
+    /// A variant was added to an enum.
+    VariantAdded {
+        /// The type path of the enum.
+        type_path: String,
+        /// The name of the variant.
+        variant: String,
+    },
+    /// A variant was removed from an enum. This is a breaking change.
+    VariantRemoved {
+        /// The type path of the enum.
+        type_path: String,
+        /// The name of the variant.
+        variant: String,
+    },
+    /// Type data was registered for a type.
+    TypeDataAdded {
+        /// The type path of the type.
+        type_path: String,
+        /// The name of the type data.
+        type_data: String,
+    },
+    /// Type data is no longer registered for a type. This is a breaking change.
+    TypeDataRemoved {
+        /// The type path of the type.
+        type_path: String,
+        /// The name of the type data.
+        type_data: String,
+    },
+}
+
+impl RegistrySnapshot {
+    /// Takes a snapshot of the types registered in `registry`.
+    pub fn new(registry: &TypeRegistry) -> Self {
+        Self {
+            types: registry
+                .iter()
+                .map(|registration| {
+                    (
+                        registration.type_info().type_path().to_owned(),
+                        TypeSnapshot::new(registration, registry),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the snapshot of the type with the given type path.
+    pub fn get(&self, type_path: &str) -> Option<&TypeSnapshot> {
+        self.types.get(type_path)
+    }
+
+    /// Returns an iterator over the type paths and snapshots of the types, sorted by type path.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &TypeSnapshot)> {
+        self.types
+            .iter()
+            .map(|(type_path, snapshot)| (type_path.as_str(), snapshot))
+    }
+
+    /// Returns the number of types in the snapshot.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if the snapshot contains no types.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Returns the changes from `self` to `new`, where `self` is the snapshot of the older
+    /// version.
+    pub fn diff(&self, new: &RegistrySnapshot) -> SnapshotDiff {
+        let mut changes = Vec::new();
+        for (type_path, old_type) in &self.types {
+            match new.types.get(type_path) {
+                Some(new_type) => diff_types(type_path, old_type, new_type, &mut changes),
+                None => changes.push(SnapshotChange::TypeRemoved {
+                    type_path: type_path.clone(),
+                }),
+            }
+        }
+        for type_path in new.types.keys() {
+            if !self.types.contains_key(type_path) {
+                changes.push(SnapshotChange::TypeAdded {
+                    type_path: type_path.clone(),
+                });
+            }
+        }
+        SnapshotDiff { changes }
+    }
+}
+
+impl TypeSnapshot {
+    /// Takes a snapshot of the type of `registration`, looking up the names of its type data in
+    /// `registry`.
+    pub fn new(registration: &TypeRegistration, registry: &TypeRegistry) -> Self {
+        let serialization_data = registration.data::<SerializationData>();
+        let is_serialized =
+            |index: &usize| !serialization_data.is_some_and(|data| data.is_field_skipped(*index));
+
+        let layout = match registration.type_info() {
+            TypeInfo::Struct(info) => TypeLayout::Struct(
+                info.iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_serialized(index))
+                    .map(|(_, field)| FieldSnapshot::new(field.name(), field.type_path()))
+                    .collect(),
+            ),
+            TypeInfo::TupleStruct(info) => TypeLayout::TupleStruct(
+                info.iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_serialized(index))
+                    .map(|(_, field)| field.type_path().to_owned())
+                    .collect(),
+            ),
+            TypeInfo::Tuple(info) => TypeLayout::Tuple(
+                info.iter()
+                    .map(|field| field.type_path().to_owned())
+                    .collect(),
+            ),
+            TypeInfo::List(info) => TypeLayout::List {
+                item: info.item_ty().path().to_owned(),
+            },
+            TypeInfo::Array(info) => TypeLayout::Array {
+                item: info.item_ty().path().to_owned(),
+                capacity: info.capacity(),
+            },
+            TypeInfo::Map(info) => TypeLayout::Map {
+                key: info.key_ty().path().to_owned(),
+                value: info.value_ty().path().to_owned(),
+            },
+            TypeInfo::Set(info) => TypeLayout::Set {
+                value: info.value_ty().path().to_owned(),
+            },
+            TypeInfo::Enum(info) => TypeLayout::Enum(
+                info.iter()
+                    .map(|variant| VariantSnapshot {
+                        name: variant.name().to_owned(),
+                        layout: match variant {
+                            VariantInfo::Struct(variant) => VariantLayout::Struct(
+                                variant
+                                    .iter()
+                                    .map(|field| {
+                                        FieldSnapshot::new(field.name(), field.type_path())
+                                    })
+                                    .collect(),
+                            ),
+                            VariantInfo::Tuple(variant) => VariantLayout::Tuple(
+                                variant
+                                    .iter()
+                                    .map(|field| field.type_path().to_owned())
+                                    .collect(),
+                            ),
+                            VariantInfo::Unit(_) => VariantLayout::Unit,
+                        },
+                    })
+                    .collect(),
+            ),
+            TypeInfo::Opaque(_) => TypeLayout::Opaque,
+        };
+
+        let mut type_data: Vec<String> = registration
+            .iter()
+            .map(|(type_id, data)| match registry.get(type_id) {
+                Some(data_registration) => data_registration.type_info().type_path().to_owned(),
+                None => ShortName(data.type_data_name()).to_string(),
+            })
+            .collect();
+        type_data.sort_unstable();
+        type_data.dedup();
+
+        Self { layout, type_data }
+    }
+
+    /// Returns `true` if the type registers [`ReflectDefault`].
+    pub fn has_default(&self) -> bool {
+        self.type_data
+            .contains(&ShortName::of::<ReflectDefault>().to_string())
+    }
+}
+
+impl FieldSnapshot {
+    fn new(name: &str, type_path: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            type_path: type_path.to_owned(),
+        }
+    }
+}
+
+impl TypeLayout {
+    /// Returns the keyword used for this kind of layout in the text format.
+    fn keyword(&self) -> &'static str {
+        match self {
+            TypeLayout::Struct(_) => "struct",
+            TypeLayout::TupleStruct(_) => "tuple_struct",
+            TypeLayout::Tuple(_) => "tuple",
+            TypeLayout::List { .. } => "list",
+            TypeLayout::Array { .. } => "array",
+            TypeLayout::Map { .. } => "map",
+            TypeLayout::Set { .. } => "set",
+            TypeLayout::Enum(_) => "enum",
+            TypeLayout::Opaque => "opaque",
+        }
+    }
+
+    /// Describes the kind of the layout and the types of its elements, but not its fields or
+    /// variants.
+    fn describe(&self) -> String {
+        match self {
+            TypeLayout::List { item } => format!("list of `{item}`"),
+            TypeLayout::Array { item, capacity } => format!("array of {capacity} `{item}`"),
+            TypeLayout::Map { key, value } => format!("map from `{key}` to `{value}`"),
+            TypeLayout::Set { value } => format!("set of `{value}`"),
+            _ => self.keyword().to_owned(),
+        }
+    }
+}
+
+impl VariantLayout {
+    fn keyword(&self) -> &'static str {
+        match self {
+            VariantLayout::Struct(_) => "struct",
+            VariantLayout::Tuple(_) => "tuple",
+            VariantLayout::Unit => "unit",
+        }
+    }
+}
+
+fn diff_types(
+    type_path: &str,
+    old: &TypeSnapshot,
+    new: &TypeSnapshot,
+    changes: &mut Vec<SnapshotChange>,
+) {
+    let mut context = DiffContext {
+        type_path,
+        variant: None,
+        changes,
+    };
+    match (&old.layout, &new.layout) {
+        (TypeLayout::Struct(old_fields), TypeLayout::Struct(new_fields)) => {
+            context.diff_named_fields(old_fields, new_fields, new.has_default());
+        }
+        (TypeLayout::TupleStruct(old_fields), TypeLayout::TupleStruct(new_fields)) => {
+            context.diff_unnamed_fields(old_fields, new_fields, new.has_default());
+        }
+        (TypeLayout::Tuple(old_fields), TypeLayout::Tuple(new_fields)) => {
+            context.diff_unnamed_fields(old_fields, new_fields, false);
+        }
+        (TypeLayout::Enum(old_variants), TypeLayout::Enum(new_variants)) => {
+            context.diff_variants(old_variants, new_variants);
+        }
+        (old_layout, new_layout) => {
+            if old_layout.describe() != new_layout.describe() {
+                context.layout_changed(old_layout.describe(), new_layout.describe());
+            }
+        }
+    }
+
+    for type_data in &old.type_data {
+        if !new.type_data.contains(type_data) {
+            changes.push(SnapshotChange::TypeDataRemoved {
+                type_path: type_path.to_owned(),
+                type_data: type_data.clone(),
+            });
+        }
+    }
+    for type_data in &new.type_data {
+        if !old.type_data.contains(type_data) {
+            changes.push(SnapshotChange::TypeDataAdded {
+                type_path: type_path.to_owned(),
+                type_data: type_data.clone(),
+            });
+        }
+    }
+}
+
+struct DiffContext<'a> {
+    type_path: &'a str,
+    variant: Option<&'a str>,
+    changes: &'a mut Vec<SnapshotChange>,
+}
+
+impl DiffContext<'_> {
+    fn layout_changed(&mut self, old: String, new: String) {
+        self.changes.push(SnapshotChange::LayoutChanged {
+            type_path: self.type_path.to_owned(),
+            variant: self.variant.map(ToOwned::to_owned),
+            old,
+            new,
+        });
+    }
+
+    fn field_added(&mut self, field: String, optional: bool) {
+        self.changes.push(SnapshotChange::FieldAdded {
+            type_path: self.type_path.to_owned(),
+            variant: self.variant.map(ToOwned::to_owned),
+            field,
+            optional,
+        });
+    }
+
+    fn field_removed(&mut self, field: String) {
+        self.changes.push(SnapshotChange::FieldRemoved {
+            type_path: self.type_path.to_owned(),
+            variant: self.variant.map(ToOwned::to_owned),
+            field,
+        });
+    }
+
+    fn field_type_changed(&mut self, field: String, old: &str, new: &str) {
+        if old != new {
+            self.changes.push(SnapshotChange::FieldTypeChanged {
+                type_path: self.type_path.to_owned(),
+                variant: self.variant.map(ToOwned::to_owned),
+                field,
+                old: old.to_owned(),
+                new: new.to_owned(),
+            });
+        }
+    }
+
+    fn diff_named_fields(&mut self, old: &[FieldSnapshot], new: &[FieldSnapshot], optional: bool) {
+        for old_field in old {
+            match new.iter().find(|field| field.name == old_field.name) {
+                Some(new_field) => self.field_type_changed(
+                    old_field.name.clone(),
+                    &old_field.type_path,
+                    &new_field.type_path,
+                ),
+                None => self.field_removed(old_field.name.clone()),
+            }
+        }
+        for new_field in new {
+            if !old.iter().any(|field| field.name == new_field.name) {
+                self.field_added(new_field.name.clone(), optional);
+            }
+        }
+    }
+
+    fn diff_unnamed_fields(&mut self, old: &[String], new: &[String], optional: bool) {
+        for (index, old_field) in old.iter().enumerate() {
+            match new.get(index) {
+                Some(new_field) => self.field_type_changed(index.to_string(), old_field, new_field),
+                None => self.field_removed(index.to_string()),
+            }
+        }
+        for index in old.len()..new.len() {
+            self.field_added(index.to_string(), optional);
+        }
+    }
+
+    fn diff_variants(&mut self, old: &[VariantSnapshot], new: &[VariantSnapshot]) {
+        for old_variant in old {
+            let Some(new_variant) = new.iter().find(|variant| variant.name == old_variant.name)
+            else {
+                self.changes.push(SnapshotChange::VariantRemoved {
+                    type_path: self.type_path.to_owned(),
+                    variant: old_variant.name.clone(),
+                });
+                continue;
+            };
+            let mut context = DiffContext {
+                type_path: self.type_path,
+                variant: Some(&old_variant.name),
+                changes: self.changes,
+            };
+            match (&old_variant.layout, &new_variant.layout) {
+                (VariantLayout::Struct(old_fields), VariantLayout::Struct(new_fields)) => {
+                    context.diff_named_fields(old_fields, new_fields, false);
+                }
+                (VariantLayout::Tuple(old_fields), VariantLayout::Tuple(new_fields)) => {
+                    context.diff_unnamed_fields(old_fields, new_fields, false);
+                }
+                (VariantLayout::Unit, VariantLayout::Unit) => {}
+                (old_layout, new_layout) => context.layout_changed(
+                    old_layout.keyword().to_owned(),
+                    new_layout.keyword().to_owned(),
+                ),
+            }
+        }
+        for new_variant in new {
+            if !old.iter().any(|variant| variant.name == new_variant.name) {
+                self.changes.push(SnapshotChange::VariantAdded {
+                    type_path: self.type_path.to_owned(),
+                    variant: new_variant.name.clone(),
+                });
+            }
+        }
+    }
+}
+
+impl SnapshotDiff {
+    /// Returns all the changes.
+    pub fn changes(&self) -> &[SnapshotChange] {
+        &self.changes
+    }
+
+    /// Returns an iterator over the breaking changes.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SnapshotChange> {
+        self.changes.iter().filter(|change| change.is_breaking())
+    }
+
+    /// Returns `true` if the snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if none of the changes are breaking.
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes().next().is_none()
+    }
+}
+
+impl SnapshotChange {
+    /// Returns the type path of the type this change applies to.
+    pub fn type_path(&self) -> &str {
+        match self {
+            SnapshotChange::TypeAdded { type_path }
+            | SnapshotChange::TypeRemoved { type_path }
+            | SnapshotChange::LayoutChanged { type_path, .. }
+            | SnapshotChange::FieldAdded { type_path, .. }
+            | SnapshotChange::FieldRemoved { type_path, .. }
+            | SnapshotChange::FieldTypeChanged { type_path, .. }
+            | SnapshotChange::VariantAdded { type_path, .. }
+            | SnapshotChange::VariantRemoved { type_path, .. }
+            | SnapshotChange::TypeDataAdded { type_path, .. }
+            | SnapshotChange::TypeDataRemoved { type_path, .. } => type_path,
+        }
+    }
+
+    /// Returns `true` if data written for the old version may fail to load with the new one.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            SnapshotChange::TypeAdded { .. }
+            | SnapshotChange::VariantAdded { .. }
+            | SnapshotChange::TypeDataAdded { .. } => false,
+            SnapshotChange::FieldAdded { optional, .. } => !optional,
+            SnapshotChange::TypeRemoved { .. }
+            | SnapshotChange::LayoutChanged { .. }
+            | SnapshotChange::FieldRemoved { .. }
+            | SnapshotChange::FieldTypeChanged { .. }
+            | SnapshotChange::VariantRemoved { .. }
+            | SnapshotChange::TypeDataRemoved { .. } => true,
+        }
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            let severity = if change.is_breaking() {
+                "breaking"
+            } else {
+                "compatible"
+            };
+            writeln!(f, "{severity}: {change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SnapshotChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let location = |type_path: &str, variant: &Option<String>| match variant {
+            Some(variant) => format!("`{type_path}::{variant}`"),
+            None => format!("`{type_path}`"),
+        };
+        match self {
+            SnapshotChange::TypeAdded { type_path } => write!(f, "added type `{type_path}`"),
+            SnapshotChange::TypeRemoved { type_path } => write!(f, "removed type `{type_path}`"),
+            SnapshotChange::LayoutChanged {
+                type_path,
+                variant,
+                old,
+                new,
+            } => write!(
+                f,
+                "{} changed from {old} to {new}",
+                location(type_path, variant)
+            ),
+            SnapshotChange::FieldAdded {
+                type_path,
+                variant,
+                field,
+                optional,
+            } => {
+                write!(
+                    f,
+                    "added field `{field}` to {}",
+                    location(type_path, variant)
+                )?;
+                if !optional {
+                    write!(f, " without a default value")?;
+                }
+                Ok(())
+            }
+            SnapshotChange::FieldRemoved {
+                type_path,
+                variant,
+                field,
+            } => write!(
+                f,
+                "removed field `{field}` from {}",
+                location(type_path, variant)
+            ),
+            SnapshotChange::FieldTypeChanged {
+                type_path,
+                variant,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "changed the type of field `{field}` of {} from `{old}` to `{new}`",
+                location(type_path, variant)
+            ),
+            SnapshotChange::VariantAdded { type_path, variant } => {
+                write!(f, "added variant `{variant}` to `{type_path}`")
+            }
+            SnapshotChange::VariantRemoved { type_path, variant } => {
+                write!(f, "removed variant `{variant}` from `{type_path}`")
+            }
+            SnapshotChange::TypeDataAdded {
+                type_path,
+                type_data,
+            } => write!(f, "registered `{type_data}` for `{type_path}`"),
+            SnapshotChange::TypeDataRemoved {
+                type_path,
+                type_data,
+            } => write!(f, "no longer registers `{type_data}` for `{type_path}`"),
+        }
+    }
+}
+
+/// Writes the snapshot in its text format.
+///
+/// Each type starts with a line containing the keyword of its kind (`struct`, `tuple_struct`,
+/// `tuple`, `list`, `array`, `map`, `set`, `enum` or `opaque`) and its type path. It is followed
+/// by indented lines describing its fields (`field`), variants (`variant`, with their fields
+/// indented further), elements (`item`, `capacity`, `key` and `value`) and type data (`data`).
+/// Types are sorted by type path, so snapshots of the same registry are identical.
+impl Display for RegistrySnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fn write_named(
+            f: &mut Formatter<'_>,
+            indent: &str,
+            fields: &[FieldSnapshot],
+        ) -> fmt::Result {
+            for field in fields {
+                writeln!(f, "{indent}field {} {}", field.name, field.type_path)?;
+            }
+            Ok(())
+        }
+
+        fn write_unnamed(f: &mut Formatter<'_>, indent: &str, fields: &[String]) -> fmt::Result {
+            for field in fields {
+                writeln!(f, "{indent}field {field}")?;
+            }
+            Ok(())
+        }
+
+        for (type_path, snapshot) in &self.types {
+            writeln!(f, "{} {type_path}", snapshot.layout.keyword())?;
+            match &snapshot.layout {
+                TypeLayout::Struct(fields) => write_named(f, INDENT, fields)?,
+                TypeLayout::TupleStruct(fields) | TypeLayout::Tuple(fields) => {
+                    write_unnamed(f, INDENT, fields)?;
+                }
+                TypeLayout::List { item } => writeln!(f, "{INDENT}item {item}")?,
+                TypeLayout::Array { item, capacity } => {
+                    writeln!(f, "{INDENT}item {item}")?;
+                    writeln!(f, "{INDENT}capacity {capacity}")?;
+                }
+                TypeLayout::Map { key, value } => {
+                    writeln!(f, "{INDENT}key {key}")?;
+                    writeln!(f, "{INDENT}value {value}")?;
+                }
+                TypeLayout::Set { value } => writeln!(f, "{INDENT}value {value}")?,
+                TypeLayout::Enum(variants) => {
+                    for variant in variants {
+                        writeln!(
+                            f,
+                            "{INDENT}variant {} {}",
+                            variant.name,
+                            variant.layout.keyword()
+                        )?;
+                        match &variant.layout {
+                            VariantLayout::Struct(fields) => {
+                                write_named(f, VARIANT_INDENT, fields)?;
+                            }
+                            VariantLayout::Tuple(fields) => {
+                                write_unnamed(f, VARIANT_INDENT, fields)?;
+                            }
+                            VariantLayout::Unit => {}
+                        }
+                    }
+                }
+                TypeLayout::Opaque => {}
+            }
+            for type_data in &snapshot.type_data {
+                writeln!(f, "{INDENT}data {type_data}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const INDENT: &str = "    ";
+const VARIANT_INDENT: &str = "        ";
+
+/// Parses a snapshot from the text format written by its [`Display`] implementation.
+impl FromStr for RegistrySnapshot {
+    type Err = SnapshotParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut snapshot = RegistrySnapshot::default();
+        // The type being parsed, with the line of its header.
+        let mut current: Option<(usize, String, TypeSnapshot)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: String| SnapshotParseError {
+                line: line_number,
+                message,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let content = line.trim_start_matches(' ');
+            let indent = line.len() - content.len();
+            let (keyword, rest) = content.split_once(' ').unwrap_or((content, ""));
+
+            if indent == 0 {
+                if let Some((line, type_path, type_snapshot)) = current.take() {
+                    snapshot.insert(line, type_path, type_snapshot)?;
+                }
+                let layout = match keyword {
+                    "struct" => TypeLayout::Struct(Vec::new()),
+                    "tuple_struct" => TypeLayout::TupleStruct(Vec::new()),
+                    "tuple" => TypeLayout::Tuple(Vec::new()),
+                    "list" => TypeLayout::List {
+                        item: String::new(),
+                    },
+                    "array" => TypeLayout::Array {
+                        item: String::new(),
+                        capacity: 0,
+                    },
+                    "map" => TypeLayout::Map {
+                        key: String::new(),
+                        value: String::new(),
+                    },
+                    "set" => TypeLayout::Set {
+                        value: String::new(),
+                    },
+                    "enum" => TypeLayout::Enum(Vec::new()),
+                    "opaque" => TypeLayout::Opaque,
+                    _ => return Err(error(format!("unknown type kind `{keyword}`"))),
+                };
+                if rest.is_empty() {
+                    return Err(error("missing type path".to_owned()));
+                }
+                current = Some((
+                    line_number,
+                    rest.to_owned(),
+                    TypeSnapshot {
+                        layout,
+                        type_data: Vec::new(),
+                    },
+                ));
+                continue;
+            }
+
+            let Some((_, _, type_snapshot)) = &mut current else {
+                return Err(error("expected a type".to_owned()));
+            };
+            if rest.is_empty() {
+                return Err(error(format!("missing value for `{keyword}`")));
+            }
+            let rest = rest.to_owned();
+            let layout_keyword = type_snapshot.layout.keyword();
+            match (indent, keyword, &mut type_snapshot.layout) {
+                (4, "data", _) => type_snapshot.type_data.push(rest),
+                (4, "field", TypeLayout::Struct(fields)) => fields.push(parse_named(rest)),
+                (4, "field", TypeLayout::TupleStruct(fields) | TypeLayout::Tuple(fields)) => {
+                    fields.push(rest);
+                }
+                (4, "item", TypeLayout::List { item } | TypeLayout::Array { item, .. }) => {
+                    *item = rest;
+                }
+                (4, "capacity", TypeLayout::Array { capacity, .. }) => {
+                    *capacity = rest
+                        .parse()
+                        .map_err(|_| error(format!("invalid capacity `{rest}`")))?;
+                }
+                (4, "key", TypeLayout::Map { key, .. }) => *key = rest,
+                (4, "value", TypeLayout::Map { value, .. } | TypeLayout::Set { value }) => {
+                    *value = rest;
+                }
+                (4, "variant", TypeLayout::Enum(variants)) => {
+                    let (name, kind) = rest.split_once(' ').unwrap_or((&rest, ""));
+                    let layout = match kind {
+                        "struct" => VariantLayout::Struct(Vec::new()),
+                        "tuple" => VariantLayout::Tuple(Vec::new()),
+                        "unit" => VariantLayout::Unit,
+                        _ => return Err(error(format!("unknown variant kind `{kind}`"))),
+                    };
+                    variants.push(VariantSnapshot {
+                        name: name.to_owned(),
+                        layout,
+                    });
+                }
+                (8, "field", TypeLayout::Enum(variants)) => {
+                    match variants.last_mut().map(|variant| &mut variant.layout) {
+                        Some(VariantLayout::Struct(fields)) => fields.push(parse_named(rest)),
+                        Some(VariantLayout::Tuple(fields)) => fields.push(rest),
+                        _ => return Err(error("unexpected field".to_owned())),
+                    }
+                }
+                _ => {
+                    return Err(error(format!(
+                        "unexpected `{keyword}` in {layout_keyword} at indentation {indent}"
+                    )));
+                }
+            }
+        }
+
+        if let Some((line, type_path, type_snapshot)) = current.take() {
+            snapshot.insert(line, type_path, type_snapshot)?;
+        }
+        Ok(snapshot)
+    }
+}
+
+fn parse_named(field: String) -> FieldSnapshot {
+    match field.split_once(' ') {
+        Some((name, type_path)) => FieldSnapshot::new(name, type_path),
+        None => FieldSnapshot::new(&field, ""),
+    }
+}
+
+impl RegistrySnapshot {
+    /// Adds a parsed type, checking that it is complete.
+    fn insert(
+        &mut self,
+        line: usize,
+        type_path: String,
+        mut snapshot: TypeSnapshot,
+    ) -> Result<(), SnapshotParseError> {
+        let missing = match &snapshot.layout {
+            TypeLayout::Struct(fields) => fields
+                .iter()
+                .any(|field| field.type_path.is_empty())
+                .then_some("field type"),
+            TypeLayout::List { item } | TypeLayout::Array { item, .. } => {
+                item.is_empty().then_some("item")
+            }
+            TypeLayout::Map { key, .. } if key.is_empty() => Some("key"),
+            TypeLayout::Map { value, .. } | TypeLayout::Set { value } => {
+                value.is_empty().then_some("value")
+            }
+            _ => None,
+        };
+        if let Some(missing) = missing {
+            return Err(SnapshotParseError {
+                line,
+                message: format!("missing {missing} for `{type_path}`"),
+            });
+        }
+        snapshot.type_data.sort_unstable();
+        snapshot.type_data.dedup();
+        self.types.insert(type_path, snapshot);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect};
+    use alloc::vec;
+    use bevy_platform::collections::HashMap;
+
+    mod v1 {
+        use crate::{self as bevy_reflect, std_traits::ReflectDefault, Reflect};
+        use alloc::{string::String, vec::Vec};
+
+        #[derive(Reflect, Default)]
+        #[reflect(Default)]
+        #[type_path = "game"]
+        pub struct Settings {
+            pub volume: f32,
+            pub name: String,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub health: u32,
+            pub items: Vec<String>,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub enum Event {
+            Spawn { x: f32 },
+            Despawn(u32),
+            Quit,
+        }
+    }
+
+    mod v2 {
+        use crate::{self as bevy_reflect, std_traits::ReflectDefault, Reflect};
+        use alloc::{string::String, vec::Vec};
+
+        #[derive(Reflect, Default)]
+        #[reflect(Default)]
+        #[type_path = "game"]
+        pub struct Settings {
+            pub volume: f32,
+            pub name: String,
+            pub fullscreen: bool,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub hp: u32,
+            pub items: Vec<u32>,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub enum Event {
+            Spawn { x: f32, y: f32 },
+            Despawn(u32),
+            Pause,
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Everything {
+        tuple: (u8, char),
+        array: [f32; 2],
+        map: HashMap<String, u64>,
+        #[reflect(skip_serializing)]
+        cache: u32,
+        state: Option<Vec<bool>>,
+    }
+
+    #[test]
+    fn should_round_trip_text_format() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Everything>();
+        registry.register::<v1::Event>();
+        let snapshot = RegistrySnapshot::new(&registry);
+        let text = snapshot.to_string();
+
+        assert_eq!(text.parse::<RegistrySnapshot>(), Ok(snapshot.clone()));
+        assert_eq!(
+            snapshot.get("game::Event").unwrap().layout,
+            TypeLayout::Enum(vec![
+                VariantSnapshot {
+                    name: "Spawn".into(),
+                    layout: VariantLayout::Struct(vec![FieldSnapshot::new("x", "f32")]),
+                },
+                VariantSnapshot {
+                    name: "Despawn".into(),
+                    layout: VariantLayout::Tuple(vec!["u32".into()]),
+                },
+                VariantSnapshot {
+                    name: "Quit".into(),
+                    layout: VariantLayout::Unit,
+                },
+            ])
+        );
+
+        let everything = snapshot
+            .get("bevy_reflect::snapshot::tests::Everything")
+            .unwrap();
+        let TypeLayout::Struct(fields) = &everything.layout else {
+            panic!("expected a struct layout");
+        };
+        let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+        assert_eq!(names, ["tuple", "array", "map", "state"]);
+        assert!(text.contains(
+            "struct bevy_reflect::snapshot::tests::Everything\n    field tuple (u8, char)\n"
+        ));
+        assert!(text.contains("array [f32; 2]\n    item f32\n    capacity 2\n"));
+    }
+
+    #[test]
+    fn should_name_type_data_independently_of_the_compiler() {
+        #[derive(Reflect, Clone)]
+        struct ReflectMarker;
+
+        impl crate::FromType<Everything> for ReflectMarker {
+            fn from_type() -> Self {
+                ReflectMarker
+            }
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Everything>();
+        registry.register::<ReflectMarker>();
+        registry.register_type_data::<Everything, ReflectMarker>();
+        let snapshot = RegistrySnapshot::new(&registry);
+        let everything = snapshot
+            .get("bevy_reflect::snapshot::tests::Everything")
+            .unwrap();
+
+        // Unregistered type data is named by its short name, registered type data by its path.
+        assert!(everything
+            .type_data
+            .contains(&"ReflectFromReflect".to_string()));
+        assert!(everything
+            .type_data
+            .contains(&"bevy_reflect::snapshot::tests::ReflectMarker".to_string()));
+    }
+
+    #[test]
+    fn should_classify_changes() {
+        let mut old_registry = TypeRegistry::empty();
+        old_registry.register::<v1::Settings>();
+        old_registry.register::<v1::Player>();
+        old_registry.register::<v1::Event>();
+        let mut new_registry = TypeRegistry::empty();
+        new_registry.register::<v2::Settings>();
+        new_registry.register::<v2::Player>();
+        new_registry.register::<v2::Event>();
+
+        let diff = RegistrySnapshot::new(&old_registry).diff(&RegistrySnapshot::new(&new_registry));
+        let changes: Vec<_> = diff
+            .changes()
+            .iter()
+            .filter(|change| change.type_path().starts_with("game::"))
+            .map(|change| (change.to_string(), change.is_breaking()))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("added field `y` to `game::Event::Spawn` without a default value".into(), true),
+                ("removed variant `Quit` from `game::Event`".into(), true),
+                ("added variant `Pause` to `game::Event`".into(), false),
+                ("removed field `health` from `game::Player`".into(), true),
+                (
+                    "changed the type of field `items` of `game::Player` from `alloc::vec::Vec<alloc::string::String>` to `alloc::vec::Vec<u32>`".into(),
+                    true
+                ),
+                ("added field `hp` to `game::Player` without a default value".into(), true),
+                ("added field `fullscreen` to `game::Settings`".into(), false),
+            ]
+        );
+        assert!(!diff.is_compatible());
+        assert!(diff
+            .to_string()
+            .contains("compatible: added field `fullscreen` to `game::Settings`\n"));
+        assert!(diff.changes().contains(&SnapshotChange::TypeAdded {
+            type_path: "alloc::vec::Vec<u32>".into()
+        }));
+        assert!(diff.changes().contains(&SnapshotChange::TypeRemoved {
+            type_path: "alloc::vec::Vec<alloc::string::String>".into()
+        }));
+    }
+
+    #[test]
+    fn should_report_layout_and_type_data_changes() {
+        let old: RegistrySnapshot =
+            "list game::Ids\n    item u32\n    data game::ReflectThing\nopaque game::Handle\n"
+                .parse()
+                .unwrap();
+        let new: RegistrySnapshot = "array game::Ids\n    item u32\n    capacity 4\nopaque game::Handle\n    data game::ReflectThing\n"
+            .parse()
+            .unwrap();
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.changes(),
+            [
+                SnapshotChange::TypeDataAdded {
+                    type_path: "game::Handle".into(),
+                    type_data: "game::ReflectThing".into(),
+                },
+                SnapshotChange::LayoutChanged {
+                    type_path: "game::Ids".into(),
+                    variant: None,
+                    old: "list of `u32`".into(),
+                    new: "array of 4 `u32`".into(),
+                },
+                SnapshotChange::TypeDataRemoved {
+                    type_path: "game::Ids".into(),
+                    type_data: "game::ReflectThing".into(),
+                },
+            ]
+        );
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn should_reject_invalid_text() {
+        assert_eq!(
+            "    field x f32".parse::<RegistrySnapshot>(),
+            Err(SnapshotParseError {
+                line: 1,
+                message: "expected a type".into(),
+            })
+        );
+        assert_eq!(
+            "struct game::A\n    item u32".parse::<RegistrySnapshot>(),
+            Err(SnapshotParseError {
+                line: 2,
+                message: "unexpected `item` in struct at indentation 4".into(),
+            })
+        );
+        assert_eq!(
+            "list game::B\n\nmap game::C".parse::<RegistrySnapshot>(),
+            Err(SnapshotParseError {
+                line: 1,
+                message: "missing item for `game::B`".into(),
+            })
+        );
+    }
+}
@@ -122,6 +122,14 @@ impl StructInfo {
     ///
     /// * `fields`: The fields of this struct in the order they are defined
     pub fn new<T: Reflect + TypePath>(fields: &[NamedField]) -> Self {
+        Self::from_type(Type::of::<T>(), fields)
+    }
+
+    /// Create a new [`StructInfo`] for the given [`Type`].
+    ///
+    /// This is used to describe [runtime types](Type::runtime), which are represented by a
+    /// [`DynamicStruct`].
+    pub fn from_type(ty: Type, fields: &[NamedField]) -> Self {
         let field_indices = fields
             .iter()
             .enumerate()
@@ -131,7 +139,7 @@ impl StructInfo {
         let field_names = fields.iter().map(NamedField::name).collect();
 
         Self {
-            ty: Type::of::<T>(),
+            ty,
             generics: Generics::new(),
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
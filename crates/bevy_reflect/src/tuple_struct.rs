@@ -91,8 +91,16 @@ impl TupleStructInfo {
     ///
     /// * `fields`: The fields of this struct in the order they are defined
     pub fn new<T: Reflect + TypePath>(fields: &[UnnamedField]) -> Self {
+        Self::from_type(Type::of::<T>(), fields)
+    }
+
+    /// Create a new [`TupleStructInfo`] for the given [`Type`].
+    ///
+    /// This is used to describe [runtime types](Type::runtime), which are represented by a
+    /// [`DynamicTupleStruct`].
+    pub fn from_type(ty: Type, fields: &[UnnamedField]) -> Self {
         Self {
-            ty: Type::of::<T>(),
+            ty,
             generics: Generics::new(),
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: Arc::new(CustomAttributes::default()),
@@ -432,6 +432,26 @@ impl Type {
         }
     }
 
+    /// Create a new [`Type`] for a type defined at runtime, such as a type imported from a
+    /// schema, which has no Rust type (and so no [`TypeId`]) of its own.
+    ///
+    /// All runtime types share the same [`TypeId`], so they are compared and registered by
+    /// [type path] instead. See [`Type::is_runtime`].
+    ///
+    /// [type path]: TypePath
+    pub fn runtime(type_path_table: TypePathTable) -> Self {
+        Self {
+            type_path_table,
+            type_id: TypeId::of::<RuntimeType>(),
+        }
+    }
+
+    /// Returns `true` if this type was created with [`Type::runtime`].
+    #[inline]
+    pub fn is_runtime(&self) -> bool {
+        self.type_id == TypeId::of::<RuntimeType>()
+    }
+
     /// Returns the [`TypeId`] of the type.
     #[inline]
     pub fn id(&self) -> TypeId {
@@ -494,14 +514,17 @@ impl Debug for Type {
 
 impl Eq for Type {}
 
-/// This implementation purely relies on the [`TypeId`] of the type,
+/// This implementation relies on the [`TypeId`] of the type,
 /// and not on the [type path].
 ///
+/// [Runtime types](Type::runtime), which all share the same [`TypeId`], are also compared by
+/// type path.
+///
 /// [type path]: TypePath
 impl PartialEq for Type {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.type_id == other.type_id
+        self.type_id == other.type_id && (!self.is_runtime() || self.path() == other.path())
     }
 }
 
@@ -516,6 +539,9 @@ impl Hash for Type {
     }
 }
 
+/// The [`TypeId`] shared by all [runtime types](Type::runtime).
+struct RuntimeType;
+
 macro_rules! impl_type_methods {
     // Generates the type methods based off a single field.
     ($field:ident) => {
@@ -186,20 +186,37 @@ impl<T: TypePath> DynamicTypePath for T {
 pub struct TypePathTable {
     // Cache the type path as it is likely the only one that will be used.
     type_path: &'static str,
-    short_type_path: fn() -> &'static str,
-    type_ident: fn() -> Option<&'static str>,
-    crate_name: fn() -> Option<&'static str>,
-    module_path: fn() -> Option<&'static str>,
+    short_type_path: TypePathPart<&'static str>,
+    type_ident: TypePathPart<Option<&'static str>>,
+    crate_name: TypePathPart<Option<&'static str>>,
+    module_path: TypePathPart<Option<&'static str>>,
+}
+
+/// A part of a [`TypePathTable`], either computed on demand from a [`TypePath`] implementation,
+/// or stored directly for types defined at runtime.
+#[derive(Clone, Copy)]
+enum TypePathPart<T> {
+    Lazy(fn() -> T),
+    Value(T),
+}
+
+impl<T: Copy> TypePathPart<T> {
+    fn get(&self) -> T {
+        match self {
+            TypePathPart::Lazy(f) => f(),
+            TypePathPart::Value(value) => *value,
+        }
+    }
 }
 
 impl fmt::Debug for TypePathTable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TypePathVtable")
             .field("type_path", &self.type_path)
-            .field("short_type_path", &(self.short_type_path)())
-            .field("type_ident", &(self.type_ident)())
-            .field("crate_name", &(self.crate_name)())
-            .field("module_path", &(self.module_path)())
+            .field("short_type_path", &self.short_type_path.get())
+            .field("type_ident", &self.type_ident.get())
+            .field("crate_name", &self.crate_name.get())
+            .field("module_path", &self.module_path.get())
             .finish()
     }
 }
@@ -209,10 +226,33 @@ impl TypePathTable {
     pub fn of<T: TypePath + ?Sized>() -> Self {
         Self {
             type_path: T::type_path(),
-            short_type_path: T::short_type_path,
-            type_ident: T::type_ident,
-            crate_name: T::crate_name,
-            module_path: T::module_path,
+            short_type_path: TypePathPart::Lazy(T::short_type_path),
+            type_ident: TypePathPart::Lazy(T::type_ident),
+            crate_name: TypePathPart::Lazy(T::crate_name),
+            module_path: TypePathPart::Lazy(T::module_path),
+        }
+    }
+
+    /// Creates a new table for a type defined at runtime, such as a type imported from a schema.
+    ///
+    /// The other parts of the path are derived from `type_path`, which should have the form
+    /// `my_crate::my_module::MyType`. Generic arguments, if any, are kept in the short path.
+    pub fn from_path(type_path: &'static str) -> Self {
+        let generics_start = type_path.find('<').unwrap_or(type_path.len());
+        let (module_path, short_type_path) = match type_path[..generics_start].rfind("::") {
+            Some(index) => (Some(&type_path[..index]), &type_path[index + 2..]),
+            None => (None, type_path),
+        };
+        let type_ident =
+            &short_type_path[..short_type_path.find('<').unwrap_or(short_type_path.len())];
+        let crate_name = module_path.map(|path| path.split("::").next().unwrap_or(path));
+
+        Self {
+            type_path,
+            short_type_path: TypePathPart::Value(short_type_path),
+            type_ident: TypePathPart::Value(Some(type_ident)),
+            crate_name: TypePathPart::Value(crate_name),
+            module_path: TypePathPart::Value(module_path),
         }
     }
 
@@ -223,21 +263,21 @@ impl TypePathTable {
 
     /// See [`TypePath::short_type_path`].
     pub fn short_path(&self) -> &'static str {
-        (self.short_type_path)()
+        self.short_type_path.get()
     }
 
     /// See [`TypePath::type_ident`].
     pub fn ident(&self) -> Option<&'static str> {
-        (self.type_ident)()
+        self.type_ident.get()
     }
 
     /// See [`TypePath::crate_name`].
     pub fn crate_name(&self) -> Option<&'static str> {
-        (self.crate_name)()
+        self.crate_name.get()
     }
 
     /// See [`TypePath::module_path`].
     pub fn module_path(&self) -> Option<&'static str> {
-        (self.module_path)()
+        self.module_path.get()
     }
 }
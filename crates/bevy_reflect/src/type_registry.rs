@@ -1,5 +1,6 @@
 use crate::{
-    convert::ReflectConvert, serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed,
+    convert::ReflectConvert, serde::Serializable, FromReflect, Reflect, Type, TypeInfo, TypePath,
+    Typed,
 };
 use alloc::{boxed::Box, string::String};
 use bevy_platform::{
@@ -33,6 +34,9 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    /// Registrations of [runtime types](Type::runtime), keyed by type path since they share a
+    /// [`TypeId`].
+    runtime_registrations: HashMap<&'static str, TypeRegistration>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -96,6 +100,7 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            runtime_registrations: Default::default(),
         }
     }
 
@@ -255,7 +260,18 @@ impl TypeRegistry {
     /// Use [`register`](Self::register) to register a type with its dependencies.
     ///
     /// Returns `true` if the registration was added and `false` if it already exists.
+    ///
+    /// Registrations of [runtime types](Type::runtime) are stored by type path, and can only
+    /// be looked up with [`get_with_type_path`](Self::get_with_type_path).
     pub fn add_registration(&mut self, registration: TypeRegistration) -> bool {
+        if registration.type_info().ty().is_runtime() {
+            let type_path = registration.type_info().type_path();
+            if self.runtime_registrations.contains_key(type_path) {
+                return false;
+            }
+            self.runtime_registrations.insert(type_path, registration);
+            return true;
+        }
         let type_id = registration.type_id();
         self.register_internal(type_id, || registration)
     }
@@ -270,6 +286,11 @@ impl TypeRegistry {
     /// This method will _not_ register type dependencies.
     /// Use [`register`](Self::register) to register a type with its dependencies.
     pub fn overwrite_registration(&mut self, registration: TypeRegistration) {
+        if registration.type_info().ty().is_runtime() {
+            self.runtime_registrations
+                .insert(registration.type_info().type_path(), registration);
+            return;
+        }
         Self::update_registration_indices(
             &registration,
             &mut self.short_path_to_id,
@@ -411,6 +432,9 @@ impl TypeRegistry {
     }
 
     /// Whether the type with given [`TypeId`] has been registered in this registry.
+    ///
+    /// This never finds [runtime types](Type::runtime), which don't have a [`TypeId`] of their
+    /// own.
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
     }
@@ -437,11 +461,14 @@ impl TypeRegistry {
     ///
     /// If no type with the given path has been registered, returns `None`.
     ///
+    /// Unlike [`get`](Self::get), this also finds [runtime types](Type::runtime).
+    ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
         self.type_path_to_id
             .get(type_path)
             .and_then(|id| self.get(*id))
+            .or_else(|| self.runtime_registrations.get(type_path))
     }
 
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
@@ -451,10 +478,24 @@ impl TypeRegistry {
     ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
-        self.type_path_to_id
-            .get(type_path)
-            .cloned()
-            .and_then(move |id| self.get_mut(id))
+        match self.type_path_to_id.get(type_path) {
+            Some(id) => self.registrations.get_mut(id),
+            None => self.runtime_registrations.get_mut(type_path),
+        }
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the given [`Type`].
+    ///
+    /// Unlike [`get`](Self::get), this also finds [runtime types](Type::runtime), by
+    /// their type path.
+    ///
+    /// If the type has not been registered, returns `None`.
+    pub fn get_with_type(&self, ty: &Type) -> Option<&TypeRegistration> {
+        if ty.is_runtime() {
+            self.runtime_registrations.get(ty.path())
+        } else {
+            self.get(ty.id())
+        }
     }
 
     /// Returns a reference to the [`TypeRegistration`] of the type with
@@ -545,19 +586,23 @@ impl TypeRegistry {
     /// Returns an iterator over the [`TypeRegistration`]s of the registered
     /// types.
     pub fn iter(&self) -> impl Iterator<Item = &TypeRegistration> {
-        self.registrations.values()
+        self.registrations
+            .values()
+            .chain(self.runtime_registrations.values())
     }
 
     /// Returns a mutable iterator over the [`TypeRegistration`]s of the registered
     /// types.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut TypeRegistration> {
-        self.registrations.values_mut()
+        self.registrations
+            .values_mut()
+            .chain(self.runtime_registrations.values_mut())
     }
 
     /// Checks to see if the [`TypeData`] of type `T` is associated with each registered type,
     /// returning a ([`TypeRegistration`], [`TypeData`]) iterator for all entries where data of that type was found.
     pub fn iter_with_data<T: TypeData>(&self) -> impl Iterator<Item = (&TypeRegistration, &T)> {
-        self.registrations.values().filter_map(|item| {
+        self.iter().filter_map(|item| {
             let type_data = item.data::<T>();
             type_data.map(|data| (item, data))
         })
@@ -625,6 +670,19 @@ impl TypeRegistration {
         }
     }
 
+    /// Creates a type registration with no [type data] from a [`TypeInfo`].
+    ///
+    /// This is mainly useful for [runtime types](Type::runtime), which have no Rust type to
+    /// create a registration with [`of`](Self::of).
+    ///
+    /// [type data]: TypeData
+    pub fn from_type_info(type_info: &'static TypeInfo) -> Self {
+        Self {
+            data: Default::default(),
+            type_info,
+        }
+    }
+
     /// Returns the [`TypeId`] of the type.
     #[inline]
     pub fn type_id(&self) -> TypeId {
@@ -804,6 +862,11 @@ impl Clone for TypeRegistration {
 pub trait TypeData: Downcast + Send + Sync {
     /// Creates a type-erased clone of this value.
     fn clone_type_data(&self) -> Box<dyn TypeData>;
+
+    /// Returns the name of the type of this value, as given by [`core::any::type_name`].
+    fn type_data_name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
 }
 
 impl_downcast!(TypeData);
@@ -1120,4 +1183,48 @@ mod test {
         let data = registration.data::<DataA>().unwrap();
         assert_eq!(data.0, 456);
     }
+
+    #[test]
+    fn register_runtime_types() {
+        use crate::{
+            structs::{DynamicStruct, StructInfo},
+            NamedField, Type, TypePathTable,
+        };
+
+        fn runtime_struct(type_path: &'static str) -> &'static TypeInfo {
+            let fields = [NamedField::new::<f32>("value")];
+            let ty = Type::runtime(TypePathTable::from_path(type_path));
+            Box::leak(Box::new(TypeInfo::Struct(StructInfo::from_type(
+                ty, &fields,
+            ))))
+        }
+
+        let foo = runtime_struct("my_crate::Foo");
+        let bar = runtime_struct("my_crate::nested::Bar");
+        assert_eq!(foo.type_path_table().short_path(), "Foo");
+        assert_eq!(
+            bar.type_path_table().module_path(),
+            Some("my_crate::nested")
+        );
+        assert_eq!(bar.type_path_table().crate_name(), Some("my_crate"));
+        assert_ne!(foo.ty(), bar.ty());
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<f32>();
+        assert!(registry.add_registration(TypeRegistration::from_type_info(foo)));
+        assert!(registry.add_registration(TypeRegistration::from_type_info(bar)));
+        assert!(!registry.add_registration(TypeRegistration::from_type_info(foo)));
+        assert!(!registry.contains(foo.type_id()));
+        assert_eq!(registry.iter().count(), 3);
+
+        let registration = registry.get_with_type_path("my_crate::Foo").unwrap();
+        assert_eq!(registration.type_info().ty(), foo.ty());
+
+        let mut value = DynamicStruct::default();
+        value.insert("value", 1.0f32);
+        value.set_represented_type(Some(foo));
+        let serialized =
+            ron::to_string(&crate::serde::ReflectSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(serialized, r#"{"my_crate::Foo":(value:1.0)}"#);
+    }
 }
@@ -0,0 +1,671 @@
+//! Validation of reflected values against [custom attributes].
+//!
+//! This module provides a standard set of attributes that describe which values a field accepts:
+//!
+//! * [`NumberRange`]: the field is a number within an inclusive range.
+//! * [`StringLength`]: the field is a string with a number of characters within a range.
+//! * [`NonEmpty`]: the field is a non-empty string or collection.
+//! * [`Pattern`]: the field is a string matching a regular expression
+//!   (requires the `regex` feature).
+//!
+//! [`validate`] walks a value (and every value nested in it) and returns a [`ValidationError`] for
+//! each value that violates an attribute of the field it is stored in, or of its own type.
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, TypeRegistry};
+//! use bevy_reflect::validation::{validate, NonEmpty, NumberRange};
+//!
+//! #[derive(Reflect)]
+//! struct Volume {
+//!     #[reflect(@NumberRange::new(0.0, 1.0))]
+//!     level: f32,
+//!     #[reflect(@NonEmpty)]
+//!     device: String,
+//! }
+//!
+//! let volume = Volume {
+//!     level: 1.5,
+//!     device: "speakers".into(),
+//! };
+//!
+//! let errors = validate(&volume, &TypeRegistry::default());
+//! assert_eq!(errors.len(), 1);
+//! assert_eq!(errors[0].path.to_string(), ".level");
+//! ```
+//!
+//! [custom attributes]: crate::attributes::CustomAttributes
+
+use crate::{
+    attributes::CustomAttributes, Access, NamedField, ParsedPath, PartialReflect, Reflect,
+    ReflectRef, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+
+/// A custom attribute requiring a number to be within an inclusive range.
+///
+/// This applies to all primitive integer and floating point types. `NaN` is never in range.
+///
+/// ```
+/// # use bevy_reflect::{Reflect, validation::NumberRange};
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(@NumberRange::at_least(1.0))]
+///     level: u32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+pub struct NumberRange {
+    /// The smallest accepted value.
+    pub min: f64,
+    /// The largest accepted value.
+    pub max: f64,
+}
+
+impl NumberRange {
+    /// Accepts numbers from `min` to `max` (included).
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Accepts numbers greater than or equal to `min`.
+    pub const fn at_least(min: f64) -> Self {
+        Self::new(min, f64::INFINITY)
+    }
+
+    /// Accepts numbers less than or equal to `max`.
+    pub const fn at_most(max: f64) -> Self {
+        Self::new(f64::NEG_INFINITY, max)
+    }
+
+    /// Returns `true` if `value` is in this range.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// A custom attribute requiring a string to have a number of characters within an inclusive
+/// range.
+///
+/// This applies to [`String`] and [`Cow<'static, str>`](Cow).
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StringLength {
+    /// The smallest accepted number of characters.
+    pub min: usize,
+    /// The largest accepted number of characters.
+    pub max: usize,
+}
+
+impl StringLength {
+    /// Accepts strings with `min` to `max` (included) characters.
+    pub const fn new(min: usize, max: usize) -> Self {
+        Self { min, max }
+    }
+
+    /// Accepts strings with at least `min` characters.
+    pub const fn at_least(min: usize) -> Self {
+        Self::new(min, usize::MAX)
+    }
+
+    /// Accepts strings with at most `max` characters.
+    pub const fn at_most(max: usize) -> Self {
+        Self::new(0, max)
+    }
+}
+
+/// A custom attribute requiring a string, list, array, map or set not to be empty.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct NonEmpty;
+
+/// A custom attribute requiring a string to match a regular expression.
+///
+/// The expression must match the whole string, as if it started with `^` and ended with `$`.
+/// It is compiled the first time it is checked, and reused afterwards.
+///
+/// ```
+/// # use bevy_reflect::{Reflect, validation::Pattern};
+/// #[derive(Reflect)]
+/// struct Account {
+///     #[reflect(@Pattern::new("[a-z0-9_]+"))]
+///     username: String,
+/// }
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Reflect, Clone)]
+pub struct Pattern {
+    pattern: Cow<'static, str>,
+    /// The compiled expression, or the reason it is invalid.
+    #[reflect(ignore)]
+    regex: std::sync::OnceLock<Result<regex::Regex, String>>,
+}
+
+#[cfg(feature = "regex")]
+impl Pattern {
+    /// Creates a pattern from a regular expression.
+    pub const fn new(pattern: &'static str) -> Self {
+        Self {
+            pattern: Cow::Borrowed(pattern),
+            regex: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the regular expression of this pattern.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the compiled expression, compiling it on first use, or the reason it is invalid.
+    fn regex(&self) -> &Result<regex::Regex, String> {
+        self.regex.get_or_init(|| {
+            regex::Regex::new(&alloc::format!("^(?:{})$", self.pattern))
+                .map_err(|error| alloc::string::ToString::to_string(&error))
+        })
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<String> for Pattern {
+    fn from(pattern: String) -> Self {
+        Self {
+            pattern: Cow::Owned(pattern),
+            regex: std::sync::OnceLock::new(),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Eq for Pattern {}
+
+#[cfg(feature = "regex")]
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pattern").field(&self.pattern).finish()
+    }
+}
+
+/// A value that violates a validation attribute, as returned by [`validate`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValidationError {
+    /// The location of the invalid value, relative to the validated value.
+    ///
+    /// Since the entries of maps and sets can't be located by a [`ParsedPath`], errors in them are
+    /// reported as if the entry was the map or set itself (e.g. `.bindings.key` for the `key`
+    /// field of a value in the `bindings` map).
+    pub path: ParsedPath,
+    /// Why the value is invalid.
+    pub kind: ValidationErrorKind,
+}
+
+/// The reason a value is invalid.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ValidationErrorKind {
+    /// The number is not within its [`NumberRange`].
+    OutOfRange {
+        /// The invalid number.
+        value: f64,
+        /// The accepted range.
+        range: NumberRange,
+    },
+    /// The number of characters in the string is not within its [`StringLength`].
+    InvalidLength {
+        /// The number of characters in the string.
+        len: usize,
+        /// The accepted number of characters.
+        length: StringLength,
+    },
+    /// The value is empty despite being [`NonEmpty`].
+    Empty,
+    /// The string doesn't match its [`Pattern`].
+    #[cfg(feature = "regex")]
+    PatternMismatch {
+        /// The regular expression.
+        pattern: Cow<'static, str>,
+    },
+    /// The regular expression of the [`Pattern`] is invalid.
+    #[cfg(feature = "regex")]
+    InvalidPattern {
+        /// The regular expression.
+        pattern: Cow<'static, str>,
+        /// The reason the expression is invalid.
+        message: String,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.0.is_empty() {
+            write!(f, "value {}", self.kind)
+        } else {
+            write!(f, "`{}` {}", self.path, self.kind)
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationErrorKind::OutOfRange { value, range } => write!(
+                f,
+                "is {value}, which is outside of the range {}..={}",
+                range.min, range.max
+            ),
+            ValidationErrorKind::InvalidLength { len, length } => write!(
+                f,
+                "has {len} characters, expected between {} and {}",
+                length.min, length.max
+            ),
+            ValidationErrorKind::Empty => f.write_str("must not be empty"),
+            #[cfg(feature = "regex")]
+            ValidationErrorKind::PatternMismatch { pattern } => {
+                write!(f, "does not match the pattern `{pattern}`")
+            }
+            #[cfg(feature = "regex")]
+            ValidationErrorKind::InvalidPattern { pattern, message } => {
+                write!(f, "has an invalid pattern `{pattern}`: {message}")
+            }
+        }
+    }
+}
+
+/// Validates `value`, and every value nested in it, against the validation attributes of this
+/// module.
+///
+/// The attributes of a field apply to the value of the field, and the attributes of a struct,
+/// tuple struct or enum apply to the value itself. Attributes are found through the
+/// [represented type](PartialReflect::get_represented_type_info) of each value, falling back on
+/// the type registered in `registry` with the value's type path.
+///
+/// Returns every violation found, or an empty list if `value` is valid.
+pub fn validate(value: &dyn PartialReflect, registry: &TypeRegistry) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        registry,
+        path: Vec::new(),
+        errors: Vec::new(),
+    };
+    validator.visit(value, None);
+    validator.errors
+}
+
+struct Validator<'a> {
+    registry: &'a TypeRegistry,
+    path: Vec<Access<'static>>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn visit(&mut self, value: &dyn PartialReflect, attributes: Option<&CustomAttributes>) {
+        if let Some(attributes) = attributes {
+            self.check(value, attributes);
+        }
+
+        let type_info = value.get_represented_type_info().or_else(|| {
+            self.registry
+                .get_with_type_path(value.reflect_type_path())
+                .map(TypeRegistration::type_info)
+        });
+        match type_info {
+            Some(TypeInfo::Struct(info)) => self.check(value, info.custom_attributes()),
+            Some(TypeInfo::TupleStruct(info)) => self.check(value, info.custom_attributes()),
+            Some(TypeInfo::Enum(info)) => self.check(value, info.custom_attributes()),
+            _ => {}
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let info = type_info.and_then(|info| info.as_struct().ok());
+                for (name, field) in value.iter_fields() {
+                    let attributes = info
+                        .and_then(|info| info.field(name))
+                        .map(NamedField::custom_attributes);
+                    self.visit_at(
+                        Access::Field(Cow::Owned(name.to_owned())),
+                        field,
+                        attributes,
+                    );
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                let info = type_info.and_then(|info| info.as_tuple_struct().ok());
+                for (index, field) in value.iter_fields().enumerate() {
+                    let attributes = info
+                        .and_then(|info| info.field_at(index))
+                        .map(UnnamedField::custom_attributes);
+                    self.visit_at(Access::TupleIndex(index), field, attributes);
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    self.visit_at(Access::TupleIndex(index), field, None);
+                }
+            }
+            ReflectRef::Enum(value) => {
+                let variant = type_info
+                    .and_then(|info| info.as_enum().ok())
+                    .and_then(|info| info.variant(value.variant_name()));
+                for index in 0..value.field_len() {
+                    let Some(field) = value.field_at(index) else {
+                        continue;
+                    };
+                    match value.name_at(index) {
+                        Some(name) => {
+                            let attributes = variant
+                                .and_then(|variant| variant.as_struct_variant().ok())
+                                .and_then(|variant| variant.field(name))
+                                .map(NamedField::custom_attributes);
+                            let access = Access::Field(Cow::Owned(name.to_owned()));
+                            self.visit_at(access, field, attributes);
+                        }
+                        None => {
+                            let attributes = variant
+                                .and_then(|variant| variant.as_tuple_variant().ok())
+                                .and_then(|variant| variant.field_at(index))
+                                .map(UnnamedField::custom_attributes);
+                            self.visit_at(Access::TupleIndex(index), field, attributes);
+                        }
+                    }
+                }
+            }
+            ReflectRef::List(value) => {
+                for (index, element) in value.iter().enumerate() {
+                    self.visit_at(Access::ListIndex(index), element, None);
+                }
+            }
+            ReflectRef::Array(value) => {
+                for (index, element) in value.iter().enumerate() {
+                    self.visit_at(Access::ListIndex(index), element, None);
+                }
+            }
+            ReflectRef::Map(value) => {
+                for (_, element) in value.iter() {
+                    self.visit(element, None);
+                }
+            }
+            ReflectRef::Set(value) => {
+                for element in value.iter() {
+                    self.visit(element, None);
+                }
+            }
+            #[cfg(feature = "functions")]
+            ReflectRef::Function(_) => {}
+            ReflectRef::Opaque(_) => {}
+        }
+    }
+
+    fn visit_at(
+        &mut self,
+        access: Access<'static>,
+        value: &dyn PartialReflect,
+        attributes: Option<&CustomAttributes>,
+    ) {
+        self.path.push(access);
+        self.visit(value, attributes);
+        self.path.pop();
+    }
+
+    fn check(&mut self, value: &dyn PartialReflect, attributes: &CustomAttributes) {
+        if let Some(range) = attributes.get::<NumberRange>()
+            && let Some(number) = as_number(value)
+            && !range.contains(number)
+        {
+            self.report(ValidationErrorKind::OutOfRange {
+                value: number,
+                range: *range,
+            });
+        }
+
+        if let Some(length) = attributes.get::<StringLength>()
+            && let Some(string) = as_str(value)
+        {
+            let len = string.chars().count();
+            if !(length.min..=length.max).contains(&len) {
+                self.report(ValidationErrorKind::InvalidLength {
+                    len,
+                    length: *length,
+                });
+            }
+        }
+
+        if attributes.contains::<NonEmpty>() && is_empty(value) == Some(true) {
+            self.report(ValidationErrorKind::Empty);
+        }
+
+        #[cfg(feature = "regex")]
+        if let Some(pattern) = attributes.get::<Pattern>()
+            && let Some(string) = as_str(value)
+        {
+            match pattern.regex() {
+                Ok(regex) if regex.is_match(string) => {}
+                Ok(_) => self.report(ValidationErrorKind::PatternMismatch {
+                    pattern: pattern.pattern.clone(),
+                }),
+                Err(message) => self.report(ValidationErrorKind::InvalidPattern {
+                    pattern: pattern.pattern.clone(),
+                    message: message.clone(),
+                }),
+            }
+        }
+    }
+
+    fn report(&mut self, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError {
+            path: ParsedPath::from(self.path.clone()),
+            kind,
+        });
+    }
+}
+
+fn as_number(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! downcast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return Some(*value as f64);
+                }
+            )*
+        };
+    }
+
+    downcast!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    None
+}
+
+fn as_str(value: &dyn PartialReflect) -> Option<&str> {
+    if let Some(value) = value.try_downcast_ref::<String>() {
+        return Some(value);
+    }
+    value
+        .try_downcast_ref::<Cow<'static, str>>()
+        .map(AsRef::as_ref)
+}
+
+fn is_empty(value: &dyn PartialReflect) -> Option<bool> {
+    if let Some(string) = as_str(value) {
+        return Some(string.is_empty());
+    }
+    match value.reflect_ref() {
+        ReflectRef::List(list) => Some(list.is_empty()),
+        ReflectRef::Array(array) => Some(array.is_empty()),
+        ReflectRef::Map(map) => Some(map.is_empty()),
+        ReflectRef::Set(set) => Some(set.is_empty()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use bevy_platform::collections::HashMap;
+
+    #[derive(Reflect)]
+    struct Settings {
+        #[reflect(@NumberRange::new(0.0, 1.0))]
+        volume: f32,
+        #[reflect(@StringLength::new(1, 8), @NonEmpty)]
+        name: String,
+        #[reflect(@NonEmpty)]
+        languages: Vec<Language>,
+        bindings: HashMap<String, Binding>,
+        mode: Mode,
+    }
+
+    #[derive(Reflect)]
+    struct Language(#[reflect(@StringLength::new(2, 2))] String);
+
+    #[derive(Reflect)]
+    struct Binding {
+        #[reflect(@NumberRange::at_most(255.0))]
+        key: u16,
+    }
+
+    #[derive(Reflect)]
+    enum Mode {
+        Windowed {
+            #[reflect(@NumberRange::at_least(320.0))]
+            width: u32,
+        },
+        Fullscreen(#[reflect(@NumberRange::at_least(0.0))] i8),
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            volume: 0.5,
+            name: "player".into(),
+            languages: vec![Language("en".into())],
+            bindings: HashMap::from_iter([("jump".into(), Binding { key: 32 })]),
+            mode: Mode::Windowed { width: 640 },
+        }
+    }
+
+    fn paths(errors: &[ValidationError]) -> Vec<String> {
+        errors.iter().map(|error| error.path.to_string()).collect()
+    }
+
+    #[test]
+    fn should_accept_valid_values() {
+        assert!(validate(&settings(), &TypeRegistry::default()).is_empty());
+    }
+
+    #[test]
+    fn should_report_paths_of_invalid_values() {
+        let mut settings = settings();
+        settings.volume = 2.0;
+        settings.name = String::new();
+        settings.languages.push(Language("english".into()));
+        settings
+            .bindings
+            .insert("fire".into(), Binding { key: 1000 });
+        settings.mode = Mode::Windowed { width: 100 };
+
+        let errors = validate(&settings, &TypeRegistry::default());
+        assert_eq!(
+            paths(&errors),
+            [
+                ".volume",
+                ".name",
+                ".name",
+                ".languages[1].0",
+                ".bindings.key",
+                ".mode.width"
+            ]
+        );
+        assert_eq!(
+            errors[0].kind,
+            ValidationErrorKind::OutOfRange {
+                value: 2.0,
+                range: NumberRange::new(0.0, 1.0)
+            }
+        );
+        assert_eq!(errors[2].kind, ValidationErrorKind::Empty);
+        assert_eq!(
+            errors[0].to_string(),
+            "`.volume` is 2, which is outside of the range 0..=1"
+        );
+
+        settings.languages.clear();
+        settings.mode = Mode::Fullscreen(-1);
+        let errors = validate(&settings, &TypeRegistry::default());
+        assert!(paths(&errors).contains(&".languages".to_string()));
+        assert!(paths(&errors).contains(&".mode.0".to_string()));
+    }
+
+    #[test]
+    fn should_validate_dynamic_values() {
+        let mut settings = settings();
+        settings.volume = -1.0;
+
+        let dynamic = settings.to_dynamic();
+        let errors = validate(dynamic.as_ref(), &TypeRegistry::default());
+        assert_eq!(paths(&errors), [".volume"]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn should_validate_patterns() {
+        use crate::Typed;
+
+        #[derive(Reflect)]
+        struct Account {
+            #[reflect(@Pattern::new("[a-z]+"))]
+            username: String,
+        }
+
+        let registry = TypeRegistry::default();
+        let valid = Account {
+            username: "ferris".into(),
+        };
+        assert!(validate(&valid, &registry).is_empty());
+
+        let invalid = Account {
+            username: "Ferris!".into(),
+        };
+        let errors = validate(&invalid, &registry);
+        assert!(matches!(
+            errors[0].kind,
+            ValidationErrorKind::PatternMismatch { .. }
+        ));
+
+        // The expression is compiled once, on the attribute stored in the type info.
+        let TypeInfo::Struct(info) = Account::type_info() else {
+            panic!("expected a struct");
+        };
+        let pattern = info
+            .field("username")
+            .unwrap()
+            .get_attribute::<Pattern>()
+            .unwrap();
+        assert!(pattern.regex.get().is_some_and(Result::is_ok));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn should_report_invalid_patterns() {
+        #[derive(Reflect)]
+        struct Account {
+            #[reflect(@Pattern::new("[a-z"))]
+            username: String,
+        }
+
+        let account = Account {
+            username: "ferris".into(),
+        };
+        let errors = validate(&account, &TypeRegistry::default());
+        assert!(matches!(
+            &errors[0].kind,
+            ValidationErrorKind::InvalidPattern { pattern, .. } if pattern == "[a-z"
+        ));
+    }
+}
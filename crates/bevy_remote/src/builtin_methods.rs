@@ -1,7 +1,6 @@
 //! Built-in verbs for the Bevy Remote Protocol.
 
 use alloc::sync::Arc;
-use core::any::TypeId;
 use std::sync::Mutex;
 
 use anyhow::{anyhow, Result as AnyhowResult};
@@ -13,7 +12,10 @@ use bevy_ecs::{
     lifecycle::RemovedComponentEntity,
     message::MessageCursor,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectMessage, ReflectResource},
+    reflect::{
+        component_registration, registration_component_id, AppTypeRegistry, ReflectComponent,
+        ReflectEvent, ReflectMessage, ReflectResource,
+    },
     resource::Resource,
     schedule::Schedules,
     system::{In, Local},
@@ -24,7 +26,8 @@ use bevy_platform::collections::HashMap;
 use bevy_reflect::{
     serde::{ReflectSerializer, TypedReflectDeserializer},
     structs::DynamicStruct,
-    GetPath, PartialReflect, Reflect, TypeRegistration, TypeRegistry,
+    validation, GetPath, PartialReflect, Reflect, ReflectPath, TypeInfo, TypeRegistration,
+    TypeRegistry,
 };
 use serde::{de::DeserializeSeed as _, de::IntoDeserializer, Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -33,6 +36,7 @@ use crate::{
     error_codes,
     schemas::{
         json_schema::{export_type, JsonSchemaBevyType},
+        json_schema_import::TypeRegistrySchemaWriter,
         open_rpc::OpenRpcDocument,
     },
     BrpError, BrpResult, PreviousScheduleBuildMetadata,
@@ -98,6 +102,9 @@ pub const BRP_WRITE_MESSAGE_METHOD: &str = "world.write_message";
 /// The method path for a `registry.schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "registry.schema";
 
+/// The method path for a `registry.import_schema` request.
+pub const BRP_REGISTRY_IMPORT_SCHEMA_METHOD: &str = "registry.import_schema";
+
 /// The method path for a `schedule.list` request.
 pub const BRP_SCHEDULE_LIST: &str = "schedule.list";
 
@@ -346,6 +353,17 @@ pub struct BrpWriteMessageParams {
     pub value: Option<Value>,
 }
 
+/// `registry.import_schema`: Registers types defined at runtime from their JSON Schemas.
+///
+/// The server responds with the [full paths] of the imported types.
+///
+/// [full paths]: bevy_reflect::TypePath::type_path
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpImportSchemaParams {
+    /// The schemas of the types to import, in the format returned by `registry.schema`.
+    pub schemas: HashMap<String, JsonSchemaBevyType>,
+}
+
 /// `world.observe+watch`: Registers an observer for the given event type and
 /// streams event data back to the client each time the event is triggered.
 ///
@@ -693,8 +711,7 @@ pub fn process_remote_get_components_watching_request(
             );
             continue;
         };
-        let Some(component_id) = world.components().get_valid_id(type_registration.type_id())
-        else {
+        let Some(component_id) = registration_component_id(type_registration, world) else {
             let err = BrpError::component_error(format!("Unknown component: `{component_path}`"));
             if strict {
                 return Err(err);
@@ -938,7 +955,7 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     // Prepare has reflect info
     let has_paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = has_ids
         .iter()
-        .map(|(type_id, _)| reflect_component_from_id(*type_id, &type_registry))
+        .map(|(registration, _)| reflect_component_from_registration(registration))
         .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
         .map_err(BrpError::component_error)?;
 
@@ -955,7 +972,7 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
             &type_registry,
             required
                 .iter()
-                .map(|(type_id, component_id)| (*type_id, Some(*component_id))),
+                .map(|(registration, component_id)| (*registration, Some(*component_id))),
         );
 
         // Optional components
@@ -968,13 +985,13 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
                         .components()
                         .iter()
                         .filter_map(|&component_id| {
-                            let info = world.components().get_info(component_id)?;
-                            let type_id = info.type_id()?;
+                            let registration =
+                                component_registration(&type_registry, world, component_id)?;
                             // Skip required components (already included)
                             if required.iter().any(|(_, cid)| cid == &component_id) {
                                 return None;
                             }
-                            Some((type_id, Some(component_id)))
+                            Some((registration, Some(component_id)))
                         });
                 components_map.extend(serialize_components(
                     entity_ref,
@@ -993,7 +1010,7 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
                     &type_registry,
                     optionals
                         .clone()
-                        .map(|(type_id, component_id)| (*type_id, Some(*component_id))),
+                        .map(|(registration, component_id)| (*registration, Some(*component_id))),
                 ));
             }
         }
@@ -1018,17 +1035,14 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
 }
 
 /// Serializes the specified components for an entity.
-/// The iterator yields ([`TypeId`], Option<[`ComponentId`]>).
-fn serialize_components(
+/// The iterator yields ([`TypeRegistration`], Option<[`ComponentId`]>).
+fn serialize_components<'r>(
     entity_ref: EntityRef,
     type_registry: &TypeRegistry,
-    components: impl Iterator<Item = (TypeId, Option<ComponentId>)>,
+    components: impl Iterator<Item = (&'r TypeRegistration, Option<ComponentId>)>,
 ) -> HashMap<String, Value> {
     let mut components_map = HashMap::new();
-    for (type_id, component_id_opt) in components {
-        let Some(type_registration) = type_registry.get(type_id) else {
-            continue;
-        };
+    for (type_registration, component_id_opt) in components {
         if let Some(reflect_component) = type_registration.data::<ReflectComponent>() {
             // If a component_id is provided, check if the entity has it
             if let Some(component_id) = component_id_opt
@@ -1215,6 +1229,16 @@ pub fn process_remote_mutate_components_request(
         .deserialize(&value)
         .map_err(BrpError::component_error)?;
 
+    // Apply the mutation to a copy first, so that values violating validation attributes are
+    // rejected before the component is changed.
+    let mut mutated = reflected.to_dynamic();
+    path.as_str()
+        .reflect_element_mut(&mut *mutated)
+        .map_err(BrpError::component_error)?
+        .try_apply(value.as_ref())
+        .map_err(BrpError::component_error)?;
+    validate_reflected(&component, &*mutated, &type_registry).map_err(BrpError::component_error)?;
+
     // Apply the mutation.
     reflected
         .reflect_path_mut(path.as_str())
@@ -1270,6 +1294,18 @@ pub fn process_remote_mutate_resources_request(
             .deserialize(&value)
             .map_err(BrpError::resource_error)?;
 
+    // Apply the value to a copy first, so that values violating validation attributes are
+    // rejected before the resource is changed.
+    let mut mutated = reflected_component.to_dynamic();
+    field_path
+        .as_str()
+        .reflect_element_mut(&mut *mutated)
+        .map_err(BrpError::resource_error)?
+        .try_apply(&*deserialized_value)
+        .map_err(BrpError::resource_error)?;
+    validate_reflected(&resource_path, &*mutated, &type_registry)
+        .map_err(BrpError::resource_error)?;
+
     // Apply the value to the resource.
     reflected_component
         .reflect_path_mut(field_path.as_str())
@@ -1718,6 +1754,28 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     serde_json::to_value(schemas).map_err(BrpError::internal)
 }
 
+/// Handles a `registry.import_schema` request (register types from their schemas) coming from a client.
+pub fn import_registry_types(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let BrpImportSchemaParams { schemas } = parse_some(params)?;
+    let schemas = schemas.into_values().collect::<Vec<_>>();
+
+    let types = world.resource::<AppTypeRegistry>();
+    let imported = types
+        .write()
+        .import_type_json_schemas(&schemas)
+        .map_err(|error| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: error.to_string(),
+            data: None,
+        })?;
+    let type_paths = imported
+        .into_iter()
+        .map(TypeInfo::type_path)
+        .collect::<Vec<_>>();
+
+    serde_json::to_value(type_paths).map_err(BrpError::internal)
+}
+
 /// Handles a `schedule.list` request coming from a client.
 pub fn schedule_list(In(_params): In<Option<Value>>, world: &World) -> BrpResult {
     let schedules = world.resource::<Schedules>();
@@ -1815,17 +1873,17 @@ fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_
 }
 
 /// Given components full path, returns a tuple that contains
-/// - A list of corresponding [`TypeId`] and [`ComponentId`] for registered components.
+/// - A list of corresponding [`TypeRegistration`] and [`ComponentId`] for registered components.
 /// - A list of unregistered component paths.
 ///
 /// Note that the supplied path names must be *full* path names: e.g.
 /// `bevy_transform::components::transform::Transform` instead of `Transform`.
-fn get_component_ids(
-    type_registry: &TypeRegistry,
+fn get_component_ids<'r>(
+    type_registry: &'r TypeRegistry,
     world: &World,
     component_paths: Vec<String>,
     strict: bool,
-) -> AnyhowResult<(Vec<(TypeId, ComponentId)>, Vec<String>)> {
+) -> AnyhowResult<(Vec<(&'r TypeRegistration, ComponentId)>, Vec<String>)> {
     let mut component_ids = vec![];
     let mut unregistered_components = vec![];
 
@@ -1833,14 +1891,11 @@ fn get_component_ids(
         let maybe_component_tuple = get_component_type_registration(type_registry, &component_path)
             .ok()
             .and_then(|type_registration| {
-                let type_id = type_registration.type_id();
-                world
-                    .components()
-                    .get_valid_id(type_id)
-                    .map(|component_id| (type_id, component_id))
+                registration_component_id(type_registration, world)
+                    .map(|component_id| (type_registration, component_id))
             });
-        if let Some((type_id, component_id)) = maybe_component_tuple {
-            component_ids.push((type_id, component_id));
+        if let Some(component_tuple) = maybe_component_tuple {
+            component_ids.push(component_tuple);
         } else if strict {
             return Err(anyhow!(
                 "Component `{}` isn't registered or used in the world",
@@ -1878,22 +1933,15 @@ fn build_has_map<'a>(
     has_map
 }
 
-/// Given a component ID, return the associated [type path] and `ReflectComponent` if possible.
+/// Given a component registration, return the associated [type path] and `ReflectComponent` if
+/// possible.
 ///
 /// The `ReflectComponent` part is the meat of this; the type path is only used for error messages.
 ///
 /// [type path]: bevy_reflect::TypePath::type_path
-fn reflect_component_from_id(
-    component_type_id: TypeId,
-    type_registry: &TypeRegistry,
+fn reflect_component_from_registration(
+    type_registration: &TypeRegistration,
 ) -> AnyhowResult<(&str, &ReflectComponent)> {
-    let Some(type_registration) = type_registry.get(component_type_id) else {
-        return Err(anyhow!(
-            "Component `{:?}` isn't registered",
-            component_type_id
-        ));
-    };
-
     let type_path = type_registration.type_info().type_path();
 
     let Some(reflect_component) = type_registration.data::<ReflectComponent>() else {
@@ -1919,6 +1967,7 @@ fn deserialize_components(
             TypedReflectDeserializer::new(component_type, type_registry)
                 .deserialize(&component)
                 .map_err(|err| anyhow!("{component_path} is invalid: {err}"))?;
+        validate_reflected(&component_path, &*reflected, type_registry)?;
         reflect_components.push(reflected);
     }
 
@@ -1939,9 +1988,24 @@ fn deserialize_resource(
         TypedReflectDeserializer::new(resource_type, type_registry)
             .deserialize(&value)
             .map_err(|err| anyhow!("{resource_path} is invalid: {err}"))?;
+    validate_reflected(resource_path, &*reflected, type_registry)?;
     Ok(reflected)
 }
 
+/// Returns an error listing the violated [validation attributes](validation) of `value`, if any.
+fn validate_reflected(
+    type_path: &str,
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> AnyhowResult<()> {
+    let errors = validation::validate(value, type_registry);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    Err(anyhow!("{type_path} is invalid: {}", errors.join(", ")))
+}
+
 /// Given a collection `reflect_components` of reflected component values, insert them into
 /// the given entity (`entity_world_mut`).
 fn insert_reflected_components(
@@ -2088,6 +2152,81 @@ mod tests {
         insert_reflected_components(e, deserialized_components).expect("FAIL");
     }
 
+    #[test]
+    fn imported_dynamic_component() {
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<f32>();
+        let mut world = World::new();
+        world.insert_resource(atr);
+
+        let schema = serde_json::json!({
+            "shortPath": "Health",
+            "typePath": "game::Health",
+            "kind": "Struct",
+            "type": "object",
+            "reflectTypes": ["Component"],
+            "properties": { "value": { "type": { "$ref": "#/$defs/f32" } } }
+        });
+        import_registry_types(
+            In(Some(
+                serde_json::json!({ "schemas": { "game::Health": schema } }),
+            )),
+            &world,
+        )
+        .expect("FAIL");
+
+        let health = serde_json::json!({ "value": 10.0 });
+        let response = process_remote_spawn_entity_request(
+            In(Some(
+                serde_json::json!({ "components": { "game::Health": health } }),
+            )),
+            &mut world,
+        )
+        .expect("FAIL");
+        let entity: BrpSpawnEntityResponse = serde_json::from_value(response).unwrap();
+
+        let response = process_remote_query_request(
+            In(Some(serde_json::json!({
+                "data": { "components": ["game::Health"] }
+            }))),
+            &mut world,
+        )
+        .expect("FAIL");
+        let rows: BrpQueryResponse = serde_json::from_value(response).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].entity, entity.entity);
+        assert_eq!(rows[0].components["game::Health"], health);
+
+        let get_health = |world: &World| {
+            let response = process_remote_get_components_request(
+                In(Some(serde_json::json!({
+                    "entity": entity.entity,
+                    "components": ["game::Health"]
+                }))),
+                world,
+            )
+            .expect("FAIL");
+            let BrpGetComponentsResponse::Lenient { components, errors } =
+                serde_json::from_value(response).unwrap()
+            else {
+                panic!("expected a lenient response");
+            };
+            assert_eq!(components.len() + errors.len(), 1);
+            components.get("game::Health").cloned()
+        };
+        assert_eq!(get_health(&world), Some(health));
+
+        process_remote_remove_components_request(
+            In(Some(serde_json::json!({
+                "entity": entity.entity,
+                "components": ["game::Health"]
+            }))),
+            &mut world,
+        )
+        .expect("FAIL");
+        assert_eq!(get_health(&world), None);
+    }
+
     #[test]
     fn trigger_reflect_only_event() {
         #[derive(Event, Reflect)]
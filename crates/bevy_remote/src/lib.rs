@@ -478,6 +478,20 @@
 //! This contains schema information about that type, including field definitions, type information, reflect type information, and other metadata
 //! helpful for understanding the structure of the type.
 //!
+//! ### `registry.import_schema`
+//!
+//! Register types defined at runtime, such as types added by scripts or mods, from their schemas.
+//! Imported types are represented by dynamic values, and can be serialized like any other type.
+//!
+//! Structs, tuple structs and enums can be imported. Types referenced by their fields must be
+//! registered already or be imported at the same time. Types which are already registered are skipped.
+//!
+//! `params`:
+//! - `schemas`: A map associating each type's [fully-qualified type name] to a [`JsonSchemaBevyType`](crate::schemas::json_schema::JsonSchemaBevyType),
+//!   as returned by `registry.schema`. A schema may provide a `default` value for its type, and so may its properties.
+//!
+//! `result`: An array containing the [fully-qualified type names] of the imported types.
+//!
 //! ### `rpc.discover`
 //!
 //! Discover available remote methods and server information. This follows the [`OpenRPC` specification for service discovery](https://spec.open-rpc.org/#service-discovery-method).
@@ -775,6 +789,11 @@ impl RemotePlugin {
             builtin_methods::export_registry_types,
             to_main,
         )
+        .with_method(
+            builtin_methods::BRP_REGISTRY_IMPORT_SCHEMA_METHOD,
+            builtin_methods::import_registry_types,
+            to_main,
+        )
         .with_method(
             builtin_methods::BRP_SCHEDULE_LIST,
             builtin_methods::schedule_list,
@@ -289,6 +289,11 @@ pub struct JsonSchemaBevyType {
     /// array elements have been evaluated against this keyword's subschema.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub items: Option<Value>,
+    /// The default value of the type, used when [importing] the schema.
+    ///
+    /// [importing]: crate::schemas::json_schema_import
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub default: Option<Value>,
 }
 
 /// Kind of json schema, maps [`TypeInfo`] type
@@ -0,0 +1,736 @@
+//! Module for importing types from JSON Schema, the inverse of [`json_schema`](super::json_schema).
+//!
+//! Imported types have no Rust type of their own: they are registered as
+//! [runtime types](bevy_reflect::Type::runtime), whose values are dynamic types such as
+//! [`DynamicStruct`] and [`DynamicEnum`] representing the imported [`TypeInfo`].
+use alloc::sync::Arc;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{
+    enums::{
+        DynamicEnum, DynamicVariant, EnumInfo, StructVariantInfo, TupleVariantInfo,
+        UnitVariantInfo, VariantInfo,
+    },
+    prelude::ReflectDefault,
+    serde::TypedReflectDeserializer,
+    structs::{DynamicStruct, StructInfo},
+    tuple::DynamicTuple,
+    tuple_struct::{DynamicTupleStruct, TupleStructInfo},
+    NamedField, PartialReflect, Type, TypeInfo, TypePathTable, TypeRegistration, TypeRegistry,
+    UnnamedField,
+};
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::schemas::json_schema::{JsonSchemaBevyType, SchemaKind};
+
+/// Helper trait for registering types from [`JsonSchemaBevyType`]s.
+pub trait TypeRegistrySchemaWriter {
+    /// Import a type from its JSON Schema.
+    ///
+    /// See [`import_type_json_schemas`](Self::import_type_json_schemas) for more information.
+    fn import_type_json_schema(
+        &mut self,
+        schema: &JsonSchemaBevyType,
+    ) -> Result<Option<&'static TypeInfo>, SchemaImportError> {
+        Ok(self
+            .import_type_json_schemas(core::slice::from_ref(schema))?
+            .pop())
+    }
+
+    /// Import types from their JSON Schemas, returning the [`TypeInfo`] of the imported types.
+    ///
+    /// Structs, tuple structs and enums are supported. The types referenced by their fields must
+    /// either be registered already or be part of `schemas`. Fields of structs are ordered by
+    /// name.
+    ///
+    /// Schemas of types which are already registered are skipped, so the output of a
+    /// `registry.schema` request can be imported as is.
+    ///
+    /// Types whose schema lists `Component` in its `reflectTypes` are registered with a
+    /// [dynamic](ReflectComponent::dynamic) [`ReflectComponent`], so they can be used as
+    /// components.
+    ///
+    /// The default value of an imported type, if any, is registered as [`ReflectRuntimeDefault`].
+    /// It comes from the `default` of its schema, or is built from the defaults of its fields:
+    /// the `default` of their property, or else the default value of their type.
+    ///
+    /// All schemas are validated before any type is registered, so nothing is registered if one of
+    /// them is invalid. If a default value is invalid, the types imported before it remain
+    /// registered.
+    fn import_type_json_schemas(
+        &mut self,
+        schemas: &[JsonSchemaBevyType],
+    ) -> Result<Vec<&'static TypeInfo>, SchemaImportError>;
+}
+
+impl TypeRegistrySchemaWriter for TypeRegistry {
+    fn import_type_json_schemas(
+        &mut self,
+        schemas: &[JsonSchemaBevyType],
+    ) -> Result<Vec<&'static TypeInfo>, SchemaImportError> {
+        let mut planner = SchemaPlanner {
+            registry: self,
+            schemas: schemas
+                .iter()
+                .map(|schema| (schema.type_path.as_str(), schema))
+                .collect(),
+            importing: HashSet::default(),
+            planned: HashMap::default(),
+            types: Vec::new(),
+        };
+        for schema in schemas {
+            planner.resolve_path(&schema.type_path)?;
+        }
+        let types = planner.types;
+
+        let mut importer = SchemaImporter {
+            registry: self,
+            imported: Vec::new(),
+        };
+        for planned in types {
+            importer.import(planned)?;
+        }
+        Ok(importer.imported)
+    }
+}
+
+/// Type data holding the default value of a type imported from a JSON Schema.
+///
+/// Imported types can't implement [`Default`], so their default value is stored as a dynamic
+/// value instead of [`ReflectDefault`].
+#[derive(Clone)]
+pub struct ReflectRuntimeDefault(Arc<dyn PartialReflect>);
+
+impl ReflectRuntimeDefault {
+    /// Creates type data with the given default value.
+    pub fn new(value: Box<dyn PartialReflect>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns a dynamic copy of the default value.
+    pub fn default(&self) -> Box<dyn PartialReflect> {
+        (*self.0).to_dynamic()
+    }
+}
+
+/// An error that occurs when importing types from JSON Schemas.
+#[derive(Debug, Error)]
+pub enum SchemaImportError {
+    /// A referenced type is neither registered nor part of the imported schemas.
+    #[error("type `{0}` is not registered and has no schema to import")]
+    UnknownType(String),
+    /// A field does not have a valid reference to its type.
+    #[error("invalid type reference `{0}`")]
+    InvalidReference(Value),
+    /// The kind of the type can't be imported.
+    #[error("cannot import `{type_path}`: types of kind {kind:?} are not supported")]
+    UnsupportedKind {
+        /// The path of the type.
+        type_path: String,
+        /// The kind of the type.
+        kind: SchemaKind,
+    },
+    /// A variant of an enum is not described by a valid schema.
+    #[error("invalid variant `{variant}` of enum `{type_path}`")]
+    InvalidVariant {
+        /// The path of the enum.
+        type_path: String,
+        /// The schema of the variant.
+        variant: Value,
+    },
+    /// The type contains itself, so it can't be built.
+    #[error("type `{0}` contains itself")]
+    RecursiveType(String),
+    /// A default value doesn't match its type.
+    #[error("invalid default value for `{type_path}`: {error}")]
+    InvalidDefault {
+        /// The path of the type of the value.
+        type_path: String,
+        /// The deserialization error.
+        error: String,
+    },
+}
+
+/// The type of a field of an imported type.
+#[derive(Clone, Copy)]
+enum FieldType {
+    /// A type that is already registered.
+    Registered(&'static TypeInfo),
+    /// A type imported from the schemas, by its index in [`SchemaPlanner::types`].
+    Planned(usize),
+}
+
+/// A type whose schema has been validated, but which isn't registered yet.
+///
+/// It only borrows from its schema, so nothing is leaked until the whole schema set is known to
+/// be valid.
+struct PlannedType<'a> {
+    schema: &'a JsonSchemaBevyType,
+    kind: PlannedKind<'a>,
+}
+
+enum PlannedKind<'a> {
+    Struct(Vec<(&'a str, FieldType)>),
+    TupleStruct(Vec<FieldType>),
+    Enum(Vec<PlannedVariant<'a>>),
+}
+
+struct PlannedVariant<'a> {
+    name: &'a str,
+    kind: PlannedVariantKind<'a>,
+}
+
+enum PlannedVariantKind<'a> {
+    Unit,
+    Struct(Vec<(&'a str, FieldType)>),
+    Tuple(Vec<FieldType>),
+}
+
+/// Validates schemas and orders the types to import so that each one comes after the types of
+/// its fields.
+struct SchemaPlanner<'r, 'a> {
+    registry: &'r TypeRegistry,
+    schemas: HashMap<&'a str, &'a JsonSchemaBevyType>,
+    /// The types being planned, used to detect recursive types.
+    importing: HashSet<&'a str>,
+    /// The index of each planned type in `types`.
+    planned: HashMap<&'a str, usize>,
+    types: Vec<PlannedType<'a>>,
+}
+
+impl<'r, 'a> SchemaPlanner<'r, 'a> {
+    /// Returns the type with the given path, planning its import if needed.
+    fn resolve_path(&mut self, type_path: &str) -> Result<FieldType, SchemaImportError> {
+        if let Some(registration) = self.registry.get_with_type_path(type_path) {
+            return Ok(FieldType::Registered(registration.type_info()));
+        }
+        if let Some(&index) = self.planned.get(type_path) {
+            return Ok(FieldType::Planned(index));
+        }
+        match self.schemas.get(type_path) {
+            Some(schema) => self.plan(schema),
+            None => Err(SchemaImportError::UnknownType(type_path.to_owned())),
+        }
+    }
+
+    /// Returns the type referenced as `{"type": {"$ref": "#/$defs/<path>"}}`.
+    fn resolve_reference(&mut self, reference: &Value) -> Result<FieldType, SchemaImportError> {
+        let type_path = reference
+            .get("type")
+            .and_then(|ty| ty.get("$ref"))
+            .and_then(Value::as_str)
+            .and_then(|path| path.strip_prefix("#/$defs/"))
+            .ok_or_else(|| SchemaImportError::InvalidReference(reference.clone()))?;
+        self.resolve_path(type_path)
+    }
+
+    fn plan(&mut self, schema: &'a JsonSchemaBevyType) -> Result<FieldType, SchemaImportError> {
+        if !self.importing.insert(&schema.type_path) {
+            return Err(SchemaImportError::RecursiveType(schema.type_path.clone()));
+        }
+
+        let kind = match schema.kind {
+            SchemaKind::Struct => PlannedKind::Struct(self.named_fields(&schema.properties)?),
+            SchemaKind::TupleStruct => {
+                PlannedKind::TupleStruct(self.unnamed_fields(&schema.prefix_items)?)
+            }
+            SchemaKind::Enum => PlannedKind::Enum(
+                schema
+                    .one_of
+                    .iter()
+                    .map(|variant| self.variant(&schema.type_path, variant))
+                    .collect::<Result<_, _>>()?,
+            ),
+            ref kind => {
+                return Err(SchemaImportError::UnsupportedKind {
+                    type_path: schema.type_path.clone(),
+                    kind: kind.clone(),
+                })
+            }
+        };
+
+        self.importing.remove(schema.type_path.as_str());
+        let index = self.types.len();
+        self.types.push(PlannedType { schema, kind });
+        self.planned.insert(&schema.type_path, index);
+        Ok(FieldType::Planned(index))
+    }
+
+    fn named_fields(
+        &mut self,
+        properties: impl IntoIterator<Item = (&'a String, &'a Value)>,
+    ) -> Result<Vec<(&'a str, FieldType)>, SchemaImportError> {
+        let mut properties = properties.into_iter().collect::<Vec<_>>();
+        properties.sort_by_key(|(name, _)| *name);
+        properties
+            .into_iter()
+            .map(|(name, property)| Ok((name.as_str(), self.resolve_reference(property)?)))
+            .collect()
+    }
+
+    fn unnamed_fields(&mut self, items: &[Value]) -> Result<Vec<FieldType>, SchemaImportError> {
+        items
+            .iter()
+            .map(|item| self.resolve_reference(item))
+            .collect()
+    }
+
+    /// Plans a variant from its schema, which is either the name of a unit variant, or an object
+    /// like the ones exported for enums with fields.
+    fn variant(
+        &mut self,
+        type_path: &str,
+        variant: &'a Value,
+    ) -> Result<PlannedVariant<'a>, SchemaImportError> {
+        let invalid_variant = || SchemaImportError::InvalidVariant {
+            type_path: type_path.to_owned(),
+            variant: variant.clone(),
+        };
+        if let Some(name) = variant.as_str() {
+            return Ok(PlannedVariant {
+                name,
+                kind: PlannedVariantKind::Unit,
+            });
+        }
+        let name = variant
+            .get("shortPath")
+            .and_then(Value::as_str)
+            .ok_or_else(invalid_variant)?;
+        let kind = match variant.get("kind").and_then(Value::as_str) {
+            Some("Struct") => {
+                let properties = variant
+                    .get("properties")
+                    .and_then(Value::as_object)
+                    .ok_or_else(invalid_variant)?;
+                PlannedVariantKind::Struct(self.named_fields(properties)?)
+            }
+            Some("Tuple") => {
+                let items = variant
+                    .get("prefixItems")
+                    .and_then(Value::as_array)
+                    .ok_or_else(invalid_variant)?;
+                PlannedVariantKind::Tuple(self.unnamed_fields(items)?)
+            }
+            Some(_) => return Err(invalid_variant()),
+            None => PlannedVariantKind::Unit,
+        };
+        Ok(PlannedVariant { name, kind })
+    }
+}
+
+/// Registers planned types, leaking their [`TypeInfo`].
+struct SchemaImporter<'a> {
+    registry: &'a mut TypeRegistry,
+    /// The imported types, in the same order as [`SchemaPlanner::types`].
+    imported: Vec<&'static TypeInfo>,
+}
+
+impl<'a> SchemaImporter<'a> {
+    fn import(&mut self, planned: PlannedType) -> Result<&'static TypeInfo, SchemaImportError> {
+        let schema = planned.schema;
+        let type_path: &'static str = schema.type_path.clone().leak();
+        let ty = Type::runtime(TypePathTable::from_path(type_path));
+        let type_info = match planned.kind {
+            PlannedKind::Struct(fields) => {
+                TypeInfo::Struct(StructInfo::from_type(ty, &self.named_fields(fields)))
+            }
+            PlannedKind::TupleStruct(fields) => {
+                TypeInfo::TupleStruct(TupleStructInfo::from_type(ty, &self.unnamed_fields(fields)))
+            }
+            PlannedKind::Enum(variants) => {
+                let variants = variants
+                    .into_iter()
+                    .map(|variant| self.variant(variant))
+                    .collect::<Vec<_>>();
+                TypeInfo::Enum(EnumInfo::from_type(ty, &variants))
+            }
+        };
+        let type_info: &'static TypeInfo = Box::leak(Box::new(type_info));
+        let mut registration = TypeRegistration::from_type_info(type_info);
+        if schema.reflect_types.iter().any(|name| name == "Component") {
+            registration.insert(ReflectComponent::dynamic(type_info));
+        }
+        self.registry.add_registration(registration);
+        self.imported.push(type_info);
+
+        if let Some(default) = self.type_default(type_info, schema)? {
+            self.registry
+                .get_with_type_path_mut(type_path)
+                .expect("imported type should be registered")
+                .insert(ReflectRuntimeDefault::new(default));
+        }
+        Ok(type_info)
+    }
+
+    fn field_type(&self, field: FieldType) -> &'static TypeInfo {
+        match field {
+            FieldType::Registered(type_info) => type_info,
+            FieldType::Planned(index) => self.imported[index],
+        }
+    }
+
+    fn named_fields(&self, fields: Vec<(&str, FieldType)>) -> Vec<NamedField> {
+        fields
+            .into_iter()
+            .map(|(name, field)| {
+                let type_info = self.field_type(field);
+                NamedField::from_type(name.to_owned().leak(), *type_info.ty(), Some(type_info))
+            })
+            .collect()
+    }
+
+    fn unnamed_fields(&self, fields: Vec<FieldType>) -> Vec<UnnamedField> {
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let type_info = self.field_type(field);
+                UnnamedField::from_type(index, *type_info.ty(), Some(type_info))
+            })
+            .collect()
+    }
+
+    fn variant(&self, variant: PlannedVariant) -> VariantInfo {
+        let name: &'static str = variant.name.to_owned().leak();
+        match variant.kind {
+            PlannedVariantKind::Unit => VariantInfo::Unit(UnitVariantInfo::new(name)),
+            PlannedVariantKind::Struct(fields) => {
+                VariantInfo::Struct(StructVariantInfo::new(name, &self.named_fields(fields)))
+            }
+            PlannedVariantKind::Tuple(fields) => {
+                VariantInfo::Tuple(TupleVariantInfo::new(name, &self.unnamed_fields(fields)))
+            }
+        }
+    }
+    /// Returns the default value of an imported type, if it has one.
+    ///
+    /// The default value of an enum is its first variant.
+    fn type_default(
+        &self,
+        type_info: &'static TypeInfo,
+        schema: &JsonSchemaBevyType,
+    ) -> Result<Option<Box<dyn PartialReflect>>, SchemaImportError> {
+        if let Some(default) = &schema.default {
+            return self.deserialize(type_info.type_path(), default).map(Some);
+        }
+        let value: Box<dyn PartialReflect> = match type_info {
+            TypeInfo::Struct(info) => {
+                let Some(mut value) =
+                    self.struct_default(info.iter(), |name| schema.properties.get(name))?
+                else {
+                    return Ok(None);
+                };
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let mut value = DynamicTupleStruct::default();
+                for (field, item) in info.iter().zip(&schema.prefix_items) {
+                    let Some(field_value) = self.field_default(field.type_path(), item)? else {
+                        return Ok(None);
+                    };
+                    value.insert_boxed(field_value);
+                }
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            TypeInfo::Enum(info) => {
+                let (Some(variant), Some(variant_schema)) =
+                    (info.variant_at(0), schema.one_of.first())
+                else {
+                    return Ok(None);
+                };
+                let dynamic_variant = match variant {
+                    VariantInfo::Unit(_) => DynamicVariant::Unit,
+                    VariantInfo::Struct(variant) => {
+                        let properties =
+                            variant_schema.get("properties").and_then(Value::as_object);
+                        let Some(value) = self.struct_default(variant.iter(), |name| {
+                            properties.and_then(|properties| properties.get(name))
+                        })?
+                        else {
+                            return Ok(None);
+                        };
+                        DynamicVariant::Struct(value)
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        let items = variant_schema
+                            .get("prefixItems")
+                            .and_then(Value::as_array)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default();
+                        let mut value = DynamicTuple::default();
+                        for (field, item) in variant.iter().zip(items) {
+                            let Some(field_value) = self.field_default(field.type_path(), item)?
+                            else {
+                                return Ok(None);
+                            };
+                            value.insert_boxed(field_value);
+                        }
+                        DynamicVariant::Tuple(value)
+                    }
+                };
+                let mut value = DynamicEnum::new_with_index(0, variant.name(), dynamic_variant);
+                value.set_represented_type(Some(type_info));
+                Box::new(value)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(value))
+    }
+
+    fn struct_default<'b>(
+        &self,
+        fields: impl Iterator<Item = &'b NamedField>,
+        property: impl Fn(&str) -> Option<&'b Value>,
+    ) -> Result<Option<DynamicStruct>, SchemaImportError> {
+        let mut value = DynamicStruct::default();
+        for field in fields {
+            let field_value = match property(field.name()) {
+                Some(property) => self.field_default(field.type_path(), property)?,
+                None => self.registered_default(field.type_path()),
+            };
+            let Some(field_value) = field_value else {
+                return Ok(None);
+            };
+            value.insert_boxed(field.name(), field_value);
+        }
+        Ok(Some(value))
+    }
+
+    /// Returns the `default` of a property, or else the default value of its type.
+    fn field_default(
+        &self,
+        type_path: &str,
+        property: &Value,
+    ) -> Result<Option<Box<dyn PartialReflect>>, SchemaImportError> {
+        match property.get("default") {
+            Some(default) => self.deserialize(type_path, default).map(Some),
+            None => Ok(self.registered_default(type_path)),
+        }
+    }
+
+    fn registered_default(&self, type_path: &str) -> Option<Box<dyn PartialReflect>> {
+        let registration = self.registry.get_with_type_path(type_path)?;
+        if let Some(reflect_default) = registration.data::<ReflectDefault>() {
+            return Some(reflect_default.default().into_partial_reflect());
+        }
+        registration
+            .data::<ReflectRuntimeDefault>()
+            .map(ReflectRuntimeDefault::default)
+    }
+
+    fn deserialize(
+        &self,
+        type_path: &str,
+        value: &Value,
+    ) -> Result<Box<dyn PartialReflect>, SchemaImportError> {
+        let registration = self
+            .registry
+            .get_with_type_path(type_path)
+            .ok_or_else(|| SchemaImportError::UnknownType(type_path.to_owned()))?;
+        TypedReflectDeserializer::new(registration, self.registry)
+            .deserialize(value)
+            .map_err(|error| SchemaImportError::InvalidDefault {
+                type_path: type_path.to_owned(),
+                error: error.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::component::Components;
+    use bevy_reflect::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        Reflect,
+    };
+    use serde_json::json;
+
+    use crate::schemas::{json_schema::export_type, SchemaTypesMetadata};
+
+    fn schemas() -> Vec<JsonSchemaBevyType> {
+        serde_json::from_value(json!([
+            {
+                "shortPath": "Player",
+                "typePath": "game::Player",
+                "kind": "Struct",
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": { "$ref": "#/$defs/alloc::string::String" },
+                        "default": "Hero"
+                    },
+                    "health": { "type": { "$ref": "#/$defs/f32" } },
+                    "team": { "type": { "$ref": "#/$defs/game::Team" } }
+                }
+            },
+            {
+                "shortPath": "Team",
+                "typePath": "game::Team",
+                "kind": "Enum",
+                "type": "string",
+                "oneOf": ["Red", "Blue"]
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn import_struct_and_enum() {
+        let mut registry = TypeRegistry::new();
+        let imported = registry.import_type_json_schemas(&schemas()).unwrap();
+
+        let paths = imported
+            .iter()
+            .map(|type_info| type_info.type_path())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["game::Team", "game::Player"]);
+
+        let player = registry.get_with_type_path("game::Player").unwrap();
+        let TypeInfo::Struct(info) = player.type_info() else {
+            panic!("expected a struct");
+        };
+        assert!(info.ty().is_runtime());
+        assert_eq!(info.type_path_table().short_path(), "Player");
+        assert_eq!(info.field_names(), ["health", "name", "team"]);
+        assert!(info.field("health").unwrap().is::<f32>());
+        assert_eq!(info.field("team").unwrap().type_path(), "game::Team");
+
+        let team = registry.get_with_type_path("game::Team").unwrap();
+        let TypeInfo::Enum(info) = team.type_info() else {
+            panic!("expected an enum");
+        };
+        assert_eq!(info.variant_names(), ["Red", "Blue"]);
+    }
+
+    #[test]
+    fn imported_types_round_trip() {
+        let mut registry = TypeRegistry::new();
+        registry.import_type_json_schemas(&schemas()).unwrap();
+
+        let input = json!({
+            "game::Player": { "health": 5.0, "name": "Alice", "team": "Blue" }
+        });
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&input)
+            .unwrap();
+        assert_eq!(
+            value.get_represented_type_info().unwrap().type_path(),
+            "game::Player"
+        );
+        let player = value.reflect_ref().as_struct().unwrap();
+        assert_eq!(
+            player.field("name").unwrap().try_downcast_ref::<String>(),
+            Some(&"Alice".to_owned())
+        );
+
+        let output = serde_json::to_value(ReflectSerializer::new(&*value, &registry)).unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn imported_types_have_defaults() {
+        let mut registry = TypeRegistry::new();
+        registry.import_type_json_schemas(&schemas()).unwrap();
+
+        let default = registry
+            .get_with_type_path("game::Player")
+            .and_then(|registration| registration.data::<ReflectRuntimeDefault>())
+            .unwrap()
+            .default();
+        let output = serde_json::to_value(ReflectSerializer::new(&*default, &registry)).unwrap();
+        assert_eq!(
+            output,
+            json!({ "game::Player": { "health": 0.0, "name": "Hero", "team": "Red" } })
+        );
+    }
+
+    #[test]
+    fn import_exported_schema() {
+        #[derive(Reflect)]
+        enum Shape {
+            Circle(f32),
+            Rect { width: f32, height: f32 },
+            Empty,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Shape>();
+        let (_, mut schema) = export_type(
+            registry.get(core::any::TypeId::of::<Shape>()).unwrap(),
+            &SchemaTypesMetadata::default(),
+            &Components::default(),
+        );
+        schema.type_path = "imported::Shape".to_owned();
+
+        let type_info = registry.import_type_json_schema(&schema).unwrap().unwrap();
+        let TypeInfo::Enum(info) = type_info else {
+            panic!("expected an enum");
+        };
+        assert_eq!(info.variant_names(), ["Circle", "Rect", "Empty"]);
+        let VariantInfo::Struct(rect) = info.variant("Rect").unwrap() else {
+            panic!("expected a struct variant");
+        };
+        assert_eq!(rect.field_names(), ["height", "width"]);
+        assert!(matches!(
+            info.variant("Circle"),
+            Some(VariantInfo::Tuple(circle)) if circle.field_at(0).unwrap().is::<f32>()
+        ));
+    }
+
+    #[test]
+    fn import_errors() {
+        let mut registry = TypeRegistry::new();
+        let unknown: JsonSchemaBevyType = serde_json::from_value(json!({
+            "shortPath": "Foo",
+            "typePath": "game::Foo",
+            "kind": "TupleStruct",
+            "type": "array",
+            "prefixItems": [{ "type": { "$ref": "#/$defs/game::Missing" } }]
+        }))
+        .unwrap();
+        assert!(matches!(
+            registry.import_type_json_schema(&unknown),
+            Err(SchemaImportError::UnknownType(path)) if path == "game::Missing"
+        ));
+
+        let recursive: JsonSchemaBevyType = serde_json::from_value(json!({
+            "shortPath": "Node",
+            "typePath": "game::Node",
+            "kind": "Struct",
+            "type": "object",
+            "properties": { "next": { "type": { "$ref": "#/$defs/game::Node" } } }
+        }))
+        .unwrap();
+        assert!(matches!(
+            registry.import_type_json_schema(&recursive),
+            Err(SchemaImportError::RecursiveType(path)) if path == "game::Node"
+        ));
+        assert!(registry.get_with_type_path("game::Node").is_none());
+    }
+    #[test]
+    fn invalid_schema_registers_nothing() {
+        let mut registry = TypeRegistry::new();
+        let mut schemas = schemas();
+        schemas.push(
+            serde_json::from_value(json!({
+                "shortPath": "Inventory",
+                "typePath": "game::Inventory",
+                "kind": "Map",
+                "type": "object"
+            }))
+            .unwrap(),
+        );
+        assert!(matches!(
+            registry.import_type_json_schemas(&schemas),
+            Err(SchemaImportError::UnsupportedKind { type_path, .. }) if type_path == "game::Inventory"
+        ));
+        for schema in &schemas {
+            assert!(registry.get_with_type_path(&schema.type_path).is_none());
+        }
+    }
+}
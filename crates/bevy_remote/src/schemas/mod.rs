@@ -11,6 +11,7 @@ use bevy_reflect::{
 use core::any::TypeId;
 
 pub mod json_schema;
+pub mod json_schema_import;
 pub mod open_rpc;
 
 /// Holds mapping of reflect [type data](TypeData) to strings,
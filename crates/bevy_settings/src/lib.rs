@@ -30,8 +30,8 @@ use bevy_log::warn;
 use bevy_reflect::{
     prelude::ReflectDefault,
     serde::{TypedReflectDeserializer, TypedReflectSerializer},
-    FromReflect, FromType, PartialReflect, ReflectMut, TypeInfo, TypePath, TypeRegistration,
-    TypeRegistry,
+    validation, FromReflect, FromType, PartialReflect, ReflectMut, TypeInfo, TypePath,
+    TypeRegistration, TypeRegistry,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -491,7 +491,12 @@ fn apply_settings_to_world(
                     value
                 };
 
-                load_properties(value, &mut *reflect, types);
+                // Load into a copy, so that invalid settings leave the resource unchanged.
+                let mut loaded = reflect.to_dynamic();
+                load_properties(value, &mut *loaded, types);
+                if validate_settings(&*loaded, settings_group, types) {
+                    reflect.apply(&*loaded);
+                }
             }
         } else {
             // The resource does not exist, so create a default.
@@ -512,6 +517,9 @@ fn apply_settings_to_world(
                 };
 
                 load_properties(value, &mut *default_value, types);
+                if !validate_settings(default_value.as_partial_reflect(), settings_group, types) {
+                    default_value = reflect_default.default();
+                }
             }
 
             // Now add the new resource to the world.
@@ -520,6 +528,20 @@ fn apply_settings_to_world(
     }
 }
 
+/// Returns `false`, and logs the violations, if `value` violates its
+/// [validation attributes](validation).
+fn validate_settings(
+    value: &dyn PartialReflect,
+    settings_group: &str,
+    types: &TypeRegistry,
+) -> bool {
+    let errors = validation::validate(value, types);
+    for error in &errors {
+        warn!("Ignoring invalid settings in [{settings_group}]: {error}");
+    }
+    errors.is_empty()
+}
+
 fn load_properties(value: &toml::Value, resource: &mut dyn PartialReflect, types: &TypeRegistry) {
     let Some(tinfo) = resource.get_represented_type_info() else {
         return;
@@ -607,7 +629,7 @@ fn handle_delayed_save(
 mod tests {
     use super::*;
     use bevy_ecs::change_detection::Tick;
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{validation::NumberRange, Reflect};
 
     /// Test resource that uses default settings group name (derived from type name)
     #[derive(Resource, SettingsGroup, Reflect, Default)]
@@ -624,6 +646,16 @@ mod tests {
         enabled: bool,
     }
 
+    /// Test resource with a validated field, that shares the same settings group name as
+    /// [`ExtraCounterSettings`]
+    #[derive(Resource, SettingsGroup, Reflect, Default)]
+    #[reflect(Resource, SettingsGroup, Default)]
+    #[settings_group(group = "counter_settings")]
+    struct BoundedCounterSettings {
+        #[reflect(@NumberRange::new(0.0, 1000.0))]
+        count: i32,
+    }
+
     #[derive(Resource, SettingsGroup, Reflect, Debug, Default, PartialEq)]
     #[reflect(Resource, SettingsGroup, Default)]
     #[settings_group(group = "counter_settings", key = "refresh_rate")]
@@ -1058,4 +1090,41 @@ mod tests {
         let refresh_rate = world.get_resource::<CounterRefreshRateSettings>().unwrap();
         assert_eq!(*refresh_rate, CounterRefreshRateSettings::Fast);
     }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        let mut world = World::new();
+        let mut types = TypeRegistry::default();
+        types.register::<BoundedCounterSettings>();
+        types.register::<ExtraCounterSettings>();
+
+        world.insert_resource(BoundedCounterSettings { count: 50 });
+
+        let mut table = toml::Table::new();
+        let mut counter_section = toml::Table::new();
+        counter_section.insert("count".to_string(), toml::Value::Integer(5000));
+        counter_section.insert("enabled".to_string(), toml::Value::Boolean(true));
+        table.insert(
+            "counter_settings".to_string(),
+            toml::Value::Table(counter_section),
+        );
+
+        let manifest = PreferenceFileManifest {
+            last_save: Tick::new(0),
+            resource_types: vec![
+                TypeId::of::<BoundedCounterSettings>(),
+                TypeId::of::<ExtraCounterSettings>(),
+            ],
+        };
+
+        apply_settings_to_world(&mut world, Some(&table), &manifest, &types);
+
+        // The out-of-range count is rejected, leaving the existing value unchanged.
+        let counter = world.get_resource::<BoundedCounterSettings>().unwrap();
+        assert_eq!(counter.count, 50);
+
+        // Other resources in the same group are still loaded.
+        let extra = world.get_resource::<ExtraCounterSettings>().unwrap();
+        assert!(extra.enabled);
+    }
 }
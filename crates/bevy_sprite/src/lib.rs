@@ -104,6 +104,14 @@ impl Plugin for SpritePlugin {
                 .after(update_text2d_layout),
         );
 
+        #[cfg(feature = "localization")]
+        app.add_systems(
+            PostUpdate,
+            bevy_text::localize_text::<Text2d>
+                .in_set(bevy_text::LocalizationSystems)
+                .run_if(resource_exists::<bevy_text::Localization>),
+        );
+
         #[cfg(feature = "bevy_picking")]
         app.add_plugins(SpritePickingPlugin);
     }
@@ -0,0 +1,172 @@
+use alloc::collections::VecDeque;
+
+use crate::TextStyleRuns;
+
+/// The state of an [`EditableText`](crate::EditableText) at a point in its edit history.
+///
+/// The selection is stored as character (not byte) offsets into `text`,
+/// so that snapshots remain valid when [`EditableText::mask`](crate::EditableText::mask) changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSnapshot {
+    /// The (unmasked) text content.
+    pub text: String,
+    /// Character offset of the selection anchor.
+    pub anchor: usize,
+    /// Character offset of the selection focus (the cursor).
+    pub focus: usize,
+    /// The styles of the text.
+    pub styles: TextStyleRuns,
+}
+
+/// Kinds of [`TextEdit`](crate::TextEdit) that can be merged into a single undo step
+/// when applied consecutively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EditGroup {
+    /// Typing, via [`TextEdit::Insert`](crate::TextEdit::Insert).
+    Insert,
+    /// [`TextEdit::Backspace`](crate::TextEdit::Backspace).
+    Backspace,
+    /// [`TextEdit::Delete`](crate::TextEdit::Delete).
+    Delete,
+}
+
+/// Undo/redo history of an [`EditableText`](crate::EditableText).
+///
+/// Every [`TextEdit`](crate::TextEdit) which changes the text or its styles records the state before the edit.
+/// Runs of consecutive inserts, backspaces or deletes are coalesced into a single step,
+/// so that undoing reverts a whole word rather than a single character.
+/// A group of inserts ends after whitespace is typed, and any other edit
+/// (including cursor movement) ends the current group.
+///
+/// History is navigated with [`TextEdit::Undo`](crate::TextEdit::Undo) and [`TextEdit::Redo`](crate::TextEdit::Redo).
+#[derive(Debug, Clone)]
+pub struct TextEditHistory {
+    /// Maximum number of undo steps to keep. The oldest steps are discarded first.
+    ///
+    /// Set this to 0 to disable the history.
+    pub max_len: usize,
+    undo: VecDeque<TextSnapshot>,
+    redo: Vec<TextSnapshot>,
+    group: Option<EditGroup>,
+}
+
+impl Default for TextEditHistory {
+    fn default() -> Self {
+        Self {
+            max_len: 100,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            group: None,
+        }
+    }
+}
+
+impl TextEditHistory {
+    /// Is there an edit which can be undone?
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Is there an undone edit which can be redone?
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Discards all undo and redo steps.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group = None;
+    }
+
+    /// Records a change to the text, where `before` is the state prior to the change.
+    ///
+    /// If `group` matches the group of the previous change, the two are merged into one step.
+    pub(crate) fn record(&mut self, before: TextSnapshot, group: Option<EditGroup>) {
+        self.redo.clear();
+        let coalesce = group.is_some() && group == self.group && !self.undo.is_empty();
+        self.group = group;
+        if coalesce || self.max_len == 0 {
+            return;
+        }
+        self.undo.push_back(before);
+        while self.undo.len() > self.max_len {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Ends the current group, so that the next change is recorded as a new step.
+    pub(crate) fn break_group(&mut self) {
+        self.group = None;
+    }
+
+    /// Steps back through the history, returning the state to restore.
+    ///
+    /// `current` is saved so that it can be restored by [`redo`](Self::redo).
+    pub(crate) fn undo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        self.group = None;
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// Steps forward through the history, returning the state to restore.
+    ///
+    /// `current` is saved so that it can be restored by [`undo`](Self::undo).
+    pub(crate) fn redo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        self.group = None;
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(text: &str) -> TextSnapshot {
+        let len = text.chars().count();
+        TextSnapshot {
+            text: text.into(),
+            anchor: len,
+            focus: len,
+            styles: TextStyleRuns::default(),
+        }
+    }
+
+    #[test]
+    fn record_coalesces_groups() {
+        let mut history = TextEditHistory::default();
+        history.record(snapshot(""), Some(EditGroup::Insert));
+        history.record(snapshot("a"), Some(EditGroup::Insert));
+        history.record(snapshot("ab"), Some(EditGroup::Backspace));
+        history.break_group();
+        history.record(snapshot("a"), Some(EditGroup::Backspace));
+
+        assert_eq!(history.undo(snapshot("")).unwrap().text, "a");
+        assert_eq!(history.undo(snapshot("a")).unwrap().text, "ab");
+        assert_eq!(history.undo(snapshot("ab")).unwrap().text, "");
+        assert!(!history.can_undo());
+        assert_eq!(history.redo(snapshot("")).unwrap().text, "ab");
+    }
+
+    #[test]
+    fn record_respects_max_len() {
+        let mut history = TextEditHistory {
+            max_len: 2,
+            ..Default::default()
+        };
+        for text in ["", "a", "ab"] {
+            history.record(snapshot(text), None);
+        }
+        assert_eq!(history.undo(snapshot("abc")).unwrap().text, "ab");
+        assert_eq!(history.undo(snapshot("ab")).unwrap().text, "a");
+        assert!(history.undo(snapshot("a")).is_none());
+
+        history.max_len = 0;
+        history.record(snapshot("a"), None);
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+    }
+}
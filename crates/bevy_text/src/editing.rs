@@ -18,7 +18,13 @@
 //! - Multi-click: double-click to select a word, triple-click to select a line
 //! - Optional select-all on focus via the `SelectAllOnFocus` component
 //! - Per-character input filtering via the [`EditableTextFilter`] component
+//! - Validation and transformation of inserted text via the [`EditableTextValidator`] component
 //! - Max character limits via [`EditableText::max_characters`]
+//! - Undo/redo, with consecutive typing and deletion coalesced into single steps (see [`TextEditHistory`])
+//! - Placeholder text, displayed when the input is empty, via the [`Placeholder`] component
+//! - Password-style character masking via [`EditableText::mask`]
+//! - Styled ranges of text (bold, italic, color, ...) via [`EditableText::styles`],
+//!   preserved across edits and when copying and pasting between inputs
 //! - Cursor blinking
 //! - Newline support for multi-line input
 //! - Soft-wrapping of long lines
@@ -29,7 +35,7 @@
 //! - Input consumption (preventing other systems from receiving keyboard input events when the text input is focused)
 //!
 //! You might use this widget as the basis for text input fields in forms, chat boxes, for naming characters,
-//! or any other scenario where you want to extract an unformatted text string from the user.
+//! or any other scenario where you want to extract a text string from the user.
 //!
 //! Reusable widgets that build on top of this basic text input field (as might be found in Bevy's Feathers UI framework),
 //! will typically combine this widget with additional UI elements such as borders, backgrounds, and labels,
@@ -45,9 +51,9 @@
 //!
 //! ## Limitations
 //!
-//! The formatting of the text is uniform throughout the entire input field.
-//! As a result, rich text-editing is out-of-scope:
-//! this widget is not intended to form the basis for a full-featured text editor.
+//! Styled ranges are limited to the font, size, weight, style and color of the text (see [`RichTextStyle`]):
+//! line height, alignment and wrapping are uniform throughout the entire input field.
+//! This widget is not intended to form the basis for a full-featured text editor.
 //!
 //! Similarly, this widget is "headless": it has no built-in styling, and is intended to be used
 //! with a themed UI framework of your choice (e.g. Feathers). This means that no text boxes, borders, or other
@@ -56,10 +62,6 @@
 //!
 //! However, the following features are planned but currently not implemented:
 //!
-//! - Placeholder text (displayed when the input is empty)
-//! - Undo/redo functionality
-//! - Text validation (e.g., email format, numeric input)
-//! - Password-style character masking
 //! - Mobile pop-up keyboard support
 //! - Overwrite mode (typically toggled by the `Insert` key)
 //! - AccessKit integration for screen readers and other assistive technologies
@@ -73,15 +75,20 @@
 // and `bevy_ui`, such as text layout and font management.
 
 use crate::{
-    text_edit::{poll_and_apply_paste, TextEdit},
-    FontCx, FontHinting, LayoutCx, LineHeight, TextBrush, TextColor, TextFont, TextLayout,
+    edit_history::{EditGroup, TextEditHistory, TextSnapshot},
+    text_edit::{log_paste_rejection, poll_paste, InsertRejection, PreeditCursor, TextEdit},
+    EditableTextStyles, FontCx, FontHinting, LayoutCx, LineHeight, RichTextStyle, TextBrush,
+    TextColor, TextFont, TextLayout, TextStyleRuns,
 };
-use alloc::sync::Arc;
+use alloc::{borrow::Cow, sync::Arc};
 use bevy_clipboard::ClipboardRead;
+use bevy_color::Color;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
-use core::time::Duration;
-use parley::{FontContext, LayoutContext, PlainEditor, SplitString};
+use core::{task::Poll, time::Duration};
+use parley::{
+    Cursor, FontContext, LayoutContext, PlainEditor, PlainEditorDriver, Selection, SplitString,
+};
 
 /// A plain-text text input field.
 ///
@@ -113,6 +120,8 @@ pub struct EditableText {
     ///
     /// Note that many more complex editing operations require working with [`PlainEditor::driver`].
     /// These operations should generally be batched together to avoid redundant layout work.
+    ///
+    /// While the text is [masked](Self::mask), the editor only contains mask characters.
     // The B: Brush generic here must match the brush used by `ComputedTextBlock` to ensure that the font system is compatible.
     pub editor: PlainEditor<TextBrush>,
     /// Text edit actions that have been requested but not yet applied.
@@ -144,6 +153,12 @@ pub struct EditableText {
     pub visible_width: Option<f32>,
     /// Allow new lines
     pub allow_newlines: bool,
+    /// The undo/redo history of this input.
+    pub history: TextEditHistory,
+    /// Password-style masking of the displayed text.
+    pub mask: TextMask,
+    /// Styles of ranges of the text.
+    pub styles: EditableTextStyles,
 }
 
 impl Default for EditableText {
@@ -159,6 +174,9 @@ impl Default for EditableText {
             visible_lines: Some(1.),
             visible_width: None,
             allow_newlines: false,
+            history: TextEditHistory::default(),
+            mask: TextMask::default(),
+            styles: EditableTextStyles::default(),
         }
     }
 }
@@ -187,8 +205,14 @@ impl EditableText {
     /// Get the current text input as a [`SplitString`].
     ///
     /// A [`SplitString`] can be converted into a [`String`] using `to_string` if needed.
+    ///
+    /// This is the real text even while the input is [masked](Self::mask).
     pub fn value(&self) -> SplitString<'_> {
-        self.editor.text()
+        if self.mask.applied.is_some() {
+            self.mask.secret.text()
+        } else {
+            self.editor.text()
+        }
     }
 
     /// Queue a [`TextEdit`] action to be applied later by the [`apply_text_edits`] system.
@@ -200,6 +224,10 @@ impl EditableText {
     ///
     /// [`FontContext`] should be gathered from the [`FontCx`] resource, and [`LayoutContext`] should be gathered from the [`LayoutCx`] resource.
     ///
+    /// Changes to the text are recorded in [`EditableText::history`], and a change to [`EditableText::mask`]
+    /// is applied before any edits. To also validate inserted text, use
+    /// [`apply_pending_edits_with_validator`](Self::apply_pending_edits_with_validator).
+    ///
     /// On platforms with async clipboard reads (wasm32), a [`TextEdit::Paste`] whose
     /// contents aren't yet available acts as a barrier: this call parks the in-flight
     /// read on [`EditableText`] and leaves the remaining edits queued in order. Each
@@ -211,25 +239,60 @@ impl EditableText {
         layout_context: &mut LayoutContext<TextBrush>,
         clipboard: &mut bevy_clipboard::Clipboard,
         char_filter: impl Fn(char) -> bool,
+    ) {
+        self.apply_pending_edits_with_validator(
+            font_context,
+            layout_context,
+            clipboard,
+            char_filter,
+            |_, _| TextValidation::Accept,
+        );
+    }
+
+    /// Applies all [`TextEdit`]s in `pending_edits` immediately, like [`EditableText::apply_pending_edits`].
+    ///
+    /// Inserted text must pass `char_filter`, and is then passed to `validator` (see [`EditableTextValidator`]).
+    pub fn apply_pending_edits_with_validator(
+        &mut self,
+        font_context: &mut FontContext,
+        layout_context: &mut LayoutContext<TextBrush>,
+        clipboard: &mut bevy_clipboard::Clipboard,
+        char_filter: impl Fn(char) -> bool,
+        validator: impl Fn(&str, &str) -> TextValidation,
     ) {
         let Self {
             editor,
             pending_edits,
             pending_paste,
             max_characters,
+            history,
+            mask,
+            styles,
             ..
         } = self;
 
-        let mut driver = editor.driver(font_context, layout_context);
+        let mut context = EditContext {
+            driver: editor.driver(font_context, layout_context),
+            mask,
+            history,
+            styles,
+            max_characters: *max_characters,
+            char_filter: &char_filter,
+            validator: &validator,
+        };
+
+        if context.mask.applied != context.mask.mask {
+            context.sync_mask();
+        }
 
         // First: resolve any paste carried over from a previous frame. If it's still
         // pending, hold the remaining edits (untouched in `pending_edits`) for next frame
         // so ordering relative to the paste is preserved.
-        if let Some(mut read) = pending_paste.take()
-            && !poll_and_apply_paste(&mut read, &mut driver, *max_characters, &char_filter)
-        {
-            *pending_paste = Some(read);
-            return;
+        if let Some(read) = pending_paste.take() {
+            *pending_paste = context.poll_paste(read, clipboard);
+            if pending_paste.is_some() {
+                return;
+            }
         }
 
         // Drain edits one at a time. A paste that resolves synchronously (always the case
@@ -237,17 +300,10 @@ impl EditableText {
         // requeues the *remaining* edits, so this loop continually requeues the pending paste until it resolves.
         let mut edits = core::mem::take(pending_edits).into_iter();
         while let Some(edit) = edits.next() {
-            match edit {
-                TextEdit::Paste => {
-                    let mut read = clipboard.fetch_text();
-                    if !poll_and_apply_paste(&mut read, &mut driver, *max_characters, &char_filter)
-                    {
-                        *pending_paste = Some(read);
-                        pending_edits.extend(edits);
-                        return;
-                    }
-                }
-                other => other.apply(&mut driver, clipboard, *max_characters, &char_filter),
+            if let Some(read) = context.apply(edit, clipboard) {
+                *pending_paste = Some(read);
+                pending_edits.extend(edits);
+                return;
             }
         }
     }
@@ -256,10 +312,15 @@ impl EditableText {
     ///
     /// Also drops any in-flight paste. The underlying clipboard read task
     /// will still complete, but its result is discarded.
+    ///
+    /// The edit history and styles are cleared too, so the cleared text can't be restored with [`TextEdit::Undo`].
     pub fn clear(&mut self) {
         self.editor.set_text("");
+        self.mask.secret.set_text("");
         self.pending_edits.clear();
         self.pending_paste = None;
+        self.history.clear();
+        self.styles.clear();
     }
 
     /// Is the IME currently composing text for this input?
@@ -271,6 +332,74 @@ impl EditableText {
     }
 }
 
+/// Password-style masking of an [`EditableText`].
+///
+/// While a mask character is set, every character of the text is displayed as that character.
+/// The [`PlainEditor`] (and so text layout and anything else reading it) only ever sees the mask
+/// characters, while the real text is stored separately, and is only available through [`EditableText::value`].
+///
+/// While masked, [`TextEdit::Copy`] and [`TextEdit::Cut`] are ignored, and IME preedit text is masked too.
+///
+/// Changes to the mask character take effect the next time edits are applied by [`apply_text_edits`].
+#[derive(Clone)]
+pub struct TextMask {
+    /// The requested mask character.
+    mask: Option<char>,
+    /// The mask character which the editor text currently uses.
+    applied: Option<char>,
+    /// The real text, while masked.
+    // This is an editor rather than a `String` so that `EditableText::value` can return a `SplitString`.
+    secret: PlainEditor<TextBrush>,
+}
+
+impl Default for TextMask {
+    fn default() -> Self {
+        Self {
+            mask: None,
+            applied: None,
+            secret: PlainEditor::new(100.),
+        }
+    }
+}
+
+impl TextMask {
+    /// Creates a mask which displays every character as `mask`, such as `'•'` or `'*'`.
+    pub fn new(mask: char) -> Self {
+        Self {
+            mask: Some(mask),
+            ..Default::default()
+        }
+    }
+
+    /// The character used to mask the text, or `None` if the text is displayed as-is.
+    pub fn get(&self) -> Option<char> {
+        self.mask
+    }
+
+    /// Sets the character used to mask the text, or `None` to display the text as-is.
+    ///
+    /// This can be used to implement a "show password" toggle.
+    pub fn set(&mut self, mask: Option<char>) {
+        self.mask = mask;
+    }
+
+    /// The real text, given the editor holding the displayed text.
+    fn real_text(&self, editor: &PlainEditor<TextBrush>) -> String {
+        match self.applied {
+            Some(_) => self.secret.raw_text().to_string(),
+            None => editor.text().to_string(),
+        }
+    }
+
+    /// The text to display for `text`.
+    fn display<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.applied {
+            Some(mask) => Cow::Owned(core::iter::repeat_n(mask, text.chars().count()).collect()),
+            None => Cow::Borrowed(text),
+        }
+    }
+}
+
 /// Wrapper around a `parley::Generation`. Used to track when `TextLayoutInfo` is stale and needs reupdating.
 /// The initial `Generation` of the `PlainEditor` is not equal to the default `Generation` value, so the
 /// `TextLayoutInfo` will always be given an initial update.
@@ -290,24 +419,95 @@ impl EditableTextFilter {
     }
 }
 
+/// The outcome of an [`EditableTextValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextValidation {
+    /// Insert the text unchanged.
+    Accept,
+    /// Ignore the edit.
+    Reject,
+    /// Insert the given text instead.
+    ///
+    /// The replacement is not checked by the validator or the [`EditableTextFilter`] again,
+    /// but must still fit within [`EditableText::max_characters`].
+    Replace(String),
+}
+
+/// Sets a validator for this text input, which can accept, reject or transform text inserted by
+/// [`TextEdit::Insert`], [`TextEdit::Paste`] and [`TextEdit::ImeCommit`].
+///
+/// The validator is called with the text being inserted, and the full text which would result from inserting it.
+/// It runs after the [`EditableTextFilter`], so only sees text which passed the filter.
+///
+/// Like the filter, the validator does not apply to text already within the `EditableText`'s text buffer,
+/// or to deletions.
+#[derive(Component, Clone, Default)]
+pub struct EditableTextValidator(
+    Option<Arc<dyn Fn(&str, &str) -> TextValidation + Send + Sync + 'static>>,
+);
+
+impl EditableTextValidator {
+    /// Create a new `EditableTextValidator` from the given validation function.
+    ///
+    /// The function receives the inserted text and the resulting text, in that order.
+    pub fn new(validator: impl Fn(&str, &str) -> TextValidation + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(validator)))
+    }
+}
+
+/// Text displayed by an [`EditableText`] while it is empty, such as "Enter your name".
+///
+/// The placeholder is laid out with the same font as the input, and drawn with `color`,
+/// or a faded version of the input's [`TextColor`] if `color` is `None`.
+/// It is never part of [`EditableText::value`], and is not masked by [`EditableText::mask`].
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct Placeholder {
+    /// The text to display.
+    pub text: String,
+    /// The color of the placeholder text.
+    pub color: Option<Color>,
+}
+
+impl Placeholder {
+    /// Creates a placeholder with the given text, drawn in a faded version of the input's [`TextColor`].
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+        }
+    }
+
+    /// Sets the color of the placeholder text.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
 /// Applies pending text edit actions to all [`EditableText`] widgets.
 pub fn apply_text_edits(
     mut query: Query<(
         Entity,
         &mut EditableText,
         Option<&EditableTextFilter>,
-        &EditableTextGeneration,
+        Option<&EditableTextValidator>,
+        &mut EditableTextGeneration,
     )>,
     mut font_context: ResMut<FontCx>,
     mut layout_context: ResMut<LayoutCx>,
     mut clipboard: ResMut<bevy_clipboard::Clipboard>,
     mut commands: Commands,
 ) {
-    for (entity, mut editable_text, filter, generation) in query.iter_mut() {
+    for (entity, mut editable_text, filter, validator, mut generation) in query.iter_mut() {
         // `pending_paste` can hold a cross-frame paste even when no new edits are queued,
         // so check for either before doing work.
-        if !editable_text.pending_edits.is_empty() || editable_text.pending_paste.is_some() {
-            editable_text.apply_pending_edits(
+        // A change to the mask must be applied before layout, even without any edits,
+        // so that the real text is never laid out.
+        if !editable_text.pending_edits.is_empty()
+            || editable_text.pending_paste.is_some()
+            || editable_text.mask.applied != editable_text.mask.mask
+        {
+            editable_text.apply_pending_edits_with_validator(
                 &mut font_context.0,
                 &mut layout_context.0,
                 &mut clipboard,
@@ -315,9 +515,19 @@ pub fn apply_text_edits(
                     Some(EditableTextFilter(Some(filter))) => filter.as_ref(),
                     _ => &|_| true,
                 },
+                match validator {
+                    Some(EditableTextValidator(Some(validator))) => validator.as_ref(),
+                    _ => &|_: &str, _: &str| TextValidation::Accept,
+                },
             );
         }
 
+        // Style changes don't change the editor's generation, so force a relayout.
+        if editable_text.styles.changed {
+            editable_text.styles.changed = false;
+            *generation = EditableTextGeneration::default();
+        }
+
         if **generation != editable_text.editor.generation() {
             commands.trigger(TextEditChange { entity });
         }
@@ -331,3 +541,632 @@ pub fn apply_text_edits(
 pub struct TextEditChange {
     entity: Entity,
 }
+
+/// Borrowed state of an [`EditableText`], used to apply [`TextEdit`]s.
+struct EditContext<'a> {
+    driver: PlainEditorDriver<'a, TextBrush>,
+    mask: &'a mut TextMask,
+    history: &'a mut TextEditHistory,
+    styles: &'a mut EditableTextStyles,
+    max_characters: Option<usize>,
+    char_filter: &'a dyn Fn(char) -> bool,
+    validator: &'a dyn Fn(&str, &str) -> TextValidation,
+}
+
+impl EditContext<'_> {
+    /// Applies a single edit.
+    ///
+    /// Returns the clipboard read if the edit is a paste that is still waiting for clipboard I/O.
+    fn apply(
+        &mut self,
+        edit: TextEdit,
+        clipboard: &mut bevy_clipboard::Clipboard,
+    ) -> Option<ClipboardRead> {
+        let masked = self.mask.applied.is_some();
+        let is_style_edit = matches!(
+            edit,
+            TextEdit::ApplyStyle(_) | TextEdit::ToggleStyle(_) | TextEdit::ClearStyle
+        );
+        match edit {
+            TextEdit::Paste => return self.poll_paste(clipboard.fetch_text(), clipboard),
+            TextEdit::Insert(text) => {
+                let _ = self.insert(&text, Some(EditGroup::Insert), None);
+            }
+            TextEdit::ImeCommit { value } => {
+                self.driver.clear_compose();
+                let _ = self.insert(&value, None, None);
+            }
+            TextEdit::Undo => {
+                let current = self.snapshot();
+                if let Some(previous) = self.history.undo(current) {
+                    self.restore(&previous);
+                }
+            }
+            TextEdit::Redo => {
+                let current = self.snapshot();
+                if let Some(next) = self.history.redo(current) {
+                    self.restore(&next);
+                }
+            }
+            edit if is_style_edit => {
+                if self.styles.enabled && !masked {
+                    self.apply_style(edit);
+                }
+            }
+            // Don't leak the real text to the clipboard.
+            TextEdit::Copy | TextEdit::Cut if masked => self.history.break_group(),
+            TextEdit::Copy if !self.styles.runs.is_empty() => {
+                self.copy_styled(clipboard);
+                self.history.break_group();
+            }
+            TextEdit::Cut if !self.styles.runs.is_empty() => {
+                if self.copy_styled(clipboard) {
+                    let before = self.snapshot();
+                    let tracking = self.begin_tracking();
+                    self.driver.delete_selection();
+                    self.end_tracking(tracking);
+                    self.record(before, None);
+                }
+            }
+            edit if self.apply_with_layout(&edit) => self.history.break_group(),
+            edit => {
+                let group = match edit {
+                    TextEdit::Backspace => Some(EditGroup::Backspace),
+                    TextEdit::Delete => Some(EditGroup::Delete),
+                    _ => None,
+                };
+                // Setting the IME preedit replaces the selection, if any.
+                let before = matches!(
+                    edit,
+                    TextEdit::Cut
+                        | TextEdit::Backspace
+                        | TextEdit::BackspaceWord
+                        | TextEdit::Delete
+                        | TextEdit::DeleteWord
+                        | TextEdit::ImeSetCompose { .. }
+                )
+                .then(|| self.snapshot());
+                let len_before = self.driver.editor.text().chars().count();
+                let tracking = self.begin_tracking();
+
+                let edit = self.mask_compose(edit);
+                edit.apply(
+                    &mut self.driver,
+                    clipboard,
+                    self.max_characters,
+                    self.char_filter,
+                );
+
+                self.end_tracking(tracking);
+                if masked {
+                    self.sync_deletion(len_before);
+                }
+                match before {
+                    Some(before) => self.record(before, group),
+                    None => self.history.break_group(),
+                }
+            }
+        }
+        // The typing style only applies to text inserted immediately after it is set.
+        if !is_style_edit {
+            self.styles.typing_style = None;
+        }
+        None
+    }
+
+    /// Applies a [`TextEdit::ApplyStyle`], [`TextEdit::ToggleStyle`] or [`TextEdit::ClearStyle`].
+    fn apply_style(&mut self, edit: TextEdit) {
+        let range = self.driver.editor.raw_selection().text_range();
+        if range.is_empty() {
+            // Style the text that is typed next, starting from the style it would inherit.
+            let styles = &mut *self.styles;
+            let mut style = styles
+                .typing_style
+                .take()
+                .or_else(|| {
+                    range
+                        .start
+                        .checked_sub(1)
+                        .and_then(|index| styles.runs.style_at(index))
+                        .cloned()
+                })
+                .unwrap_or_default();
+            match edit {
+                TextEdit::ApplyStyle(applied) => style.merge(&applied),
+                TextEdit::ToggleStyle(toggled) if style.contains(&toggled) => {
+                    style.remove(&toggled);
+                }
+                TextEdit::ToggleStyle(toggled) => style.merge(&toggled),
+                _ => style = RichTextStyle::default(),
+            }
+            styles.typing_style = Some(style);
+            self.history.break_group();
+            return;
+        }
+
+        let before = self.snapshot();
+        let runs = &mut self.styles.runs;
+        match edit {
+            TextEdit::ApplyStyle(applied) => runs.update(range, |style| style.merge(&applied)),
+            TextEdit::ToggleStyle(toggled) if runs.contains(range.clone(), &toggled) => {
+                runs.update(range, |style| style.remove(&toggled));
+            }
+            TextEdit::ToggleStyle(toggled) => runs.update(range, |style| style.merge(&toggled)),
+            _ => runs.update(range, |style| *style = RichTextStyle::default()),
+        }
+        self.styles.changed = true;
+        self.styles.layout = None;
+        self.record(before, None);
+    }
+
+    /// Copies the selection to the clipboard, along with its styles.
+    ///
+    /// Returns `false` if nothing was copied.
+    fn copy_styled(&mut self, clipboard: &mut bevy_clipboard::Clipboard) -> bool {
+        let editor = &*self.driver.editor;
+        let Some(text) = editor.selected_text() else {
+            return false;
+        };
+        let styles = self.styles.runs.slice(editor.raw_selection().text_range());
+        match clipboard.set_text_with_metadata(text, styles) {
+            Ok(()) => true,
+            Err(e) => {
+                bevy_log::warn!("Failed to write selection to clipboard: {e:?}");
+                false
+            }
+        }
+    }
+
+    /// Applies cursor movements which depend on the layout using the layout of the styled text,
+    /// as it may differ from the editor's unstyled layout.
+    ///
+    /// Returns `false` if the edit wasn't applied.
+    fn apply_with_layout(&mut self, edit: &TextEdit) -> bool {
+        let Some(layout) = self.styles.layout.as_ref() else {
+            return false;
+        };
+        let selection = self.driver.editor.raw_selection().refresh(layout);
+        let selection = match *edit {
+            TextEdit::MoveToPoint(point) => Cursor::from_point(layout, point.x, point.y).into(),
+            TextEdit::SelectWordAtPoint(point) => {
+                Selection::word_from_point(layout, point.x, point.y)
+            }
+            TextEdit::SelectLineAtPoint(point) => {
+                Selection::line_from_point(layout, point.x, point.y)
+            }
+            TextEdit::SelectedHardLineAtPoint(point) => {
+                Selection::hard_line_from_point(layout, point.x, point.y)
+            }
+            TextEdit::ExtendSelectionToPoint(point) => {
+                selection.extend_to_point(layout, point.x, point.y)
+            }
+            TextEdit::ShiftClickExtension(point) => {
+                selection.shift_click_extension(layout, point.x, point.y)
+            }
+            TextEdit::Up(extend) => selection.previous_line(layout, extend),
+            TextEdit::Down(extend) => selection.next_line(layout, extend),
+            TextEdit::LineStart(extend) => selection.line_start(layout, extend),
+            TextEdit::LineEnd(extend) => selection.line_end(layout, extend),
+            _ => return false,
+        };
+        self.driver
+            .select_byte_range(selection.anchor().index(), selection.focus().index());
+        true
+    }
+
+    /// Captures the text before an edit, if needed to keep the styles in place.
+    fn begin_tracking(&self) -> Option<(String, usize)> {
+        self.styles.is_tracking().then(|| {
+            let editor = &*self.driver.editor;
+            (
+                editor.raw_text().to_string(),
+                editor.raw_selection().text_range().start,
+            )
+        })
+    }
+
+    /// Updates the styles after an edit, given the result of [`begin_tracking`](Self::begin_tracking).
+    fn end_tracking(&mut self, tracking: Option<(String, usize)>) {
+        if let Some((old, hint)) = tracking {
+            self.styles
+                .track_change(&old, self.driver.editor.raw_text(), hint);
+        }
+    }
+
+    /// Polls a clipboard read, inserting the clipboard text if the read has resolved.
+    ///
+    /// If the text was copied from a styled [`EditableText`], its styles are pasted too.
+    ///
+    /// Returns the read if it is still pending.
+    fn poll_paste(
+        &mut self,
+        mut read: ClipboardRead,
+        clipboard: &bevy_clipboard::Clipboard,
+    ) -> Option<ClipboardRead> {
+        match poll_paste(&mut read) {
+            Poll::Ready(Some(text)) => {
+                let styles = self
+                    .styles
+                    .enabled
+                    .then(|| clipboard.metadata::<TextStyleRuns>(&text))
+                    .flatten();
+                if let Err(rejection) = self.insert(&text, None, styles) {
+                    log_paste_rejection(&rejection);
+                }
+                None
+            }
+            Poll::Ready(None) => None,
+            Poll::Pending => Some(read),
+        }
+    }
+
+    /// Insert (or replace the current selection with) `text`, subject to the char filter,
+    /// the validator and `max_characters`, and record the change in the history.
+    ///
+    /// If `styles` is given, it is applied to the inserted text, unless the validator replaced the text.
+    fn insert(
+        &mut self,
+        text: &str,
+        group: Option<EditGroup>,
+        styles: Option<&TextStyleRuns>,
+    ) -> Result<(), InsertRejection> {
+        if !text.chars().all(self.char_filter) {
+            return Err(InsertRejection::CharFilter);
+        }
+        if self.mask.applied.is_some() {
+            // The masked preedit text isn't part of the real text, so it would offset the selection.
+            self.driver.clear_compose();
+        }
+
+        let before = self.snapshot();
+        let editor = &*self.driver.editor;
+        let selection = editor.raw_selection().text_range();
+        let start = char_to_byte(
+            &before.text,
+            byte_to_char(editor.raw_text(), selection.start),
+        );
+        let end = char_to_byte(&before.text, byte_to_char(editor.raw_text(), selection.end));
+        let splice = |inserted: &str| {
+            let mut result = String::with_capacity(before.text.len() + inserted.len());
+            result.push_str(&before.text[..start]);
+            result.push_str(inserted);
+            result.push_str(&before.text[end..]);
+            result
+        };
+
+        let mut result = splice(text);
+        let (text, styles) = match (self.validator)(text, &result) {
+            TextValidation::Accept => (Cow::Borrowed(text), styles),
+            TextValidation::Reject => return Err(InsertRejection::Validator),
+            TextValidation::Replace(replacement) => {
+                result = splice(&replacement);
+                (Cow::Owned(replacement), None)
+            }
+        };
+        if self
+            .max_characters
+            .is_some_and(|max| result.chars().count() > max)
+        {
+            return Err(InsertRejection::MaxLength);
+        }
+
+        let display = self.mask.display(&text).into_owned();
+        let tracking = self.begin_tracking();
+        self.driver.insert_or_replace_selection(&display);
+        self.end_tracking(tracking);
+        if self.mask.applied.is_some() {
+            self.mask.secret.set_text(&result);
+        } else if let Some(styles) = styles {
+            let end = self.driver.editor.raw_selection().text_range().end;
+            let range = end - display.len()..end;
+            self.styles
+                .runs
+                .update(range.clone(), |style| *style = RichTextStyle::default());
+            self.styles.runs.apply_runs(range.start, styles);
+            self.styles.changed = true;
+            self.styles.layout = None;
+        }
+
+        let ends_word = text.ends_with(char::is_whitespace);
+        self.record(before, group);
+        if ends_word {
+            self.history.break_group();
+        }
+        Ok(())
+    }
+
+    /// Records a change in the history, if the text or its styles changed.
+    fn record(&mut self, before: TextSnapshot, group: Option<EditGroup>) {
+        if self.mask.real_text(self.driver.editor) != before.text
+            || self.styles.runs != before.styles
+        {
+            self.history.record(before, group);
+        } else {
+            self.history.break_group();
+        }
+    }
+
+    /// Captures the current text and selection.
+    fn snapshot(&self) -> TextSnapshot {
+        let editor = &*self.driver.editor;
+        let text = editor.raw_text();
+        let selection = editor.raw_selection();
+        TextSnapshot {
+            text: self.mask.real_text(editor),
+            anchor: byte_to_char(text, selection.anchor().index()),
+            focus: byte_to_char(text, selection.focus().index()),
+            styles: self.styles.runs.clone(),
+        }
+    }
+
+    /// Replaces the text and selection with a snapshot.
+    fn restore(&mut self, snapshot: &TextSnapshot) {
+        self.driver.clear_compose();
+        if self.mask.applied.is_some() {
+            self.mask.secret.set_text(&snapshot.text);
+        }
+        let display = self.mask.display(&snapshot.text).into_owned();
+        self.driver.editor.set_text(&display);
+        let anchor = char_to_byte(&display, snapshot.anchor);
+        let focus = char_to_byte(&display, snapshot.focus);
+        self.driver.select_byte_range(anchor, focus);
+        self.styles.set_runs(snapshot.styles.clone());
+        self.styles.typing_style = None;
+    }
+
+    /// Switches the editor text between the real text and the masked text,
+    /// after [`TextMask::set`] has been called.
+    fn sync_mask(&mut self) {
+        self.driver.clear_compose();
+        let snapshot = self.snapshot();
+        self.mask.applied = self.mask.mask;
+        self.mask.secret.set_text("");
+        self.restore(&snapshot);
+    }
+
+    /// Masks the IME preedit text.
+    fn mask_compose(&self, edit: TextEdit) -> TextEdit {
+        match (self.mask.applied, edit) {
+            (Some(mask), TextEdit::ImeSetCompose { value, cursor }) if !value.is_empty() => {
+                let to_mask = |index: usize| value[..index].chars().count() * mask.len_utf8();
+                TextEdit::ImeSetCompose {
+                    value: self.mask.display(&value).as_ref().into(),
+                    cursor: cursor.map(|cursor| PreeditCursor {
+                        anchor: to_mask(cursor.anchor),
+                        focus: to_mask(cursor.focus),
+                    }),
+                }
+            }
+            (_, edit) => edit,
+        }
+    }
+
+    /// Removes characters from the real text after an edit deleted them from the masked text.
+    ///
+    /// `len_before` is the number of characters in the masked text before the edit.
+    fn sync_deletion(&mut self, len_before: usize) {
+        let editor = &*self.driver.editor;
+        let removed = len_before.saturating_sub(editor.text().chars().count());
+        if removed == 0 {
+            return;
+        }
+        // Every deletion leaves the cursor (or the preedit text which replaced the selection)
+        // at the start of the deleted range.
+        let at = match editor.raw_compose() {
+            Some(compose) => compose.start,
+            None => editor.raw_selection().text_range().start,
+        };
+        let at = byte_to_char(editor.raw_text(), at);
+        let mut secret = self.mask.secret.raw_text().to_string();
+        let start = char_to_byte(&secret, at);
+        let end = char_to_byte(&secret, at + removed);
+        secret.replace_range(start..end, "");
+        self.mask.secret.set_text(&secret);
+    }
+}
+
+/// Converts a character offset into `text` into a byte offset, clamped to the end of the text.
+fn char_to_byte(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(i, _)| i)
+}
+
+/// Converts a byte offset into `text` into a character offset.
+fn byte_to_char(text: &str, index: usize) -> usize {
+    text.get(..index).map_or(0, |prefix| prefix.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_clipboard::Clipboard;
+    use parley::{fontique::Blob, FontContext, FontFamily, LayoutContext, StyleProperty};
+
+    /// An [`EditableText`] along with everything needed to apply edits to it.
+    struct TestInput {
+        text: EditableText,
+        font_cx: FontContext,
+        layout_cx: LayoutContext<TextBrush>,
+        clipboard: Clipboard,
+    }
+
+    impl TestInput {
+        fn new(mask: Option<char>) -> Self {
+            let mut font_cx = FontContext::default();
+            font_cx.collection.register_fonts(
+                Blob::from(include_bytes!("FiraMono-subset.ttf").to_vec()),
+                None,
+            );
+            let mut text = EditableText::default();
+            text.mask.set(mask);
+            // Cursor movement needs a font to lay out the text.
+            text.editor
+                .edit_styles()
+                .insert(StyleProperty::FontFamily(FontFamily::named("Fira Mono")));
+            Self {
+                text,
+                font_cx,
+                layout_cx: LayoutContext::default(),
+                clipboard: Clipboard::default(),
+            }
+        }
+
+        fn type_text(&mut self, typed: &str) {
+            for c in typed.chars() {
+                self.text.queue_edit(TextEdit::Insert(c.to_string().into()));
+            }
+        }
+
+        fn edit(&mut self, edit: TextEdit) {
+            self.text.queue_edit(edit);
+            self.apply();
+        }
+
+        fn apply(&mut self) {
+            self.apply_validated(|_, _| TextValidation::Accept);
+        }
+
+        fn apply_validated(&mut self, validator: impl Fn(&str, &str) -> TextValidation) {
+            self.text.apply_pending_edits_with_validator(
+                &mut self.font_cx,
+                &mut self.layout_cx,
+                &mut self.clipboard,
+                |_| true,
+                validator,
+            );
+        }
+
+        fn value(&self) -> String {
+            self.text.value().to_string()
+        }
+
+        fn focus(&self) -> usize {
+            self.text.editor.raw_selection().focus().index()
+        }
+    }
+
+    #[test]
+    fn undo_reverts_whole_word() {
+        let mut input = TestInput::new(None);
+        input.type_text("hello");
+        input.apply();
+        assert_eq!(input.value(), "hello");
+
+        input.edit(TextEdit::Undo);
+        assert_eq!(input.value(), "");
+        assert!(input.text.history.can_redo());
+
+        input.edit(TextEdit::Redo);
+        assert_eq!(input.value(), "hello");
+        assert_eq!(input.focus(), 5);
+    }
+
+    #[test]
+    fn space_ends_undo_group() {
+        let mut input = TestInput::new(None);
+        input.type_text("hi yo");
+        input.apply();
+
+        input.edit(TextEdit::Undo);
+        assert_eq!(input.value(), "hi ");
+
+        input.edit(TextEdit::Undo);
+        assert_eq!(input.value(), "");
+        assert!(!input.text.history.can_undo());
+    }
+
+    #[test]
+    fn masked_text() {
+        let mut input = TestInput::new(Some('•'));
+        input.type_text("pässword");
+        input.apply();
+        assert_eq!(input.text.editor.raw_text(), "••••••••");
+        assert_eq!(input.value(), "pässword");
+
+        // Deletions are applied to the real text too.
+        input.text.queue_edit(TextEdit::Left(false));
+        input.text.queue_edit(TextEdit::Backspace);
+        input.apply();
+        assert_eq!(input.text.editor.raw_text(), "•••••••");
+        assert_eq!(input.value(), "pässwod");
+    }
+
+    #[test]
+    fn copy_and_cut_ignored_while_masked() {
+        let mut input = TestInput::new(Some('*'));
+        input.type_text("secret");
+        input.text.queue_edit(TextEdit::SelectAll);
+        input.text.queue_edit(TextEdit::Copy);
+        input.text.queue_edit(TextEdit::Cut);
+        input.apply();
+        assert_eq!(input.value(), "secret");
+        assert_eq!(input.text.editor.raw_text(), "******");
+
+        #[cfg(not(feature = "system_clipboard"))]
+        {
+            let mut read = input.clipboard.fetch_text();
+            assert_eq!(read.poll_result().unwrap().unwrap(), "");
+        }
+    }
+
+    #[test]
+    fn toggling_mask_keeps_text_and_cursor() {
+        let mut input = TestInput::new(Some('•'));
+        input.type_text("abc");
+        input.text.queue_edit(TextEdit::Left(false));
+        input.apply();
+        // '•' is 3 bytes long
+        assert_eq!(input.focus(), 6);
+
+        input.text.mask.set(None);
+        input.apply();
+        assert_eq!(input.text.editor.raw_text(), "abc");
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.focus(), 2);
+
+        input.text.mask.set(Some('•'));
+        input.apply();
+        assert_eq!(input.text.editor.raw_text(), "•••");
+        assert_eq!(input.value(), "abc");
+        assert_eq!(input.focus(), 6);
+
+        // Editing continues at the cursor
+        input.type_text("x");
+        input.apply();
+        assert_eq!(input.value(), "abxc");
+    }
+
+    #[test]
+    fn validator_rejects_and_replaces() {
+        let mut input = TestInput::new(None);
+        let validator = |inserted: &str, result: &str| {
+            if inserted.chars().any(|c| c.is_ascii_digit()) || result.len() > 4 {
+                TextValidation::Reject
+            } else {
+                TextValidation::Replace(inserted.to_uppercase())
+            }
+        };
+        input.type_text("a1b");
+        input.apply_validated(validator);
+        assert_eq!(input.value(), "AB");
+
+        input.type_text("cde");
+        input.apply_validated(validator);
+        assert_eq!(input.value(), "ABCD");
+    }
+
+    #[cfg(not(feature = "system_clipboard"))]
+    #[test]
+    fn validator_applies_to_paste() {
+        let mut input = TestInput::new(None);
+        input.clipboard.set_text("paste").unwrap();
+        input.text.queue_edit(TextEdit::Paste);
+        input.apply_validated(|inserted, _| TextValidation::Replace(inserted.to_uppercase()));
+        assert_eq!(input.value(), "PASTE");
+
+        input.text.queue_edit(TextEdit::Paste);
+        input.apply_validated(|_, _| TextValidation::Reject);
+        assert_eq!(input.value(), "PASTE");
+    }
+}
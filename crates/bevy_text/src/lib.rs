@@ -33,6 +33,7 @@ extern crate alloc;
 
 mod bounds;
 mod cursor;
+mod edit_history;
 mod editing;
 mod error;
 mod font;
@@ -40,14 +41,18 @@ mod font_atlas;
 mod font_atlas_set;
 mod font_loader;
 mod glyph;
+#[cfg(feature = "localization")]
+mod localization;
 mod parley_context;
 mod pipeline;
+mod rich_text;
 mod text;
 mod text_access;
 mod text_edit;
 
 pub use bounds::*;
 pub use cursor::*;
+pub use edit_history::*;
 pub use editing::*;
 pub use error::*;
 pub use font::*;
@@ -55,8 +60,11 @@ pub use font_atlas::*;
 pub use font_atlas_set::*;
 pub use font_loader::*;
 pub use glyph::*;
+#[cfg(feature = "localization")]
+pub use localization::*;
 pub use parley_context::*;
 pub use pipeline::*;
+pub use rich_text::*;
 pub use text::*;
 pub use text_access::*;
 pub use text_edit::*;
@@ -113,6 +121,10 @@ impl Plugin for TextPlugin {
         if !app.is_plugin_added::<bevy_clipboard::ClipboardPlugin>() {
             app.add_plugins(bevy_clipboard::ClipboardPlugin);
         }
+        #[cfg(feature = "localization")]
+        if !app.is_plugin_added::<LocalizationPlugin>() {
+            app.add_plugins(LocalizationPlugin);
+        }
         app.init_asset::<Font>()
             .init_asset_loader::<FontLoader>()
             .init_resource::<FontAtlasSet>()
@@ -0,0 +1,557 @@
+//! Localization of text content using [Fluent](https://projectfluent.org/).
+//!
+//! Translations are written in Fluent (`.ftl`) files, loaded as [`FluentMessages`] assets and
+//! registered with the [`Localization`] resource under the locale they translate to. Entities with a
+//! [`LocalizedText`] component then have their text content set to the translated message, which
+//! is updated whenever the [`AssetLocale`] changes or the messages are (hot) reloaded.
+//!
+//! ```ftl
+//! # locales/en-US/ui.ftl
+//! greeting = Hello, { $name }!
+//! unread = { $count ->
+//!     [one] You have one unread message.
+//!    *[other] You have { $count } unread messages.
+//! }
+//! ```
+//!
+//! ```
+//! # use bevy_asset::AssetServer;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_text::{Localization, LocalizedText, TextSpan};
+//! fn setup(
+//!     mut commands: Commands,
+//!     asset_server: Res<AssetServer>,
+//!     mut localization: ResMut<Localization>,
+//! ) {
+//!     localization.set_default_locale("en-US");
+//!     localization.add_messages("en-US", asset_server.load("locales/en-US/ui.ftl"));
+//!     localization.add_messages("fr", asset_server.load("locales/fr/ui.ftl"));
+//!
+//!     commands.spawn((
+//!         TextSpan::default(),
+//!         LocalizedText::new("unread").with_arg("count", 3),
+//!     ));
+//! }
+//! ```
+
+use crate::{detect_text_needs_rerender, TextSection, TextSpan};
+use bevy_app::prelude::*;
+use bevy_asset::{
+    io::Reader, Asset, AssetApp, AssetEvent, AssetLoader, AssetLocale, Assets, Handle, LoadContext,
+};
+use bevy_ecs::prelude::*;
+use bevy_platform::{
+    collections::HashSet,
+    sync::{Arc, Mutex, PoisonError},
+};
+use bevy_reflect::prelude::*;
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use thiserror::Error;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+/// Adds support for localizing text with [Fluent](https://projectfluent.org/) messages.
+///
+/// This registers the [`FluentMessages`] asset, its loader and the [`Localization`] resource, and
+/// keeps the text of [`TextSpan`] entities with a [`LocalizedText`] component up to date. Root text
+/// components (such as `Text` and `Text2d`) are updated by their own plugins when their
+/// `localization` feature is enabled, using [`localize_text`].
+///
+/// The active locale is the [`AssetLocale`] resource of `bevy_asset`, so switching it both picks
+/// localized variants of assets and changes the language of localized text.
+///
+/// This plugin is added by [`TextPlugin`](crate::TextPlugin) when the `localization` feature is enabled.
+#[derive(Default)]
+pub struct LocalizationPlugin;
+
+/// System set in [`PostUpdate`] where [`LocalizedText`] is applied to text components.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct LocalizationSystems;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<FluentMessages>()
+            .init_asset_loader::<FluentLoader>()
+            .init_resource::<Localization>()
+            .configure_sets(
+                PostUpdate,
+                LocalizationSystems.before(detect_text_needs_rerender),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    update_localization.before(LocalizationSystems),
+                    localize_text::<TextSpan>.in_set(LocalizationSystems),
+                ),
+            );
+    }
+}
+
+/// A set of Fluent messages, loaded from a `.ftl` file.
+///
+/// Register it with [`Localization::add_messages`] to use its messages in [`LocalizedText`].
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct FluentMessages {
+    resource: Arc<FluentResource>,
+}
+
+impl FluentMessages {
+    /// Parses Fluent messages from `source`.
+    pub fn parse(source: String) -> Result<Self, FluentLoaderError> {
+        match FluentResource::try_new(source) {
+            Ok(resource) => Ok(Self {
+                resource: Arc::new(resource),
+            }),
+            Err((_, errors)) => Err(FluentLoaderError::Parse(
+                errors.iter().map(ToString::to_string).collect(),
+            )),
+        }
+    }
+}
+
+#[derive(Default, TypePath)]
+/// An [`AssetLoader`] for [`FluentMessages`], for use by the [`AssetServer`](bevy_asset::AssetServer)
+pub struct FluentLoader;
+
+/// Possible errors that can be produced by [`FluentLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum FluentLoaderError {
+    /// The file contains syntax errors.
+    #[error("Failed to parse Fluent messages: {}", .0.join(", "))]
+    Parse(Vec<String>),
+    /// The file is not valid UTF-8.
+    #[error(transparent)]
+    Utf8(#[from] alloc::string::FromUtf8Error),
+    /// An [IO](std::io) Error
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl AssetLoader for FluentLoader {
+    type Asset = FluentMessages;
+    type Settings = ();
+    type Error = FluentLoaderError;
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<FluentMessages, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        FluentMessages::parse(String::from_utf8(bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+/// The value of an argument of a [`LocalizedText`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+pub enum LocalizedArg {
+    /// A string, inserted as is.
+    String(String),
+    /// A number. Numbers can be used to select plural forms in messages.
+    Number(f64),
+}
+
+impl From<&str> for LocalizedArg {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for LocalizedArg {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for LocalizedArg {
+                fn from(value: $ty) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, u8, u16, u32, u64, usize, isize, f32, f64);
+
+/// Sets the content of the text component on the same entity (such as [`TextSpan`], `Text` or
+/// `Text2d`) to a localized message.
+///
+/// The `key` is the identifier of a message in the registered [`FluentMessages`], or
+/// `message.attribute` to use an attribute of a message. While the message is not available, the
+/// key itself is displayed.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, Clone, PartialEq)]
+pub struct LocalizedText {
+    /// The identifier of the message.
+    pub key: String,
+    /// The arguments passed to the message, by name.
+    pub args: Vec<(String, LocalizedArg)>,
+}
+
+impl LocalizedText {
+    /// Creates a [`LocalizedText`] for the message with the given key, without arguments.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Returns this [`LocalizedText`] with the argument `name` set to `value`.
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl Into<LocalizedArg>) -> Self {
+        self.set_arg(name, value);
+        self
+    }
+
+    /// Sets the argument `name` to `value`, replacing any previous value.
+    pub fn set_arg(&mut self, name: impl Into<String>, value: impl Into<LocalizedArg>) {
+        let name = name.into();
+        let value = value.into();
+        match self.args.iter_mut().find(|(arg, _)| *arg == name) {
+            Some((_, previous)) => *previous = value,
+            None => self.args.push((name, value)),
+        }
+    }
+}
+
+/// The Fluent messages used to localize text, by locale.
+///
+/// Messages are looked up in the locales of the [`AssetLocale`] fallback chain, followed by the
+/// [default locale](Localization::set_default_locale). The first locale that defines a message is
+/// used to format it.
+#[derive(Resource, Default)]
+pub struct Localization {
+    default_locale: Option<String>,
+    sources: Vec<(String, Handle<FluentMessages>)>,
+    bundles: Vec<FluentBundle<Arc<FluentResource>>>,
+    /// Whether all the messages for the current locales are loaded.
+    loaded: bool,
+    /// Whether `sources` or `default_locale` changed since the bundles were built.
+    dirty: bool,
+    missing_keys: Mutex<HashSet<String>>,
+}
+
+impl Localization {
+    /// Sets the locale used when a message is not available in any locale of the [`AssetLocale`].
+    pub fn set_default_locale(&mut self, locale: impl Into<String>) {
+        self.default_locale = Some(locale.into());
+        self.dirty = true;
+    }
+
+    /// Returns the locale used when a message is not available in any locale of the
+    /// [`AssetLocale`].
+    pub fn default_locale(&self) -> Option<&str> {
+        self.default_locale.as_deref()
+    }
+
+    /// Registers `messages` as translations for `locale`, such as `fr` or `en-US`.
+    ///
+    /// When several [`FluentMessages`] of the same locale define a message, the one added last
+    /// is used.
+    pub fn add_messages(&mut self, locale: impl Into<String>, messages: Handle<FluentMessages>) {
+        self.sources.push((locale.into(), messages));
+        self.dirty = true;
+    }
+
+    /// Returns `true` if all the messages for the current locales are loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    /// Formats the message with the given key (or `message.attribute`) with the given arguments.
+    ///
+    /// Returns [`None`] if no locale defines the message. Errors that occur while formatting the
+    /// message, such as a missing argument, are logged and the erroneous parts are replaced by
+    /// placeholders.
+    pub fn format(&self, key: &str, args: &[(String, LocalizedArg)]) -> Option<String> {
+        let (id, attribute) = match key.split_once('.') {
+            Some((id, attribute)) => (id, Some(attribute)),
+            None => (key, None),
+        };
+        let args = (!args.is_empty()).then(|| {
+            let mut fluent_args = FluentArgs::with_capacity(args.len());
+            for (name, value) in args {
+                let value = match value {
+                    LocalizedArg::String(string) => FluentValue::from(string.as_str()),
+                    LocalizedArg::Number(number) => FluentValue::from(*number),
+                };
+                fluent_args.set(name.as_str(), value);
+            }
+            fluent_args
+        });
+
+        self.bundles.iter().find_map(|bundle| {
+            let message = bundle.get_message(id)?;
+            let pattern = match attribute {
+                Some(attribute) => message.get_attribute(attribute)?.value(),
+                None => message.value()?,
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args.as_ref(), &mut errors);
+            for error in errors {
+                warn!("Error while formatting localized message `{key}`: {error}");
+            }
+            Some(text.into_owned())
+        })
+    }
+
+    /// Returns the keys of [`LocalizedText`] messages that could not be found in any locale since
+    /// all messages finished loading.
+    pub fn missing_keys(&self) -> Vec<String> {
+        self.missing_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Records that `key` is missing, warning the first time it is reported.
+    fn report_missing_key(&self, key: &str) {
+        if !self.loaded {
+            // The message may be defined by messages that are still loading.
+            return;
+        }
+        let mut missing_keys = self
+            .missing_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if !missing_keys.contains(key) {
+            warn!("Localized message `{key}` is not defined for any of the current locales.");
+            missing_keys.insert(key.into());
+        }
+    }
+
+    /// Rebuilds the bundles used to format messages for the given locales.
+    fn rebuild(&mut self, asset_locale: &AssetLocale, messages: &Assets<FluentMessages>) {
+        let mut locales = asset_locale.fallback_chain();
+        if let Some(default_locale) = self.default_locale.as_deref()
+            && !locales
+                .iter()
+                .any(|locale| locale.eq_ignore_ascii_case(default_locale))
+        {
+            locales.push(default_locale);
+        }
+
+        self.loaded = true;
+        self.bundles.clear();
+        for locale in locales {
+            let mut resources = Vec::new();
+            for (source_locale, handle) in &self.sources {
+                if !source_locale.eq_ignore_ascii_case(locale) {
+                    continue;
+                }
+                match messages.get(handle) {
+                    Some(messages) => resources.push(messages.resource.clone()),
+                    None => self.loaded = false,
+                }
+            }
+            if resources.is_empty() {
+                continue;
+            }
+
+            let language = locale
+                .parse::<LanguageIdentifier>()
+                .unwrap_or_else(|error| {
+                    warn!("Invalid locale `{locale}` used for localized messages: {error}");
+                    LanguageIdentifier::default()
+                });
+            let mut bundle = FluentBundle::new_concurrent(vec![language]);
+            // Unicode isolation marks are not supported by most fonts.
+            bundle.set_use_isolating(false);
+            for resource in resources {
+                bundle.add_resource_overriding(resource);
+            }
+            self.bundles.push(bundle);
+        }
+        self.dirty = false;
+        self.missing_keys
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+/// System that rebuilds the [`Localization`] when the [`AssetLocale`] or the registered
+/// [`FluentMessages`] change.
+pub fn update_localization(
+    mut localization: ResMut<Localization>,
+    asset_locale: Res<AssetLocale>,
+    messages: Res<Assets<FluentMessages>>,
+    mut events: MessageReader<AssetEvent<FluentMessages>>,
+) {
+    let messages_changed = events.read().any(|event| {
+        localization.sources.iter().any(|(_, handle)| {
+            event.is_loaded_with_dependencies(handle) || event.is_modified(handle)
+        })
+    });
+    if localization.dirty || asset_locale.is_changed() || messages_changed {
+        localization.rebuild(&asset_locale, &messages);
+    }
+}
+
+/// System that sets the content of `T` text components to their [`LocalizedText`].
+///
+/// Text is only updated when the [`LocalizedText`] or the [`Localization`] changes.
+pub fn localize_text<T: TextSection>(
+    localization: Res<Localization>,
+    mut query: Query<(Ref<LocalizedText>, &mut T)>,
+) {
+    let localization_changed = localization.is_changed();
+    for (localized, mut text) in &mut query {
+        if !localization_changed && !localized.is_changed() {
+            continue;
+        }
+        let value = localization
+            .format(&localized.key, &localized.args)
+            .unwrap_or_else(|| {
+                localization.report_missing_key(&localized.key);
+                localized.key.clone()
+            });
+        if text.get_text() != value {
+            *text.get_text_mut() = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: &str = "
+greeting = Hello, { $name }!
+unread = { $count ->
+    [one] You have one unread message.
+   *[other] You have { $count } unread messages.
+}
+";
+    const FR: &str = "greeting = Bonjour, { $name } !";
+
+    fn setup_test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Assets<FluentMessages>>()
+            .add_message::<AssetEvent<FluentMessages>>()
+            .init_resource::<AssetLocale>()
+            .init_resource::<Localization>()
+            .add_systems(
+                Update,
+                (update_localization, localize_text::<TextSpan>).chain(),
+            );
+        app
+    }
+
+    fn add_messages(app: &mut App, locale: &str, source: &str) {
+        let messages = FluentMessages::parse(source.into()).unwrap();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<FluentMessages>>()
+            .add(messages);
+        app.world_mut()
+            .resource_mut::<Localization>()
+            .add_messages(locale, handle);
+    }
+
+    fn text(app: &App, entity: Entity) -> &str {
+        &app.world().get::<TextSpan>(entity).unwrap().0
+    }
+
+    #[test]
+    fn parse_errors() {
+        let Err(FluentLoaderError::Parse(errors)) =
+            FluentMessages::parse("greeting = Hello\n!!! not a message".into())
+        else {
+            panic!("expected a parse error");
+        };
+        assert!(!errors.is_empty());
+        assert!(FluentMessages::parse(EN.into()).is_ok());
+    }
+
+    #[test]
+    fn format_args_and_plurals() {
+        let mut app = setup_test_app();
+        app.insert_resource(AssetLocale::new("en-US"));
+        add_messages(&mut app, "en", EN);
+        app.update();
+
+        let localization = app.world().resource::<Localization>();
+        assert!(localization.is_loaded());
+        let args = |count: i32| vec![("count".to_string(), LocalizedArg::from(count))];
+        assert_eq!(
+            localization
+                .format("greeting", &[("name".into(), "Bevy".into())])
+                .as_deref(),
+            Some("Hello, Bevy!")
+        );
+        assert_eq!(
+            localization.format("unread", &args(1)).as_deref(),
+            Some("You have one unread message.")
+        );
+        assert_eq!(
+            localization.format("unread", &args(3)).as_deref(),
+            Some("You have 3 unread messages.")
+        );
+        assert_eq!(localization.format("missing", &[]), None);
+    }
+
+    #[test]
+    fn missing_key_falls_back_to_key() {
+        let mut app = setup_test_app();
+        app.world_mut()
+            .resource_mut::<Localization>()
+            .set_default_locale("en");
+        add_messages(&mut app, "en", EN);
+        let entity = app
+            .world_mut()
+            .spawn((TextSpan::default(), LocalizedText::new("missing")))
+            .id();
+        app.update();
+
+        assert_eq!(text(&app, entity), "missing");
+        assert_eq!(
+            app.world().resource::<Localization>().missing_keys(),
+            vec!["missing".to_string()]
+        );
+    }
+
+    #[test]
+    fn locale_switch_updates_text() {
+        let mut app = setup_test_app();
+        app.insert_resource(AssetLocale::new("en"));
+        add_messages(&mut app, "en", EN);
+        add_messages(&mut app, "fr", FR);
+        let entity = app
+            .world_mut()
+            .spawn((
+                TextSpan::default(),
+                LocalizedText::new("greeting").with_arg("name", "Bevy"),
+            ))
+            .id();
+        app.update();
+        assert_eq!(text(&app, entity), "Hello, Bevy!");
+
+        app.insert_resource(AssetLocale::new("fr-FR"));
+        app.update();
+        assert_eq!(text(&app, entity), "Bonjour, Bevy !");
+
+        // Messages missing from the locale use the default locale.
+        app.world_mut()
+            .resource_mut::<Localization>()
+            .set_default_locale("en");
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(LocalizedText::new("unread").with_arg("count", 2));
+        app.update();
+        assert_eq!(text(&app, entity), "You have 2 unread messages.");
+    }
+}
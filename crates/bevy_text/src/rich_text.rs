@@ -0,0 +1,566 @@
+use core::ops::Range;
+
+use bevy_color::Color;
+use bevy_reflect::Reflect;
+use parley::Layout;
+
+use crate::{FontSize, FontSource, FontStyle, FontWeight, TextBrush};
+
+/// Formatting applied to a range of an [`EditableText`](crate::EditableText),
+/// on top of the entity's own [`TextFont`](crate::TextFont) and [`TextColor`](crate::TextColor).
+///
+/// Properties which are `None` are inherited from the entity.
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct RichTextStyle {
+    /// Overrides [`TextFont::font`](crate::TextFont::font).
+    pub font: Option<FontSource>,
+    /// Overrides [`TextFont::font_size`](crate::TextFont::font_size).
+    pub font_size: Option<FontSize>,
+    /// Overrides [`TextFont::weight`](crate::TextFont::weight).
+    pub weight: Option<FontWeight>,
+    /// Overrides [`TextFont::style`](crate::TextFont::style).
+    pub style: Option<FontStyle>,
+    /// Overrides the [`TextColor`](crate::TextColor).
+    pub color: Option<Color>,
+}
+
+// `FontSource` opts out of `Unpin` to specialize its template, which would otherwise
+// prevent types containing a `RichTextStyle` (such as `EditableText`) from using the
+// blanket `Clone + Default` template.
+impl Unpin for RichTextStyle {}
+
+impl RichTextStyle {
+    /// A style which only sets the weight to [`FontWeight::BOLD`].
+    pub fn bold() -> Self {
+        Self {
+            weight: Some(FontWeight::BOLD),
+            ..Default::default()
+        }
+    }
+
+    /// A style which only sets the font style to [`FontStyle::Italic`].
+    pub fn italic() -> Self {
+        Self {
+            style: Some(FontStyle::Italic),
+            ..Default::default()
+        }
+    }
+
+    /// A style which only sets the color.
+    pub fn color(color: impl Into<Color>) -> Self {
+        Self {
+            color: Some(color.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if no properties are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Sets each property which is set in `other`.
+    pub fn merge(&mut self, other: &Self) {
+        if other.font.is_some() {
+            self.font.clone_from(&other.font);
+        }
+        self.font_size = other.font_size.or(self.font_size);
+        self.weight = other.weight.or(self.weight);
+        self.style = other.style.or(self.style);
+        self.color = other.color.or(self.color);
+    }
+
+    /// Clears each property which is set in `other`.
+    pub fn remove(&mut self, other: &Self) {
+        if other.font.is_some() {
+            self.font = None;
+        }
+        if other.font_size.is_some() {
+            self.font_size = None;
+        }
+        if other.weight.is_some() {
+            self.weight = None;
+        }
+        if other.style.is_some() {
+            self.style = None;
+        }
+        if other.color.is_some() {
+            self.color = None;
+        }
+    }
+
+    /// Returns `true` if every property which is set in `other` is set to the same value in `self`.
+    pub fn contains(&self, other: &Self) -> bool {
+        (other.font.is_none() || self.font == other.font)
+            && (other.font_size.is_none() || self.font_size == other.font_size)
+            && (other.weight.is_none() || self.weight == other.weight)
+            && (other.style.is_none() || self.style == other.style)
+            && (other.color.is_none() || self.color == other.color)
+    }
+}
+
+/// A [`RichTextStyle`] applied to a range of text.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct StyleRun {
+    /// Byte range of the styled text.
+    pub range: Range<usize>,
+    /// The style of the text.
+    pub style: RichTextStyle,
+}
+
+/// The formatting of a text, as a sorted list of non-overlapping, non-empty [`StyleRun`]s.
+///
+/// Text which isn't covered by any run is unstyled.
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct TextStyleRuns(Vec<StyleRun>);
+
+impl TextStyleRuns {
+    /// The runs, sorted by their position in the text.
+    pub fn runs(&self) -> &[StyleRun] {
+        &self.0
+    }
+
+    /// Returns `true` if the text is unstyled.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The style of the byte at `index`, if it is styled.
+    pub fn style_at(&self, index: usize) -> Option<&RichTextStyle> {
+        self.0
+            .iter()
+            .find(|run| run.range.contains(&index))
+            .map(|run| &run.style)
+    }
+
+    /// Returns `true` if all of `range` has every property which is set in `style`.
+    pub fn contains(&self, range: Range<usize>, style: &RichTextStyle) -> bool {
+        if style.is_empty() {
+            return true;
+        }
+        let mut covered = range.start;
+        for run in &self.0 {
+            if run.range.end <= covered {
+                continue;
+            }
+            if run.range.start > covered || !run.style.contains(style) {
+                break;
+            }
+            covered = run.range.end;
+            if covered >= range.end {
+                break;
+            }
+        }
+        covered >= range.end
+    }
+
+    /// Modifies the style of every part of `range` with `f`, including unstyled parts.
+    pub fn update(&mut self, range: Range<usize>, f: impl Fn(&mut RichTextStyle)) {
+        if range.is_empty() {
+            return;
+        }
+        self.split_at(range.start);
+        self.split_at(range.end);
+
+        let mut updated = Vec::with_capacity(self.0.len() + 2);
+        let mut covered = range.start;
+        for mut run in core::mem::take(&mut self.0) {
+            if run.range.end <= range.start || run.range.start >= range.end {
+                updated.push(run);
+                continue;
+            }
+            if run.range.start > covered {
+                updated.push(Self::new_run(covered..run.range.start, &f));
+            }
+            covered = run.range.end;
+            f(&mut run.style);
+            updated.push(run);
+        }
+        if covered < range.end {
+            updated.push(Self::new_run(covered..range.end, &f));
+        }
+
+        updated.sort_by_key(|run| run.range.start);
+        self.0 = updated;
+        self.normalize();
+    }
+
+    /// Returns the runs within `range`, relative to the start of the range.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|run| run.range.start < range.end && run.range.end > range.start)
+                .map(|run| StyleRun {
+                    range: run.range.start.max(range.start) - range.start
+                        ..run.range.end.min(range.end) - range.start,
+                    style: run.style.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Merges the styles of `runs`, offset by `offset`, into these runs.
+    ///
+    /// This is the reverse of [`slice`](Self::slice).
+    pub fn apply_runs(&mut self, offset: usize, runs: &TextStyleRuns) {
+        for run in &runs.0 {
+            self.update(run.range.start + offset..run.range.end + offset, |style| {
+                style.merge(&run.style);
+            });
+        }
+    }
+
+    /// Updates the runs after the text in `range` has been replaced by `inserted` bytes of text.
+    ///
+    /// The inserted text takes the style of the text before it.
+    pub(crate) fn replace_range(&mut self, range: Range<usize>, inserted: usize) {
+        let removed = range.len();
+        let start = range.start;
+        let map_start = |index: usize| {
+            if index < start {
+                index
+            } else if index >= range.end {
+                index - removed + inserted
+            } else {
+                start + inserted
+            }
+        };
+        let map_end = |index: usize| {
+            if index < start {
+                index
+            } else if index == start || index < range.end {
+                start + inserted
+            } else {
+                index - removed + inserted
+            }
+        };
+        for run in &mut self.0 {
+            run.range = map_start(run.range.start)..map_end(run.range.end);
+        }
+        self.normalize();
+    }
+
+    fn new_run(range: Range<usize>, f: impl Fn(&mut RichTextStyle)) -> StyleRun {
+        let mut style = RichTextStyle::default();
+        f(&mut style);
+        StyleRun { range, style }
+    }
+
+    /// Splits the run containing `index`, if any, so that a run starts at `index`.
+    fn split_at(&mut self, index: usize) {
+        if let Some(position) = self
+            .0
+            .iter()
+            .position(|run| run.range.start < index && index < run.range.end)
+        {
+            let run = &mut self.0[position];
+            let tail = StyleRun {
+                range: index..run.range.end,
+                style: run.style.clone(),
+            };
+            run.range.end = index;
+            self.0.insert(position + 1, tail);
+        }
+    }
+
+    /// Removes empty runs, and merges adjacent runs with the same style.
+    fn normalize(&mut self) {
+        self.0
+            .retain(|run| !run.range.is_empty() && !run.style.is_empty());
+        self.0.dedup_by(|next, previous| {
+            let merge = previous.range.end == next.range.start && previous.style == next.style;
+            if merge {
+                previous.range.end = next.range.end;
+            }
+            merge
+        });
+    }
+}
+
+/// Rich text formatting of an [`EditableText`](crate::EditableText).
+///
+/// Ranges of text can be styled with [`TextEdit::ApplyStyle`](crate::TextEdit::ApplyStyle),
+/// [`TextEdit::ToggleStyle`](crate::TextEdit::ToggleStyle) and [`TextEdit::ClearStyle`](crate::TextEdit::ClearStyle),
+/// which apply to the selection, or to the next inserted text if the selection is collapsed.
+/// Styles are kept in place as the text is edited: inserted text takes the style of the text before it.
+///
+/// Copying styled text places its styles on the [`Clipboard`](bevy_clipboard::Clipboard) along with the plain text,
+/// so that pasting it into an input with styles enabled preserves them.
+/// Other applications only receive the plain text.
+#[derive(Clone, Default)]
+pub struct EditableTextStyles {
+    /// Allows styling the text.
+    ///
+    /// If `false`, style edits are ignored, and pasted text is unstyled.
+    pub enabled: bool,
+    pub(crate) runs: TextStyleRuns,
+    pub(crate) typing_style: Option<RichTextStyle>,
+    pub(crate) layout: Option<Layout<TextBrush>>,
+    /// Set when the runs change, so that the text is laid out again.
+    pub(crate) changed: bool,
+}
+
+impl EditableTextStyles {
+    /// Creates styles for an input which allows styling its text.
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// The styled runs of the text.
+    ///
+    /// The ranges are byte ranges into the editor's [raw text](parley::PlainEditor::raw_text).
+    pub fn runs(&self) -> &TextStyleRuns {
+        &self.runs
+    }
+
+    /// Replaces the styled runs of the text.
+    pub fn set_runs(&mut self, runs: TextStyleRuns) {
+        self.runs = runs;
+        self.changed = true;
+        self.layout = None;
+    }
+
+    /// The style which will be given to the next inserted text, if it has been set by a style edit
+    /// while the selection was collapsed.
+    pub fn typing_style(&self) -> Option<&RichTextStyle> {
+        self.typing_style.as_ref()
+    }
+
+    /// The layout of the styled text, if the text is styled.
+    ///
+    /// This is computed by the UI layout systems, and is used in place of the [`PlainEditor`](parley::PlainEditor)'s
+    /// own layout to place the cursor at a point. It is `None` until the layout has been updated after an edit.
+    pub fn layout(&self) -> Option<&Layout<TextBrush>> {
+        self.layout.as_ref()
+    }
+
+    /// Sets the layout of the styled text. This should only be called by layout systems.
+    pub fn set_layout(&mut self, layout: Option<Layout<TextBrush>>) {
+        self.layout = layout;
+    }
+
+    /// Clears the styles of the whole text.
+    pub fn clear(&mut self) {
+        self.set_runs(TextStyleRuns::default());
+        self.typing_style = None;
+    }
+
+    /// Does this need to follow changes to the text?
+    pub(crate) fn is_tracking(&self) -> bool {
+        !self.runs.is_empty() || self.typing_style.is_some()
+    }
+
+    /// Updates the runs after the text changed from `old` to `new`.
+    ///
+    /// `hint` is the start of the selection before the change, which disambiguates where the change happened
+    /// when the text is repetitive.
+    pub(crate) fn track_change(&mut self, old: &str, new: &str, hint: usize) {
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(old.len().min(new.len()), |((index, _), _)| index)
+            .min(hint);
+        let max_suffix = (old.len() - prefix).min(new.len() - prefix);
+        let suffix = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .scan(0, |total, len| {
+                *total += len;
+                (*total <= max_suffix).then_some(*total)
+            })
+            .last()
+            .unwrap_or(0);
+        if old.len() == new.len() && prefix + suffix == old.len() {
+            return;
+        }
+
+        let inserted = new.len() - suffix - prefix;
+        self.runs
+            .replace_range(prefix..old.len() - suffix, inserted);
+        if inserted > 0
+            && let Some(typing_style) = self.typing_style.take()
+        {
+            self.runs.update(prefix..prefix + inserted, |style| {
+                *style = typing_style.clone();
+            });
+        }
+        self.changed = true;
+        self.layout = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(range: Range<usize>, style: RichTextStyle) -> StyleRun {
+        StyleRun { range, style }
+    }
+
+    fn bold_italic() -> RichTextStyle {
+        let mut style = RichTextStyle::bold();
+        style.merge(&RichTextStyle::italic());
+        style
+    }
+
+    fn styles(runs: TextStyleRuns) -> EditableTextStyles {
+        EditableTextStyles {
+            runs,
+            ..EditableTextStyles::enabled()
+        }
+    }
+
+    #[test]
+    fn update_splits_and_merges_runs() {
+        let mut runs = TextStyleRuns::default();
+        runs.update(2..5, |style| style.merge(&RichTextStyle::bold()));
+        runs.update(5..8, |style| style.merge(&RichTextStyle::bold()));
+        assert_eq!(runs.runs(), &[run(2..8, RichTextStyle::bold())]);
+
+        runs.update(3..4, |style| style.merge(&RichTextStyle::italic()));
+        assert_eq!(
+            runs.runs(),
+            &[
+                run(2..3, RichTextStyle::bold()),
+                run(3..4, bold_italic()),
+                run(4..8, RichTextStyle::bold()),
+            ]
+        );
+
+        // Unstyled parts of the range get new runs, and removing the style merges the runs again.
+        runs.update(0..10, |style| style.merge(&RichTextStyle::italic()));
+        assert_eq!(
+            runs.runs(),
+            &[
+                run(0..2, RichTextStyle::italic()),
+                run(2..8, bold_italic()),
+                run(8..10, RichTextStyle::italic()),
+            ]
+        );
+        runs.update(0..10, |style| style.remove(&RichTextStyle::italic()));
+        assert_eq!(runs.runs(), &[run(2..8, RichTextStyle::bold())]);
+    }
+
+    #[test]
+    fn contains_partially_styled_range() {
+        let mut runs = TextStyleRuns::default();
+        runs.update(2..5, |style| style.merge(&RichTextStyle::bold()));
+        runs.update(5..7, |style| style.merge(&bold_italic()));
+
+        let bold = RichTextStyle::bold();
+        assert!(runs.contains(2..7, &bold));
+        assert!(runs.contains(3..4, &bold));
+        assert!(!runs.contains(0..5, &bold));
+        assert!(!runs.contains(4..8, &bold));
+        assert!(!runs.contains(2..7, &RichTextStyle::italic()));
+        assert!(runs.contains(5..7, &RichTextStyle::italic()));
+        assert!(runs.contains(0..10, &RichTextStyle::default()));
+    }
+
+    #[test]
+    fn insertion_takes_preceding_style() {
+        let mut runs = TextStyleRuns::default();
+        runs.update(0..3, |style| style.merge(&RichTextStyle::bold()));
+
+        // Inserting at the end of the run extends it.
+        runs.replace_range(3..3, 2);
+        assert_eq!(runs.runs(), &[run(0..5, RichTextStyle::bold())]);
+
+        // Inserting at the start of the text shifts it.
+        runs.replace_range(0..0, 1);
+        assert_eq!(runs.runs(), &[run(1..6, RichTextStyle::bold())]);
+    }
+
+    #[test]
+    fn deletion_across_runs() {
+        let mut runs = TextStyleRuns::default();
+        runs.update(0..3, |style| style.merge(&RichTextStyle::bold()));
+        runs.update(3..6, |style| style.merge(&RichTextStyle::italic()));
+        runs.update(6..9, |style| style.merge(&RichTextStyle::bold()));
+
+        runs.replace_range(2..7, 0);
+        assert_eq!(runs.runs(), &[run(0..4, RichTextStyle::bold())]);
+    }
+
+    #[test]
+    fn slice_and_apply_runs() {
+        let mut runs = TextStyleRuns::default();
+        runs.update(2..5, |style| style.merge(&RichTextStyle::bold()));
+        runs.update(7..9, |style| style.merge(&RichTextStyle::italic()));
+
+        let slice = runs.slice(4..8);
+        assert_eq!(
+            slice.runs(),
+            &[
+                run(0..1, RichTextStyle::bold()),
+                run(3..4, RichTextStyle::italic()),
+            ]
+        );
+
+        let mut pasted = TextStyleRuns::default();
+        pasted.apply_runs(10, &slice);
+        assert_eq!(
+            pasted.runs(),
+            &[
+                run(10..11, RichTextStyle::bold()),
+                run(13..14, RichTextStyle::italic()),
+            ]
+        );
+    }
+
+    #[test]
+    fn track_change_multi_byte() {
+        let mut runs = TextStyleRuns::default();
+        // "é" in "aéb"
+        runs.update(1..3, |style| style.merge(&RichTextStyle::bold()));
+        let mut styles = styles(runs);
+
+        styles.track_change("aéb", "aüéb", 1);
+        assert_eq!(styles.runs().runs(), &[run(3..5, RichTextStyle::bold())]);
+        assert_eq!(&"aüéb"[3..5], "é");
+
+        styles.track_change("aüéb", "aéb", 1);
+        assert_eq!(styles.runs().runs(), &[run(1..3, RichTextStyle::bold())]);
+        assert!(styles.changed);
+    }
+
+    #[test]
+    fn track_change_uses_hint_in_repetitive_text() {
+        let mut runs = TextStyleRuns::default();
+        // The middle "a"
+        runs.update(1..2, |style| style.merge(&RichTextStyle::bold()));
+
+        // Typed at the start: the styled "a" moves.
+        let mut at_start = styles(runs.clone());
+        at_start.track_change("aaa", "aaaa", 0);
+        assert_eq!(at_start.runs().runs(), &[run(2..3, RichTextStyle::bold())]);
+
+        // Typed at the end: the styled "a" stays.
+        let mut at_end = styles(runs);
+        at_end.track_change("aaa", "aaaa", 3);
+        assert_eq!(at_end.runs().runs(), &[run(1..2, RichTextStyle::bold())]);
+    }
+
+    #[test]
+    fn track_change_applies_typing_style() {
+        let mut styles = styles(TextStyleRuns::default());
+        styles.typing_style = Some(RichTextStyle::italic());
+        assert!(styles.is_tracking());
+
+        styles.track_change("ab", "axyb", 1);
+        assert_eq!(styles.runs().runs(), &[run(1..3, RichTextStyle::italic())]);
+        assert!(styles.typing_style().is_none());
+
+        // Text typed after the styled text continues its style.
+        styles.track_change("axyb", "axyzb", 3);
+        assert_eq!(styles.runs().runs(), &[run(1..4, RichTextStyle::italic())]);
+    }
+}
@@ -1,10 +1,11 @@
 use bevy_clipboard::ClipboardRead;
 use bevy_math::Vec2;
 use bevy_reflect::Reflect;
+use core::task::Poll;
 use parley::PlainEditorDriver;
 use smol_str::SmolStr;
 
-use crate::TextBrush;
+use crate::{RichTextStyle, TextBrush};
 
 /// A selection within IME preedit text, expressed as byte offsets from the start of the preedit.
 ///
@@ -199,6 +200,32 @@ pub enum TextEdit {
         /// The committed text to insert at the cursor.
         value: SmolStr,
     },
+    /// Reverts the last change to the text, as recorded in [`EditableText::history`](crate::EditableText::history).
+    ///
+    /// Typically generated in response to Ctrl + Z or Cmd + Z.
+    Undo,
+    /// Reapplies the last change reverted by [`TextEdit::Undo`].
+    ///
+    /// Typically generated in response to Ctrl + Y, Ctrl + Shift + Z or Cmd + Shift + Z.
+    Redo,
+    /// Sets the properties of the given style on the selection,
+    /// or on the next inserted text if the selection is collapsed.
+    ///
+    /// Ignored unless [`EditableTextStyles::enabled`](crate::EditableTextStyles::enabled) is set.
+    ApplyStyle(RichTextStyle),
+    /// Removes the properties of the given style from the selection if all of it already has them,
+    /// and otherwise sets them, like [`TextEdit::ApplyStyle`].
+    ///
+    /// Ignored unless [`EditableTextStyles::enabled`](crate::EditableTextStyles::enabled) is set.
+    ///
+    /// Typically generated in response to Ctrl + B or Cmd + B (with [`RichTextStyle::bold`]),
+    /// and Ctrl + I or Cmd + I (with [`RichTextStyle::italic`]).
+    ToggleStyle(RichTextStyle),
+    /// Removes all styles from the selection,
+    /// or from the next inserted text if the selection is collapsed.
+    ///
+    /// Ignored unless [`EditableTextStyles::enabled`](crate::EditableTextStyles::enabled) is set.
+    ClearStyle,
 }
 
 impl TextEdit {
@@ -313,19 +340,27 @@ impl TextEdit {
                     driver.insert_or_replace_selection(text.as_str());
                 }
             }
+            // The edit history and styles are stored on `EditableText`, and are handled by `apply_pending_edits`.
+            TextEdit::Undo
+            | TextEdit::Redo
+            | TextEdit::ApplyStyle(_)
+            | TextEdit::ToggleStyle(_)
+            | TextEdit::ClearStyle => {}
         }
     }
 }
 
 /// Reason an [`insert_filtered`] call was rejected.
 ///
-/// The two branches matter to callers (paste warns on [`CharFilter`](Self::CharFilter) but
+/// The branches matter to callers (paste warns on [`CharFilter`](Self::CharFilter) but
 /// not on [`MaxLength`](Self::MaxLength)), so a bool return wouldn't suffice.
-enum InsertRejection {
+pub(crate) enum InsertRejection {
     /// At least one character failed the user-supplied filter.
     CharFilter,
     /// The insertion would exceed `max_characters`.
     MaxLength,
+    /// The [`EditableTextValidator`](crate::EditableTextValidator) rejected the insertion.
+    Validator,
 }
 
 /// Insert (or replace the current selection with) `text`, subject to the char filter and
@@ -356,34 +391,55 @@ fn insert_filtered(
     Ok(())
 }
 
+/// Polls a clipboard read, logging any error.
+///
+/// Returns [`Poll::Ready`] when the read has resolved, with the clipboard text if it succeeded.
+/// Returns [`Poll::Pending`] when the read is still pending
+/// and the caller should hold onto the [`ClipboardRead`] to poll again on a later frame.
+pub(crate) fn poll_paste(read: &mut ClipboardRead) -> Poll<Option<String>> {
+    match read.poll_result() {
+        Some(Ok(text)) => Poll::Ready(Some(text)),
+        Some(Err(e)) => {
+            bevy_log::warn!("Failed to read clipboard for paste: {e:?}");
+            Poll::Ready(None)
+        }
+        None => Poll::Pending,
+    }
+}
+
+/// Logs why a paste was rejected, if it's worth mentioning.
+pub(crate) fn log_paste_rejection(rejection: &InsertRejection) {
+    match rejection {
+        InsertRejection::CharFilter => bevy_log::debug!(
+            "Paste rejected: clipboard contents contained characters not allowed by the char filter."
+        ),
+        InsertRejection::Validator => {
+            bevy_log::debug!("Paste rejected: clipboard contents were rejected by the validator.");
+        }
+        InsertRejection::MaxLength => {}
+    }
+}
+
 /// Polls a clipboard read and, if ready, applies the resulting text as a paste.
 ///
 /// Returns `true` when the read has resolved (applied, filter-rejected, or errored)
 /// and the caller should move on.
 /// Returns `false` when the read is still pending
 /// and the caller should hold onto the [`ClipboardRead`] to poll again on a later frame.
-pub(crate) fn poll_and_apply_paste(
+fn poll_and_apply_paste(
     read: &mut ClipboardRead,
     driver: &mut PlainEditorDriver<TextBrush>,
     max_characters: Option<usize>,
     char_filter: impl Fn(char) -> bool,
 ) -> bool {
-    match read.poll_result() {
-        Some(Ok(text)) => {
-            if matches!(
-                insert_filtered(driver, &text, max_characters, char_filter),
-                Err(InsertRejection::CharFilter)
-            ) {
-                bevy_log::debug!(
-                    "Paste rejected: clipboard contents contained characters not allowed by the char filter."
-                );
+    match poll_paste(read) {
+        Poll::Ready(Some(text)) => {
+            if let Err(rejection) = insert_filtered(driver, &text, max_characters, char_filter) {
+                log_paste_rejection(&rejection);
             }
             true
         }
-        Some(Err(e)) => {
-            bevy_log::warn!("Failed to read clipboard for paste: {e:?}");
-            true
-        }
-        None => false,
+        Poll::Ready(None) => true,
+        Poll::Pending => false,
     }
 }
@@ -80,3 +80,22 @@ pub(crate) fn on_remove_checked(remove: On<Remove, Checked>, mut world: Deferred
         accessibility.set_toggled(accesskit::Toggled::False);
     }
 }
+
+/// Component that indicates that an item in a list, tree or other selectable collection is
+/// selected.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Selected;
+
+pub(crate) fn on_add_selected(add: On<Add, Selected>, mut world: DeferredWorld) {
+    let mut entity = world.entity_mut(add.entity);
+    if let Some(mut accessibility) = entity.get_mut::<AccessibilityNode>() {
+        accessibility.set_selected(true);
+    }
+}
+
+pub(crate) fn on_remove_selected(remove: On<Remove, Selected>, mut world: DeferredWorld) {
+    let mut entity = world.entity_mut(remove.entity);
+    if let Some(mut accessibility) = entity.get_mut::<AccessibilityNode>() {
+        accessibility.clear_selected();
+    }
+}
@@ -21,6 +21,7 @@ pub mod widget;
 pub mod gradients;
 #[cfg(feature = "bevy_picking")]
 pub mod picking_backend;
+pub mod transition;
 pub mod ui_transform;
 
 use bevy_derive::{Deref, DerefMut};
@@ -41,9 +42,10 @@ use bevy_text::{detect_text_needs_rerender, EditableTextSystems};
 pub use focus::*;
 pub use geometry::*;
 pub use gradients::*;
-pub use interaction_states::{Checkable, Checked, InteractionDisabled, Pressed};
+pub use interaction_states::{Checkable, Checked, InteractionDisabled, Pressed, Selected};
 pub use layout::*;
 pub use measurement::*;
+pub use transition::*;
 pub use ui_node::*;
 pub use ui_transform::*;
 pub use widget::TextNodeFlags;
@@ -62,6 +64,7 @@ pub mod prelude {
         crate::{
             geometry::*,
             gradients::*,
+            transition::*,
             ui_node::*,
             ui_transform::*,
             widget::{Button, ImageNode, Label, NodeImageMode, ViewportNode},
@@ -81,7 +84,8 @@ use bevy_transform::TransformSystems;
 use layout::ui_surface::UiSurface;
 use stack::ui_stack_system;
 pub use stack::{ComputedStackIndex, UiStack};
-use update::{propagate_ui_target_cameras, update_clipping_system};
+use transition::animate_transitions;
+use update::{propagate_ui_target_cameras, update_clipping_system, update_opacity_system};
 
 /// The basic plugin for Bevy UI
 #[derive(Default)]
@@ -194,6 +198,15 @@ impl Plugin for UiPlugin {
                     .ambiguous_with(bevy_sprite::update_text2d_layout),
                 ui_stack_system.in_set(UiSystems::Stack),
                 update_clipping_system.in_set(UiSystems::PostLayout),
+                update_opacity_system.in_set(UiSystems::PostLayout),
+                (
+                    animate_transitions::<BackgroundColor>,
+                    animate_transitions::<BorderColor>,
+                    animate_transitions::<Node>,
+                    animate_transitions::<UiTransform>,
+                    animate_transitions::<UiOpacity>,
+                )
+                    .in_set(UiSystems::Prepare),
                 // Potential conflicts: `Assets<Image>`
                 // They run independently since `widget::image_node_system` will only ever observe
                 // its own ImageNode, and `widget::text_system` & `bevy_text::update_text2d_layout`
@@ -278,7 +291,9 @@ fn build_text_interop(app: &mut App) {
         .add_observer(interaction_states::on_add_checkable)
         .add_observer(interaction_states::on_remove_checkable)
         .add_observer(interaction_states::on_add_checked)
-        .add_observer(interaction_states::on_remove_checked);
+        .add_observer(interaction_states::on_remove_checked)
+        .add_observer(interaction_states::on_add_selected)
+        .add_observer(interaction_states::on_remove_selected);
 
     app.configure_sets(
         PostUpdate,
@@ -292,4 +307,12 @@ fn build_text_interop(app: &mut App) {
 
     // We cannot set this up in bevy_text as this would create a circular dependency between bevy_ui and bevy_text
     app.configure_sets(PostUpdate, EditableTextSystems.in_set(UiSystems::Content));
+
+    #[cfg(feature = "localization")]
+    app.add_systems(
+        PostUpdate,
+        bevy_text::localize_text::<widget::Text>
+            .in_set(bevy_text::LocalizationSystems)
+            .run_if(resource_exists::<bevy_text::Localization>),
+    );
 }
@@ -0,0 +1,465 @@
+//! Animated transitions between the style values of UI nodes.
+//!
+//! See [`Transition`] for details.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use bevy_color::{Alpha, Color, Mix, Oklaba};
+use bevy_ecs::{component::Mutable, prelude::*};
+use bevy_math::{curve::Curve, curve::EaseFunction, FloatExt};
+use bevy_reflect::prelude::*;
+use bevy_time::{Real, Time};
+
+use crate::{BackgroundColor, BorderColor, Node, UiOpacity, UiTransform, Val, Val2};
+
+/// Animates changes to the style of a UI node.
+///
+/// Without a `Transition`, changing a style component such as [`BackgroundColor`] takes effect
+/// on the next frame. With one, the displayed value eases from its previous value to the new one
+/// over the duration configured for that [`TransitionProperty`], so that (for example) hover and
+/// focus states can fade in and out rather than snapping.
+///
+/// Transitions are started by simply changing the component: there is no need to know whether
+/// the node is animated. If the value is changed again mid-transition, a new transition starts from
+/// the currently displayed value.
+///
+/// Transitions are advanced using [`Time<Real>`], so they keep running while virtual time is paused.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use bevy_color::palettes::basic::BLUE;
+/// # use bevy_math::curve::EaseFunction;
+/// # use core::time::Duration;
+/// fn spawn_button(mut commands: Commands) {
+///     commands.spawn((
+///         Node::default(),
+///         BackgroundColor(BLUE.into()),
+///         Transition::new(
+///             TransitionProperty::BackgroundColor,
+///             Duration::from_millis(150),
+///             EaseFunction::CubicOut,
+///         )
+///         .with_property(
+///             TransitionProperty::Transform,
+///             Duration::from_millis(100),
+///             EaseFunction::QuadraticInOut,
+///         ),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, Clone, PartialEq)]
+pub struct Transition {
+    /// The animated properties. Properties not in this list change immediately.
+    pub properties: Vec<PropertyTransition>,
+}
+
+impl Transition {
+    /// Creates a transition which animates a single property.
+    pub fn new(property: TransitionProperty, duration: Duration, easing: EaseFunction) -> Self {
+        Self::default().with_property(property, duration, easing)
+    }
+
+    /// Creates a transition which animates every [`TransitionProperty`] with the same timing.
+    pub fn all(duration: Duration, easing: EaseFunction) -> Self {
+        TransitionProperty::ALL
+            .into_iter()
+            .fold(Self::default(), |transition, property| {
+                transition.with_property(property, duration, easing)
+            })
+    }
+
+    /// Animates `property`, replacing any previous timing for it.
+    pub fn with_property(
+        mut self,
+        property: TransitionProperty,
+        duration: Duration,
+        easing: EaseFunction,
+    ) -> Self {
+        self.properties
+            .retain(|transition| transition.property != property);
+        self.properties.push(PropertyTransition {
+            property,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Returns the timing of `property`, or `None` if it isn't animated.
+    pub fn get(&self, property: TransitionProperty) -> Option<&PropertyTransition> {
+        self.properties
+            .iter()
+            .find(|transition| transition.property == property)
+    }
+}
+
+/// The timing of a single animated property of a [`Transition`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+pub struct PropertyTransition {
+    /// The animated property.
+    pub property: TransitionProperty,
+    /// How long it takes to reach a new value.
+    pub duration: Duration,
+    /// The easing curve used to interpolate between the old and new value.
+    pub easing: EaseFunction,
+}
+
+/// A style property of a UI node which can be animated by a [`Transition`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+pub enum TransitionProperty {
+    /// The [`BackgroundColor`] of the node.
+    BackgroundColor,
+    /// The [`BorderColor`] of the node.
+    BorderColor,
+    /// The lengths of the [`Node`]: its size constraints, position, margin, padding,
+    /// border widths, border radius, gaps and flex basis.
+    ///
+    /// Lengths are only interpolated if the old and new [`Val`] use the same unit,
+    /// otherwise they change immediately.
+    Node,
+    /// The [`UiTransform`] of the node.
+    Transform,
+    /// The [`UiOpacity`] of the node.
+    Opacity,
+}
+
+impl TransitionProperty {
+    /// Every animatable property.
+    pub const ALL: [Self; 5] = [
+        Self::BackgroundColor,
+        Self::BorderColor,
+        Self::Node,
+        Self::Transform,
+        Self::Opacity,
+    ];
+}
+
+/// A component which can be animated by a [`Transition`].
+pub(crate) trait Transitionable:
+    Component<Mutability = Mutable> + Clone + PartialEq
+{
+    /// The property of a [`Transition`] which animates this component.
+    const PROPERTY: TransitionProperty;
+
+    /// Interpolates between `self` and `target`, where `t` is the eased progress.
+    fn interpolate(&self, target: &Self, t: f32) -> Self;
+
+    /// Returns the target of a new transition, after the component was changed from the
+    /// displayed value `current` to `self` while transitioning to `target`.
+    fn retarget(&self, _current: &Self, _target: &Self) -> Self {
+        self.clone()
+    }
+}
+
+/// The progress of the [`Transition`] of a component `C`.
+#[derive(Component)]
+pub(crate) struct TransitionState<C: Transitionable> {
+    from: C,
+    to: C,
+    /// The last value written to the component.
+    current: C,
+    elapsed: Duration,
+}
+
+/// Animates components of type `C` towards their most recently set value.
+///
+/// Changes made to the component by other systems are detected by comparing it against the
+/// value written on the previous run, then that value is restored and eased towards the new one.
+pub(crate) fn animate_transitions<C: Transitionable>(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &Transition, Mut<C>, Option<&mut TransitionState<C>>)>,
+) {
+    let delta = time.delta();
+    for (entity, transition, mut value, state) in &mut query {
+        let Some(timing) = transition.get(C::PROPERTY) else {
+            continue;
+        };
+
+        let Some(mut state) = state else {
+            // The initial value is displayed immediately.
+            commands.entity(entity).try_insert(TransitionState {
+                from: value.clone(),
+                to: value.clone(),
+                current: value.clone(),
+                elapsed: Duration::MAX,
+            });
+            continue;
+        };
+
+        if value.is_changed() && *value != state.current {
+            let target = value.retarget(&state.current, &state.to);
+            if state.current.interpolate(&target, 0.) == target {
+                // Only fields which aren't animated were changed
+                state.from = target.clone();
+                state.to = target.clone();
+                state.current = target;
+                state.elapsed = Duration::MAX;
+                continue;
+            }
+            state.from = state.current.clone();
+            state.to = target;
+            state.elapsed = Duration::ZERO;
+        } else if state.elapsed >= timing.duration {
+            continue;
+        }
+
+        state.elapsed = state.elapsed.saturating_add(delta).min(timing.duration);
+        let progress = if timing.duration.is_zero() {
+            1.
+        } else {
+            state.elapsed.as_secs_f32() / timing.duration.as_secs_f32()
+        };
+        let next = if progress >= 1. {
+            state.to.clone()
+        } else {
+            let t = timing.easing.sample_clamped(progress);
+            state.from.interpolate(&state.to, t)
+        };
+        state.current = next.clone();
+        *value = next;
+    }
+}
+
+/// Interpolates between two colors in [`Oklaba`] space.
+///
+/// If either color is fully transparent, only the alpha is interpolated,
+/// so that fading in or out doesn't pass through black.
+fn mix_color(from: Color, to: Color, t: f32) -> Color {
+    let mut from = Oklaba::from(from);
+    let mut to = Oklaba::from(to);
+    if from.alpha == 0. {
+        from = to.with_alpha(0.);
+    } else if to.alpha == 0. {
+        to = from.with_alpha(0.);
+    }
+    from.mix(&to, t).into()
+}
+
+/// Interpolates between two lengths with the same unit. Lengths with different units can't be
+/// interpolated, and return `to`.
+fn lerp_val(from: Val, to: Val, t: f32) -> Val {
+    match (from, to) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a.lerp(b, t)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a.lerp(b, t)),
+        (Val::Vw(a), Val::Vw(b)) => Val::Vw(a.lerp(b, t)),
+        (Val::Vh(a), Val::Vh(b)) => Val::Vh(a.lerp(b, t)),
+        (Val::VMin(a), Val::VMin(b)) => Val::VMin(a.lerp(b, t)),
+        (Val::VMax(a), Val::VMax(b)) => Val::VMax(a.lerp(b, t)),
+        _ => to,
+    }
+}
+
+impl Transitionable for BackgroundColor {
+    const PROPERTY: TransitionProperty = TransitionProperty::BackgroundColor;
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self(mix_color(self.0, target.0, t))
+    }
+}
+
+impl Transitionable for BorderColor {
+    const PROPERTY: TransitionProperty = TransitionProperty::BorderColor;
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            top: mix_color(self.top, target.top, t),
+            right: mix_color(self.right, target.right, t),
+            bottom: mix_color(self.bottom, target.bottom, t),
+            left: mix_color(self.left, target.left, t),
+        }
+    }
+}
+
+impl Transitionable for UiTransform {
+    const PROPERTY: TransitionProperty = TransitionProperty::Transform;
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self {
+            translation: Val2 {
+                x: lerp_val(self.translation.x, target.translation.x, t),
+                y: lerp_val(self.translation.y, target.translation.y, t),
+            },
+            scale: self.scale.lerp(target.scale, t),
+            rotation: self.rotation.slerp(target.rotation, t),
+        }
+    }
+}
+
+impl Transitionable for UiOpacity {
+    const PROPERTY: TransitionProperty = TransitionProperty::Opacity;
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Self(self.0.lerp(target.0, t))
+    }
+}
+
+/// Calls `$m!(field)` for every length of a [`Node`].
+macro_rules! for_each_node_length {
+    ($m:ident) => {
+        $m!(left);
+        $m!(right);
+        $m!(top);
+        $m!(bottom);
+        $m!(width);
+        $m!(height);
+        $m!(min_width);
+        $m!(min_height);
+        $m!(max_width);
+        $m!(max_height);
+        $m!(margin.left);
+        $m!(margin.right);
+        $m!(margin.top);
+        $m!(margin.bottom);
+        $m!(padding.left);
+        $m!(padding.right);
+        $m!(padding.top);
+        $m!(padding.bottom);
+        $m!(border.left);
+        $m!(border.right);
+        $m!(border.top);
+        $m!(border.bottom);
+        $m!(border_radius.top_left);
+        $m!(border_radius.top_right);
+        $m!(border_radius.bottom_right);
+        $m!(border_radius.bottom_left);
+        $m!(flex_basis);
+        $m!(row_gap);
+        $m!(column_gap);
+    };
+}
+
+impl Transitionable for Node {
+    const PROPERTY: TransitionProperty = TransitionProperty::Node;
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        // Everything other than the lengths is taken from the target.
+        let mut node = target.clone();
+        macro_rules! lerp {
+            ($($field:ident).+) => {
+                node.$($field).+ = lerp_val(self.$($field).+, target.$($field).+, t);
+            };
+        }
+        for_each_node_length!(lerp);
+        node
+    }
+
+    fn retarget(&self, current: &Self, target: &Self) -> Self {
+        // Lengths which weren't changed (e.g. when only `display` was set) keep their previous target.
+        let mut node = self.clone();
+        macro_rules! keep_target {
+            ($($field:ident).+) => {
+                if self.$($field).+ == current.$($field).+ {
+                    node.$($field).+ = target.$($field).+;
+                }
+            };
+        }
+        for_each_node_length!(keep_target);
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_app::{App, Update};
+    use bevy_color::Color;
+    use bevy_math::curve::EaseFunction;
+    use bevy_time::{Real, Time};
+
+    use super::animate_transitions;
+    use crate::{BackgroundColor, Display, Node, Transition, TransitionProperty, Val};
+
+    fn setup_test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>();
+        app.add_systems(
+            Update,
+            (
+                animate_transitions::<BackgroundColor>,
+                animate_transitions::<Node>,
+            ),
+        );
+        app
+    }
+
+    fn advance(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::from_millis(millis));
+        app.update();
+    }
+
+    #[test]
+    fn transition_background_color() {
+        let mut app = setup_test_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                BackgroundColor(Color::BLACK),
+                Transition::new(
+                    TransitionProperty::BackgroundColor,
+                    Duration::from_millis(100),
+                    EaseFunction::Linear,
+                ),
+            ))
+            .id();
+        advance(&mut app, 0);
+        assert_eq!(
+            app.world().get::<BackgroundColor>(entity).unwrap().0,
+            Color::BLACK
+        );
+
+        app.world_mut()
+            .get_mut::<BackgroundColor>(entity)
+            .unwrap()
+            .0 = Color::WHITE;
+        advance(&mut app, 50);
+        let halfway = app.world().get::<BackgroundColor>(entity).unwrap().0;
+        assert_ne!(halfway, Color::BLACK);
+        assert_ne!(halfway, Color::WHITE);
+
+        advance(&mut app, 50);
+        assert_eq!(
+            app.world().get::<BackgroundColor>(entity).unwrap().0,
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn transition_node_lengths_keep_target() {
+        let mut app = setup_test_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Node {
+                    width: Val::Px(0.),
+                    ..Default::default()
+                },
+                Transition::new(
+                    TransitionProperty::Node,
+                    Duration::from_millis(100),
+                    EaseFunction::Linear,
+                ),
+            ))
+            .id();
+        advance(&mut app, 0);
+
+        app.world_mut().get_mut::<Node>(entity).unwrap().width = Val::Px(100.);
+        advance(&mut app, 50);
+        assert_eq!(app.world().get::<Node>(entity).unwrap().width, Val::Px(50.));
+
+        // Changing another field doesn't interrupt the transition to the new width
+        app.world_mut().get_mut::<Node>(entity).unwrap().display = Display::Grid;
+        advance(&mut app, 100);
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.width, Val::Px(100.));
+        assert_eq!(node.display, Display::Grid);
+    }
+}
@@ -2411,6 +2411,46 @@ pub struct CalculatedClip {
     pub clip: Rect,
 }
 
+/// The opacity of a UI node and all of its descendants.
+///
+/// Opacity is multiplied down the hierarchy: a node with an opacity of `0.5`, inside a node with an
+/// opacity of `0.5`, is drawn at a quarter of its normal alpha. The computed result is stored in
+/// [`CalculatedOpacity`].
+///
+/// Opacity is applied to backgrounds, borders, images, text, gradients and box shadows,
+/// but not to nodes rendered with a custom `UiMaterial`.
+#[derive(Component, Copy, Clone, Debug, Deref, DerefMut, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiOpacity(pub f32);
+
+impl UiOpacity {
+    /// Fully opaque.
+    pub const OPAQUE: Self = Self(1.);
+    /// Fully transparent.
+    pub const TRANSPARENT: Self = Self(0.);
+}
+
+impl Default for UiOpacity {
+    fn default() -> Self {
+        Self::OPAQUE
+    }
+}
+
+/// The opacity of a node after multiplying together the [`UiOpacity`] of the node and its ancestors.
+///
+/// Only present on nodes with a calculated opacity below `1.0`.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+pub struct CalculatedOpacity {
+    /// The opacity, between `0.0` and `1.0`.
+    pub opacity: f32,
+}
+
 /// UI node entities with this component will ignore any clipping rect they inherit,
 /// the node will not be clipped regardless of its ancestors' `Overflow` setting.
 #[derive(Component, Clone, Default)]
@@ -3,16 +3,17 @@
 use crate::{
     experimental::{UiChildren, UiRootNodes},
     ui_transform::UiGlobalTransform,
-    CalculatedClip, ComputedUiRenderTargetInfo, ComputedUiTargetCamera, DefaultUiCamera, Display,
-    Node, OverrideClip, UiScale, UiTargetCamera,
+    CalculatedClip, CalculatedOpacity, ComputedUiRenderTargetInfo, ComputedUiTargetCamera,
+    DefaultUiCamera, Display, Node, OverrideClip, UiOpacity, UiScale, UiTargetCamera,
 };
 
 use super::ComputedNode;
 use bevy_app::Propagate;
 use bevy_camera::Camera;
 use bevy_ecs::{
+    change_detection::DetectChangesMut,
     entity::Entity,
-    query::Has,
+    query::{Has, With},
     system::{Commands, Query, Res},
 };
 use bevy_math::{Rect, UVec2};
@@ -112,6 +113,51 @@ fn update_clipping(
     }
 }
 
+/// Updates [`CalculatedOpacity`] for all nodes
+pub fn update_opacity_system(
+    mut commands: Commands,
+    root_nodes: UiRootNodes,
+    mut node_query: Query<(Option<&UiOpacity>, Option<&mut CalculatedOpacity>), With<Node>>,
+    ui_children: UiChildren,
+) {
+    for root_node in root_nodes.iter() {
+        update_opacity(&mut commands, &ui_children, &mut node_query, root_node, 1.);
+    }
+}
+
+fn update_opacity(
+    commands: &mut Commands,
+    ui_children: &UiChildren,
+    node_query: &mut Query<(Option<&UiOpacity>, Option<&mut CalculatedOpacity>), With<Node>>,
+    entity: Entity,
+    inherited_opacity: f32,
+) {
+    let Ok((maybe_opacity, maybe_calculated_opacity)) = node_query.get_mut(entity) else {
+        return;
+    };
+
+    let opacity = maybe_opacity.map_or(inherited_opacity, |opacity| {
+        inherited_opacity * opacity.0.clamp(0., 1.)
+    });
+
+    if opacity < 1. {
+        if let Some(mut calculated_opacity) = maybe_calculated_opacity {
+            calculated_opacity.set_if_neq(CalculatedOpacity { opacity });
+        } else {
+            commands
+                .entity(entity)
+                .try_insert(CalculatedOpacity { opacity });
+        }
+    } else if maybe_calculated_opacity.is_some() {
+        // Fully opaque, remove the component
+        commands.entity(entity).remove::<CalculatedOpacity>();
+    }
+
+    for child in ui_children.iter_ui_children(entity) {
+        update_opacity(commands, ui_children, node_query, child, opacity);
+    }
+}
+
 pub fn propagate_ui_target_cameras(
     mut commands: Commands,
     default_ui_camera: DefaultUiCamera,
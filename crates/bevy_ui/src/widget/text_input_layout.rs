@@ -18,11 +18,11 @@ use bevy_platform::hash::FixedHasher;
 use bevy_text::{
     add_glyph_to_atlas, get_glyph_atlas_info, resolve_font_source, EditableText,
     EditableTextGeneration, Font, FontAtlasKey, FontAtlasSet, FontCx, FontHinting, FontSize,
-    GlyphCacheKey, LayoutCx, LineBreak, LineHeight, PositionedGlyph, RemSize, RunGeometry, ScaleCx,
-    TextBrush, TextFont, TextLayout, TextLayoutInfo,
+    GlyphCacheKey, LayoutCx, LineBreak, LineHeight, Placeholder, PositionedGlyph, RemSize,
+    RunGeometry, ScaleCx, TextBrush, TextFont, TextLayout, TextLayoutInfo,
 };
 use bevy_time::{Real, Time};
-use parley::{BoundingBox, PositionedLayoutItem, StyleProperty};
+use parley::{AlignmentOptions, BoundingBox, PositionedLayoutItem, StyleProperty};
 use swash::FontRef;
 use taffy::MaybeMath;
 
@@ -250,8 +250,12 @@ pub fn update_editable_text_styles(
 
 /// Refreshes the [`EditableText`]'s layout if stale and then writes it
 /// it to [`TextLayoutInfo`] for rendering and picking.
+/// While the [`EditableText`] is empty, the layout of its [`Placeholder`] is written instead.
+/// If the text has styled ranges, it is laid out with those styles and the layout is stored in
+/// [`EditableText::styles`].
 /// Adds required glyphs to the texture atlas
 pub fn update_editable_text_layout(
+    fonts: Res<Assets<Font>>,
     mut font_cx: ResMut<FontCx>,
     mut layout_cx: ResMut<LayoutCx>,
     mut scale_cx: ResMut<ScaleCx>,
@@ -266,6 +270,8 @@ pub fn update_editable_text_layout(
         &mut TextLayoutInfo,
         Ref<ComputedNode>,
         &mut EditableTextGeneration,
+        &TextLayout,
+        Option<Ref<Placeholder>>,
     )>,
     rem_size: Res<RemSize>,
     input_focus: Option<Res<InputFocus>>,
@@ -283,6 +289,8 @@ pub fn update_editable_text_layout(
         mut info,
         computed_node,
         mut generation,
+        text_layout,
+        placeholder,
     ) in input_field_query.iter_mut()
     {
         let cursor_width = editable_text.cursor_width;
@@ -294,21 +302,104 @@ pub fn update_editable_text_layout(
                 .set_width(Some(computed_node.content_box().width()));
         }
 
-        let mut driver = editable_text
+        editable_text
             .editor
-            .driver(&mut font_cx.0, &mut layout_cx.0);
-
-        driver.refresh_layout();
+            .refresh_layout(&mut font_cx.0, &mut layout_cx.0);
+        let editable_text = &mut *editable_text;
+        let editor = &editable_text.editor;
 
-        let compose_range = driver.editor.raw_compose().clone();
+        let compose_range = editor.raw_compose().clone();
 
-        let layout_changed = driver.editor.generation() != **generation;
+        let layout_changed = editor.generation() != **generation;
         if layout_changed {
-            **generation = driver.editor.generation();
+            **generation = editor.generation();
         }
 
-        if layout_changed || hinting.is_changed() {
-            let layout = driver.layout();
+        let placeholder = placeholder
+            .filter(|placeholder| !placeholder.text.is_empty() && editor.raw_text().is_empty());
+
+        if layout_changed
+            || hinting.is_changed()
+            || placeholder
+                .as_ref()
+                .is_some_and(|placeholder| placeholder.is_changed() || computed_node.is_changed())
+        {
+            // The placeholder is laid out with the same styles as the editor's text.
+            let placeholder_layout = placeholder.map(|placeholder| {
+                let mut builder = layout_cx.0.ranged_builder(
+                    &mut font_cx.0,
+                    &placeholder.text,
+                    editor.get_scale(),
+                    true,
+                );
+                for property in editor.get_styles().inner().values() {
+                    builder.push_default(property.clone());
+                }
+                let mut layout = builder.build(&placeholder.text);
+                layout.break_all_lines(Some(computed_node.content_box().width()));
+                layout.align(text_layout.justify.into(), AlignmentOptions::default());
+                layout
+            });
+            // The editor only supports uniform styles, so styled text is laid out separately.
+            let rich_layout = (placeholder_layout.is_none()
+                && !editable_text.styles.runs().is_empty())
+            .then(|| {
+                let text = editor.raw_text();
+                let mut builder =
+                    layout_cx
+                        .0
+                        .ranged_builder(&mut font_cx.0, text, editor.get_scale(), true);
+                for property in editor.get_styles().inner().values() {
+                    builder.push_default(property.clone());
+                }
+                if let Some(compose_range) = &compose_range {
+                    builder.push(StyleProperty::Underline(true), compose_range.clone());
+                }
+                for (index, run) in editable_text.styles.runs().runs().iter().enumerate() {
+                    let style = &run.style;
+                    let range = run.range.clone();
+                    if let Some(font) = &style.font
+                        && let Ok(family) = resolve_font_source(font, fonts.as_ref())
+                    {
+                        builder.push(StyleProperty::FontFamily(family), range.clone());
+                    }
+                    if let Some(font_size) = style.font_size {
+                        builder.push(
+                            StyleProperty::FontSize(
+                                font_size.eval(target.logical_size(), rem_size.0),
+                            ),
+                            range.clone(),
+                        );
+                    }
+                    if let Some(weight) = style.weight {
+                        builder.push(StyleProperty::FontWeight(weight.into()), range.clone());
+                    }
+                    if let Some(font_style) = style.style {
+                        builder.push(StyleProperty::FontStyle(font_style.into()), range.clone());
+                    }
+                    // Section 0 is the editor's default style, so runs start at 1.
+                    builder.push(
+                        StyleProperty::Brush(TextBrush::new(
+                            index as u32 + 1,
+                            text_font.font_smoothing,
+                        )),
+                        range,
+                    );
+                }
+                let mut layout = builder.build(text);
+                layout.break_all_lines(Some(computed_node.content_box().width()));
+                layout.align(text_layout.justify.into(), AlignmentOptions::default());
+                layout
+            });
+            editable_text.styles.set_layout(rich_layout);
+            let editor = &editable_text.editor;
+            let Some(layout) = placeholder_layout
+                .as_ref()
+                .or(editable_text.styles.layout())
+                .or(editor.try_layout())
+            else {
+                continue;
+            };
 
             info.scale_factor = layout.scale();
             info.size = (layout.full_width(), layout.height()).into();
@@ -440,14 +531,28 @@ pub fn update_editable_text_layout(
                 }
             }
 
-            info.selection_rects = driver
-                .editor
-                .selection_geometry()
-                .iter()
-                .map(|&b| bounding_box_to_rect(b.0))
-                .collect();
+            info.selection_rects = match editable_text.styles.layout() {
+                Some(layout) => editor.raw_selection().refresh(layout).geometry(layout),
+                None => editor.selection_geometry(),
+            }
+            .iter()
+            .map(|&b| bounding_box_to_rect(b.0))
+            .collect();
         }
 
+        let editor = &editable_text.editor;
+        let cursor_geometry = |width| {
+            let geometry = editor.cursor_geometry(width)?;
+            Some(match editable_text.styles.layout() {
+                Some(layout) => editor
+                    .raw_selection()
+                    .focus()
+                    .refresh(layout)
+                    .geometry(layout, width),
+                None => geometry,
+            })
+        };
+
         if let Some(input_focus) = input_focus.as_ref()
             && Some(entity) == input_focus.get()
         {
@@ -455,17 +560,13 @@ pub fn update_editable_text_layout(
                 *cursor_timer = Duration::ZERO;
             }
 
-            info.cursor = driver
-                .editor
-                .cursor_geometry(
-                    cursor_width * text_font.font_size.eval(target.logical_size(), rem_size.0),
-                )
-                .map(bounding_box_to_rect)
-                .map(|rect| (*cursor_timer < cursor_blink_period / 2, rect));
+            info.cursor = cursor_geometry(
+                cursor_width * text_font.font_size.eval(target.logical_size(), rem_size.0),
+            )
+            .map(bounding_box_to_rect)
+            .map(|rect| (*cursor_timer < cursor_blink_period / 2, rect));
         } else {
-            info.cursor = driver
-                .editor
-                .cursor_geometry(0.)
+            info.cursor = cursor_geometry(0.)
                 .map(bounding_box_to_rect)
                 .map(|rect| (false, rect));
         }
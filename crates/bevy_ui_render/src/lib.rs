@@ -9,6 +9,7 @@
 
 pub mod box_shadow;
 mod gradient;
+mod opacity;
 mod pipeline;
 pub mod render_pass;
 mod text;
@@ -66,8 +67,9 @@ use gradient::GradientPlugin;
 
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_text::{
-    ComputedTextBlock, PositionedGlyph, Strikethrough, StrikethroughColor, TextBackgroundColor,
-    TextColor, TextCursorStyle, TextLayoutInfo, Underline, UnderlineColor,
+    ComputedTextBlock, EditableText, Placeholder, PositionedGlyph, Strikethrough,
+    StrikethroughColor, TextBackgroundColor, TextColor, TextCursorStyle, TextLayoutInfo, Underline,
+    UnderlineColor,
 };
 use bevy_transform::components::GlobalTransform;
 use box_shadow::BoxShadowPlugin;
@@ -79,6 +81,7 @@ pub use render_pass::*;
 pub use ui_material_pipeline::*;
 use ui_texture_slice_pipeline::UiTextureSlicerPlugin;
 
+use crate::opacity::extract_ui_opacity;
 use crate::shader_flags::INVERT;
 use crate::text::{extract_preedit_underlines, extract_text_cursor};
 
@@ -134,6 +137,8 @@ pub enum RenderUiSystems {
     ExtractCursor,
     ExtractDebug,
     ExtractGradient,
+    /// Applies the [`CalculatedOpacity`](bevy_ui::CalculatedOpacity) of nodes to everything extracted for them.
+    ExtractOpacity,
 }
 
 /// Marker for controlling whether UI is rendered with or without anti-aliasing
@@ -234,6 +239,13 @@ impl Plugin for UiRenderPlugin {
                 )
                     .chain(),
             )
+            .configure_sets(
+                ExtractSchedule,
+                RenderUiSystems::ExtractOpacity
+                    .after(RenderUiSystems::ExtractDebug)
+                    .after(RenderUiSystems::ExtractViewportNodes)
+                    .after(RenderUiSystems::ExtractGradient),
+            )
             .add_systems(RenderStartup, init_ui_pipeline)
             .add_systems(
                 ExtractSchedule,
@@ -252,6 +264,7 @@ impl Plugin for UiRenderPlugin {
                     extract_preedit_underlines.in_set(RenderUiSystems::ExtractCursor),
                     #[cfg(feature = "bevy_ui_debug")]
                     debug_overlay::extract_debug_overlay.in_set(RenderUiSystems::ExtractDebug),
+                    extract_ui_opacity.in_set(RenderUiSystems::ExtractOpacity),
                 ),
             )
             .add_systems(
@@ -969,6 +982,8 @@ pub fn extract_text_sections(
             &TextLayoutInfo,
             Option<&TextScroll>,
             Option<&TextCursorStyle>,
+            Option<&EditableText>,
+            Option<&Placeholder>,
         )>,
     >,
     text_styles: Extract<Query<&TextColor>>,
@@ -991,6 +1006,8 @@ pub fn extract_text_sections(
         text_layout_info,
         text_scroll,
         cursor_style,
+        editable_text,
+        placeholder,
     ) in &uinode_query
     {
         // Skip if not visible or if size is set to zero (e.g. when a parent is set to `Display::None`)
@@ -1020,6 +1037,17 @@ pub fn extract_text_sections(
 
         let mut color = text_color.0.to_linear();
 
+        // An empty `EditableText` displays its placeholder instead.
+        if let Some(editable_text) = editable_text
+            && let Some(placeholder) = placeholder
+            && editable_text.editor.raw_text().is_empty()
+        {
+            color = placeholder
+                .color
+                .unwrap_or_else(|| text_color.0.with_alpha(text_color.0.alpha() * 0.5))
+                .to_linear();
+        }
+
         let selected_text_color = cursor_style
             .and_then(|cursor_style| cursor_style.selected_text_color)
             .map(|selected_text_color| selected_text_color.to_linear());
@@ -1037,6 +1065,17 @@ pub fn extract_text_sections(
         ) in text_layout_info.glyphs.iter().enumerate()
         {
             if current_section_index != *section_index
+                && let Some(editable_text) = editable_text
+            {
+                // Sections after the first are the styled runs of the text.
+                color = section_index
+                    .checked_sub(1)
+                    .and_then(|index| editable_text.styles.runs().runs().get(index))
+                    .and_then(|run| run.style.color)
+                    .unwrap_or(text_color.0)
+                    .to_linear();
+                current_section_index = *section_index;
+            } else if current_section_index != *section_index
                 && let Some(section_entity) = computed_block
                     .entities()
                     .get(*section_index)
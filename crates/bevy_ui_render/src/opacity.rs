@@ -0,0 +1,72 @@
+use bevy_color::{Alpha, LinearRgba};
+use bevy_ecs::prelude::*;
+use bevy_render::{sync_world::MainEntity, Extract};
+use bevy_ui::CalculatedOpacity;
+
+use crate::{
+    box_shadow::ExtractedBoxShadows,
+    gradient::{ExtractedColorStops, ExtractedGradients},
+    ui_texture_slice_pipeline::ExtractedUiTextureSlices,
+    ExtractedUiItem, ExtractedUiNodes,
+};
+
+/// Applies the [`CalculatedOpacity`] of UI nodes to everything extracted for them.
+///
+/// Runs after all other UI extraction systems.
+pub fn extract_ui_opacity(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut extracted_box_shadows: ResMut<ExtractedBoxShadows>,
+    extracted_gradients: Res<ExtractedGradients>,
+    mut extracted_color_stops: ResMut<ExtractedColorStops>,
+    mut extracted_slices: ResMut<ExtractedUiTextureSlices>,
+    opacity_query: Extract<Query<&CalculatedOpacity>>,
+) {
+    if opacity_query.is_empty() {
+        return;
+    }
+
+    let opacity_of = |main_entity: MainEntity| {
+        opacity_query
+            .get(main_entity.id())
+            .ok()
+            .map(|calculated_opacity| calculated_opacity.opacity)
+    };
+    let fade = |color: &mut LinearRgba, opacity: f32| {
+        color.set_alpha(color.alpha() * opacity);
+    };
+
+    let ExtractedUiNodes { uinodes, glyphs } = &mut *extracted_uinodes;
+    for uinode in uinodes.iter_mut() {
+        let Some(opacity) = opacity_of(uinode.main_entity) else {
+            continue;
+        };
+        match &mut uinode.item {
+            ExtractedUiItem::Node { color, .. } => fade(color, opacity),
+            ExtractedUiItem::Glyphs { range } => {
+                for glyph in &mut glyphs[range.clone()] {
+                    fade(&mut glyph.color, opacity);
+                }
+            }
+        }
+    }
+
+    for shadow in &mut extracted_box_shadows.box_shadows {
+        if let Some(opacity) = opacity_of(shadow.main_entity) {
+            fade(&mut shadow.color, opacity);
+        }
+    }
+
+    for gradient in &extracted_gradients.items {
+        if let Some(opacity) = opacity_of(gradient.main_entity) {
+            for (color, ..) in &mut extracted_color_stops.0[gradient.stops_range.clone()] {
+                fade(color, opacity);
+            }
+        }
+    }
+
+    for slice in &mut extracted_slices.slices {
+        if let Some(opacity) = opacity_of(slice.main_entity) {
+            fade(&mut slice.color, opacity);
+        }
+    }
+}
@@ -0,0 +1,577 @@
+//! Standard widget components for data tables, such as profiler or statistics screens.
+//!
+//! A data table is made of a [`DataTable`] entity, which holds the column definitions, with two
+//! descendants: a [`DataTableHeader`] row containing a [`DataTableHeaderCell`] for each column,
+//! and a [`DataTableBody`], which is a [`VirtualList`] whose rows are the rows of the table. The
+//! header and the rows are laid out with [`Display::Grid`], using the widths of the columns as
+//! the grid tracks, so the cells of the header and of every row line up. Like any
+//! [`VirtualList`], the body only keeps entities for the visible rows, and triggers a
+//! [`VirtualListBind`] event on a row when the app should fill in its cells.
+//!
+//! The table uses external state management for sorting and resizing:
+//!
+//! - Clicking a sortable header cell emits a [`ValueChange<ColumnSort>`] event on the table, and
+//!   the app is expected to update the [`DataTableSort`] component (or use
+//!   [`data_table_self_sort`]). When the sort changes, every row of the body is bound again, so
+//!   that the app can fill it in with the sorted data.
+//! - Dragging a [`DataTableResizeHandle`] emits [`ColumnResize`] events on the table, and the app
+//!   is expected to update the column widths of the [`DataTable`] (or use
+//!   [`data_table_self_resize`]).
+//!
+//! Row selection is handled by the [`VirtualList`] of the body, see [`VirtualListSelection`].
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    lifecycle::Add,
+    observer::On,
+    query::{Has, With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+    world::Ref,
+};
+use bevy_picking::events::{Cancel, Click, Drag, DragEnd, DragStart, Pointer};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_ui::{Display, InteractionDisabled, Node, RepeatedGridTrack, UiScale, UiSystems};
+
+use crate::{ValueChange, VirtualList, VirtualListBind, VirtualListRow};
+
+#[cfg(doc)]
+use crate::VirtualListSelection;
+
+/// The definition of a column of a [`DataTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default, PartialEq, Clone)]
+pub struct TableColumn {
+    /// The width of the column, in logical pixels.
+    pub width: f32,
+    /// The smallest width the column can be resized to, in logical pixels.
+    pub min_width: f32,
+    /// Whether clicking the header of the column sorts the table by this column.
+    pub sortable: bool,
+    /// Whether the column can be resized by dragging its [`DataTableResizeHandle`].
+    pub resizable: bool,
+}
+
+impl Default for TableColumn {
+    fn default() -> Self {
+        Self {
+            width: 100.,
+            min_width: 24.,
+            sortable: true,
+            resizable: true,
+        }
+    }
+}
+
+impl TableColumn {
+    /// Construct a new sortable, resizable column with the given width.
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the smallest width the column can be resized to.
+    pub fn with_min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether the column is sortable.
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the column is resizable.
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// Headless widget implementation for a data table. See the [module documentation](self) for an
+/// overview of how tables are structured.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Table)), DataTableSort)]
+#[reflect(Component, Default)]
+pub struct DataTable {
+    /// The columns of the table, in order.
+    pub columns: Vec<TableColumn>,
+}
+
+impl DataTable {
+    /// Construct a new table with the given columns.
+    pub fn new(columns: impl IntoIterator<Item = TableColumn>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+        }
+    }
+
+    /// Returns the grid tracks for the columns of the table.
+    pub fn grid_template_columns(&self) -> Vec<RepeatedGridTrack> {
+        self.columns
+            .iter()
+            .map(|column| RepeatedGridTrack::px(1, column.width))
+            .collect()
+    }
+}
+
+/// The direction in which a [`DataTable`] is sorted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, PartialEq, Hash, Clone)]
+pub enum SortDirection {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The column a [`DataTable`] is sorted by, and in which direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(PartialEq, Hash, Clone)]
+pub struct ColumnSort {
+    /// The index of the column.
+    pub column: usize,
+    /// The sort direction.
+    pub direction: SortDirection,
+}
+
+/// The current sort of a [`DataTable`], if it is sorted.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct DataTableSort(pub Option<ColumnSort>);
+
+/// The header row of a [`DataTable`]. This should be a descendant of the table, and contain a
+/// [`DataTableHeaderCell`] for each column.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Row)))]
+#[reflect(Component, Default)]
+pub struct DataTableHeader;
+
+/// A cell of the [`DataTableHeader`] of a table. Clicking the cell sorts the table by its column,
+/// if the column is sortable.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::ColumnHeader)))]
+#[reflect(Component, Default)]
+pub struct DataTableHeaderCell {
+    /// The index of the column.
+    pub column: usize,
+}
+
+/// The body of a [`DataTable`], which is a [`VirtualList`] containing the rows of the table. This
+/// should be a descendant of the table, and its [`VirtualList::item_count`] should be the number
+/// of rows.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[require(VirtualList)]
+#[reflect(Component, Default)]
+pub struct DataTableBody;
+
+/// A handle that resizes a column of a [`DataTable`] when dragged horizontally. This is usually
+/// placed at the right edge of a [`DataTableHeaderCell`].
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[require(ColumnResizeState)]
+#[reflect(Component, Default)]
+pub struct DataTableResizeHandle {
+    /// The index of the column.
+    pub column: usize,
+}
+
+/// Drag state of a [`DataTableResizeHandle`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ColumnResizeState {
+    /// The table being resized, while a drag is in progress.
+    table: Option<Entity>,
+    /// The width of the column when the drag started.
+    start_width: f32,
+}
+
+/// Event triggered on a [`DataTable`] while the user drags a [`DataTableResizeHandle`].
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct ColumnResize {
+    /// The table.
+    #[event_target]
+    pub table: Entity,
+    /// The index of the column being resized.
+    pub column: usize,
+    /// The new width of the column, in logical pixels.
+    pub width: f32,
+    /// True if this is the end of the drag.
+    pub is_final: bool,
+}
+
+/// Returns the nearest [`DataTable`] ancestor of `entity`.
+fn find_table(
+    entity: Entity,
+    q_parents: &Query<&ChildOf>,
+    q_table: &Query<(&DataTable, &DataTableSort, Has<InteractionDisabled>)>,
+) -> Option<Entity> {
+    q_parents
+        .iter_ancestors(entity)
+        .find(|ancestor| q_table.contains(*ancestor))
+}
+
+fn data_table_header_on_click(
+    mut click: On<Pointer<Click>>,
+    q_cell: Query<&DataTableHeaderCell>,
+    q_parents: Query<&ChildOf>,
+    q_table: Query<(&DataTable, &DataTableSort, Has<InteractionDisabled>)>,
+    mut commands: Commands,
+) {
+    let Ok(cell) = q_cell.get(click.entity) else {
+        return;
+    };
+    let Some(table_id) = find_table(click.entity, &q_parents, &q_table) else {
+        return;
+    };
+    click.propagate(false);
+    let Ok((table, sort, disabled)) = q_table.get(table_id) else {
+        return;
+    };
+    if disabled || !table.columns.get(cell.column).is_some_and(|c| c.sortable) {
+        return;
+    }
+    // Clicking the column the table is sorted by reverses the direction.
+    let direction = match sort.0 {
+        Some(sort) if sort.column == cell.column => sort.direction.reverse(),
+        _ => SortDirection::Ascending,
+    };
+    commands.trigger(ValueChange {
+        source: table_id,
+        value: ColumnSort {
+            column: cell.column,
+            direction,
+        },
+        is_final: true,
+    });
+}
+
+fn data_table_resize_on_drag_start(
+    mut drag_start: On<Pointer<DragStart>>,
+    mut q_handle: Query<(&DataTableResizeHandle, &mut ColumnResizeState)>,
+    q_parents: Query<&ChildOf>,
+    q_table: Query<(&DataTable, &DataTableSort, Has<InteractionDisabled>)>,
+) {
+    let Ok((handle, mut state)) = q_handle.get_mut(drag_start.entity) else {
+        return;
+    };
+    drag_start.propagate(false);
+    let Some(table_id) = find_table(drag_start.entity, &q_parents, &q_table) else {
+        return;
+    };
+    let Ok((table, _, disabled)) = q_table.get(table_id) else {
+        return;
+    };
+    if let Some(column) = table.columns.get(handle.column)
+        && column.resizable
+        && !disabled
+    {
+        state.table = Some(table_id);
+        state.start_width = column.width;
+    }
+}
+
+fn data_table_resize_on_drag(
+    mut drag: On<Pointer<Drag>>,
+    q_handle: Query<(&DataTableResizeHandle, &ColumnResizeState)>,
+    q_table: Query<&DataTable>,
+    ui_scale: Res<UiScale>,
+    mut commands: Commands,
+) {
+    let Ok((handle, state)) = q_handle.get(drag.entity) else {
+        return;
+    };
+    drag.propagate(false);
+    let Some(table_id) = state.table else {
+        return;
+    };
+    if let Ok(table) = q_table.get(table_id) {
+        emit_column_resize(
+            &mut commands,
+            table_id,
+            table,
+            handle,
+            state,
+            drag.distance.x / ui_scale.0,
+            false,
+        );
+    }
+}
+
+fn data_table_resize_on_drag_end(
+    mut drag_end: On<Pointer<DragEnd>>,
+    mut q_handle: Query<(&DataTableResizeHandle, &mut ColumnResizeState)>,
+    q_table: Query<&DataTable>,
+    ui_scale: Res<UiScale>,
+    mut commands: Commands,
+) {
+    let Ok((handle, mut state)) = q_handle.get_mut(drag_end.entity) else {
+        return;
+    };
+    drag_end.propagate(false);
+    let Some(table_id) = state.table.take() else {
+        return;
+    };
+    if let Ok(table) = q_table.get(table_id) {
+        emit_column_resize(
+            &mut commands,
+            table_id,
+            table,
+            handle,
+            &state,
+            drag_end.distance.x / ui_scale.0,
+            true,
+        );
+    }
+}
+
+fn data_table_resize_on_cancel(
+    mut cancel: On<Pointer<Cancel>>,
+    mut q_handle: Query<&mut ColumnResizeState, With<DataTableResizeHandle>>,
+) {
+    if let Ok(mut state) = q_handle.get_mut(cancel.entity) {
+        cancel.propagate(false);
+        state.table = None;
+    }
+}
+
+/// Clicks on a resize handle shouldn't sort the table.
+fn data_table_resize_on_click(
+    mut click: On<Pointer<Click>>,
+    q_handle: Query<(), With<DataTableResizeHandle>>,
+) {
+    if q_handle.contains(click.entity) {
+        click.propagate(false);
+    }
+}
+
+fn emit_column_resize(
+    commands: &mut Commands,
+    table_id: Entity,
+    table: &DataTable,
+    handle: &DataTableResizeHandle,
+    state: &ColumnResizeState,
+    distance: f32,
+    is_final: bool,
+) {
+    let Some(column) = table.columns.get(handle.column) else {
+        return;
+    };
+    let width = (state.start_width + distance).max(column.min_width);
+    if width != column.width || is_final {
+        commands.trigger(ColumnResize {
+            table: table_id,
+            column: handle.column,
+            width,
+            is_final,
+        });
+    }
+}
+
+/// Lays out the rows of a [`DataTableBody`] as grid rows when they are spawned.
+fn data_table_on_add_row(
+    add: On<Add, VirtualListRow>,
+    mut q_row: Query<(&ChildOf, &mut Node, &mut AccessibilityNode)>,
+    q_body: Query<(), With<DataTableBody>>,
+    q_parents: Query<&ChildOf>,
+    q_table: Query<(&DataTable, &DataTableSort, Has<InteractionDisabled>)>,
+) {
+    let Ok((ChildOf(body), mut node, mut accessibility)) = q_row.get_mut(add.entity) else {
+        return;
+    };
+    if !q_body.contains(*body) {
+        return;
+    }
+    accessibility.set_role(Role::Row);
+    let Some(Ok((table, ..))) =
+        find_table(*body, &q_parents, &q_table).map(|table| q_table.get(table))
+    else {
+        return;
+    };
+    node.display = Display::Grid;
+    node.grid_template_columns = table.grid_template_columns();
+}
+
+/// Keeps the grid tracks of the header and rows in sync with the columns of the table, and rebinds
+/// the rows when the sort changes.
+fn update_data_tables(
+    mut q_table: Query<(Ref<DataTable>, Ref<DataTableSort>, &mut AccessibilityNode)>,
+    mut q_header: Query<(Entity, Ref<DataTableHeader>, &mut Node, Option<&Children>)>,
+    mut q_header_cell: Query<(&DataTableHeaderCell, &mut AccessibilityNode), Without<DataTable>>,
+    q_body: Query<(Entity, Option<&Children>), With<DataTableBody>>,
+    mut q_row: Query<(&VirtualListRow, &mut Node), Without<DataTableHeader>>,
+    q_parents: Query<&ChildOf>,
+    mut commands: Commands,
+) {
+    for (table, _, mut accessibility) in q_table.iter_mut() {
+        if table.is_changed() {
+            accessibility.set_column_count(table.columns.len());
+        }
+    }
+
+    for (header_id, header, mut node, cells) in q_header.iter_mut() {
+        let Some(Ok((table, sort, _))) = q_parents
+            .iter_ancestors(header_id)
+            .find(|ancestor| q_table.contains(*ancestor))
+            .map(|table| q_table.get(table))
+        else {
+            continue;
+        };
+        if table.is_changed() || header.is_added() {
+            node.display = Display::Grid;
+            node.grid_template_columns = table.grid_template_columns();
+        }
+        if sort.is_changed() || header.is_added() {
+            for cell in cells.into_iter().flatten() {
+                let Ok((cell, mut accessibility)) = q_header_cell.get_mut(*cell) else {
+                    continue;
+                };
+                match sort.0.filter(|sort| sort.column == cell.column) {
+                    Some(sort) => accessibility.set_sort_direction(match sort.direction {
+                        SortDirection::Ascending => accesskit::SortDirection::Ascending,
+                        SortDirection::Descending => accesskit::SortDirection::Descending,
+                    }),
+                    None => accessibility.clear_sort_direction(),
+                }
+            }
+        }
+    }
+
+    for (body_id, rows) in q_body.iter() {
+        let Some(Ok((table, sort, _))) = q_parents
+            .iter_ancestors(body_id)
+            .find(|ancestor| q_table.contains(*ancestor))
+            .map(|table| q_table.get(table))
+        else {
+            continue;
+        };
+        let resized = table.is_changed();
+        let sorted = sort.is_changed() && !sort.is_added();
+        if !resized && !sorted {
+            continue;
+        }
+        for row_id in rows.into_iter().flatten() {
+            let Ok((row, mut node)) = q_row.get_mut(*row_id) else {
+                continue;
+            };
+            if resized {
+                node.display = Display::Grid;
+                node.grid_template_columns = table.grid_template_columns();
+            }
+            // The rows now show different items, so let the app fill them in again.
+            if sorted {
+                commands.trigger(VirtualListBind {
+                    row: *row_id,
+                    list: body_id,
+                    index: row.index,
+                });
+            }
+        }
+    }
+}
+
+/// Observer function which updates the [`DataTableSort`] of a table in response to a
+/// [`ValueChange<ColumnSort>`] event. This can be used to make tables manage their own sort
+/// state, as opposed to managing it externally.
+pub fn data_table_self_sort(
+    value_change: On<ValueChange<ColumnSort>>,
+    mut q_table: Query<&mut DataTableSort, With<DataTable>>,
+) {
+    if let Ok(mut sort) = q_table.get_mut(value_change.source) {
+        sort.0 = Some(value_change.value);
+    }
+}
+
+/// Observer function which updates the column widths of a table in response to a
+/// [`ColumnResize`] event. This can be used to make tables manage their own column widths, as
+/// opposed to managing them externally.
+pub fn data_table_self_resize(resize: On<ColumnResize>, mut q_table: Query<&mut DataTable>) {
+    if let Ok(mut table) = q_table.get_mut(resize.table)
+        && let Some(column) = table.columns.get_mut(resize.column)
+    {
+        column.width = resize.width;
+    }
+}
+
+/// Plugin that adds the observers and systems for the [`DataTable`] widget.
+pub struct DataTablePlugin;
+
+impl Plugin for DataTablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(data_table_header_on_click)
+            .add_observer(data_table_resize_on_drag_start)
+            .add_observer(data_table_resize_on_drag)
+            .add_observer(data_table_resize_on_drag_end)
+            .add_observer(data_table_resize_on_cancel)
+            .add_observer(data_table_resize_on_click)
+            .add_observer(data_table_on_add_row)
+            .add_systems(PostUpdate, update_data_tables.in_set(UiSystems::Prepare));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    #[test]
+    fn test_header_follows_columns() {
+        let mut world = World::new();
+        let table = world
+            .spawn(DataTable::new([
+                TableColumn::new(80.),
+                TableColumn::new(120.),
+            ]))
+            .id();
+        let header = world
+            .spawn((DataTableHeader, Node::default(), ChildOf(table)))
+            .id();
+        let cell = world
+            .spawn((DataTableHeaderCell { column: 1 }, ChildOf(header)))
+            .id();
+        world
+            .entity_mut(table)
+            .insert(DataTableSort(Some(ColumnSort {
+                column: 1,
+                direction: SortDirection::Descending,
+            })));
+
+        world.run_system_once(update_data_tables).unwrap();
+        let node = world.get::<Node>(header).unwrap();
+        assert_eq!(node.display, Display::Grid);
+        assert_eq!(
+            node.grid_template_columns,
+            vec![
+                RepeatedGridTrack::px(1, 80.),
+                RepeatedGridTrack::px(1, 120.)
+            ]
+        );
+        assert_eq!(
+            world
+                .get::<AccessibilityNode>(cell)
+                .unwrap()
+                .sort_direction(),
+            Some(accesskit::SortDirection::Descending)
+        );
+    }
+}
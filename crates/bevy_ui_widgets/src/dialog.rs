@@ -0,0 +1,313 @@
+//! Modal dialogs, such as confirmation prompts.
+//!
+//! A [`Dialog`] is meant to be the root of a modal overlay: a node that covers the whole window
+//! (for example with [`PositionType::Absolute`](bevy_ui::PositionType::Absolute), a size of 100%
+//! and a high [`GlobalZIndex`](bevy_ui::GlobalZIndex)) acting as a backdrop, containing the
+//! visible dialog panel. Because the backdrop is pickable, it blocks pointer interaction with the
+//! UI underneath it.
+//!
+//! When a dialog is spawned, it remembers the entity which had the [`InputFocus`], and moves the
+//! focus to the first focusable descendant of the dialog. The dialog is a modal [`TabGroup`], so
+//! tab navigation stays inside it. When the [`Dialog`] component is removed, for example by
+//! despawning the dialog, the focus is returned to the entity that had it before.
+//!
+//! Like the other widgets, dialogs use external state management: the dialog never closes itself.
+//! Instead, it emits events and the app is expected to despawn the dialog in response (or use
+//! [`dialog_self_close`]):
+//!
+//! - Activating a button with a [`DialogButton<R>`] component emits a [`DialogResult<R>`] event
+//!   on the dialog, with the value of the button. The result type can be any type registered with
+//!   a [`DialogResultPlugin<R>`]; [`DialogResponse`] is registered by default.
+//! - Pressing `Escape` inside the dialog, or clicking the backdrop if
+//!   [`Dialog::dismiss_on_backdrop`] is set, emits a [`DialogDismiss`] event on the dialog.
+
+use core::marker::PhantomData;
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entities, Entity},
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    lifecycle::{Add, Remove},
+    observer::On,
+    query::With,
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_input_focus::{
+    tab_navigation::{NavAction, TabGroup, TabNavigation},
+    FocusCause, FocusedInput, InputFocus,
+};
+use bevy_picking::events::{Click, Pointer};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+
+use crate::Activate;
+
+/// Headless widget implementation for a modal dialog. See the [module documentation](self) for an
+/// overview of how dialogs work.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[require(
+    AccessibilityNode = dialog_accessibility_node(),
+    TabGroup::modal(),
+    DialogFocusState
+)]
+#[reflect(Component, Default)]
+pub struct Dialog {
+    /// Whether clicking the backdrop, outside of the dialog panel, dismisses the dialog.
+    pub dismiss_on_backdrop: bool,
+}
+
+fn dialog_accessibility_node() -> AccessibilityNode {
+    let mut node = accesskit::Node::new(Role::Dialog);
+    node.set_modal();
+    AccessibilityNode(node)
+}
+
+/// Focus state of a [`Dialog`].
+#[derive(Component, Debug, Default, Clone)]
+pub struct DialogFocusState {
+    /// The entity that had focus before the dialog was opened.
+    previous: Option<Entity>,
+    /// Whether the focus was moved into the dialog.
+    initialized: bool,
+}
+
+/// The standard results of a dialog.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash, Clone)]
+pub enum DialogResponse {
+    /// The user accepted the dialog, for example with an "OK" button.
+    Confirm,
+    /// The user declined the dialog, for example with a "Cancel" button.
+    #[default]
+    Cancel,
+}
+
+/// A button inside a [`Dialog`] which closes it with a result. When the button is activated, a
+/// [`DialogResult<R>`] event is emitted on the dialog with the value of the button.
+///
+/// The result type must be registered with a [`DialogResultPlugin<R>`].
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct DialogButton<R: Clone + Send + Sync + 'static = DialogResponse>(pub R);
+
+/// Event triggered on a [`Dialog`] when one of its [`DialogButton`]s is activated.
+#[derive(EntityEvent, Clone, Debug, PartialEq)]
+pub struct DialogResult<R: Clone + Send + Sync + 'static = DialogResponse> {
+    /// The dialog.
+    #[event_target]
+    pub dialog: Entity,
+    /// The value of the button.
+    pub value: R,
+}
+
+/// Event triggered on a [`Dialog`] when the user dismisses it without choosing a result, by
+/// pressing `Escape` or by clicking the backdrop.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct DialogDismiss {
+    /// The dialog.
+    #[event_target]
+    pub dialog: Entity,
+}
+
+fn dialog_on_add(
+    add: On<Add, Dialog>,
+    mut q_dialog: Query<&mut DialogFocusState>,
+    focus: Option<Res<InputFocus>>,
+) {
+    if let Ok(mut state) = q_dialog.get_mut(add.entity) {
+        state.previous = focus.and_then(|focus| focus.get());
+        state.initialized = false;
+    }
+}
+
+fn dialog_on_remove(
+    remove: On<Remove, Dialog>,
+    q_dialog: Query<&DialogFocusState>,
+    q_parent: Query<&ChildOf>,
+    entities: &Entities,
+    focus: Option<ResMut<InputFocus>>,
+) {
+    let (Ok(state), Some(mut focus)) = (q_dialog.get(remove.entity), focus) else {
+        return;
+    };
+    // Only restore the focus if it is still inside the dialog: if the app moved it elsewhere in
+    // the meantime, leave it there.
+    let focus_inside = match focus.get() {
+        Some(focus_ent) => {
+            !entities.contains(focus_ent)
+                || focus_ent == remove.entity
+                || q_parent
+                    .iter_ancestors(focus_ent)
+                    .any(|ancestor| ancestor == remove.entity)
+        }
+        None => true,
+    };
+    if !focus_inside {
+        return;
+    }
+    match state
+        .previous
+        .filter(|previous| entities.contains(*previous))
+    {
+        Some(previous) => focus.set(previous, FocusCause::Navigated),
+        None => focus.clear(),
+    }
+}
+
+/// Moves the focus into newly opened dialogs.
+fn dialog_acquire_focus(
+    mut q_dialog: Query<(Entity, &mut DialogFocusState), With<Dialog>>,
+    mut focus: ResMut<InputFocus>,
+    tab_navigation: TabNavigation,
+) {
+    for (dialog, mut state) in q_dialog.iter_mut() {
+        if state.initialized {
+            continue;
+        }
+        state.initialized = true;
+        // If there is nothing focusable in the dialog, focus the dialog itself, so that it still
+        // receives keyboard input.
+        let target = tab_navigation
+            .initialize(dialog, NavAction::First)
+            .unwrap_or(dialog);
+        focus.set(target, FocusCause::Navigated);
+    }
+}
+
+fn dialog_on_key_input(
+    mut ev: On<FocusedInput<KeyboardInput>>,
+    q_dialog: Query<(), With<Dialog>>,
+    mut commands: Commands,
+) {
+    // The event bubbles up from the focused entity, so this runs when it reaches the dialog.
+    if !q_dialog.contains(ev.focused_entity) {
+        return;
+    }
+    let event = &ev.event().input;
+    if event.state == ButtonState::Pressed && !event.repeat && event.key_code == KeyCode::Escape {
+        let dialog = ev.focused_entity;
+        ev.propagate(false);
+        commands.trigger(DialogDismiss { dialog });
+    }
+}
+
+fn dialog_on_backdrop_click(
+    mut click: On<Pointer<Click>>,
+    q_dialog: Query<&Dialog>,
+    mut commands: Commands,
+) {
+    // Clicks on the dialog panel bubble up to the dialog too, so only handle clicks that hit the
+    // backdrop itself.
+    if click.original_event_target() != click.entity {
+        return;
+    }
+    if let Ok(dialog) = q_dialog.get(click.entity) {
+        click.propagate(false);
+        if dialog.dismiss_on_backdrop {
+            commands.trigger(DialogDismiss {
+                dialog: click.entity,
+            });
+        }
+    }
+}
+
+fn dialog_button_on_activate<R: Clone + Send + Sync + 'static>(
+    activate: On<Activate>,
+    q_button: Query<&DialogButton<R>>,
+    q_parent: Query<&ChildOf>,
+    q_dialog: Query<(), With<Dialog>>,
+    mut commands: Commands,
+) {
+    let Ok(button) = q_button.get(activate.entity) else {
+        return;
+    };
+    if let Some(dialog) = q_parent
+        .iter_ancestors(activate.entity)
+        .find(|ancestor| q_dialog.contains(*ancestor))
+    {
+        commands.trigger(DialogResult {
+            dialog,
+            value: button.0.clone(),
+        });
+    }
+}
+
+/// Observer function which despawns a [`Dialog`] in response to a [`DialogDismiss`] event. This
+/// can be used to make dialogs close themselves when dismissed, as opposed to closing them
+/// externally. To close dialogs when they produce a result, use [`dialog_self_close_on_result`].
+pub fn dialog_self_close(dismiss: On<DialogDismiss>, mut commands: Commands) {
+    commands.entity(dismiss.dialog).despawn();
+}
+
+/// Observer function which despawns a [`Dialog`] in response to a [`DialogResult<R>`] event.
+pub fn dialog_self_close_on_result<R: Clone + Send + Sync + 'static>(
+    result: On<DialogResult<R>>,
+    mut commands: Commands,
+) {
+    commands.entity(result.dialog).despawn();
+}
+
+/// Plugin that registers the observer which emits [`DialogResult<R>`] events for
+/// [`DialogButton<R>`]s. [`DialogPlugin`] adds this for [`DialogResponse`].
+pub struct DialogResultPlugin<R>(PhantomData<fn() -> R>);
+
+impl<R> Default for DialogResultPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: Clone + Send + Sync + 'static> Plugin for DialogResultPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(dialog_button_on_activate::<R>);
+    }
+}
+
+/// Plugin that adds the observers and systems for the [`Dialog`] widget.
+pub struct DialogPlugin;
+
+impl Plugin for DialogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(DialogResultPlugin::<DialogResponse>::default())
+            .add_systems(Update, dialog_acquire_focus)
+            .add_observer(dialog_on_add)
+            .add_observer(dialog_on_remove)
+            .add_observer(dialog_on_key_input)
+            .add_observer(dialog_on_backdrop_click);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_input_focus::tab_navigation::TabIndex;
+
+    #[test]
+    fn test_dialog_focus_is_restored() {
+        let mut world = World::new();
+        world.add_observer(dialog_on_add);
+        world.add_observer(dialog_on_remove);
+        world.init_resource::<InputFocus>();
+        let group = world.spawn(TabGroup::new(0)).id();
+        let opener = world.spawn((TabIndex(0), ChildOf(group))).id();
+        world
+            .resource_mut::<InputFocus>()
+            .set(opener, FocusCause::Navigated);
+
+        let dialog = world.spawn(Dialog::default()).id();
+        let ok = world.spawn((TabIndex(0), ChildOf(dialog))).id();
+        world.run_system_once(dialog_acquire_focus).unwrap();
+        assert_eq!(world.resource::<InputFocus>().get(), Some(ok));
+
+        world.entity_mut(dialog).despawn();
+        assert_eq!(world.resource::<InputFocus>().get(), Some(opener));
+    }
+}
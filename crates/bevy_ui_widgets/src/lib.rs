@@ -28,22 +28,34 @@
 
 mod button;
 mod checkbox;
+mod data_table;
+mod dialog;
 mod menu;
 mod observe;
 pub mod popover;
 mod radio;
 mod scrollbar;
 mod slider;
+mod tabs;
 mod text_input;
+mod tooltip;
+mod tree_view;
+mod virtual_list;
 
 pub use button::*;
 pub use checkbox::*;
+pub use data_table::*;
+pub use dialog::*;
 pub use menu::*;
 pub use observe::*;
 pub use radio::*;
 pub use scrollbar::*;
 pub use slider::*;
+pub use tabs::*;
 pub use text_input::*;
+pub use tooltip::*;
+pub use tree_view::*;
+pub use virtual_list::*;
 
 use bevy_app::{PluginGroup, PluginGroupBuilder};
 use bevy_ecs::{entity::Entity, event::EntityEvent};
@@ -61,11 +73,17 @@ impl PluginGroup for UiWidgetsPlugins {
             .add(PopoverPlugin)
             .add(ButtonPlugin)
             .add(CheckboxPlugin)
+            .add(DataTablePlugin)
+            .add(DialogPlugin)
             .add(MenuPlugin)
             .add(RadioGroupPlugin)
             .add(ScrollbarPlugin)
             .add(SliderPlugin)
+            .add(TabsPlugin)
             .add(EditableTextInputPlugin)
+            .add(TooltipPlugin)
+            .add(TreeViewPlugin)
+            .add(VirtualListPlugin)
     }
 }
 
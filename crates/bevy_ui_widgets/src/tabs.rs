@@ -0,0 +1,428 @@
+//! Standard widget components for tabs, following the WAI-ARIA tabs pattern.
+//! See <https://www.w3.org/WAI/ARIA/apg/patterns/tabs/>.
+//!
+//! A set of tabs consists of a [`TabGroup`] entity (the tab list), whose descendants are the
+//! [`Tab`] entities, and one [`TabPanel`] entity per tab. Each tab refers to its panel with
+//! [`Tab::panel`]; the panels can be placed anywhere in the UI hierarchy, typically below the tab
+//! list.
+//!
+//! Note that [`TabGroup`] is unrelated to the
+//! [`TabGroup`](bevy_input_focus::tab_navigation::TabGroup) used for tab navigation.
+//!
+//! The active tab is the one with the [`Selected`] component. Only the panel of the active tab is
+//! displayed: the other panels have their [`Node::display`] set to [`Display::None`] (the previous
+//! display is restored when they are shown again), and, if the group uses
+//! [`TabPanelPolicy::Despawn`], their children are despawned. In that case, the content of a
+//! panel can be spawned lazily when it is shown, in response to a [`TabPanelActivated`] event.
+//!
+//! Like the other widgets, tabs use external state management: clicking a tab, or moving between
+//! tabs with the keyboard, emits a [`TabChanged`] event on the [`TabGroup`], and the app is
+//! expected to update the [`Selected`] components in response, or to use [`tab_self_select`].
+//!
+//! For keyboard navigation, each tab should be focusable, for example by adding a
+//! [`TabIndex`]. Only the active tab is reachable with sequential tab navigation: the
+//! [`TabIndex`] of the other tabs is set to `-1`. While a tab has focus:
+//!
+//! - `ArrowLeft` / `ArrowUp` and `ArrowRight` / `ArrowDown` move to the previous and next tab,
+//!   wrapping around.
+//! - `Home` and `End` move to the first and last tab.
+//! - `Space` and `Enter` activate the focused tab.
+//!
+//! Moving to a tab activates it.
+
+use accesskit::{NodeId, Role};
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    observer::On,
+    query::{Has, With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, ResMut},
+    template::FromTemplate,
+};
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_input_focus::{
+    tab_navigation::TabIndex, FocusCause, FocusedInput, InputFocus, InputFocusVisible,
+};
+use bevy_picking::events::{Click, Pointer, Press};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_ui::{Display, InteractionDisabled, Node, Selected, UiSystems};
+
+/// Headless widget implementation for a tab list. See the [module documentation](self) for an
+/// overview of how tabs are structured.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::TabList)))]
+#[reflect(Component, Default)]
+pub struct TabGroup {
+    /// What happens to the panels of inactive tabs.
+    pub policy: TabPanelPolicy,
+}
+
+/// What happens to the [`TabPanel`]s of the inactive tabs of a [`TabGroup`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq, Clone)]
+pub enum TabPanelPolicy {
+    /// Inactive panels are hidden, and keep their content.
+    #[default]
+    Hide,
+    /// Inactive panels are hidden, and their children are despawned.
+    Despawn,
+}
+
+/// Headless widget implementation for a tab in a [`TabGroup`].
+#[derive(Component, FromTemplate, Debug, Clone, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Tab)))]
+#[reflect(Component)]
+pub struct Tab {
+    /// The [`TabPanel`] displayed when this tab is active.
+    pub panel: Entity,
+}
+
+/// Headless widget implementation for the panel of a [`Tab`].
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::TabPanel)), TabPanelState)]
+#[reflect(Component, Default)]
+pub struct TabPanel;
+
+/// Whether a [`TabPanel`] is currently shown.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TabPanelState {
+    /// `None` until the panel is first updated.
+    active: Option<bool>,
+    /// The [`Node::display`] of the panel before it was hidden, restored when it is shown.
+    display: Display,
+}
+
+impl TabPanelState {
+    /// Returns `true` if the panel is shown.
+    pub fn is_active(&self) -> bool {
+        self.active == Some(true)
+    }
+}
+
+/// Event triggered on a [`TabGroup`] when the user activates one of its tabs.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct TabChanged {
+    /// The tab group.
+    #[event_target]
+    pub group: Entity,
+    /// The tab that was activated.
+    pub tab: Entity,
+}
+
+/// Event triggered on a [`TabPanel`] when it is shown, including when it is first shown. Panels
+/// of a group with [`TabPanelPolicy::Despawn`] can spawn their content in response.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct TabPanelActivated {
+    /// The panel.
+    #[event_target]
+    pub panel: Entity,
+    /// The tab of the panel.
+    pub tab: Entity,
+}
+
+/// Returns the [`TabGroup`] containing a tab.
+fn tab_group(
+    tab: Entity,
+    q_parents: &Query<&ChildOf>,
+    q_group: &Query<&TabGroup>,
+) -> Option<Entity> {
+    q_parents
+        .iter_ancestors(tab)
+        .find(|ancestor| q_group.contains(*ancestor))
+}
+
+fn tab_on_key_input(
+    mut ev: On<FocusedInput<KeyboardInput>>,
+    q_tab: Query<(Has<Selected>, Has<InteractionDisabled>), With<Tab>>,
+    q_group: Query<&TabGroup>,
+    q_parents: Query<&ChildOf>,
+    q_children: Query<&Children>,
+    mut focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    let tab = ev.focused_entity;
+    let Ok((selected, disabled)) = q_tab.get(tab) else {
+        return;
+    };
+    let event = &ev.event().input;
+    let (key_code, repeat) = (event.key_code, event.repeat);
+    if event.state != ButtonState::Pressed
+        || !matches!(
+            key_code,
+            KeyCode::ArrowUp
+                | KeyCode::ArrowDown
+                | KeyCode::ArrowLeft
+                | KeyCode::ArrowRight
+                | KeyCode::Home
+                | KeyCode::End
+                | KeyCode::Space
+                | KeyCode::Enter
+        )
+    {
+        return;
+    }
+    let Some(group) = tab_group(tab, &q_parents, &q_group) else {
+        return;
+    };
+    ev.propagate(false);
+
+    if matches!(key_code, KeyCode::Space | KeyCode::Enter) {
+        if !repeat && !selected && !disabled {
+            commands.trigger(TabChanged { group, tab });
+        }
+        return;
+    }
+
+    let tabs = q_children
+        .iter_descendants(group)
+        .filter(|entity| matches!(q_tab.get(*entity), Ok((_, false))) || *entity == tab)
+        .collect::<Vec<_>>();
+    let Some(index) = tabs.iter().position(|entity| *entity == tab) else {
+        return;
+    };
+    let next_index = match key_code {
+        KeyCode::ArrowUp | KeyCode::ArrowLeft => (index + tabs.len() - 1) % tabs.len(),
+        KeyCode::ArrowDown | KeyCode::ArrowRight => (index + 1) % tabs.len(),
+        KeyCode::Home => 0,
+        KeyCode::End => tabs.len() - 1,
+        _ => return,
+    };
+    let next = tabs[next_index];
+    if next == tab {
+        return;
+    }
+    focus.set(next, FocusCause::Navigated);
+    if !matches!(q_tab.get(next), Ok((true, _))) {
+        commands.trigger(TabChanged { group, tab: next });
+    }
+}
+
+fn tab_on_pointer_down(
+    mut press: On<Pointer<Press>>,
+    q_tab: Query<Has<InteractionDisabled>, With<Tab>>,
+    focus: Option<ResMut<InputFocus>>,
+    focus_visible: Option<ResMut<InputFocusVisible>>,
+) {
+    let Ok(disabled) = q_tab.get(press.entity) else {
+        return;
+    };
+    press.propagate(false);
+    if disabled {
+        return;
+    }
+    // Pressing a tab makes it the focused input, and hides the focus ring if it was visible.
+    if let Some(mut focus) = focus {
+        focus.set(press.entity, FocusCause::Pressed);
+    }
+    if let Some(mut focus_visible) = focus_visible {
+        focus_visible.0 = false;
+    }
+}
+
+fn tab_on_click(
+    mut click: On<Pointer<Click>>,
+    q_tab: Query<(Has<Selected>, Has<InteractionDisabled>), With<Tab>>,
+    q_group: Query<&TabGroup>,
+    q_parents: Query<&ChildOf>,
+    mut commands: Commands,
+) {
+    let Ok((selected, disabled)) = q_tab.get(click.entity) else {
+        return;
+    };
+    click.propagate(false);
+    if selected || disabled {
+        return;
+    }
+    if let Some(group) = tab_group(click.entity, &q_parents, &q_group) {
+        commands.trigger(TabChanged {
+            group,
+            tab: click.entity,
+        });
+    }
+}
+
+/// Shows the panel of the active tab of each group and hides the others, and updates the
+/// accessibility properties and tab indices of the tabs.
+fn update_tabs(
+    q_group: Query<(Entity, &TabGroup)>,
+    q_children: Query<&Children>,
+    mut q_tab: Query<(
+        &Tab,
+        Has<Selected>,
+        Option<&mut TabIndex>,
+        &mut AccessibilityNode,
+    )>,
+    mut q_panel: Query<
+        (
+            &mut TabPanelState,
+            &mut AccessibilityNode,
+            Option<&mut Node>,
+        ),
+        Without<Tab>,
+    >,
+    mut commands: Commands,
+) {
+    for (group, group_settings) in q_group.iter() {
+        let tabs = q_children
+            .iter_descendants(group)
+            .filter(|entity| q_tab.contains(*entity))
+            .collect::<Vec<_>>();
+        // If no tab is active, keep the first tab reachable with sequential navigation.
+        let any_selected = tabs
+            .iter()
+            .any(|tab| matches!(q_tab.get(*tab), Ok((_, true, _, _))));
+        for (index, &tab_ent) in tabs.iter().enumerate() {
+            let Ok((tab, selected, tab_index, mut tab_accessibility)) = q_tab.get_mut(tab_ent)
+            else {
+                continue;
+            };
+            if let Some(mut tab_index) = tab_index {
+                let tabbable = selected || (!any_selected && index == 0);
+                tab_index.set_if_neq(TabIndex(if tabbable { 0 } else { -1 }));
+            }
+            let panel_ent = tab.panel;
+            let controls = [NodeId(panel_ent.to_bits())];
+            if tab_accessibility.controls() != controls.as_slice() {
+                tab_accessibility.set_controls(controls);
+            }
+
+            let Ok((mut state, mut accessibility, node)) = q_panel.get_mut(panel_ent) else {
+                continue;
+            };
+            let labelled_by = [NodeId(tab_ent.to_bits())];
+            if accessibility.labelled_by() != labelled_by.as_slice() {
+                accessibility.set_labelled_by(labelled_by);
+            }
+            if state.active == Some(selected) {
+                continue;
+            }
+            state.active = Some(selected);
+            if let Some(mut node) = node {
+                if selected {
+                    if node.display == Display::None {
+                        node.display = state.display;
+                    }
+                } else if node.display != Display::None {
+                    state.display = node.display;
+                    node.display = Display::None;
+                }
+            }
+            if selected {
+                accessibility.clear_hidden();
+                commands.trigger(TabPanelActivated {
+                    panel: panel_ent,
+                    tab: tab_ent,
+                });
+            } else {
+                accessibility.set_hidden();
+                if group_settings.policy == TabPanelPolicy::Despawn {
+                    commands.entity(panel_ent).despawn_related::<Children>();
+                }
+            }
+        }
+    }
+}
+
+/// Observer function which makes a tab the active tab of its group in response to a
+/// [`TabChanged`] event. This can be used to make tabs automatically switch when clicked, as
+/// opposed to managing the active tab externally.
+pub fn tab_self_select(
+    change: On<TabChanged>,
+    q_children: Query<&Children>,
+    q_tab: Query<Has<Selected>, With<Tab>>,
+    mut commands: Commands,
+) {
+    for entity in q_children.iter_descendants(change.group) {
+        match (q_tab.get(entity), entity == change.tab) {
+            (Ok(false), true) => {
+                commands.entity(entity).insert(Selected);
+            }
+            (Ok(true), false) => {
+                commands.entity(entity).remove::<Selected>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Plugin that adds the observers and systems for the [`TabGroup`] widget.
+pub struct TabsPlugin;
+
+impl Plugin for TabsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(tab_on_key_input)
+            .add_observer(tab_on_pointer_down)
+            .add_observer(tab_on_click)
+            .add_systems(PostUpdate, update_tabs.in_set(UiSystems::Prepare));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    #[test]
+    fn test_tab_panels_follow_selection() {
+        let mut world = World::new();
+        world.add_observer(tab_self_select);
+        let group = world
+            .spawn(TabGroup {
+                policy: TabPanelPolicy::Despawn,
+            })
+            .id();
+        let panel_a = world.spawn((TabPanel, Node::default())).id();
+        let panel_b = world
+            .spawn((
+                TabPanel,
+                Node {
+                    display: Display::Grid,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let content_a = world.spawn(ChildOf(panel_a)).id();
+        let tab_a = world
+            .spawn((
+                Tab { panel: panel_a },
+                TabIndex(0),
+                Selected,
+                ChildOf(group),
+            ))
+            .id();
+        let tab_b = world
+            .spawn((Tab { panel: panel_b }, TabIndex(0), ChildOf(group)))
+            .id();
+        let display = |world: &World, panel| world.get::<Node>(panel).unwrap().display;
+
+        world.run_system_once(update_tabs).unwrap();
+        assert_eq!(display(&world, panel_a), Display::Flex);
+        assert_eq!(display(&world, panel_b), Display::None);
+        assert_eq!(world.get::<TabIndex>(tab_b), Some(&TabIndex(-1)));
+
+        world.trigger(TabChanged { group, tab: tab_b });
+        world.flush();
+        world.run_system_once(update_tabs).unwrap();
+        assert!(!world.entity(tab_a).contains::<Selected>());
+        assert_eq!(display(&world, panel_a), Display::None);
+        // The panel's own display is restored when it is shown.
+        assert_eq!(display(&world, panel_b), Display::Grid);
+        assert!(world.get_entity(content_a).is_err());
+
+        world.trigger(TabChanged { group, tab: tab_a });
+        world.flush();
+        world.run_system_once(update_tabs).unwrap();
+        assert_eq!(display(&world, panel_a), Display::Flex);
+        assert_eq!(display(&world, panel_b), Display::None);
+    }
+}
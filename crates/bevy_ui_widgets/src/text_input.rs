@@ -17,7 +17,7 @@ use bevy_input_focus::{
 use bevy_math::Vec2;
 use bevy_picking::events::{Click, Drag, Pointer, Press, Release};
 use bevy_picking::pointer::PointerButton;
-use bevy_text::{EditableText, PreeditCursor, TextEdit};
+use bevy_text::{EditableText, PreeditCursor, RichTextStyle, TextEdit};
 use bevy_ui::widget::{scroll_editable_text, update_editable_text_layout, TextScroll};
 use bevy_ui::UiSystems;
 use bevy_ui::{
@@ -71,6 +71,7 @@ fn on_focused_keyboard_input(
     }
 
     let allow_newlines = editable_text.allow_newlines;
+    let rich_text = editable_text.styles.enabled;
 
     // Bitflags representing states of modifier keys.
     // On macOS Option is mapped to `Key::Alt` by `bevy_input`.
@@ -104,6 +105,20 @@ fn on_focused_keyboard_input(
         (COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("v") => {
             queue_edit(TextEdit::Paste);
         }
+        (NONE, Key::Undo) => queue_edit(TextEdit::Undo),
+        (NONE, Key::Redo) => queue_edit(TextEdit::Redo),
+        (COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("z") => queue_edit(TextEdit::Undo),
+        (SHIFT_COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("z") => {
+            queue_edit(TextEdit::Redo);
+        }
+        #[cfg(not(target_os = "macos"))]
+        (COMMAND, Key::Character(c)) if c.eq_ignore_ascii_case("y") => queue_edit(TextEdit::Redo),
+        (COMMAND, Key::Character(c)) if rich_text && c.eq_ignore_ascii_case("b") => {
+            queue_edit(TextEdit::ToggleStyle(RichTextStyle::bold()));
+        }
+        (COMMAND, Key::Character(c)) if rich_text && c.eq_ignore_ascii_case("i") => {
+            queue_edit(TextEdit::ToggleStyle(RichTextStyle::italic()));
+        }
         #[cfg(not(target_os = "macos"))]
         (SHIFT, Key::Delete) => queue_edit(TextEdit::Cut),
         (WORD, Key::Backspace) => queue_edit(TextEdit::BackspaceWord),
@@ -0,0 +1,311 @@
+//! Tooltips: popovers which describe an element when it is hovered or focused.
+//!
+//! To give an element a tooltip, add a [`Tooltip`] component to it, and spawn the content of the
+//! tooltip as a child of the element, with a [`TooltipContent`] component. The content can be any
+//! UI hierarchy, such as text with an icon. It is positioned next to the element by its
+//! [`Popover`] component, which defaults to placing it above the element, or below if there isn't
+//! room.
+//!
+//! The content is hidden until the pointer has hovered the element for [`Tooltip::delay`], or
+//! immediately when the element receives keyboard focus. It is hidden again when the pointer
+//! leaves the element, when the element loses focus, and when the user presses a pointer button,
+//! scrolls, or presses `Escape`. After being dismissed, the tooltip doesn't show again until the
+//! pointer leaves the element.
+//!
+//! For accessibility, the content has the [`Role::Tooltip`] role, and the element is described
+//! by it, so screen readers announce the tooltip along with the element.
+
+use core::time::Duration;
+
+use accesskit::{NodeId, Role};
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_camera::visibility::Visibility;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    hierarchy::Children,
+    observer::On,
+    query::{With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+    world::Ref,
+};
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_input_focus::{FocusedInput, InputFocus, InputFocusVisible};
+use bevy_picking::{
+    events::{Out, Over, Pointer, Press, Scroll},
+    PickingSystems,
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_time::Time;
+
+use crate::popover::{Popover, PopoverAlign, PopoverPlacement, PopoverSide};
+
+/// Component which gives an element a tooltip. The content of the tooltip is the child of the
+/// element with a [`TooltipContent`] component.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[require(TooltipState)]
+#[reflect(Component, Default)]
+pub struct Tooltip {
+    /// How long the pointer has to hover the element before the tooltip is shown.
+    pub delay: Duration,
+    /// Whether the tooltip is shown when the element receives keyboard focus.
+    pub show_on_focus: bool,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            show_on_focus: true,
+        }
+    }
+}
+
+impl Tooltip {
+    /// Construct a new tooltip which is shown after hovering for `delay`.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            ..Self::default()
+        }
+    }
+}
+
+/// The content of a [`Tooltip`]. This should be a child of the element with the tooltip.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[require(
+    AccessibilityNode(accesskit::Node::new(Role::Tooltip)),
+    Visibility = Visibility::Hidden,
+    Popover = tooltip_popover(),
+)]
+#[reflect(Component, Default)]
+pub struct TooltipContent;
+
+/// The default [`Popover`] of [`TooltipContent`]: centered above the element, or below it.
+fn tooltip_popover() -> Popover {
+    Popover {
+        positions: vec![
+            PopoverPlacement {
+                side: PopoverSide::Top,
+                align: PopoverAlign::Center,
+                gap: 4.0,
+            },
+            PopoverPlacement {
+                side: PopoverSide::Bottom,
+                align: PopoverAlign::Center,
+                gap: 4.0,
+            },
+        ],
+        window_margin: 4.0,
+    }
+}
+
+/// Hover and focus state of an element with a [`Tooltip`].
+#[derive(Component, Debug, Default, Clone)]
+pub struct TooltipState {
+    /// Whether a pointer is over the element or one of its descendants.
+    hovered: bool,
+    /// When the pointer started hovering the element, in [`Time::elapsed`].
+    hover_start: Option<Duration>,
+    /// Whether the tooltip was dismissed, and should stay hidden until the pointer leaves.
+    dismissed: bool,
+    /// Whether the tooltip is shown.
+    visible: bool,
+}
+
+impl TooltipState {
+    /// Returns `true` if the tooltip is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+fn tooltip_on_over(
+    over: On<Pointer<Over>>,
+    mut q_state: Query<&mut TooltipState>,
+    time: Res<Time>,
+) {
+    // `Over` bubbles, so this also runs when the pointer moves between descendants of the element.
+    if let Ok(mut state) = q_state.get_mut(over.entity) {
+        state.hovered = true;
+        if state.hover_start.is_none() {
+            state.hover_start = Some(time.elapsed());
+        }
+    }
+}
+
+fn tooltip_on_out(out: On<Pointer<Out>>, mut q_state: Query<&mut TooltipState>) {
+    if let Ok(mut state) = q_state.get_mut(out.entity) {
+        state.hovered = false;
+    }
+}
+
+fn tooltip_on_press(press: On<Pointer<Press>>, mut q_state: Query<&mut TooltipState>) {
+    if let Ok(mut state) = q_state.get_mut(press.entity) {
+        state.dismissed = true;
+    }
+}
+
+fn tooltip_on_scroll(scroll: On<Pointer<Scroll>>, mut q_state: Query<&mut TooltipState>) {
+    if let Ok(mut state) = q_state.get_mut(scroll.entity) {
+        state.dismissed = true;
+    }
+}
+
+fn tooltip_on_key_input(
+    ev: On<FocusedInput<KeyboardInput>>,
+    mut q_state: Query<&mut TooltipState>,
+) {
+    let input = &ev.event().input;
+    if input.state == ButtonState::Pressed
+        && input.key_code == KeyCode::Escape
+        && let Ok(mut state) = q_state.get_mut(ev.focused_entity)
+        && state.visible
+    {
+        // Don't stop propagation: `Escape` may also close a dialog or menu containing the element.
+        state.dismissed = true;
+    }
+}
+
+/// Shows and hides tooltip content based on the hover and focus state of the elements.
+fn update_tooltips(
+    mut q_tooltip: Query<(
+        Entity,
+        &Tooltip,
+        &mut TooltipState,
+        Option<Ref<Children>>,
+        Option<&mut AccessibilityNode>,
+    )>,
+    mut q_content: Query<
+        (&mut Visibility, &mut AccessibilityNode),
+        (With<TooltipContent>, Without<TooltipState>),
+    >,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    time: Res<Time>,
+) {
+    for (entity, tooltip, mut state, children, accessibility) in q_tooltip.iter_mut() {
+        let focused = tooltip.show_on_focus && focus.get() == Some(entity) && focus_visible.0;
+        if !state.hovered && !focused && (state.hover_start.is_some() || state.dismissed) {
+            // Leaving the element, or moving the focus away, resets the tooltip.
+            state.hover_start = None;
+            state.dismissed = false;
+        }
+        let hover_elapsed = state
+            .hover_start
+            .filter(|_| state.hovered)
+            .is_some_and(|start| time.elapsed().saturating_sub(start) >= tooltip.delay);
+        let visible = !state.dismissed && (hover_elapsed || focused);
+        if state.visible != visible {
+            state.visible = visible;
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        let content = children
+            .iter()
+            .copied()
+            .filter(|child| q_content.contains(*child))
+            .collect::<Vec<_>>();
+        if children.is_changed()
+            && let Some(mut accessibility) = accessibility
+        {
+            let described_by = content
+                .iter()
+                .map(|child| NodeId(child.to_bits()))
+                .collect::<Vec<_>>();
+            if accessibility.described_by() != described_by.as_slice() {
+                accessibility.set_described_by(described_by);
+            }
+        }
+        for child in content {
+            let Ok((mut visibility, mut accessibility)) = q_content.get_mut(child) else {
+                continue;
+            };
+            visibility.set_if_neq(match visible {
+                true => Visibility::Inherited,
+                false => Visibility::Hidden,
+            });
+            if accessibility.is_hidden() == visible {
+                match visible {
+                    true => accessibility.clear_hidden(),
+                    false => accessibility.set_hidden(),
+                }
+            }
+        }
+    }
+}
+
+/// Plugin that adds the observers and systems for the [`Tooltip`] widget.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(tooltip_on_over)
+            .add_observer(tooltip_on_out)
+            .add_observer(tooltip_on_press)
+            .add_observer(tooltip_on_scroll)
+            .add_observer(tooltip_on_key_input)
+            .add_systems(PreUpdate, update_tooltips.in_set(PickingSystems::Last));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{hierarchy::ChildOf, system::RunSystemOnce, world::World};
+
+    #[test]
+    fn test_tooltip_delay_and_dismiss() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<InputFocus>();
+        world.init_resource::<InputFocusVisible>();
+        let anchor = world
+            .spawn((
+                Tooltip::new(Duration::from_millis(300)),
+                AccessibilityNode::from(accesskit::Node::new(Role::Button)),
+            ))
+            .id();
+        let content = world.spawn((TooltipContent, ChildOf(anchor))).id();
+        let visibility = |world: &World| *world.get::<Visibility>(content).unwrap();
+
+        world.run_system_once(update_tooltips).unwrap();
+        assert_eq!(visibility(&world), Visibility::Hidden);
+        assert_eq!(
+            world
+                .get::<AccessibilityNode>(anchor)
+                .unwrap()
+                .described_by(),
+            &[NodeId(content.to_bits())]
+        );
+
+        // Hovering shows the tooltip once the delay has passed.
+        world.get_mut::<TooltipState>(anchor).unwrap().hovered = true;
+        world.get_mut::<TooltipState>(anchor).unwrap().hover_start = Some(Duration::ZERO);
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(200));
+        world.run_system_once(update_tooltips).unwrap();
+        assert_eq!(visibility(&world), Visibility::Hidden);
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(200));
+        world.run_system_once(update_tooltips).unwrap();
+        assert_eq!(visibility(&world), Visibility::Inherited);
+
+        // Dismissing hides it until the pointer leaves.
+        world.get_mut::<TooltipState>(anchor).unwrap().dismissed = true;
+        world.run_system_once(update_tooltips).unwrap();
+        assert_eq!(visibility(&world), Visibility::Hidden);
+        assert!(world.get::<AccessibilityNode>(content).unwrap().is_hidden());
+    }
+}
@@ -0,0 +1,693 @@
+//! Standard widget components for tree views, such as an outliner for an entity hierarchy.
+//!
+//! A tree view consists of a [`TreeView`] entity and any number of [`TreeItem`] entities. The
+//! structure of the tree is modeled by the [`TreeParent`] relationship, independently of the UI
+//! hierarchy: the parent of a top-level item is the [`TreeView`] itself, and the parent of a
+//! nested item is another item. This means that the item nodes can be laid out however the app
+//! wants, for example as a flat column of rows, indented by their [`TreeDepth`].
+//!
+//! Items are expanded when they have the [`Expanded`] component. Items inside a collapsed item are
+//! hidden by setting their [`Node::display`] to [`Display::None`], and their previous display is
+//! restored when they are shown again.
+//!
+//! Like the other widgets, the tree view uses external state management: the widget emits
+//! [`ValueChange<bool>`] events on items to expand or collapse them, [`TreeSelectionChange`]
+//! events when the selection changes, and [`TreeReparent`] events when an item is dragged onto
+//! another item. The app is expected to update the [`Expanded`], [`Selected`] and [`TreeParent`]
+//! components in response, or to use [`tree_item_self_expand`], [`tree_view_self_select`] and
+//! [`tree_view_self_reparent`].
+//!
+//! For keyboard navigation, each item should be focusable, for example by adding a
+//! [`TabIndex`](bevy_input_focus::tab_navigation::TabIndex). While an item has focus:
+//!
+//! - `ArrowUp` and `ArrowDown` move to the previous and next visible item.
+//! - `Home` and `End` move to the first and last visible item.
+//! - `ArrowRight` expands the item, or moves to its first child if it is already expanded.
+//! - `ArrowLeft` collapses the item, or moves to its parent if it is already collapsed.
+//! - `Space` selects the item, and `Enter` triggers an [`Activate`] event on it.
+//!
+//! Moving to an item selects it, unless `Control` is held. In a [`TreeView::multi_select`] tree,
+//! holding `Shift` extends the selection, and holding `Control` (or `Super`) while pressing
+//! `Space` or clicking toggles the selection of an item.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    lifecycle::RemovedComponents,
+    observer::On,
+    query::{Added, Changed, Has, Or, With},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut, SystemParam},
+};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_input_focus::{FocusCause, FocusedInput, InputFocus, InputFocusVisible};
+use bevy_picking::events::{Click, DragDrop, Pointer, Press};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_ui::{Display, InteractionDisabled, Node, Selected, UiSystems};
+
+use crate::{Activate, ValueChange};
+
+/// Headless widget implementation for a tree view. See the [module documentation](self) for
+/// an overview of how tree views are structured.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Tree)), TreeViewState)]
+#[reflect(Component, Default)]
+pub struct TreeView {
+    /// Whether more than one item can be selected at a time.
+    pub multi_select: bool,
+}
+
+/// Headless widget implementation for an item in a [`TreeView`]. The item should have a
+/// [`TreeParent`] component pointing to its parent item, or to the tree view for top-level items.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[require(
+    AccessibilityNode(accesskit::Node::new(Role::TreeItem)),
+    TreeDepth,
+    TreeItemState
+)]
+#[reflect(Component, Default)]
+pub struct TreeItem;
+
+/// State of a [`TreeItem`] that is maintained by the tree view.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TreeItemState {
+    /// The [`Node::display`] of the item before it was hidden, restored when it is shown.
+    display: Display,
+}
+
+/// The parent of a [`TreeItem`]: either another item, or the [`TreeView`] for top-level items.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[relationship(relationship_target = TreeChildren)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct TreeParent(#[entities] pub Entity);
+
+/// The items whose [`TreeParent`] is this entity, in order.
+///
+/// Like [`Children`](bevy_ecs::hierarchy::Children), this is populated automatically and should
+/// not be modified directly. Despawning an item also despawns the items inside it.
+#[derive(Component, Default, Debug, PartialEq, Eq)]
+#[relationship_target(relationship = TreeParent, linked_spawn)]
+pub struct TreeChildren(Vec<Entity>);
+
+/// Marker component for a [`TreeItem`] whose children are shown.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct Expanded;
+
+/// The nesting level of a [`TreeItem`], starting at 0 for top-level items. This is updated
+/// automatically, and can be used to indent the item.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct TreeDepth(pub usize);
+
+/// Interaction state of a [`TreeView`].
+#[derive(Component, Debug, Default)]
+pub struct TreeViewState {
+    /// The item that range selections extend from.
+    anchor: Option<Entity>,
+}
+
+/// Event triggered on a [`TreeView`] when the user changes the selection.
+#[derive(EntityEvent, Clone, Debug, PartialEq)]
+pub struct TreeSelectionChange {
+    /// The tree view.
+    #[event_target]
+    pub tree: Entity,
+    /// The items that should be selected, in the order they appear in the tree.
+    pub selection: Vec<Entity>,
+}
+
+/// Event triggered on a [`TreeView`] when the user drags an item onto another item, or onto the
+/// tree view itself.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct TreeReparent {
+    /// The tree view.
+    #[event_target]
+    pub tree: Entity,
+    /// The item that was dragged.
+    pub item: Entity,
+    /// The item it was dropped onto, or the tree view.
+    pub new_parent: Entity,
+}
+
+/// How a new item affects the selection of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectMode {
+    /// Select only the item.
+    Replace,
+    /// Add the item to the selection, or remove it if it is selected.
+    Toggle,
+    /// Select the visible items between the anchor and the item.
+    Extend,
+}
+
+/// A [`SystemParam`] for walking the structure of [`TreeView`]s.
+#[derive(SystemParam)]
+pub struct TreeNavigation<'w, 's> {
+    q_tree: Query<'w, 's, (&'static TreeView, Has<InteractionDisabled>)>,
+    q_parent: Query<'w, 's, &'static TreeParent>,
+    q_children: Query<'w, 's, &'static TreeChildren>,
+    q_item: Query<'w, 's, (Has<Expanded>, Has<Selected>), With<TreeItem>>,
+}
+
+impl TreeNavigation<'_, '_> {
+    /// Returns the [`TreeView`] that `item` belongs to.
+    pub fn tree(&self, item: Entity) -> Option<Entity> {
+        self.q_parent
+            .iter_ancestors(item)
+            .find(|ancestor| self.q_tree.contains(*ancestor))
+    }
+
+    /// Returns the parent of `item`, if it is another item.
+    pub fn parent_item(&self, item: Entity) -> Option<Entity> {
+        self.q_parent
+            .get(item)
+            .ok()
+            .map(|parent| parent.0)
+            .filter(|parent| self.q_item.contains(*parent))
+    }
+
+    /// Returns the items inside `entity`, which can be an item or a tree view.
+    pub fn children(&self, entity: Entity) -> &[Entity] {
+        self.q_children
+            .get(entity)
+            .map(|children| children.0.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if `item` is expanded.
+    pub fn is_expanded(&self, item: Entity) -> bool {
+        self.q_item.get(item).is_ok_and(|(expanded, _)| expanded)
+    }
+
+    /// Returns the items of `tree` that aren't inside a collapsed item, in order.
+    pub fn visible_items(&self, tree: Entity) -> Vec<Entity> {
+        let mut items = Vec::new();
+        self.collect_items(tree, true, &mut items);
+        items
+    }
+
+    /// Returns the selected items of `tree`, in order.
+    pub fn selected_items(&self, tree: Entity) -> Vec<Entity> {
+        let mut items = Vec::new();
+        self.collect_items(tree, false, &mut items);
+        items.retain(|item| self.q_item.get(*item).is_ok_and(|(_, selected)| selected));
+        items
+    }
+
+    fn collect_items(&self, entity: Entity, visible_only: bool, items: &mut Vec<Entity>) {
+        for &child in self.children(entity) {
+            let Ok((expanded, _)) = self.q_item.get(child) else {
+                continue;
+            };
+            items.push(child);
+            if expanded || !visible_only {
+                self.collect_items(child, visible_only, items);
+            }
+        }
+    }
+
+    /// Returns the selection of `tree` after selecting `item` with the given mode.
+    fn select(
+        &self,
+        tree: Entity,
+        item: Entity,
+        mode: SelectMode,
+        anchor: Option<Entity>,
+    ) -> Vec<Entity> {
+        let multi_select = self
+            .q_tree
+            .get(tree)
+            .is_ok_and(|(tree, _)| tree.multi_select);
+        match mode {
+            SelectMode::Replace => vec![item],
+            _ if !multi_select => vec![item],
+            SelectMode::Toggle => {
+                let mut selection = self.selected_items(tree);
+                if let Some(index) = selection.iter().position(|selected| *selected == item) {
+                    selection.remove(index);
+                } else {
+                    selection.push(item);
+                }
+                selection
+            }
+            SelectMode::Extend => {
+                let visible = self.visible_items(tree);
+                let anchor = anchor.unwrap_or(item);
+                match (
+                    visible.iter().position(|entity| *entity == anchor),
+                    visible.iter().position(|entity| *entity == item),
+                ) {
+                    (Some(a), Some(b)) => visible[a.min(b)..=a.max(b)].to_vec(),
+                    _ => vec![item],
+                }
+            }
+        }
+    }
+}
+
+fn tree_item_on_key_input(
+    mut ev: On<FocusedInput<KeyboardInput>>,
+    nav: TreeNavigation,
+    mut q_state: Query<&mut TreeViewState>,
+    keys: Res<ButtonInput<Key>>,
+    mut focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    let item = ev.focused_entity;
+    if !nav.q_item.contains(item) {
+        return;
+    }
+    let Some(tree) = nav.tree(item) else {
+        return;
+    };
+    let event = &ev.event().input;
+    let key_code = event.key_code;
+    if event.state != ButtonState::Pressed
+        || !matches!(
+            key_code,
+            KeyCode::ArrowUp
+                | KeyCode::ArrowDown
+                | KeyCode::ArrowLeft
+                | KeyCode::ArrowRight
+                | KeyCode::Home
+                | KeyCode::End
+                | KeyCode::Space
+                | KeyCode::Enter
+        )
+    {
+        return;
+    }
+    ev.propagate(false);
+    if nav.q_tree.get(tree).is_ok_and(|(_, disabled)| disabled) {
+        return;
+    }
+
+    let shift = keys.pressed(Key::Shift);
+    let control = keys.pressed(Key::Control) || keys.pressed(Key::Super);
+    let has_children = !nav.children(item).is_empty();
+    let expanded = nav.is_expanded(item);
+    let visible = nav.visible_items(tree);
+    let position = visible.iter().position(|entity| *entity == item);
+
+    let next = match key_code {
+        KeyCode::ArrowUp => position.and_then(|index| index.checked_sub(1)),
+        KeyCode::ArrowDown => position.map(|index| index + 1),
+        KeyCode::Home => Some(0),
+        KeyCode::End => visible.len().checked_sub(1),
+        KeyCode::ArrowRight if has_children && !expanded => {
+            commands.trigger(ValueChange {
+                source: item,
+                value: true,
+                is_final: true,
+            });
+            return;
+        }
+        KeyCode::ArrowRight if has_children => {
+            return move_to(
+                nav.children(item).first().copied(),
+                tree,
+                &nav,
+                &mut q_state,
+                shift,
+                control,
+                &mut focus,
+                &mut commands,
+            );
+        }
+        KeyCode::ArrowLeft if has_children && expanded => {
+            commands.trigger(ValueChange {
+                source: item,
+                value: false,
+                is_final: true,
+            });
+            return;
+        }
+        KeyCode::ArrowLeft => {
+            return move_to(
+                nav.parent_item(item),
+                tree,
+                &nav,
+                &mut q_state,
+                shift,
+                control,
+                &mut focus,
+                &mut commands,
+            );
+        }
+        KeyCode::Space => {
+            let mode = match control {
+                true => SelectMode::Toggle,
+                false => SelectMode::Replace,
+            };
+            let mut state = q_state.get_mut(tree).ok();
+            let anchor = state.as_ref().and_then(|state| state.anchor);
+            if let Some(state) = state.as_mut() {
+                state.anchor = Some(item);
+            }
+            commands.trigger(TreeSelectionChange {
+                tree,
+                selection: nav.select(tree, item, mode, anchor),
+            });
+            return;
+        }
+        KeyCode::Enter => {
+            commands.trigger(Activate { entity: item });
+            return;
+        }
+        _ => return,
+    };
+    move_to(
+        next.and_then(|index| visible.get(index).copied()),
+        tree,
+        &nav,
+        &mut q_state,
+        shift,
+        control,
+        &mut focus,
+        &mut commands,
+    );
+}
+
+/// Moves the focus to `item` and updates the selection, in response to a navigation key.
+fn move_to(
+    item: Option<Entity>,
+    tree: Entity,
+    nav: &TreeNavigation,
+    q_state: &mut Query<&mut TreeViewState>,
+    shift: bool,
+    control: bool,
+    focus: &mut InputFocus,
+    commands: &mut Commands,
+) {
+    let Some(item) = item else {
+        return;
+    };
+    focus.set(item, FocusCause::Navigated);
+    if control {
+        return;
+    }
+    let Ok(mut state) = q_state.get_mut(tree) else {
+        return;
+    };
+    let selection = match shift {
+        true => nav.select(tree, item, SelectMode::Extend, state.anchor),
+        false => {
+            state.anchor = Some(item);
+            nav.select(tree, item, SelectMode::Replace, None)
+        }
+    };
+    commands.trigger(TreeSelectionChange { tree, selection });
+}
+
+fn tree_item_on_pointer_down(
+    mut press: On<Pointer<Press>>,
+    nav: TreeNavigation,
+    focus: Option<ResMut<InputFocus>>,
+    focus_visible: Option<ResMut<InputFocusVisible>>,
+) {
+    if !nav.q_item.contains(press.entity) {
+        return;
+    }
+    press.propagate(false);
+    // Pressing an item makes it the focused input, and hides the focus ring if it was visible.
+    if let Some(mut focus) = focus {
+        focus.set(press.entity, FocusCause::Pressed);
+    }
+    if let Some(mut focus_visible) = focus_visible {
+        focus_visible.0 = false;
+    }
+}
+
+fn tree_item_on_click(
+    mut click: On<Pointer<Click>>,
+    nav: TreeNavigation,
+    mut q_state: Query<&mut TreeViewState>,
+    keys: Res<ButtonInput<Key>>,
+    mut commands: Commands,
+) {
+    let item = click.entity;
+    if !nav.q_item.contains(item) {
+        return;
+    }
+    click.propagate(false);
+    let Some(tree) = nav.tree(item) else {
+        return;
+    };
+    let Ok(mut state) = q_state.get_mut(tree) else {
+        return;
+    };
+    if nav.q_tree.get(tree).is_ok_and(|(_, disabled)| disabled) {
+        return;
+    }
+
+    let mode = if keys.pressed(Key::Shift) {
+        SelectMode::Extend
+    } else if keys.pressed(Key::Control) || keys.pressed(Key::Super) {
+        SelectMode::Toggle
+    } else {
+        SelectMode::Replace
+    };
+    let selection = nav.select(tree, item, mode, state.anchor);
+    if mode != SelectMode::Extend {
+        state.anchor = Some(item);
+    }
+    commands.trigger(TreeSelectionChange { tree, selection });
+}
+
+fn tree_item_on_drag_drop(
+    mut drop: On<Pointer<DragDrop>>,
+    nav: TreeNavigation,
+    mut commands: Commands,
+) {
+    let target = drop.entity;
+    let item = drop.event().dropped;
+    if !nav.q_item.contains(item) || !(nav.q_item.contains(target) || nav.q_tree.contains(target)) {
+        return;
+    }
+    drop.propagate(false);
+    let Some(tree) = nav.tree(item) else {
+        return;
+    };
+    let target_tree = match nav.q_tree.contains(target) {
+        true => Some(target),
+        false => nav.tree(target),
+    };
+    if target_tree != Some(tree)
+        || nav.q_tree.get(tree).is_ok_and(|(_, disabled)| disabled)
+        || nav
+            .q_parent
+            .get(item)
+            .is_ok_and(|parent| parent.0 == target)
+    {
+        return;
+    }
+    // Items can't be moved inside themselves.
+    if target == item
+        || nav
+            .q_parent
+            .iter_ancestors(target)
+            .any(|entity| entity == item)
+    {
+        return;
+    }
+    commands.trigger(TreeReparent {
+        tree,
+        item,
+        new_parent: target,
+    });
+}
+
+/// Updates the depth, accessibility properties and visibility of tree items when the structure
+/// of a tree or the expanded state of its items changes.
+fn update_tree_items(
+    q_trees: Query<Entity, With<TreeView>>,
+    q_changed: Query<
+        (),
+        Or<(
+            Added<TreeView>,
+            Added<TreeItem>,
+            Changed<TreeParent>,
+            Added<Expanded>,
+        )>,
+    >,
+    mut removed_expanded: RemovedComponents<Expanded>,
+    mut removed_parent: RemovedComponents<TreeParent>,
+    nav: TreeNavigation,
+    mut q_item: Query<(
+        &mut TreeDepth,
+        &mut AccessibilityNode,
+        &mut TreeItemState,
+        Option<&mut Node>,
+    )>,
+) {
+    let removed = removed_expanded.read().count() + removed_parent.read().count();
+    if q_changed.is_empty() && removed == 0 {
+        return;
+    }
+
+    fn update(
+        entity: Entity,
+        depth: usize,
+        revealed: bool,
+        nav: &TreeNavigation,
+        q_item: &mut Query<(
+            &mut TreeDepth,
+            &mut AccessibilityNode,
+            &mut TreeItemState,
+            Option<&mut Node>,
+        )>,
+    ) {
+        for &child in nav.children(entity) {
+            let Ok((mut tree_depth, mut accessibility, mut state, node)) = q_item.get_mut(child)
+            else {
+                continue;
+            };
+            let expanded = nav.is_expanded(child);
+            tree_depth.set_if_neq(TreeDepth(depth));
+            if accessibility.level() != Some(depth + 1) {
+                accessibility.set_level(depth + 1);
+            }
+            match nav.children(child).is_empty() {
+                true if accessibility.is_expanded().is_some() => accessibility.clear_expanded(),
+                false if accessibility.is_expanded() != Some(expanded) => {
+                    accessibility.set_expanded(expanded);
+                }
+                _ => {}
+            }
+            if let Some(mut node) = node {
+                match (revealed, node.display) {
+                    (true, Display::None) => node.display = state.display,
+                    (false, display) if display != Display::None => {
+                        state.display = display;
+                        node.display = Display::None;
+                    }
+                    _ => {}
+                }
+            }
+            update(child, depth + 1, revealed && expanded, nav, q_item);
+        }
+    }
+
+    for tree in q_trees.iter() {
+        update(tree, 0, true, &nav, &mut q_item);
+    }
+}
+
+/// Observer function which expands or collapses a [`TreeItem`] in response to a
+/// [`ValueChange<bool>`] event. This can be used to make tree items manage their own expanded
+/// state, as opposed to managing it externally.
+pub fn tree_item_self_expand(
+    value_change: On<ValueChange<bool>>,
+    q_item: Query<(), With<TreeItem>>,
+    mut commands: Commands,
+) {
+    if q_item.contains(value_change.source) {
+        if value_change.value {
+            commands.entity(value_change.source).insert(Expanded);
+        } else {
+            commands.entity(value_change.source).remove::<Expanded>();
+        }
+    }
+}
+
+/// Observer function which updates the [`Selected`] state of the items of a [`TreeView`] in
+/// response to a [`TreeSelectionChange`] event.
+pub fn tree_view_self_select(
+    selection_change: On<TreeSelectionChange>,
+    nav: TreeNavigation,
+    mut commands: Commands,
+) {
+    let selection = &selection_change.selection;
+    let mut items = Vec::new();
+    nav.collect_items(selection_change.tree, false, &mut items);
+    for item in items {
+        let selected = nav.q_item.get(item).is_ok_and(|(_, selected)| selected);
+        match (selection.contains(&item), selected) {
+            (true, false) => {
+                commands.entity(item).insert(Selected);
+            }
+            (false, true) => {
+                commands.entity(item).remove::<Selected>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Observer function which moves a [`TreeItem`] to its new parent in response to a
+/// [`TreeReparent`] event. The item is added after the existing children of the new parent.
+pub fn tree_view_self_reparent(reparent: On<TreeReparent>, mut commands: Commands) {
+    commands
+        .entity(reparent.item)
+        .insert(TreeParent(reparent.new_parent));
+}
+
+/// Plugin that adds the observers and systems for the [`TreeView`] widget.
+pub struct TreeViewPlugin;
+
+impl Plugin for TreeViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(tree_item_on_key_input)
+            .add_observer(tree_item_on_pointer_down)
+            .add_observer(tree_item_on_click)
+            .add_observer(tree_item_on_drag_drop)
+            .add_systems(PostUpdate, update_tree_items.in_set(UiSystems::Prepare));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    #[test]
+    fn test_collapsed_items_are_hidden() {
+        let mut world = World::new();
+        let tree = world.spawn(TreeView::default()).id();
+        let a = world
+            .spawn((TreeItem, TreeParent(tree), Node::default()))
+            .id();
+        let b = world
+            .spawn((
+                TreeItem,
+                TreeParent(a),
+                Node {
+                    display: Display::Grid,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let c = world.spawn((TreeItem, TreeParent(b), Node::default())).id();
+        let d = world
+            .spawn((TreeItem, TreeParent(tree), Node::default()))
+            .id();
+
+        world.run_system_once(update_tree_items).unwrap();
+        assert_eq!(world.get::<TreeDepth>(c), Some(&TreeDepth(2)));
+        assert_eq!(world.get::<Node>(b).unwrap().display, Display::None);
+        let visible = world
+            .run_system_once(move |nav: TreeNavigation| nav.visible_items(tree))
+            .unwrap();
+        assert_eq!(visible, vec![a, d]);
+
+        world.entity_mut(a).insert(Expanded);
+        world.run_system_once(update_tree_items).unwrap();
+        // The item's own display is restored when it is shown.
+        assert_eq!(world.get::<Node>(b).unwrap().display, Display::Grid);
+        assert_eq!(world.get::<Node>(c).unwrap().display, Display::None);
+        let visible = world
+            .run_system_once(move |nav: TreeNavigation| nav.visible_items(tree))
+            .unwrap();
+        assert_eq!(visible, vec![a, b, d]);
+    }
+}
@@ -0,0 +1,585 @@
+use core::ops::Range;
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    observer::On,
+    query::{Has, With, Without},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_input::keyboard::{KeyCode, KeyboardInput};
+use bevy_input::ButtonState;
+use bevy_input_focus::FocusedInput;
+use bevy_picking::events::{Click, Pointer};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_ui::{
+    ComputedNode, InteractionDisabled, Node, PositionType, ScrollPosition, Selected, UiSystems, Val,
+};
+
+use crate::ValueChange;
+
+/// How the rows of a [`VirtualList`] are sized.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(PartialEq, Clone)]
+pub enum RowHeight {
+    /// Every row has the same height, in logical pixels. Rows are given this height.
+    Fixed(f32),
+    /// Each row is sized by its content. Rows that haven't been laid out yet are assumed to be
+    /// `estimate` logical pixels tall, and are measured after layout.
+    Measured {
+        /// The assumed height of rows that haven't been measured yet.
+        estimate: f32,
+    },
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Fixed(20.)
+    }
+}
+
+/// Headless widget implementation for a virtualized, vertically scrolling list.
+///
+/// A [`VirtualList`] can show a very large number of items, because it only keeps entities for
+/// the rows that are visible, plus a few [`overscan`](Self::overscan) rows above and below.
+/// As the [`ScrollPosition`] changes, rows that scroll out of view are recycled for the rows that
+/// scroll into view.
+///
+/// The list entity should be a scrolling container: a [`Node`] with a vertical
+/// [`OverflowAxis::Scroll`](bevy_ui::OverflowAxis::Scroll). The widget manages the children of
+/// the list: rows are spawned as absolutely positioned children with a [`VirtualListRow`]
+/// component, along with a spacer child that sizes the scrolling content. Rows don't have any
+/// content of their own; whenever a row is assigned an item, a [`VirtualListBind`] event is
+/// triggered on the row, and the app is expected to fill in or update the row content for that
+/// item.
+///
+/// Like the other widgets, the list uses external state management for its selection: clicking
+/// a row or using the arrow, `Home`, `End`, `PageUp` and `PageDown` keys while the list has focus
+/// emits a [`ValueChange<usize>`] event with the index of the newly selected item, and the app is
+/// expected to update the [`VirtualListSelection`] in response (or use
+/// [`virtual_list_self_update`]). The selected row is marked with the [`Selected`] component, and
+/// the list scrolls to keep the selected row in view whenever the selection changes.
+///
+/// To receive keyboard input, the list needs to be focusable, for example by adding a
+/// [`TabIndex`](bevy_input_focus::tab_navigation::TabIndex).
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[require(
+    AccessibilityNode(accesskit::Node::new(Role::ListBox)),
+    ScrollPosition,
+    VirtualListSelection,
+    VirtualListState
+)]
+#[reflect(Component, Default)]
+pub struct VirtualList {
+    /// The number of items in the list.
+    pub item_count: usize,
+    /// How the rows are sized.
+    pub row_height: RowHeight,
+    /// The number of rows kept above and below the visible rows, so that scrolling doesn't
+    /// reveal rows that haven't been filled in yet.
+    pub overscan: usize,
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            row_height: RowHeight::default(),
+            overscan: 2,
+        }
+    }
+}
+
+impl VirtualList {
+    /// Construct a new list of `item_count` items.
+    pub fn new(item_count: usize, row_height: RowHeight) -> Self {
+        Self {
+            item_count,
+            row_height,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the number of rows kept above and below the visible rows.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+/// The index of the selected item of a [`VirtualList`], if any.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct VirtualListSelection(pub Option<usize>);
+
+/// A row of a [`VirtualList`]. Rows are spawned and recycled by the list.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::ListBoxOption)))]
+#[reflect(Component)]
+pub struct VirtualListRow {
+    /// The index of the item shown by this row.
+    pub index: usize,
+}
+
+/// Event triggered on a [`VirtualListRow`] when it is spawned or recycled to show another item.
+/// The app should fill in the content of the row for the item.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+pub struct VirtualListBind {
+    /// The row entity.
+    #[event_target]
+    pub row: Entity,
+    /// The list that owns the row.
+    pub list: Entity,
+    /// The index of the item the row now shows.
+    pub index: usize,
+}
+
+/// Layout state of a [`VirtualList`]: its row entities and the heights of its items.
+#[derive(Component, Debug, Default)]
+pub struct VirtualListState {
+    /// Row entities and the index of the item they show.
+    rows: Vec<(Entity, usize)>,
+    /// The child that sizes the scrolling content.
+    spacer: Option<Entity>,
+    /// Measured heights of the items, for [`RowHeight::Measured`].
+    measured: Vec<Option<f32>>,
+    /// Offset of the top of each item, followed by the total height.
+    offsets: Vec<f32>,
+    /// Whether `offsets` needs to be recomputed.
+    dirty: bool,
+    /// Indices of the items that are at least partially visible.
+    visible: Range<usize>,
+}
+
+impl VirtualListState {
+    /// Returns the indices of the items that are at least partially visible, not including
+    /// overscan.
+    pub fn visible_range(&self) -> Range<usize> {
+        self.visible.clone()
+    }
+
+    /// Returns the offset of the top of the item at `index` from the top of the list, in
+    /// logical pixels.
+    pub fn item_offset(&self, index: usize) -> f32 {
+        let last = self.offsets.len().saturating_sub(1);
+        self.offsets.get(index.min(last)).copied().unwrap_or(0.)
+    }
+
+    /// Returns the height of the item at `index`, in logical pixels.
+    pub fn item_height(&self, index: usize) -> f32 {
+        self.item_offset(index + 1) - self.item_offset(index)
+    }
+
+    /// Returns the total height of the items, in logical pixels.
+    pub fn total_height(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or(0.)
+    }
+
+    /// Recomputes the item offsets if the list or the measured heights changed.
+    fn update_offsets(&mut self, list: &VirtualList, list_changed: bool) {
+        if !list_changed && !self.dirty && self.offsets.len() == list.item_count + 1 {
+            return;
+        }
+        self.measured.resize(list.item_count, None);
+        self.offsets.clear();
+        self.offsets.reserve(list.item_count + 1);
+        let mut offset = 0.;
+        self.offsets.push(offset);
+        for index in 0..list.item_count {
+            offset += match list.row_height {
+                RowHeight::Fixed(height) => height,
+                RowHeight::Measured { estimate } => self.measured[index].unwrap_or(estimate),
+            };
+            self.offsets.push(offset);
+        }
+        self.dirty = false;
+    }
+
+    /// Returns the index of the item at `y` logical pixels from the top of the list.
+    fn item_at(&self, y: f32) -> usize {
+        let item_count = self.offsets.len().saturating_sub(1);
+        self.offsets
+            .partition_point(|offset| *offset <= y)
+            .saturating_sub(1)
+            .min(item_count.saturating_sub(1))
+    }
+
+    /// Returns the indices of the items overlapping `top..top + height`.
+    fn range_in_view(&self, top: f32, height: f32) -> Range<usize> {
+        if self.offsets.len() < 2 {
+            return 0..0;
+        }
+        let first = self.item_at(top);
+        let last = self.item_at(top + height.max(0.));
+        first..last + 1
+    }
+}
+
+/// Returns the height of the visible area of a scrolling list, in logical pixels.
+fn viewport_height(node: &ComputedNode) -> f32 {
+    let inset = node.content_inset();
+    ((node.size().y - node.scrollbar_size.y - inset.min_inset.y - inset.max_inset.y)
+        * node.inverse_scale_factor())
+    .max(0.)
+}
+
+fn update_virtual_list_rows(
+    mut q_list: Query<
+        (
+            Entity,
+            Ref<VirtualList>,
+            &mut VirtualListState,
+            Ref<VirtualListSelection>,
+            &mut ScrollPosition,
+            &ComputedNode,
+            &mut AccessibilityNode,
+        ),
+        Without<VirtualListRow>,
+    >,
+    mut q_row: Query<(
+        &mut VirtualListRow,
+        &mut Node,
+        &mut AccessibilityNode,
+        Has<Selected>,
+    )>,
+    mut q_spacer: Query<&mut Node, Without<VirtualListRow>>,
+    mut commands: Commands,
+) {
+    for (list_id, list, mut state, selection, mut scroll_position, node, mut accessibility) in
+        q_list.iter_mut()
+    {
+        let state = &mut *state;
+        state.update_offsets(&list, list.is_changed());
+        if list.is_changed() {
+            accessibility.set_size_of_set(list.item_count);
+        }
+
+        // Keep the selected item in view.
+        let view_height = viewport_height(node);
+        if selection.is_changed()
+            && !selection.is_added()
+            && let Some(index) = selection.0.filter(|index| *index < list.item_count)
+        {
+            let top = state.item_offset(index);
+            let bottom = top + state.item_height(index);
+            if top < scroll_position.y {
+                scroll_position.y = top;
+            } else if bottom > scroll_position.y + view_height {
+                scroll_position.y = bottom - view_height;
+            }
+        }
+
+        let max_scroll = (state.total_height() - view_height).max(0.);
+        let scroll_y = scroll_position.y.clamp(0., max_scroll);
+        state.visible = state.range_in_view(scroll_y, view_height);
+        let range = state.visible.start.saturating_sub(list.overscan)
+            ..(state.visible.end + list.overscan).min(list.item_count);
+
+        // Size the scrolling content.
+        let total_height = Val::Px(state.total_height());
+        match state
+            .spacer
+            .and_then(|spacer| q_spacer.get_mut(spacer).ok())
+        {
+            Some(mut spacer) => {
+                if spacer.height != total_height {
+                    spacer.height = total_height;
+                }
+            }
+            None => {
+                let spacer = commands
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.),
+                            height: total_height,
+                            ..Default::default()
+                        },
+                        ChildOf(list_id),
+                    ))
+                    .id();
+                state.spacer = Some(spacer);
+            }
+        }
+
+        // Forget rows that were despawned by the app, and recycle the rows that are out of view.
+        state.rows.retain(|(row, _)| q_row.contains(*row));
+        let mut shown = Vec::with_capacity(range.len());
+        let mut free = Vec::new();
+        for &(row, index) in &state.rows {
+            if range.contains(&index) {
+                shown.push((row, index));
+            } else {
+                free.push(row);
+            }
+        }
+        shown.sort_unstable_by_key(|(_, index)| *index);
+        let mut missing = range
+            .clone()
+            .filter(|index| shown.binary_search_by_key(index, |(_, i)| *i).is_err())
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let mut rows = shown;
+        for row in free {
+            if let Some(index) = missing.next() {
+                if let Ok((mut row_data, ..)) = q_row.get_mut(row) {
+                    row_data.index = index;
+                }
+                commands.trigger(VirtualListBind {
+                    row,
+                    list: list_id,
+                    index,
+                });
+                rows.push((row, index));
+            } else {
+                commands.entity(row).despawn();
+            }
+        }
+        for index in missing {
+            let row = commands
+                .spawn((
+                    VirtualListRow { index },
+                    row_node(&list, state, index),
+                    ChildOf(list_id),
+                ))
+                .id();
+            if selection.0 == Some(index) {
+                commands.entity(row).insert(Selected);
+            }
+            commands.trigger(VirtualListBind {
+                row,
+                list: list_id,
+                index,
+            });
+            rows.push((row, index));
+        }
+        state.rows = rows;
+
+        // Position the existing rows, and keep their selection state up to date.
+        for &(row, index) in &state.rows {
+            let Ok((_, mut row_node_data, mut row_accessibility, selected)) = q_row.get_mut(row)
+            else {
+                continue;
+            };
+            let new_node = row_node(&list, state, index);
+            if row_node_data.position_type != new_node.position_type
+                || row_node_data.top != new_node.top
+                || row_node_data.left != new_node.left
+                || row_node_data.right != new_node.right
+                || row_node_data.height != new_node.height
+            {
+                row_node_data.position_type = new_node.position_type;
+                row_node_data.top = new_node.top;
+                row_node_data.left = new_node.left;
+                row_node_data.right = new_node.right;
+                row_node_data.height = new_node.height;
+            }
+            if row_accessibility.position_in_set() != Some(index + 1) {
+                row_accessibility.set_position_in_set(index + 1);
+            }
+            match (selection.0 == Some(index), selected) {
+                (true, false) => {
+                    commands.entity(row).insert(Selected);
+                }
+                (false, true) => {
+                    commands.entity(row).remove::<Selected>();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the layout properties of a row, which are managed by the list.
+fn row_node(list: &VirtualList, state: &VirtualListState, index: usize) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(state.item_offset(index)),
+        left: Val::Px(0.),
+        right: Val::Px(0.),
+        height: match list.row_height {
+            RowHeight::Fixed(height) => Val::Px(height),
+            RowHeight::Measured { .. } => Val::Auto,
+        },
+        ..Default::default()
+    }
+}
+
+fn measure_virtual_list_rows(
+    mut q_list: Query<(&VirtualList, &mut VirtualListState)>,
+    q_row: Query<(&VirtualListRow, &ComputedNode)>,
+) {
+    for (list, mut state) in q_list.iter_mut() {
+        if !matches!(list.row_height, RowHeight::Measured { .. }) {
+            continue;
+        }
+        let state = state.bypass_change_detection();
+        for &(row, _) in &state.rows {
+            let Ok((row_data, node)) = q_row.get(row) else {
+                continue;
+            };
+            let height = node.size().y * node.inverse_scale_factor();
+            let Some(measured) = state.measured.get_mut(row_data.index) else {
+                continue;
+            };
+            if height > 0. && measured.is_none_or(|measured| (measured - height).abs() > 0.5) {
+                *measured = Some(height);
+                state.dirty = true;
+            }
+        }
+    }
+}
+
+fn virtual_list_on_key_input(
+    mut ev: On<FocusedInput<KeyboardInput>>,
+    q_list: Query<(
+        &VirtualList,
+        &VirtualListState,
+        &VirtualListSelection,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok((list, state, selection, disabled)) = q_list.get(ev.focused_entity) else {
+        return;
+    };
+    let event = &ev.event().input;
+    let key_code = event.key_code;
+    if event.state != ButtonState::Pressed
+        || !matches!(
+            key_code,
+            KeyCode::ArrowUp
+                | KeyCode::ArrowDown
+                | KeyCode::Home
+                | KeyCode::End
+                | KeyCode::PageUp
+                | KeyCode::PageDown
+        )
+    {
+        return;
+    }
+    ev.propagate(false);
+    if disabled || list.item_count == 0 {
+        return;
+    }
+
+    let last = list.item_count - 1;
+    let page = state.visible.len().saturating_sub(1).max(1);
+    let current = selection.0.map(|index| index.min(last));
+    let next = match (key_code, current) {
+        (KeyCode::ArrowUp, Some(index)) => index.saturating_sub(1),
+        (KeyCode::ArrowUp, None) | (KeyCode::End, _) => last,
+        (KeyCode::ArrowDown, Some(index)) => (index + 1).min(last),
+        (KeyCode::ArrowDown, None) | (KeyCode::Home, _) => 0,
+        (KeyCode::PageUp, index) => index.unwrap_or(0).saturating_sub(page),
+        (KeyCode::PageDown, index) => (index.unwrap_or(0) + page).min(last),
+        _ => return,
+    };
+    if current != Some(next) {
+        commands.trigger(ValueChange {
+            source: ev.focused_entity,
+            value: next,
+            is_final: true,
+        });
+    }
+}
+
+fn virtual_list_row_on_click(
+    mut click: On<Pointer<Click>>,
+    q_row: Query<(&VirtualListRow, &ChildOf)>,
+    q_list: Query<(&VirtualListSelection, Has<InteractionDisabled>), With<VirtualList>>,
+    mut commands: Commands,
+) {
+    let Ok((row, ChildOf(list))) = q_row.get(click.entity) else {
+        return;
+    };
+    let Ok((selection, disabled)) = q_list.get(*list) else {
+        return;
+    };
+    click.propagate(false);
+    if !disabled && selection.0 != Some(row.index) {
+        commands.trigger(ValueChange {
+            source: *list,
+            value: row.index,
+            is_final: true,
+        });
+    }
+}
+
+/// Observer function which updates the [`VirtualListSelection`] of a list in response to a
+/// [`ValueChange<usize>`] event. This can be used to make lists manage their own selection, as
+/// opposed to managing it externally.
+pub fn virtual_list_self_update(
+    value_change: On<ValueChange<usize>>,
+    mut q_list: Query<&mut VirtualListSelection, With<VirtualList>>,
+) {
+    if let Ok(mut selection) = q_list.get_mut(value_change.source) {
+        selection.set_if_neq(VirtualListSelection(Some(value_change.value)));
+    }
+}
+
+/// Plugin that adds the observers and systems for the [`VirtualList`] widget.
+pub struct VirtualListPlugin;
+
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(virtual_list_on_key_input)
+            .add_observer(virtual_list_row_on_click)
+            .add_systems(
+                PostUpdate,
+                (
+                    update_virtual_list_rows.in_set(UiSystems::Prepare),
+                    measure_virtual_list_rows.in_set(UiSystems::PostLayout),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_state(list: &VirtualList) -> VirtualListState {
+        let mut state = VirtualListState::default();
+        state.update_offsets(list, true);
+        state
+    }
+
+    #[test]
+    fn test_fixed_row_range() {
+        let list = VirtualList::new(10_000, RowHeight::Fixed(20.));
+        let state = list_state(&list);
+        assert_eq!(state.total_height(), 200_000.);
+        assert_eq!(state.range_in_view(0., 100.), 0..6);
+        assert_eq!(state.range_in_view(30., 100.), 1..7);
+        assert_eq!(state.range_in_view(199_950., 100.), 9_997..10_000);
+        assert_eq!(state.item_offset(3), 60.);
+    }
+
+    #[test]
+    fn test_measured_row_range() {
+        let list = VirtualList::new(4, RowHeight::Measured { estimate: 10. });
+        let mut state = list_state(&list);
+        assert_eq!(state.total_height(), 40.);
+
+        state.measured[1] = Some(50.);
+        state.dirty = true;
+        state.update_offsets(&list, false);
+        assert_eq!(state.total_height(), 80.);
+        assert_eq!(state.item_height(1), 50.);
+        assert_eq!(state.range_in_view(20., 30.), 1..2);
+        assert_eq!(state.range_in_view(55., 20.), 1..4);
+
+        let empty = list_state(&VirtualList::default());
+        assert_eq!(empty.range_in_view(0., 100.), 0..0);
+    }
+}
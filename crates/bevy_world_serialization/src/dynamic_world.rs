@@ -119,11 +119,12 @@ impl DynamicWorld {
                         type_path: component.reflect_type_path().to_string(),
                     }
                 })?;
-                let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                    WorldInstanceSpawnError::UnregisteredButReflectedType {
-                        type_path: type_info.type_path().to_string(),
-                    }
-                })?;
+                let registration =
+                    type_registry.get_with_type(type_info.ty()).ok_or_else(|| {
+                        WorldInstanceSpawnError::UnregisteredButReflectedType {
+                            type_path: type_info.type_path().to_string(),
+                        }
+                    })?;
                 let reflect_component =
                     registration.data::<ReflectComponent>().ok_or_else(|| {
                         WorldInstanceSpawnError::UnregisteredComponent {
@@ -165,7 +166,7 @@ impl DynamicWorld {
                     type_path: resource.reflect_type_path().to_string(),
                 }
             })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            let registration = type_registry.get_with_type(type_info.ty()).ok_or_else(|| {
                 WorldInstanceSpawnError::UnregisteredButReflectedType {
                     type_path: type_info.type_path().to_string(),
                 }
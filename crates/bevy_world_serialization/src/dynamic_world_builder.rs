@@ -8,7 +8,7 @@ use bevy_ecs::{
     component::{Component, ComponentId},
     entity_disabling::DefaultQueryFilters,
     prelude::Entity,
-    reflect::{ReflectComponent, ReflectResource},
+    reflect::{component_registration, ReflectComponent, ReflectResource},
     resource::Resource,
     world::World,
 };
@@ -308,21 +308,21 @@ impl<'w> DynamicWorldBuilder<'w> {
 
             for &component_id in original_entity.archetype().components().iter() {
                 let mut extract_and_push = || {
-                    let type_id = self
-                        .original_world
-                        .components()
-                        .get_info(component_id)?
-                        .type_id()?;
+                    let type_registration = component_registration(
+                        self.type_registry,
+                        self.original_world,
+                        component_id,
+                    )?;
 
-                    let is_denied = self.component_filter.is_denied_by_id(type_id);
+                    let is_denied = self
+                        .component_filter
+                        .is_denied_by_id(type_registration.type_id());
 
                     if is_denied {
                         // Component is either in the denylist or _not_ in the allowlist
                         return None;
                     }
 
-                    let type_registration = self.type_registry.get(type_id)?;
-
                     let component = type_registration
                         .data::<ReflectComponent>()?
                         .reflect(original_entity)?;
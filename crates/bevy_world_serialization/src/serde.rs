@@ -11,7 +11,7 @@ use bevy_reflect::{
         ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
         TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    validation, PartialReflect, ReflectFromReflect, TypeRegistry,
 };
 use core::fmt::Formatter;
 use serde::{
@@ -499,6 +499,7 @@ impl<'a, 'de> Visitor<'de> for WorldMapVisitor<'a> {
     {
         let mut dynamic_properties = Vec::new();
         while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
+            validate_value(&*entity, self.registry)?;
             dynamic_properties.push(entity);
         }
 
@@ -514,7 +515,7 @@ impl<'a, 'de> Visitor<'de> for WorldMapVisitor<'a> {
         while let Some(registration) =
             map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
         {
-            if !added.insert(registration.type_id()) {
+            if !added.insert(registration.type_info().type_path()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
                     registration.type_info().type_path(),
@@ -538,6 +539,7 @@ impl<'a, 'de> Visitor<'de> for WorldMapVisitor<'a> {
                 .map(PartialReflect::into_partial_reflect)
                 .unwrap_or(value);
 
+            validate_value(&*value, self.registry)?;
             entries.push(value);
         }
 
@@ -545,6 +547,22 @@ impl<'a, 'de> Visitor<'de> for WorldMapVisitor<'a> {
     }
 }
 
+/// Rejects values that violate their [validation attributes](validation).
+fn validate_value<E: Error>(value: &dyn PartialReflect, registry: &TypeRegistry) -> Result<(), E> {
+    let errors = validation::validate(value, registry);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let type_path = value
+        .get_represented_type_info()
+        .map_or(value.reflect_type_path(), |info| info.type_path());
+    let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    Err(Error::custom(format_args!(
+        "invalid `{type_path}`: {}",
+        errors.join(", ")
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -557,9 +575,14 @@ mod tests {
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
         reflect::AppTypeRegistry,
-        world::FromWorld,
+        world::{EntityRef, FromWorld},
+    };
+    use bevy_reflect::{
+        structs::{DynamicStruct, StructInfo},
+        validation::NumberRange,
+        NamedField, Reflect, ReflectDeserialize, ReflectRef, ReflectSerialize, Type, TypeInfo,
+        TypePathTable, TypeRegistration,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
     use core::any::TypeId;
     use ron;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
@@ -632,6 +655,13 @@ mod tests {
         foo: i32,
     }
 
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct BoundedResource {
+        #[reflect(@NumberRange::at_least(0.0))]
+        foo: i32,
+    }
+
     #[derive(Clone, Component, Reflect, PartialEq)]
     #[reflect(Component, PartialEq)]
     struct MyEntityRef(#[entities] Entity);
@@ -822,6 +852,33 @@ mod tests {
         (dynamic_world, deserialized_world)
     }
 
+    #[test]
+    fn should_reject_invalid_values() {
+        let world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<BoundedResource>();
+
+        let input = r#"(
+  resources: {
+    "bevy_world_serialization::serde::tests::BoundedResource": (
+      foo: -1,
+    ),
+  },
+  entities: {},
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let world_deserializer = WorldDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+            load_from_path: &mut FakeHandleCreator,
+        };
+        let Err(error) = world_deserializer.deserialize(&mut deserializer) else {
+            panic!("expected the out-of-range resource to be rejected");
+        };
+        assert!(error.to_string().contains("`.foo` is -1"));
+    }
+
     #[test]
     fn should_roundtrip_with_later_generations_and_obsolete_references() {
         let mut world = create_world();
@@ -900,6 +957,78 @@ mod tests {
         );
     }
 
+    fn register_dynamic_health(world: &World) -> ReflectComponent {
+        static HEALTH: std::sync::OnceLock<&'static TypeInfo> = std::sync::OnceLock::new();
+        let type_info = *HEALTH.get_or_init(|| {
+            let fields = [
+                NamedField::new::<f32>("value"),
+                NamedField::new::<Entity>("target"),
+            ];
+            let ty = Type::runtime(TypePathTable::from_path("game::Health"));
+            Box::leak(Box::new(TypeInfo::Struct(StructInfo::from_type(
+                ty, &fields,
+            ))))
+        });
+        let reflect_component = ReflectComponent::dynamic(type_info);
+        let mut registration = TypeRegistration::from_type_info(type_info);
+        registration.insert(reflect_component.clone());
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .add_registration(registration);
+        reflect_component
+    }
+
+    #[test]
+    fn should_roundtrip_with_dynamic_components() {
+        let mut world = create_world();
+        let health = register_dynamic_health(&world);
+
+        let target = world.spawn(Foo(123)).id();
+        let mut value = DynamicStruct::default();
+        value.insert("value", 10.0f32);
+        value.insert("target", target);
+        {
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let mut entity = world.spawn_empty();
+            health.insert(&mut entity, &value, &registry.read());
+        }
+
+        let (input_world, deserialized_world) = roundtrip_ron(&world);
+
+        assert_eq!(2, deserialized_world.entities.len());
+        assert_world_eq(&input_world, &deserialized_world);
+
+        let mut dst_world = create_world();
+        let health = register_dynamic_health(&dst_world);
+        deserialized_world
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let target = dst_world
+            .query_filtered::<Entity, With<Foo>>()
+            .single(&dst_world)
+            .unwrap();
+        let id = health.dynamic_component_id(&dst_world).unwrap();
+        let mut query = dst_world.query::<EntityRef>();
+        let entity = query
+            .iter(&dst_world)
+            .find(|entity| entity.contains_id(id))
+            .unwrap();
+        let value = health.reflect(entity).unwrap();
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            panic!("expected a struct");
+        };
+        assert_eq!(
+            value.field("value").unwrap().try_downcast_ref::<f32>(),
+            Some(&10.0)
+        );
+        assert_eq!(
+            value.field("target").unwrap().try_downcast_ref::<Entity>(),
+            Some(&target)
+        );
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -8,7 +8,7 @@ use bevy_ecs::{
     component::ComponentCloneBehavior,
     entity::{Entity, EntityHashMap, SceneEntityMapper},
     entity_disabling::DefaultQueryFilters,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{component_registration, AppTypeRegistry, ReflectComponent, ReflectResource},
     relationship::RelationshipHookMode,
     world::World,
 };
@@ -165,11 +165,11 @@ impl WorldAsset {
                         continue;
                     }
 
-                    let registration = type_registry
-                        .get(component_info.type_id().unwrap())
-                        .ok_or_else(|| WorldInstanceSpawnError::UnregisteredType {
-                            std_type_name: component_info.name(),
-                        })?;
+                    let registration =
+                        component_registration(&type_registry, &self.world, component_id)
+                            .ok_or_else(|| WorldInstanceSpawnError::UnregisteredType {
+                                std_type_name: component_info.name(),
+                            })?;
                     let reflect_component =
                         registration.data::<ReflectComponent>().ok_or_else(|| {
                             WorldInstanceSpawnError::UnregisteredComponent {
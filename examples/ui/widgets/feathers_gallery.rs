@@ -11,6 +11,8 @@ use bevy::{
         dark_theme::create_dark_theme,
         display::{icon, label, label_dim, label_small},
         font_styles::InheritableFont,
+        high_contrast_theme::create_high_contrast_theme,
+        light_theme::create_light_theme,
         palette,
         rounded_corners::RoundedCorners,
         theme::{ThemeBackgroundColor, ThemedText, UiTheme},
@@ -43,6 +45,15 @@ enum SwatchType {
     Hsl,
 }
 
+/// The theme selected by a radio button.
+#[derive(Component, Clone, Copy, Default, VariantDefaults)]
+enum DemoTheme {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
 #[derive(Component, Clone, Copy, Default)]
 struct HexColorInput;
 
@@ -368,6 +379,38 @@ fn demo_column_1() -> impl Scene {
                     )
                 ]
             ),
+            (
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: px(4),
+                }
+                RadioGroup
+                on(radio_self_update)
+                on(switch_theme)
+                Children [
+                    :label_dim("Theme"),
+                    (
+                        :FeathersRadio {
+                            @caption: {bsn! { Text("Dark") ThemedText }}
+                        }
+                        DemoTheme::Dark
+                        Checked
+                    ),
+                    (
+                        :FeathersRadio {
+                            @caption: {bsn! { Text("Light") ThemedText }}
+                        }
+                        DemoTheme::Light
+                    ),
+                    (
+                        :FeathersRadio {
+                            @caption: {bsn! { Text("High Contrast") ThemedText }}
+                        }
+                        DemoTheme::HighContrast
+                    ),
+                ]
+            ),
             (
                 Node {
                     display: Display::Flex,
@@ -804,3 +847,17 @@ fn handle_hex_color_change(
         colors.rgb_color = color;
     }
 }
+
+fn switch_theme(
+    value_change: On<ValueChange<Entity>>,
+    q_theme: Query<&DemoTheme>,
+    mut theme: ResMut<UiTheme>,
+) {
+    if let Ok(demo_theme) = q_theme.get(value_change.value) {
+        theme.0 = match demo_theme {
+            DemoTheme::Dark => create_dark_theme(),
+            DemoTheme::Light => create_light_theme(),
+            DemoTheme::HighContrast => create_high_contrast_theme(),
+        };
+    }
+}